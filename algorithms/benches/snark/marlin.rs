@@ -19,6 +19,7 @@ extern crate criterion;
 
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
+    polycommit::sonic_pc::SonicKZG10,
     snark::marlin::{ahp::AHPForR1CS, FiatShamirAlgebraicSpongeRng, MarlinHidingMode, MarlinSNARK},
     SNARK,
 };
@@ -30,7 +31,7 @@ use snarkvm_utilities::{ops::MulAssign, UniformRand};
 use criterion::Criterion;
 use rand::{self, thread_rng};
 
-type MarlinInst = MarlinSNARK<Bls12_377, FS, MarlinHidingMode, [Fr]>;
+type MarlinInst = MarlinSNARK<Fr, Fq, SonicKZG10<Bls12_377>, FS, MarlinHidingMode, [Fr]>;
 type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
 
 #[derive(Copy, Clone)]
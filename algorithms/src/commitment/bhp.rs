@@ -17,10 +17,10 @@
 use crate::{crh::BHPCRH, crypto_hash::hash_to_curve, CommitmentError, CommitmentScheme, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::BitIteratorLE;
+use snarkvm_utilities::{format, vec::Vec, BitIteratorLE};
 
+use core::fmt::Debug;
 use itertools::Itertools;
-use std::fmt::Debug;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BHPCommitment<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
@@ -17,7 +17,7 @@
 use crate::{crh::PedersenCRH, crypto_hash::hash_to_curve, CommitmentError, CommitmentScheme, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::BitIteratorLE;
+use snarkvm_utilities::{format, vec::Vec, BitIteratorLE};
 
 use itertools::Itertools;
 
@@ -17,9 +17,9 @@
 use crate::{crypto_hash::hash_to_curve, CRHError, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::BigInteger;
+use snarkvm_utilities::{format, sync::Arc, vec::Vec, BigInteger};
 
-use std::{fmt::Debug, sync::Arc};
+use core::fmt::Debug;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
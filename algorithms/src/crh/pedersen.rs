@@ -16,9 +16,10 @@
 
 use crate::{crypto_hash::hash_to_curve, CRHError, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_utilities::{borrow::Cow, format, vec::Vec};
 
+use core::fmt::Debug;
 use itertools::Itertools;
-use std::{borrow::Cow, fmt::Debug};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PedersenCRH<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
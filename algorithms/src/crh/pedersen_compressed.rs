@@ -17,7 +17,7 @@
 use crate::{crh::PedersenCRH, CRHError, CRH};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PedersenCompressedCRH<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> {
@@ -16,8 +16,9 @@
 
 use crate::{crypto_hash::Poseidon, CRHError, CRH};
 use snarkvm_fields::{FieldParameters, PoseidonParameters, PrimeField, ToConstraintField};
+use snarkvm_utilities::{borrow::Cow, sync::Arc};
 
-use std::{borrow::Cow, fmt::Debug, sync::Arc};
+use core::fmt::Debug;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PoseidonCRH<F: PrimeField, const INPUT_SIZE_FE: usize>(Poseidon<F, 4, false>);
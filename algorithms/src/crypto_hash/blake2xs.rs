@@ -20,6 +20,7 @@
 ///! This implementation is based on the BLAKE2Xs specification in Section 2 of
 ///! <https://www.blake2.net/blake2x.pdf>
 ///!
+use snarkvm_utilities::{vec, vec::Vec};
 
 #[rustfmt::skip]
 #[macro_export]
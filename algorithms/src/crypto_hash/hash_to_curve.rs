@@ -14,8 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::crypto_hash::Blake2Xs;
-use snarkvm_curves::AffineCurve;
+use crate::{crypto_hash::Blake2Xs, errors::HashToCurveError};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377G1Parameters, Bls12_377G2Parameters, Bls12_377Parameters, Fq12Parameters},
+    edwards_bls12::{EdwardsAffine, EdwardsParameters},
+    templates::{bls12::Bls12Parameters, short_weierstrass_jacobian},
+    AffineCurve,
+    Elligator2Parameters,
+    ProjectiveCurve,
+    SWUParameters,
+};
+use snarkvm_fields::{ExtensionField, Field, Fp12Parameters, PrimeField};
+use snarkvm_utilities::{format, string::String, vec::Vec, BitIteratorBE};
+
+use sha2::{Digest, Sha256};
 
 /// Runs hash-to-curve and returns the generator, message, and counter on success.
 #[inline]
@@ -56,6 +68,164 @@ pub fn try_hash_to_curve<G: AffineCurve>(input: &str) -> Option<G> {
     })
 }
 
+/// The output size in bytes of SHA-256.
+const SHA256_OUTPUT_SIZE: usize = 32;
+
+/// The input block size in bytes of SHA-256.
+const SHA256_BLOCK_SIZE: usize = 64;
+
+/// Returns `len_in_bytes` uniformly random bytes derived from `msg` and the domain separation tag `dst`,
+/// using `expand_message_xmd` of RFC 9380, section 5.3.1, with SHA-256.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, HashToCurveError> {
+    if dst.is_empty() {
+        return Err(HashToCurveError::EmptyDomainSeparationTag);
+    }
+    let ell = (len_in_bytes + SHA256_OUTPUT_SIZE - 1) / SHA256_OUTPUT_SIZE;
+    if len_in_bytes == 0 || ell > 255 {
+        return Err(HashToCurveError::InvalidOutputLength(len_in_bytes));
+    }
+
+    // Domain separation tags longer than 255 bytes are hashed, as in RFC 9380, section 5.3.3.
+    let oversize_dst;
+    let dst = match dst.len() > 255 {
+        true => {
+            oversize_dst = Sha256::new().chain_update(b"H2C-OVERSIZE-DST-").chain_update(dst).finalize();
+            &oversize_dst[..]
+        }
+        false => dst,
+    };
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain_update([0u8; SHA256_BLOCK_SIZE])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_SIZE);
+    let mut b_i = Sha256::new().chain_update(b_0).chain_update([1u8]).chain_update(&dst_prime).finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xor = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>();
+        b_i = Sha256::new().chain_update(xor).chain_update([i as u8]).chain_update(&dst_prime).finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// Returns `count` field elements derived from `msg` and the domain separation tag `dst`,
+/// using `hash_to_field` of RFC 9380, section 5.2, with `expand_message_xmd` and SHA-256.
+pub fn hash_to_field<F: ExtensionField>(msg: &[u8], dst: &[u8], count: usize) -> Result<Vec<F>, HashToCurveError> {
+    let degree = F::extension_degree() as usize;
+    // Each coordinate is reduced from `L = ceil((ceil(log2(p)) + k) / 8)` bytes, for the security parameter `k = 128`.
+    let length = (F::BasePrimeField::size_in_bits() + 128 + 7) / 8;
+
+    let uniform_bytes = expand_message_xmd(msg, dst, count * degree * length)?;
+    Ok(uniform_bytes
+        .chunks(degree * length)
+        .map(|bytes| {
+            let elements = bytes.chunks(length).map(F::BasePrimeField::from_bytes_be_mod_order).collect::<Vec<_>>();
+            F::from_base_prime_field_elems(&elements).expect("the number of coordinates is the extension degree")
+        })
+        .collect())
+}
+
+/// A hash-to-curve suite of RFC 9380 for a curve, with `expand_message_xmd` and SHA-256.
+pub trait HashToCurve: AffineCurve
+where
+    Self::BaseField: ExtensionField,
+{
+    /// The identifier of the random oracle suite, named as in RFC 9380, section 8.10.
+    /// The identifier of the nonuniform suite replaces the suffix `RO_` with `NU_`.
+    const SUITE_ID: &'static str;
+
+    /// Returns the image of `u` on the curve, which may not be in the prime-order subgroup.
+    fn map_to_curve(u: &Self::BaseField) -> Self;
+
+    /// Maps a point on the curve to the prime-order subgroup, using `clear_cofactor` of RFC 9380, section 7.
+    /// By default, this multiplies by the cofactor, which is the effective cofactor `h_eff` of twisted Edwards curves.
+    fn clear_cofactor(&self) -> Self {
+        self.mul_by_cofactor()
+    }
+
+    /// Returns a point in the prime-order subgroup derived from `msg` and the domain separation tag `dst`,
+    /// using the random oracle encoding `hash_to_curve` of RFC 9380, section 3.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Result<Self, HashToCurveError> {
+        let u = hash_to_field::<Self::BaseField>(msg, dst, 2)?;
+        let q = Self::map_to_curve(&u[0]).to_projective() + Self::map_to_curve(&u[1]).to_projective();
+        Ok(q.to_affine().clear_cofactor())
+    }
+
+    /// Returns a point in the prime-order subgroup derived from `msg` and the domain separation tag `dst`,
+    /// using the nonuniform encoding `encode_to_curve` of RFC 9380, section 3.
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Result<Self, HashToCurveError> {
+        let u = hash_to_field::<Self::BaseField>(msg, dst, 1)?;
+        Ok(Self::map_to_curve(&u[0]).clear_cofactor())
+    }
+}
+
+// The implementations for the BLS12-377 groups are written for the underlying affine types,
+// as the aliases `G1Affine` and `G2Affine` are projections that coherence cannot tell apart.
+impl HashToCurve for short_weierstrass_jacobian::Affine<Bls12_377G1Parameters> {
+    const SUITE_ID: &'static str = "BLS12377G1_XMD:SHA-256_SSWU_RO_";
+
+    fn map_to_curve(u: &Self::BaseField) -> Self {
+        Bls12_377G1Parameters::map_to_curve(u)
+    }
+
+    /// Multiplies by the effective cofactor `h_eff = 1 - x`, where `x` is the BLS parameter,
+    /// as for BLS12-381 in RFC 9380, section 8.8.1.
+    fn clear_cofactor(&self) -> Self {
+        (self.to_projective() - self.mul_bits(BitIteratorBE::new(Bls12_377Parameters::X))).to_affine()
+    }
+}
+
+impl HashToCurve for short_weierstrass_jacobian::Affine<Bls12_377G2Parameters> {
+    const SUITE_ID: &'static str = "BLS12377G2_XMD:SHA-256_SSWU_RO_";
+
+    fn map_to_curve(u: &Self::BaseField) -> Self {
+        Bls12_377G2Parameters::map_to_curve(u)
+    }
+
+    /// Computes `[x^2 - x - 1] P + [x - 1] psi(P) + [2] psi^2(P)`, where `x` is the BLS parameter,
+    /// using the method of Budroni and Pintore, as in `clear_cofactor_bls12381_g2` of RFC 9380, appendix G.3.
+    fn clear_cofactor(&self) -> Self {
+        let mul_by_x = |p: &Self| p.mul_bits(BitIteratorBE::new(Bls12_377Parameters::X));
+
+        let t1 = mul_by_x(self);
+        let t2 = bls12_377_g2_psi(self).to_projective();
+        let t3 = bls12_377_g2_psi(&bls12_377_g2_psi(&self.to_projective().double().to_affine())).to_projective();
+        let t3 = t3 - t2;
+        let t2 = mul_by_x(&(t1 + t2).to_affine());
+        (t3 + t2 - t1 - self.to_projective()).to_affine()
+    }
+}
+
+/// Returns the image of `p` under the endomorphism `psi = twist^-1 o frobenius o twist` of the BLS12-377 G2 curve.
+///
+/// As the curve is a D-type twist by `u`, this is `psi(x, y) = (c^2 * conj(x), c^3 * conj(y))`,
+/// for the constant `c = u^((q - 1) / 6)`.
+fn bls12_377_g2_psi(
+    p: &short_weierstrass_jacobian::Affine<Bls12_377G2Parameters>,
+) -> short_weierstrass_jacobian::Affine<Bls12_377G2Parameters> {
+    let c = Fq12Parameters::FROBENIUS_COEFF_FP12_C1[1];
+    let (mut x, mut y) = (p.x, p.y);
+    x.frobenius_map(1);
+    y.frobenius_map(1);
+    short_weierstrass_jacobian::Affine::new(x * c.square(), y * c.square() * c, p.infinity)
+}
+
+impl HashToCurve for EdwardsAffine {
+    const SUITE_ID: &'static str = "edwardsBLS12_XMD:SHA-256_ELL2_RO_";
+
+    fn map_to_curve(u: &Self::BaseField) -> Self {
+        EdwardsParameters::map_to_curve(u)
+    }
+}
+
 #[cfg(test)]
 mod bls12_377 {
     use crate::crypto_hash::hash_to_curve::{hash_to_curve, try_hash_to_curve};
@@ -244,3 +414,256 @@ mod bls12_377 {
         );
     }
 }
+
+#[cfg(test)]
+mod rfc9380 {
+    use crate::{
+        crypto_hash::hash_to_curve::{bls12_377_g2_psi, expand_message_xmd, HashToCurve},
+        errors::HashToCurveError,
+    };
+    use snarkvm_curves::{
+        bls12_377::{Fq, Fq2, Fr, G1Affine, G2Affine},
+        edwards_bls12::{EdwardsAffine, Fq as EdwardsFq},
+        AffineCurve,
+        ProjectiveCurve,
+    };
+    use snarkvm_fields::{ExtensionField, Zero};
+    use snarkvm_utilities::{
+        rand::{test_rng, UniformRand},
+        BitIteratorBE,
+    };
+
+    use core::ops::Mul;
+    use rand::Rng;
+    use std::str::FromStr;
+
+    const ITERATIONS: usize = 100;
+
+    /// Checks the hash-to-curve and encode-to-curve outputs of the suite `G` for the messages `""` and `"abc"`.
+    ///
+    /// RFC 9380 has no test vectors for BLS12-377 or Edwards BLS12, so these are self-generated regression
+    /// vectors, which only borrow the `QUUX` domain separation tags from the format of the RFC 9380 test vectors.
+    fn check_known_answers<G: HashToCurve>(hash_to_curve: [G; 2], encode_to_curve: [G; 2])
+    where
+        G::BaseField: ExtensionField,
+    {
+        let ro_dst = format!("QUUX-V01-CS02-with-{}", G::SUITE_ID);
+        let nu_dst = ro_dst.replace("RO_", "NU_");
+        for (i, msg) in [&b""[..], b"abc"].iter().enumerate() {
+            assert_eq!(G::hash_to_curve(msg, ro_dst.as_bytes()).unwrap(), hash_to_curve[i]);
+            assert_eq!(G::encode_to_curve(msg, nu_dst.as_bytes()).unwrap(), encode_to_curve[i]);
+        }
+    }
+
+    /// Checks that the outputs of the suite `G` are in the prime-order subgroup and depend on the domain separation tag.
+    fn check_outputs<G: HashToCurve>()
+    where
+        G::BaseField: ExtensionField,
+    {
+        let rng = &mut test_rng();
+        for _ in 0..ITERATIONS {
+            let msg: [u8; 32] = rng.gen();
+            for point in [G::hash_to_curve(&msg, b"DST").unwrap(), G::encode_to_curve(&msg, b"DST").unwrap()] {
+                assert!(point.is_on_curve());
+                assert!(point.is_in_correct_subgroup_assuming_on_curve());
+            }
+            assert_ne!(G::hash_to_curve(&msg, b"DST").unwrap(), G::hash_to_curve(&msg, b"DST2").unwrap());
+        }
+    }
+
+    /// Checks that `clear_cofactor` of the suite `G` multiplies by the effective cofactor `h_eff`,
+    /// whose absolute value is given by its little-endian limbs.
+    fn check_clear_cofactor<G: HashToCurve>(h_eff: &[u64], h_eff_is_negative: bool)
+    where
+        G::BaseField: ExtensionField,
+    {
+        let rng = &mut test_rng();
+        for _ in 0..ITERATIONS {
+            let point = G::map_to_curve(&G::BaseField::rand(rng));
+
+            let expected = point.mul_bits(BitIteratorBE::new(h_eff)).to_affine();
+            let expected = if h_eff_is_negative { -expected } else { expected };
+            assert_eq!(point.clear_cofactor(), expected);
+        }
+    }
+
+    #[test]
+    fn test_clear_cofactor() {
+        // h_eff = 1 - x, where x = 0x8508c00000000001.
+        check_clear_cofactor::<G1Affine>(&[0x8508c00000000000], true);
+        // h_eff = 3 * (x^2 - 1) * h, where h is the cofactor of G2.
+        check_clear_cofactor::<G2Affine>(
+            &[
+                0x1e34800000000000,
+                0xcf664765b0000003,
+                0x8e8e73ad8a538800,
+                0x78ba279637388559,
+                0xb85860aaaad29276,
+                0xf7ee7c4b03103b45,
+                0x8f6ade35a5c7d769,
+                0xa951764c46f4edd2,
+                0x53648d3d9502abfb,
+                0x1f60243677e306,
+            ],
+            false,
+        );
+        check_clear_cofactor::<EdwardsAffine>(&[4], false);
+    }
+
+    #[test]
+    fn test_bls12_377_g2_psi() {
+        // On G2, `psi` acts as the multiplication by `q`, which is `x` modulo the group order.
+        let rng = &mut test_rng();
+        for _ in 0..ITERATIONS {
+            let point = G2Affine::prime_subgroup_generator().mul(Fr::rand(rng)).to_affine();
+            assert_eq!(bls12_377_g2_psi(&point), point.mul(Fr::from(0x8508c00000000001u64)).to_affine());
+        }
+        assert!(bls12_377_g2_psi(&G2Affine::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // The test vectors of RFC 9380, appendix K.1.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], usize, &str); 6] = [
+            (b"", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            (b"abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            (b"abcdef0123456789", 0x20, "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1"),
+            (
+                &[b"q128_".as_ref(), &[b'q'; 128]].concat(),
+                0x20,
+                "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+            ),
+            (
+                &[b"a512_".as_ref(), &[b'a'; 512]].concat(),
+                0x20,
+                "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+            ),
+            (
+                b"abc",
+                0x80,
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d8\
+                 90f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+                 058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+        ];
+        for (msg, len_in_bytes, expected) in vectors {
+            assert_eq!(hex::encode(expand_message_xmd(msg, dst, len_in_bytes).unwrap()), expected);
+        }
+
+        assert!(matches!(expand_message_xmd(b"abc", b"", 32), Err(HashToCurveError::EmptyDomainSeparationTag)));
+        assert!(matches!(expand_message_xmd(b"abc", dst, 0), Err(HashToCurveError::InvalidOutputLength(0))));
+        assert!(matches!(expand_message_xmd(b"abc", dst, 8161), Err(HashToCurveError::InvalidOutputLength(8161))));
+        assert_eq!(expand_message_xmd(b"abc", dst, 8160).unwrap().len(), 8160);
+    }
+
+    #[test]
+    fn test_bls12_377_g1() {
+        let point = |x: &str, y: &str| G1Affine::new(Fq::from_str(x).unwrap(), Fq::from_str(y).unwrap(), false);
+        check_known_answers::<G1Affine>(
+            [
+                point(
+                    "124034784381326704893424602250651076061603302247918038891269690308844529039022239852604422142025430274650717943380",
+                    "30184947484739239547510570739133655804416120126017488826711523651501243702878448226791726620892426452797746115362",
+                ),
+                point(
+                    "199199131965979865966544882278492710046944371362975162998184256648008311492789980504861966894729772238439437827733",
+                    "69099739440726057485125980861173839958090093276003149170368969790554947119896476253572103402335093098166427772980",
+                ),
+            ],
+            [
+                point(
+                    "151918103695187650736891481629725611525925879788028595758311140764385133821231659152223412117982393880144917996984",
+                    "4656167673130766160578773142970392140095850698533342965471074355211810291131166396180937483276213202781194651811",
+                ),
+                point(
+                    "207916065267248194873132247199394510659042347604993613884058298259027637867508741523164239972089964642362209614505",
+                    "118687449510594881198321252800225193179076898539341502686263596685804087921747753083352694399015941441891645511234",
+                ),
+            ],
+        );
+        check_outputs::<G1Affine>();
+    }
+
+    #[test]
+    fn test_bls12_377_g2() {
+        let fq2 = |c0: &str, c1: &str| Fq2::new(Fq::from_str(c0).unwrap(), Fq::from_str(c1).unwrap());
+        let point = |x: Fq2, y: Fq2| G2Affine::new(x, y, false);
+        check_known_answers::<G2Affine>(
+            [
+                point(
+                    fq2(
+                        "116887930300830858748072530995462068340007849644160148596128512899241305973554237690954329819009759847416975989053",
+                        "144442680020278341820093779983208465251725636641995614880707431960090106083211541507557694654407776468219292033227",
+                    ),
+                    fq2(
+                        "182209272923250470251242837907371806657025294339258253406252863134592594611356939124988410670627425954828337226928",
+                        "206219004890272045999503651950896720284831620536773231769144634353662581711356066247258484193866035345140322761529",
+                    ),
+                ),
+                point(
+                    fq2(
+                        "58847785798669810350927331067877867552448000929150035797845395809015124150175655730364865289277325190264581203477",
+                        "53459807813911565192370784754492131155111577076151696139237888523309647716153837346382759515664170664216212322184",
+                    ),
+                    fq2(
+                        "88645776910431848550355753340222062840437134051672224469014484175638964365670200650871745755618536759857027591104",
+                        "224063183467750819377728347007551795494715801814695881338345914404584589476449700853503989402160023433454754453264",
+                    ),
+                ),
+            ],
+            [
+                point(
+                    fq2(
+                        "11992818244892782505183120994096586035021511289292165076098771382012249679743061360842049313117633386775494111889",
+                        "96531391207639436785940418653444088112025562360563609186315853257499336150573292943633143320003683373165344958675",
+                    ),
+                    fq2(
+                        "102019955643761806075724262607607389425290394571845878315449087809268521458620573019292497863876773144027408383325",
+                        "48736997645209855474931126883614620864839778396753338959195245969719762124396645953111209736060605452885595992724",
+                    ),
+                ),
+                point(
+                    fq2(
+                        "238410218403941621203154213384151172951810426136575337974590820745083103730935923662319073397777702777123626374707",
+                        "59865513877067925522958785046286308919488687208051942327642272825657609136871853900123248125700838770243325488809",
+                    ),
+                    fq2(
+                        "69818272984303506575981893279730118559421605211119496040903321149275405309704610095135685529686805422380627324842",
+                        "23868445228265387704198892280281142553247813583136875302950684075891179787487190623139884597472998215196461798019",
+                    ),
+                ),
+            ],
+        );
+        check_outputs::<G2Affine>();
+    }
+
+    #[test]
+    fn test_edwards_bls12() {
+        let point =
+            |x: &str, y: &str| EdwardsAffine::new(EdwardsFq::from_str(x).unwrap(), EdwardsFq::from_str(y).unwrap());
+        check_known_answers::<EdwardsAffine>(
+            [
+                point(
+                    "5222136356701808091850222996300240294078955299326018882766300205015963441893",
+                    "1688778051786301412636717277375024928936161970115509457048609139771154057928",
+                ),
+                point(
+                    "3696409883003542808950578477568585282836471489899995742593159939671847539625",
+                    "6130497830697772552095945470758273825503602513854029245421813583142580403782",
+                ),
+            ],
+            [
+                point(
+                    "8409933055425297583475356830335500312722578973178123174263042571825001096747",
+                    "3946328012602997102915774656461509037212035378300601578277687137296868240449",
+                ),
+                point(
+                    "5628900009092663310301273343179849722592191091102273477264159440253839006336",
+                    "874121429637442921097864059419772356829701439944476046079257541195841983839",
+                ),
+            ],
+        );
+        check_outputs::<EdwardsAffine>();
+    }
+}
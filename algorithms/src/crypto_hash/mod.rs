@@ -23,6 +23,15 @@ pub use hash_to_curve::*;
 pub mod poseidon;
 pub use poseidon::*;
 
+// Poseidon2 derives its round numbers with the floating-point search of `snarkvm-fields`.
+#[cfg(feature = "std")]
+pub mod poseidon2;
+#[cfg(feature = "std")]
+pub use poseidon2::*;
+
+pub mod rescue_prime;
+pub use rescue_prime::*;
+
 pub mod sha256;
 pub use sha256::*;
 
@@ -16,12 +16,10 @@
 
 use crate::{AlgebraicSponge, DefaultCapacityAlgebraicSponge, DuplexSpongeMode};
 use snarkvm_fields::{PoseidonParameters, PrimeField};
+use snarkvm_utilities::{sync::Arc, vec, vec::Vec};
 
+use core::ops::{Index, IndexMut};
 use smallvec::SmallVec;
-use std::{
-    ops::{Index, IndexMut},
-    sync::Arc,
-};
 
 #[derive(Copy, Clone, Debug)]
 pub struct State<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
@@ -63,6 +61,147 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> IndexMut<usize> fo
     }
 }
 
+/// A duplex sponge over a permutation of its state, which absorbs into and squeezes from the rate elements.
+pub(crate) trait DuplexSponge<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// Returns the state of the sponge.
+    fn state(&mut self) -> &mut State<F, RATE, CAPACITY>;
+
+    /// Returns the mode of the sponge.
+    fn mode(&mut self) -> &mut DuplexSpongeMode;
+
+    /// Applies the permutation to the state.
+    fn permute(&mut self);
+
+    /// Absorbs the input into the rate elements, permuting the state whenever they are full.
+    fn duplex_absorb(&mut self, input: &[F]) {
+        if !input.is_empty() {
+            match *self.mode() {
+                DuplexSpongeMode::Absorbing { mut next_absorb_index } => {
+                    if next_absorb_index == RATE {
+                        self.permute();
+                        next_absorb_index = 0;
+                    }
+                    self.absorb_internal(next_absorb_index, input);
+                }
+                DuplexSpongeMode::Squeezing { next_squeeze_index: _ } => {
+                    self.permute();
+                    self.absorb_internal(0, input);
+                }
+            }
+        }
+    }
+
+    /// Squeezes `num_elements` elements from the rate elements, permuting the state whenever they are exhausted.
+    fn duplex_squeeze(&mut self, num_elements: usize) -> SmallVec<[F; 10]> {
+        if num_elements == 0 {
+            return SmallVec::new();
+        }
+        let mut output = if num_elements <= 10 {
+            smallvec::smallvec_inline![F::zero(); 10]
+        } else {
+            smallvec::smallvec![F::zero(); num_elements]
+        };
+
+        match *self.mode() {
+            DuplexSpongeMode::Absorbing { next_absorb_index: _ } => {
+                self.permute();
+                self.squeeze_internal(0, &mut output[..num_elements]);
+            }
+            DuplexSpongeMode::Squeezing { mut next_squeeze_index } => {
+                if next_squeeze_index == RATE {
+                    self.permute();
+                    next_squeeze_index = 0;
+                }
+                self.squeeze_internal(next_squeeze_index, &mut output[..num_elements]);
+            }
+        }
+
+        output.truncate(num_elements);
+        output
+    }
+
+    /// Absorbs everything in elements, this does not end in an absorption.
+    #[inline]
+    fn absorb_internal(&mut self, mut rate_start: usize, input: &[F]) {
+        if !input.is_empty() {
+            let first_chunk_size = core::cmp::min(RATE - rate_start, input.len());
+            let num_elements_remaining = input.len() - first_chunk_size;
+            let (first_chunk, rest_chunk) = input.split_at(first_chunk_size);
+            let rest_chunks = rest_chunk.chunks(RATE);
+            // The total number of chunks is `elements[num_elements_remaining..].len() / RATE`, plus 1
+            // for the remainder.
+            let total_num_chunks = 1 + // 1 for the first chunk
+                // We add all the chunks that are perfectly divisible by `RATE`
+                (num_elements_remaining / RATE) +
+                // And also add 1 if the last chunk is non-empty
+                // (i.e. if `num_elements_remaining` is not a multiple of `RATE`)
+                usize::from((num_elements_remaining % RATE) != 0);
+
+            // Absorb the input elements, `RATE` elements at a time, except for the first chunk, which
+            // is of size `RATE - rate_start`.
+            for (i, chunk) in core::iter::once(first_chunk).chain(rest_chunks).enumerate() {
+                for (element, state_elem) in chunk.iter().zip(&mut self.state().rate_state[rate_start..]) {
+                    *state_elem += element;
+                }
+                // Are we in the last chunk?
+                // If so, let's wrap up.
+                if i == total_num_chunks - 1 {
+                    *self.mode() = DuplexSpongeMode::Absorbing { next_absorb_index: rate_start + chunk.len() };
+                    return;
+                } else {
+                    self.permute();
+                }
+                rate_start = 0;
+            }
+        }
+    }
+
+    /// Squeeze |output| many elements. This does not end in a squeeze
+    #[inline]
+    fn squeeze_internal(&mut self, mut rate_start: usize, output: &mut [F]) {
+        let output_size = output.len();
+        if output_size != 0 {
+            let first_chunk_size = core::cmp::min(RATE - rate_start, output.len());
+            let num_output_remaining = output.len() - first_chunk_size;
+            let (first_chunk, rest_chunk) = output.split_at_mut(first_chunk_size);
+            assert_eq!(rest_chunk.len(), num_output_remaining);
+            let rest_chunks = rest_chunk.chunks_mut(RATE);
+            // The total number of chunks is `output[num_output_remaining..].len() / RATE`, plus 1
+            // for the remainder.
+            let total_num_chunks = 1 + // 1 for the first chunk
+                // We add all the chunks that are perfectly divisible by `RATE`
+                (num_output_remaining / RATE) +
+                // And also add 1 if the last chunk is non-empty
+                // (i.e. if `num_output_remaining` is not a multiple of `RATE`)
+                usize::from((num_output_remaining % RATE) != 0);
+
+            // Absorb the input output, `RATE` output at a time, except for the first chunk, which
+            // is of size `RATE - rate_start`.
+            for (i, chunk) in core::iter::once(first_chunk).chain(rest_chunks).enumerate() {
+                let range = rate_start..(rate_start + chunk.len());
+                debug_assert_eq!(
+                    chunk.len(),
+                    self.state().rate_state[range.clone()].len(),
+                    "failed with squeeze {} at rate {} and rate_start {}",
+                    output_size,
+                    RATE,
+                    rate_start
+                );
+                chunk.copy_from_slice(&self.state().rate_state[range]);
+                // Are we in the last chunk?
+                // If so, let's wrap up.
+                if i == total_num_chunks - 1 {
+                    *self.mode() = DuplexSpongeMode::Squeezing { next_squeeze_index: (rate_start + chunk.len()) };
+                    return;
+                } else {
+                    self.permute();
+                }
+                rate_start = 0;
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Poseidon<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool> {
     parameters: Arc<PoseidonParameters<F, RATE, 1>>,
@@ -134,49 +273,11 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<F,
     }
 
     fn absorb(&mut self, input: &[F]) {
-        if !input.is_empty() {
-            match self.mode {
-                DuplexSpongeMode::Absorbing { mut next_absorb_index } => {
-                    if next_absorb_index == RATE {
-                        self.permute();
-                        next_absorb_index = 0;
-                    }
-                    self.absorb_internal(next_absorb_index, input);
-                }
-                DuplexSpongeMode::Squeezing { next_squeeze_index: _ } => {
-                    self.permute();
-                    self.absorb_internal(0, input);
-                }
-            }
-        }
+        self.duplex_absorb(input);
     }
 
     fn squeeze(&mut self, num_elements: usize) -> SmallVec<[F; 10]> {
-        if num_elements == 0 {
-            return SmallVec::new();
-        }
-        let mut output = if num_elements <= 10 {
-            smallvec::smallvec_inline![F::zero(); 10]
-        } else {
-            smallvec::smallvec![F::zero(); num_elements]
-        };
-
-        match self.mode {
-            DuplexSpongeMode::Absorbing { next_absorb_index: _ } => {
-                self.permute();
-                self.squeeze_internal(0, &mut output[..num_elements]);
-            }
-            DuplexSpongeMode::Squeezing { mut next_squeeze_index } => {
-                if next_squeeze_index == RATE {
-                    self.permute();
-                    next_squeeze_index = 0;
-                }
-                self.squeeze_internal(next_squeeze_index, &mut output[..num_elements]);
-            }
-        }
-
-        output.truncate(num_elements);
-        output
+        self.duplex_squeeze(num_elements)
     }
 }
 
@@ -210,6 +311,18 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonSponge<F,
         });
         self.state = new_state;
     }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> DuplexSponge<F, RATE, CAPACITY>
+    for PoseidonSponge<F, RATE, CAPACITY>
+{
+    fn state(&mut self) -> &mut State<F, RATE, CAPACITY> {
+        &mut self.state
+    }
+
+    fn mode(&mut self) -> &mut DuplexSpongeMode {
+        &mut self.mode
+    }
 
     #[inline]
     fn permute(&mut self) {
@@ -227,85 +340,4 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonSponge<F,
             self.apply_mds();
         }
     }
-
-    /// Absorbs everything in elements, this does not end in an absorption.
-    #[inline]
-    fn absorb_internal(&mut self, mut rate_start: usize, input: &[F]) {
-        if !input.is_empty() {
-            let first_chunk_size = std::cmp::min(RATE - rate_start, input.len());
-            let num_elements_remaining = input.len() - first_chunk_size;
-            let (first_chunk, rest_chunk) = input.split_at(first_chunk_size);
-            let rest_chunks = rest_chunk.chunks(RATE);
-            // The total number of chunks is `elements[num_elements_remaining..].len() / RATE`, plus 1
-            // for the remainder.
-            let total_num_chunks = 1 + // 1 for the first chunk
-                // We add all the chunks that are perfectly divisible by `RATE`
-                (num_elements_remaining / RATE) +
-                // And also add 1 if the last chunk is non-empty
-                // (i.e. if `num_elements_remaining` is not a multiple of `RATE`)
-                usize::from((num_elements_remaining % RATE) != 0);
-
-            // Absorb the input elements, `RATE` elements at a time, except for the first chunk, which
-            // is of size `RATE - rate_start`.
-            for (i, chunk) in std::iter::once(first_chunk).chain(rest_chunks).enumerate() {
-                for (element, state_elem) in chunk.iter().zip(&mut self.state.rate_state[rate_start..]) {
-                    *state_elem += element;
-                }
-                // Are we in the last chunk?
-                // If so, let's wrap up.
-                if i == total_num_chunks - 1 {
-                    self.mode = DuplexSpongeMode::Absorbing { next_absorb_index: rate_start + chunk.len() };
-                    return;
-                } else {
-                    self.permute();
-                }
-                rate_start = 0;
-            }
-        }
-    }
-
-    /// Squeeze |output| many elements. This does not end in a squeeze
-    #[inline]
-    fn squeeze_internal(&mut self, mut rate_start: usize, output: &mut [F]) {
-        let output_size = output.len();
-        if output_size != 0 {
-            let first_chunk_size = std::cmp::min(RATE - rate_start, output.len());
-            let num_output_remaining = output.len() - first_chunk_size;
-            let (first_chunk, rest_chunk) = output.split_at_mut(first_chunk_size);
-            assert_eq!(rest_chunk.len(), num_output_remaining);
-            let rest_chunks = rest_chunk.chunks_mut(RATE);
-            // The total number of chunks is `output[num_output_remaining..].len() / RATE`, plus 1
-            // for the remainder.
-            let total_num_chunks = 1 + // 1 for the first chunk
-                // We add all the chunks that are perfectly divisible by `RATE`
-                (num_output_remaining / RATE) +
-                // And also add 1 if the last chunk is non-empty
-                // (i.e. if `num_output_remaining` is not a multiple of `RATE`)
-                usize::from((num_output_remaining % RATE) != 0);
-
-            // Absorb the input output, `RATE` output at a time, except for the first chunk, which
-            // is of size `RATE - rate_start`.
-            for (i, chunk) in std::iter::once(first_chunk).chain(rest_chunks).enumerate() {
-                let range = rate_start..(rate_start + chunk.len());
-                debug_assert_eq!(
-                    chunk.len(),
-                    self.state.rate_state[range.clone()].len(),
-                    "failed with squeeze {} at rate {} and rate_start {}",
-                    output_size,
-                    RATE,
-                    rate_start
-                );
-                chunk.copy_from_slice(&self.state.rate_state[range]);
-                // Are we in the last chunk?
-                // If so, let's wrap up.
-                if i == total_num_chunks - 1 {
-                    self.mode = DuplexSpongeMode::Squeezing { next_squeeze_index: (rate_start + chunk.len()) };
-                    return;
-                } else {
-                    self.permute();
-                }
-                rate_start = 0;
-            }
-        }
-    }
 }
@@ -0,0 +1,265 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::{DuplexSponge, State},
+    AlgebraicSponge,
+    DefaultCapacityAlgebraicSponge,
+    DuplexSpongeMode,
+};
+use snarkvm_fields::{
+    find_poseidon_round_numbers,
+    is_secure_mds,
+    is_valid_sbox_exponent,
+    smallest_sbox_exponent,
+    PoseidonGrainLFSR,
+    PrimeField,
+};
+use snarkvm_utilities::{sync::Arc, vec, vec::Vec};
+
+use anyhow::{anyhow, bail, Result};
+use smallvec::SmallVec;
+
+/// The maximum number of internal matrices to sample from the Grain LFSR before giving up.
+const MAX_INTERNAL_MATRICES: usize = 1000;
+
+/// The parameters of the Poseidon2 permutation of [GKS23](https://eprint.iacr.org/2023/323).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Poseidon2Parameters<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// The number of full rounds.
+    pub full_rounds: usize,
+    /// The number of partial rounds.
+    pub partial_rounds: usize,
+    /// The exponent used in S-boxes.
+    pub alpha: u64,
+    /// The round constants of the full rounds, indexed by `external_round_constants[round][state_element_index]`.
+    pub external_round_constants: Vec<Vec<F>>,
+    /// The round constants of the partial rounds, which are added to the first state element.
+    pub internal_round_constants: Vec<F>,
+    /// The diagonal of the internal matrix, whose other entries are all one.
+    pub internal_diagonal: Vec<F>,
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> Poseidon2Parameters<F, RATE, CAPACITY> {
+    ///
+    /// Returns the parameters of a Poseidon2 instance with the S-box `x^alpha`, which provides `security_level` bits of security.
+    ///
+    /// The round numbers are the ones of Poseidon, and the round constants and internal matrix are sampled from the Poseidon Grain LFSR.
+    /// The state size `RATE + CAPACITY` must be 2, 3 or a multiple of 4.
+    ///
+    pub fn generate(alpha: u64, security_level: u32) -> Result<Self> {
+        let state_len = RATE + CAPACITY;
+        if !(state_len == 2 || state_len == 3 || state_len % 4 == 0) {
+            bail!("Poseidon2 is not defined for a state of {} elements", state_len);
+        }
+        if !is_valid_sbox_exponent::<F>(alpha) {
+            bail!("x^{} is not a permutation of the field", alpha);
+        }
+
+        let (full_rounds, partial_rounds) =
+            find_poseidon_round_numbers(F::size_in_bits() as u32, state_len, alpha, security_level)
+                .ok_or_else(|| anyhow!("No Poseidon2 round numbers for {} bits of security", security_level))?;
+
+        let mut lfsr = PoseidonGrainLFSR::new(
+            false,
+            F::size_in_bits() as u64,
+            state_len as u64,
+            full_rounds as u64,
+            partial_rounds as u64,
+        );
+
+        // Sample the round constants as in Poseidon, and keep only the first constant of the partial rounds.
+        let mut external_round_constants = Vec::with_capacity(full_rounds);
+        let mut internal_round_constants = Vec::with_capacity(partial_rounds);
+        for round in 0..(full_rounds + partial_rounds) {
+            let constants = lfsr.get_field_elements_rejection_sampling::<F>(state_len);
+            match (full_rounds / 2..full_rounds / 2 + partial_rounds).contains(&round) {
+                true => internal_round_constants.push(constants[0]),
+                false => external_round_constants.push(constants),
+            }
+        }
+
+        let internal_diagonal = match state_len {
+            2 => vec![F::from(2u64), F::from(3u64)],
+            3 => vec![F::from(2u64), F::from(2u64), F::from(3u64)],
+            _ => (0..MAX_INTERNAL_MATRICES)
+                .map(|_| lfsr.get_field_elements_mod_p::<F>(state_len))
+                .find(|diagonal| {
+                    let matrix = internal_matrix(diagonal);
+                    is_invertible(&matrix) && is_secure_mds(&matrix)
+                })
+                .ok_or_else(|| {
+                    anyhow!("No secure internal matrix among the first {} candidates", MAX_INTERNAL_MATRICES)
+                })?,
+        };
+
+        Ok(Self {
+            full_rounds,
+            partial_rounds,
+            alpha,
+            external_round_constants,
+            internal_round_constants,
+            internal_diagonal,
+        })
+    }
+}
+
+/// Returns the internal matrix with the given diagonal and all other entries equal to one.
+fn internal_matrix<F: PrimeField>(diagonal: &[F]) -> Vec<Vec<F>> {
+    (0..diagonal.len())
+        .map(|i| (0..diagonal.len()).map(|j| if i == j { diagonal[i] } else { F::one() }).collect())
+        .collect()
+}
+
+/// Returns `true` if the determinant of `J + diag(d - 1)` is non-zero,
+/// which is `prod_i (d_i - 1) * (1 + sum_i 1 / (d_i - 1))` if no `d_i` is one.
+fn is_invertible<F: PrimeField>(matrix: &[Vec<F>]) -> bool {
+    let offsets = matrix.iter().enumerate().map(|(i, row)| row[i] - F::one()).collect::<Vec<_>>();
+    match offsets.iter().all(|offset| !offset.is_zero()) {
+        true => !(F::one() + offsets.iter().map(|offset| offset.inverse().unwrap()).sum::<F>()).is_zero(),
+        // Two rows of the matrix are equal if two offsets are zero, and the determinant is the product
+        // of the other offsets if one offset is zero.
+        false => offsets.iter().filter(|offset| offset.is_zero()).count() == 1,
+    }
+}
+
+/// A duplex sponge based on the Poseidon2 permutation.
+#[derive(Clone, Debug)]
+pub struct Poseidon2Sponge<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// Sponge Parameters
+    parameters: Arc<Poseidon2Parameters<F, RATE, CAPACITY>>,
+    /// Current sponge's state (current elements in the permutation block)
+    state: State<F, RATE, CAPACITY>,
+    /// Current mode (whether its absorbing or squeezing)
+    pub mode: DuplexSpongeMode,
+}
+
+impl<F: PrimeField, const RATE: usize> DefaultCapacityAlgebraicSponge<F, RATE> for Poseidon2Sponge<F, RATE, 1> {
+    /// Returns the parameters for 128 bits of security with the smallest valid S-box exponent.
+    /// This panics if `RATE + 1` is not 2, 3 or a multiple of 4.
+    fn sample_parameters() -> Arc<Poseidon2Parameters<F, RATE, 1>> {
+        Arc::new(Poseidon2Parameters::generate(smallest_sbox_exponent::<F>(), 128).unwrap())
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<F, RATE, CAPACITY>
+    for Poseidon2Sponge<F, RATE, CAPACITY>
+{
+    type Parameters = Arc<Poseidon2Parameters<F, RATE, CAPACITY>>;
+
+    fn new(parameters: &Self::Parameters) -> Self {
+        Self {
+            parameters: parameters.clone(),
+            state: State::default(),
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+
+    fn absorb(&mut self, input: &[F]) {
+        self.duplex_absorb(input);
+    }
+
+    fn squeeze(&mut self, num_elements: usize) -> SmallVec<[F; 10]> {
+        self.duplex_squeeze(num_elements)
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> Poseidon2Sponge<F, RATE, CAPACITY> {
+    /// Adds the round constants to the state, and applies the S-box to every element and the external matrix.
+    #[inline]
+    fn apply_full_round(&mut self, constants: &[F]) {
+        let alpha = self.parameters.alpha;
+        for (element, constant) in self.state.iter_mut().zip(constants) {
+            *element = (*element + constant).pow(&[alpha]);
+        }
+        self.apply_external_matrix();
+    }
+
+    /// Adds the round constant to the first element, and applies the S-box to it and the internal matrix.
+    #[inline]
+    fn apply_partial_round(&mut self, constant: &F) {
+        self.state[0] = (self.state[0] + constant).pow(&[self.parameters.alpha]);
+        self.apply_internal_matrix();
+    }
+
+    /// Applies the external matrix, which is `circ(2, 1)` or `circ(2, 1, 1)` for a state of 2 or 3 elements,
+    /// `M_4` for 4 elements, and `circ(2 M_4, M_4, ..., M_4)` for a multiple of 4 elements.
+    #[inline]
+    fn apply_external_matrix(&mut self) {
+        match RATE + CAPACITY {
+            2 | 3 => {
+                let sum = self.state.iter().sum::<F>();
+                self.state.iter_mut().for_each(|element| *element += sum);
+            }
+            _ => {
+                let mut elements = self.state.iter().copied().collect::<Vec<_>>();
+                elements.chunks_mut(4).for_each(apply_m4);
+                if elements.len() > 4 {
+                    let sums = (0..4).map(|i| elements.iter().skip(i).step_by(4).sum::<F>()).collect::<Vec<_>>();
+                    elements.iter_mut().enumerate().for_each(|(i, element)| *element += sums[i % 4]);
+                }
+                self.state.iter_mut().zip(elements).for_each(|(element, new_element)| *element = new_element);
+            }
+        }
+    }
+
+    /// Applies the internal matrix, whose diagonal is `internal_diagonal` and other entries are one.
+    #[inline]
+    fn apply_internal_matrix(&mut self) {
+        let sum = self.state.iter().sum::<F>();
+        for (element, diagonal) in self.state.iter_mut().zip(&self.parameters.internal_diagonal) {
+            *element = *element * (*diagonal - F::one()) + sum;
+        }
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> DuplexSponge<F, RATE, CAPACITY>
+    for Poseidon2Sponge<F, RATE, CAPACITY>
+{
+    fn state(&mut self) -> &mut State<F, RATE, CAPACITY> {
+        &mut self.state
+    }
+
+    fn mode(&mut self) -> &mut DuplexSpongeMode {
+        &mut self.mode
+    }
+
+    #[inline]
+    fn permute(&mut self) {
+        let parameters = self.parameters.clone();
+        let (first_full_rounds, last_full_rounds) =
+            parameters.external_round_constants.split_at(parameters.full_rounds / 2);
+
+        self.apply_external_matrix();
+        first_full_rounds.iter().for_each(|constants| self.apply_full_round(constants));
+        parameters.internal_round_constants.iter().for_each(|constant| self.apply_partial_round(constant));
+        last_full_rounds.iter().for_each(|constants| self.apply_full_round(constants));
+    }
+}
+
+/// Applies the matrix `M_4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]` with the addition chain of GKS23.
+#[inline]
+fn apply_m4<F: PrimeField>(x: &mut [F]) {
+    let t0 = x[0] + x[1];
+    let t1 = x[2] + x[3];
+    let t2 = x[1].double() + t1;
+    let t3 = x[3].double() + t0;
+    let t4 = t1.double().double() + t3;
+    let t5 = t0.double().double() + t2;
+    let t6 = t3 + t5;
+    let t7 = t2 + t4;
+    x.copy_from_slice(&[t6, t5, t7, t4]);
+}
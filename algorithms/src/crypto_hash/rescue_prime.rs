@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::{DuplexSponge, State},
+    AlgebraicSponge,
+    DefaultCapacityAlgebraicSponge,
+    DuplexSpongeMode,
+};
+use snarkvm_fields::{sample_cauchy_matrix, smallest_sbox_exponent, PoseidonGrainLFSR, PrimeField, MAX_SKIP_MATRICES};
+use snarkvm_utilities::{sync::Arc, vec::Vec};
+
+use anyhow::{anyhow, Result};
+use smallvec::SmallVec;
+
+/// The maximum number of rounds considered by the security analysis.
+const MAX_ROUNDS: usize = 25;
+
+/// The parameters of the Rescue-Prime permutation of [SAD20](https://eprint.iacr.org/2020/1143).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RescuePrimeParameters<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// The number of rounds, each of which applies the S-box and the inverse S-box.
+    pub rounds: usize,
+    /// The exponent used in S-boxes.
+    pub alpha: u64,
+    /// The exponent `1 / alpha mod (p - 1)` used in inverse S-boxes, as little-endian limbs.
+    pub alpha_inverse: Vec<u64>,
+    /// The round constants, indexed by `round_constants[2 * round + step][state_element_index]`.
+    pub round_constants: Vec<Vec<F>>,
+    /// Maximally Distance Separating Matrix.
+    pub mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> RescuePrimeParameters<F, RATE, CAPACITY> {
+    ///
+    /// Returns the parameters of a Rescue-Prime instance which provides `security_level` bits of security.
+    ///
+    /// The S-box exponent is the smallest prime not dividing `p - 1`, and the number of rounds is 1.5 times the
+    /// number of rounds that resist the Gröbner basis attack of SAD20, and at least 8.
+    /// The round constants and MDS matrix are sampled from the Poseidon Grain LFSR, with the S-box marked as inverse.
+    ///
+    pub fn generate(security_level: u32) -> Result<Self> {
+        let state_len = RATE + CAPACITY;
+        let alpha = smallest_sbox_exponent::<F>();
+
+        // Find the number of rounds N such that the Gröbner basis attack costs binomial(v + d, v)^2 > 2^security_level,
+        // where v := m (N - 1) + rate and d := (alpha - 1) m (N - 1) / 2 + 2.
+        let attack_rounds = (1..MAX_ROUNDS)
+            .find(|&rounds| {
+                let num_variables = state_len * (rounds - 1) + RATE;
+                let degree = ((alpha - 1) as usize * state_len * (rounds - 1)) / 2 + 2;
+                let log_binomial = (1..=num_variables).map(|i| ((degree + i) as f64 / i as f64).log2()).sum::<f64>();
+                2.0 * log_binomial > security_level as f64
+            })
+            .ok_or_else(|| anyhow!("No Rescue-Prime round number for {} bits of security", security_level))?;
+        let rounds = (3 * attack_rounds.max(5) + 1) / 2;
+
+        let mut lfsr = PoseidonGrainLFSR::new(true, F::size_in_bits() as u64, state_len as u64, rounds as u64, 0);
+        let round_constants =
+            (0..2 * rounds).map(|_| lfsr.get_field_elements_rejection_sampling::<F>(state_len)).collect();
+        let mds = (0..MAX_SKIP_MATRICES)
+            .find_map(|_| sample_cauchy_matrix::<F>(&mut lfsr, state_len))
+            .ok_or_else(|| anyhow!("No Rescue-Prime MDS matrix among the first {} candidates", MAX_SKIP_MATRICES))?;
+
+        Ok(Self { rounds, alpha, alpha_inverse: inverse_exponent::<F>(alpha), round_constants, mds })
+    }
+}
+
+/// Returns `1 / alpha mod (p - 1)` as little-endian limbs, which is `(k (p - 1) + 1) / alpha`
+/// for the `k < alpha` such that `alpha` divides `k (p - 1) + 1`.
+fn inverse_exponent<F: PrimeField>(alpha: u64) -> Vec<u64> {
+    let mut modulus_minus_one = F::modulus().as_ref().to_vec();
+    // The modulus is odd, so subtracting one does not borrow.
+    modulus_minus_one[0] -= 1;
+
+    for k in 1..alpha {
+        // Compute k (p - 1) + 1, with an additional limb for the carry.
+        let mut numerator = Vec::with_capacity(modulus_minus_one.len() + 1);
+        let mut carry = 1u128;
+        for limb in &modulus_minus_one {
+            let value = *limb as u128 * k as u128 + carry;
+            numerator.push(value as u64);
+            carry = value >> 64;
+        }
+        numerator.push(carry as u64);
+
+        // Divide by alpha, from the most significant limb.
+        let mut remainder = 0u128;
+        for limb in numerator.iter_mut().rev() {
+            let value = (remainder << 64) + *limb as u128;
+            *limb = (value / alpha as u128) as u64;
+            remainder = value % alpha as u128;
+        }
+        if remainder == 0 {
+            return numerator;
+        }
+    }
+    unreachable!("alpha must be coprime with p - 1")
+}
+
+/// A duplex sponge based on the Rescue-Prime permutation.
+#[derive(Clone, Debug)]
+pub struct RescuePrimeSponge<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// Sponge Parameters
+    parameters: Arc<RescuePrimeParameters<F, RATE, CAPACITY>>,
+    /// Current sponge's state (current elements in the permutation block)
+    state: State<F, RATE, CAPACITY>,
+    /// Current mode (whether its absorbing or squeezing)
+    pub mode: DuplexSpongeMode,
+}
+
+impl<F: PrimeField, const RATE: usize> DefaultCapacityAlgebraicSponge<F, RATE> for RescuePrimeSponge<F, RATE, 1> {
+    fn sample_parameters() -> Arc<RescuePrimeParameters<F, RATE, 1>> {
+        Arc::new(RescuePrimeParameters::generate(128).unwrap())
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<F, RATE, CAPACITY>
+    for RescuePrimeSponge<F, RATE, CAPACITY>
+{
+    type Parameters = Arc<RescuePrimeParameters<F, RATE, CAPACITY>>;
+
+    fn new(parameters: &Self::Parameters) -> Self {
+        Self {
+            parameters: parameters.clone(),
+            state: State::default(),
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+
+    fn absorb(&mut self, input: &[F]) {
+        self.duplex_absorb(input);
+    }
+
+    fn squeeze(&mut self, num_elements: usize) -> SmallVec<[F; 10]> {
+        self.duplex_squeeze(num_elements)
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> RescuePrimeSponge<F, RATE, CAPACITY> {
+    /// Applies the S-box `x^exponent` to every element, followed by the MDS matrix and the round constants.
+    #[inline]
+    fn apply_step(&mut self, exponent: &[u64], round_constants: &[F]) {
+        let mut new_state = State::default();
+        let powers = self.state.iter().map(|element| element.pow(exponent)).collect::<Vec<_>>();
+        new_state.iter_mut().zip(&self.parameters.mds).zip(round_constants).for_each(
+            |((new_elem, mds_row), constant)| {
+                *new_elem = powers.iter().zip(mds_row).map(|(power, &mds_elem)| mds_elem * power).sum::<F>() + constant;
+            },
+        );
+        self.state = new_state;
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> DuplexSponge<F, RATE, CAPACITY>
+    for RescuePrimeSponge<F, RATE, CAPACITY>
+{
+    fn state(&mut self) -> &mut State<F, RATE, CAPACITY> {
+        &mut self.state
+    }
+
+    fn mode(&mut self) -> &mut DuplexSpongeMode {
+        &mut self.mode
+    }
+
+    #[inline]
+    fn permute(&mut self) {
+        let parameters = self.parameters.clone();
+        for constants in parameters.round_constants.chunks(2) {
+            self.apply_step(&[parameters.alpha], &constants[0]);
+            self.apply_step(&parameters.alpha_inverse, &constants[1]);
+        }
+    }
+}
@@ -14,9 +14,29 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode};
+use crate::{
+    crypto_hash::{Poseidon2Parameters, Poseidon2Sponge, PoseidonSponge, RescuePrimeParameters, RescuePrimeSponge},
+    AlgebraicSponge,
+    DefaultCapacityAlgebraicSponge,
+    DuplexSpongeMode,
+};
 use snarkvm_curves::bls12_377::Fr;
-use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
+use snarkvm_fields::{
+    find_poseidon_round_numbers,
+    is_secure_mds,
+    is_valid_sbox_exponent,
+    smallest_sbox_exponent,
+    Field,
+    One,
+    PoseidonDefaultField,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+    PoseidonGrainLFSR,
+    PoseidonParameters,
+    PrimeField,
+    Zero,
+};
+use snarkvm_utilities::{rand::UniformRand, test_rng};
 
 use itertools::Itertools;
 use std::{path::PathBuf, sync::Arc};
@@ -94,3 +114,133 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>(true);
     single_rate_test::<8>(true);
 }
+
+#[test]
+fn test_poseidon_parameter_generation_matches_defaults() {
+    type Parameters = <Fr as PrimeField>::Parameters;
+    for entry in Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().chain(&Parameters::PARAMS_OPT_FOR_WEIGHTS) {
+        let generated = PoseidonDefaultParametersEntry::generate::<Fr>(entry.rate, entry.alpha as u64, 128).unwrap();
+        assert_eq!(*entry, generated);
+    }
+    assert_eq!(
+        PoseidonParameters::<Fr, 2, 1>::generate(17, 128).unwrap(),
+        Fr::default_poseidon_parameters::<2>(false).unwrap()
+    );
+}
+
+#[test]
+fn test_poseidon_round_numbers() {
+    // The round numbers of Fr for alpha = 5, 17 and 257, with the security margin.
+    assert_eq!(find_poseidon_round_numbers(253, 3, 5, 128), Some((8, 56)));
+    assert_eq!(find_poseidon_round_numbers(253, 9, 5, 128), Some((8, 57)));
+    assert_eq!(find_poseidon_round_numbers(253, 3, 17, 128), Some((8, 31)));
+    assert_eq!(find_poseidon_round_numbers(253, 3, 257, 128), Some((8, 13)));
+
+    // p - 1 is divisible by 3, 5, 7 and 13, but not by 11 and 17.
+    for alpha in [1, 2, 3, 5, 7, 13] {
+        assert!(!is_valid_sbox_exponent::<Fr>(alpha));
+    }
+    for alpha in [11, 17, 257] {
+        assert!(is_valid_sbox_exponent::<Fr>(alpha));
+    }
+    assert_eq!(smallest_sbox_exponent::<Fr>(), 11);
+    assert!(PoseidonDefaultParametersEntry::generate::<Fr>(2, 5, 128).is_err());
+}
+
+#[test]
+fn test_secure_mds() {
+    let mds = Fr::default_poseidon_parameters::<2>(false).unwrap().mds;
+    assert!(is_secure_mds(&mds));
+
+    // A diagonal matrix leaves the subspace with an inactive S-box invariant.
+    let diagonal = (0..3)
+        .map(|i| (0..3).map(|j| if i == j { Fr::from((i + 2) as u64) } else { Fr::zero() }).collect())
+        .collect::<Vec<Vec<Fr>>>();
+    assert!(!is_secure_mds(&diagonal));
+
+    // A block matrix leaves the subspace of the last two elements invariant.
+    let mut block = mds;
+    for row in block.iter_mut().skip(1) {
+        row[0] = Fr::zero();
+    }
+    assert!(!is_secure_mds(&block));
+}
+
+/// Checks that the sponge follows the duplex modes, and that its outputs depend on all its inputs.
+fn check_sponge<S: DefaultCapacityAlgebraicSponge<Fr, 2>>()
+where
+    S: AlgebraicSponge<Fr, 2, 1>,
+{
+    let rng = &mut test_rng();
+    let parameters = S::sample_parameters();
+    let input = (0..5).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+    let mut sponge = S::new(&parameters);
+    sponge.absorb(&input);
+    let output = sponge.squeeze(3);
+    assert_eq!(output.len(), 3);
+
+    // Absorbing in several calls is equivalent to absorbing at once.
+    let mut split_sponge = S::new(&parameters);
+    split_sponge.absorb(&input[..2]);
+    split_sponge.absorb(&input[2..]);
+    assert_eq!(output, split_sponge.squeeze(3));
+
+    // Squeezing in several calls is equivalent to squeezing at once.
+    let mut split_sponge = S::new(&parameters);
+    split_sponge.absorb(&input);
+    let mut split_output = split_sponge.squeeze(1);
+    split_output.extend_from_slice(&split_sponge.squeeze(2));
+    assert_eq!(output, split_output);
+
+    for i in 0..input.len() {
+        let mut modified_input = input.clone();
+        modified_input[i] += Fr::one();
+        let mut sponge = S::new(&parameters);
+        sponge.absorb(&modified_input);
+        assert_ne!(output, sponge.squeeze(3));
+    }
+}
+
+#[test]
+fn test_poseidon2_sponge() {
+    check_sponge::<Poseidon2Sponge<Fr, 2, 1>>();
+
+    let parameters = Poseidon2Parameters::<Fr, 7, 1>::generate(17, 128).unwrap();
+    assert_eq!((parameters.full_rounds, parameters.partial_rounds), (8, 31));
+    assert_eq!(parameters.external_round_constants.len(), 8);
+    assert_eq!(parameters.internal_round_constants.len(), 31);
+    assert_eq!(parameters.internal_diagonal.len(), 8);
+
+    // The round constants of the full rounds are the ones of Poseidon.
+    let poseidon_parameters = Fr::default_poseidon_parameters::<7>(false).unwrap();
+    assert_eq!(parameters.external_round_constants[..4], poseidon_parameters.ark[..4]);
+    assert_eq!(parameters.internal_round_constants[0], poseidon_parameters.ark[4][0]);
+
+    // The state sizes must be 2, 3 or a multiple of 4.
+    assert!(Poseidon2Parameters::<Fr, 4, 1>::generate(17, 128).is_err());
+    assert!(Poseidon2Parameters::<Fr, 2, 1>::generate(5, 128).is_err());
+
+    let mut sponge = Poseidon2Sponge::<Fr, 7, 1>::new(&std::sync::Arc::new(parameters));
+    sponge.absorb(&[Fr::one(); 9]);
+    assert_eq!(sponge.mode, DuplexSpongeMode::Absorbing { next_absorb_index: 2 });
+    sponge.squeeze(3);
+    assert_eq!(sponge.mode, DuplexSpongeMode::Squeezing { next_squeeze_index: 3 });
+}
+
+#[test]
+fn test_rescue_prime_sponge() {
+    check_sponge::<RescuePrimeSponge<Fr, 2, 1>>();
+
+    let parameters = RescuePrimeParameters::<Fr, 2, 1>::generate(128).unwrap();
+    assert_eq!((parameters.rounds, parameters.alpha), (11, 11));
+    assert_eq!(parameters.round_constants.len(), 22);
+    assert_eq!(RescuePrimeParameters::<Fr, 7, 1>::generate(128).unwrap().rounds, 8);
+
+    // The inverse S-box inverts the S-box.
+    let rng = &mut test_rng();
+    for _ in 0..10 {
+        let element = Fr::rand(rng);
+        assert_eq!(element.pow(&[parameters.alpha]).pow(&parameters.alpha_inverse), element);
+    }
+}
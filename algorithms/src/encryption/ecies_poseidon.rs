@@ -20,23 +20,27 @@ use crate::{
     EncryptionScheme,
 };
 use snarkvm_curves::{
-    templates::twisted_edwards_extended::{Affine as TEAffine, Projective},
+    templates::twisted_edwards_extended::{Affine as TEAffine, CombTable, Projective},
     AffineCurve,
     ProjectiveCurve,
     TwistedEdwardsParameters,
 };
 use snarkvm_fields::{FieldParameters, PrimeField};
-use snarkvm_utilities::{ops::Mul, serialize::*, BitIteratorBE, FromBits, ToBits, UniformRand};
+use snarkvm_utilities::{cfg_iter, serialize::*, string::ToString, BitIteratorBE, FromBits, ToBits, UniformRand};
 
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ECIESPoseidonEncryption<TE: TwistedEdwardsParameters>
 where
     TE::BaseField: PrimeField,
 {
     generator: TEAffine<TE>,
+    generator_table: CombTable<TE>,
     poseidon: Poseidon<TE::BaseField, 4, false>,
     symmetric_key_commitment_domain: TE::BaseField,
     symmetric_encryption_domain: TE::BaseField,
@@ -57,11 +61,12 @@ where
 
     fn setup(message: &str) -> Self {
         let (generator, _, _) = hash_to_curve::<TEAffine<TE>>(message);
+        let generator_table = CombTable::new(&generator);
         let poseidon = Poseidon::<TE::BaseField, 4, false>::setup();
         let symmetric_key_commitment_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoSymmetricKeyCommitment0");
         let symmetric_encryption_domain = TE::BaseField::from_bytes_le_mod_order(b"AleoSymmetricEncryption0");
 
-        Self { generator, poseidon, symmetric_key_commitment_domain, symmetric_encryption_domain }
+        Self { generator, generator_table, poseidon, symmetric_key_commitment_domain, symmetric_encryption_domain }
     }
 
     fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
@@ -69,7 +74,7 @@ where
     }
 
     fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
-        self.generator.to_projective().mul(*private_key).to_affine()
+        self.generator_table.mul_ct(private_key).to_affine()
     }
 
     ///
@@ -90,8 +95,7 @@ where
         let randomness: Self::ScalarRandomness = UniformRand::rand(rng);
 
         // Compute the randomizer := G^r
-        let ciphertext_randomizer =
-            self.generator.mul_bits(BitIteratorBE::new_without_leading_zeros(randomness.to_repr()));
+        let ciphertext_randomizer = self.generator_table.mul_ct(&randomness);
 
         // Compute the ECDH value := public_key^r.
         // Note for twisted Edwards curves, only one of (x, y) or (x, -y) is in the prime-order subgroup.
@@ -117,28 +121,40 @@ where
         private_key: &<Self as EncryptionScheme>::PrivateKey,
         ciphertext_randomizer: Self::CiphertextRandomizer,
     ) -> Option<Self::SymmetricKey> {
-        // Recover the ciphertext randomizer group element.
-        let mut randomizer = None;
+        self.generate_symmetric_keys(private_key, &[ciphertext_randomizer]).pop().flatten()
+    }
 
-        if let Some(element) = TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, true) {
-            if element.is_in_correct_subgroup_assuming_on_curve() {
-                randomizer = Some(element);
-            }
-        }
-        if randomizer.is_none() {
-            if let Some(element) = TEAffine::<TE>::from_x_coordinate(ciphertext_randomizer, false) {
-                if element.is_in_correct_subgroup_assuming_on_curve() {
-                    randomizer = Some(element);
-                }
-            }
-        }
+    ///
+    /// Given the private key and ciphertext randomizers, return the symmetric key of each randomizer.
+    /// The randomizer group elements are recovered with a batched decompression.
+    ///
+    fn generate_symmetric_keys(
+        &self,
+        private_key: &<Self as EncryptionScheme>::PrivateKey,
+        ciphertext_randomizers: &[Self::CiphertextRandomizer],
+    ) -> Vec<Option<Self::SymmetricKey>> {
+        // Recover the ciphertext randomizer group elements.
+        let x_coordinates = ciphertext_randomizers.iter().map(|x| (*x, true)).collect::<Vec<_>>();
+        let randomizers = TEAffine::<TE>::batch_from_x_coordinate(&x_coordinates)
+            .into_iter()
+            .map(|element| {
+                let element = element?;
+                // If the point with the largest y-coordinate is not in the subgroup, try the point with the other one.
+                [element, TEAffine::<TE>::new(element.x, -element.y)]
+                    .into_iter()
+                    .find(|element| element.is_in_correct_subgroup_assuming_on_curve())
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the symmetric keys.
+        let mut symmetric_keys = cfg_iter!(randomizers)
+            .flatten()
+            .map(|randomizer| randomizer.mul_bits(BitIteratorBE::new_without_leading_zeros(private_key.to_repr())))
+            .collect::<Vec<_>>();
+        Projective::<TE>::batch_normalization(&mut symmetric_keys);
 
-        randomizer.map(|randomizer| {
-            randomizer
-                .mul_bits(BitIteratorBE::new_without_leading_zeros(private_key.to_repr()))
-                .to_affine()
-                .to_x_coordinate()
-        })
+        let mut symmetric_keys = symmetric_keys.into_iter().map(|key| key.to_affine().to_x_coordinate());
+        randomizers.iter().map(|randomizer| randomizer.and_then(|_| symmetric_keys.next())).collect()
     }
 
     ///
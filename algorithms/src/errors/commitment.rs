@@ -14,31 +14,60 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::io::{Error, ErrorKind};
+use snarkvm_utilities::{error::Error, format, io, string::String};
 
-#[derive(Debug, Error)]
-pub enum CommitmentError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
+use core::fmt;
 
-    #[error("{}: {}", _0, _1)]
+#[derive(Debug)]
+pub enum CommitmentError {
+    AnyhowError(anyhow::Error),
     Crate(&'static str, String),
+    CRHError(crate::CRHError),
+    Message(String),
+}
 
-    #[error("{}", _0)]
-    CRHError(#[from] crate::CRHError),
+impl fmt::Display for CommitmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{}", error),
+            Self::Crate(crate_name, message) => write!(f, "{}: {}", crate_name, message),
+            Self::CRHError(error) => write!(f, "{}", error),
+            Self::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
 
-    #[error("{}", _0)]
-    Message(String),
+impl Error for CommitmentError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AnyhowError(error) => Some(error.as_ref()),
+            Self::CRHError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for CommitmentError {
+    fn from(error: anyhow::Error) -> Self {
+        CommitmentError::AnyhowError(error)
+    }
+}
+
+impl From<crate::CRHError> for CommitmentError {
+    fn from(error: crate::CRHError) -> Self {
+        CommitmentError::CRHError(error)
+    }
 }
 
-impl From<Error> for CommitmentError {
-    fn from(error: Error) -> Self {
+impl From<io::Error> for CommitmentError {
+    fn from(error: io::Error) -> Self {
         CommitmentError::Crate("std::io", format!("{:?}", error))
     }
 }
 
-impl From<CommitmentError> for Error {
-    fn from(error: CommitmentError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+impl From<CommitmentError> for io::Error {
+    fn from(error: CommitmentError) -> Self {
+        snarkvm_utilities::error(format!("{}", error))
     }
 }
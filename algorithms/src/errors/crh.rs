@@ -14,34 +14,64 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::io::{Error, ErrorKind};
+use snarkvm_utilities::{error::Error, format, io, string::String};
 
-#[derive(Debug, Error)]
-pub enum CRHError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
+use core::fmt;
 
-    #[error("{}: {}", _0, _1)]
+#[derive(Debug)]
+pub enum CRHError {
+    AnyhowError(anyhow::Error),
     Crate(&'static str, String),
+    ConstraintFieldError(snarkvm_fields::ConstraintFieldError),
+    IncorrectInputLength(usize, usize, usize),
+    Message(String),
+}
 
-    #[error("{}", _0)]
-    ConstraintFieldError(#[from] snarkvm_fields::ConstraintFieldError),
+impl fmt::Display for CRHError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{}", error),
+            Self::Crate(crate_name, message) => write!(f, "{}: {}", crate_name, message),
+            Self::ConstraintFieldError(error) => write!(f, "{}", error),
+            Self::IncorrectInputLength(length, num_windows, window_size) => {
+                write!(f, "incorrect input length {} x 8 for window params {}x{}", length, num_windows, window_size)
+            }
+            Self::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
 
-    #[error("incorrect input length {} x 8 for window params {}x{}", _0, _1, _2)]
-    IncorrectInputLength(usize, usize, usize),
+impl Error for CRHError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AnyhowError(error) => Some(error.as_ref()),
+            Self::ConstraintFieldError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
 
-    #[error("{}", _0)]
-    Message(String),
+impl From<anyhow::Error> for CRHError {
+    fn from(error: anyhow::Error) -> Self {
+        CRHError::AnyhowError(error)
+    }
+}
+
+impl From<snarkvm_fields::ConstraintFieldError> for CRHError {
+    fn from(error: snarkvm_fields::ConstraintFieldError) -> Self {
+        CRHError::ConstraintFieldError(error)
+    }
 }
 
-impl From<Error> for CRHError {
-    fn from(error: Error) -> Self {
+impl From<io::Error> for CRHError {
+    fn from(error: io::Error) -> Self {
         CRHError::Crate("std::io", format!("{:?}", error))
     }
 }
 
-impl From<CRHError> for Error {
-    fn from(error: CRHError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+impl From<CRHError> for io::Error {
+    fn from(error: CRHError) -> Self {
+        snarkvm_utilities::error(format!("{}", error))
     }
 }
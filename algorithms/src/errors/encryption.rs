@@ -14,35 +14,57 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-#[derive(Debug, Error)]
-pub enum EncryptionError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
+use snarkvm_utilities::{error::Error, format, io, string::String};
 
-    #[error("{}: {}", _0, _1)]
-    Crate(&'static str, String),
+use core::fmt;
 
-    #[error("Invalid private key")]
+#[derive(Debug)]
+pub enum EncryptionError {
+    AnyhowError(anyhow::Error),
+    Crate(&'static str, String),
     InvalidPrivateKey,
-
-    #[error("The ciphertext is not encrypted for this address.")]
     MismatchingAddress,
-
-    #[error("Missing inverse for group element")]
     MissingInverse,
-
-    #[error("{}", _0)]
     Message(String),
 }
 
-impl From<std::io::Error> for EncryptionError {
-    fn from(error: std::io::Error) -> Self {
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{}", error),
+            Self::Crate(crate_name, message) => write!(f, "{}: {}", crate_name, message),
+            Self::InvalidPrivateKey => write!(f, "Invalid private key"),
+            Self::MismatchingAddress => write!(f, "The ciphertext is not encrypted for this address."),
+            Self::MissingInverse => write!(f, "Missing inverse for group element"),
+            Self::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for EncryptionError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AnyhowError(error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for EncryptionError {
+    fn from(error: anyhow::Error) -> Self {
+        EncryptionError::AnyhowError(error)
+    }
+}
+
+impl From<io::Error> for EncryptionError {
+    fn from(error: io::Error) -> Self {
         EncryptionError::Crate("std::io", format!("{:?}", error))
     }
 }
 
-impl From<EncryptionError> for std::io::Error {
+impl From<EncryptionError> for io::Error {
     fn from(error: EncryptionError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, format!("{}", error))
+        snarkvm_utilities::error(format!("{}", error))
     }
 }
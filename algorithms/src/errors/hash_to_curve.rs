@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::error::Error;
+
+use core::fmt;
+
+#[derive(Debug)]
+pub enum HashToCurveError {
+    EmptyDomainSeparationTag,
+    InvalidOutputLength(usize),
+}
+
+impl fmt::Display for HashToCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyDomainSeparationTag => write!(f, "the domain separation tag must not be empty"),
+            Self::InvalidOutputLength(length) => write!(f, "cannot expand a message to {} bytes", length),
+        }
+    }
+}
+
+impl Error for HashToCurveError {}
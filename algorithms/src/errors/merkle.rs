@@ -14,41 +14,70 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-#[derive(Debug, Error)]
-pub enum MerkleError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
-
-    #[error("{}: {}", _0, _1)]
-    Crate(&'static str, String),
+use snarkvm_utilities::{error::Error, format, io, string::String};
 
-    #[error("{}", _0)]
-    CRHError(#[from] crate::CRHError),
+use core::fmt;
 
-    #[error("Incorrect leaf index: {}", _0)]
+#[derive(Debug)]
+pub enum MerkleError {
+    AnyhowError(anyhow::Error),
+    Crate(&'static str, String),
+    CRHError(crate::CRHError),
     IncorrectLeafIndex(usize),
-
-    #[error("Incorrect path length: {}", _0)]
     IncorrectPathLength(usize),
-
-    #[error("Invalid leaf")]
     InvalidLeaf,
-
-    #[error("Invalid path length: {}. Must be less than or equal to: {}", _0, _1)]
     InvalidPathLength(usize, usize),
-
-    #[error("Invalid tree depth: {}. Must be less than or equal to: {}", _0, _1)]
     InvalidTreeDepth(usize, usize),
-
-    #[error("{}", _0)]
     Message(String),
-
-    #[error("Missing leaf entry: {}", _0)]
     MissingLeaf(String),
 }
 
-impl From<std::io::Error> for MerkleError {
-    fn from(error: std::io::Error) -> Self {
+impl fmt::Display for MerkleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{}", error),
+            Self::Crate(crate_name, message) => write!(f, "{}: {}", crate_name, message),
+            Self::CRHError(error) => write!(f, "{}", error),
+            Self::IncorrectLeafIndex(index) => write!(f, "Incorrect leaf index: {}", index),
+            Self::IncorrectPathLength(length) => write!(f, "Incorrect path length: {}", length),
+            Self::InvalidLeaf => write!(f, "Invalid leaf"),
+            Self::InvalidPathLength(length, max) => {
+                write!(f, "Invalid path length: {}. Must be less than or equal to: {}", length, max)
+            }
+            Self::InvalidTreeDepth(depth, max) => {
+                write!(f, "Invalid tree depth: {}. Must be less than or equal to: {}", depth, max)
+            }
+            Self::Message(message) => write!(f, "{}", message),
+            Self::MissingLeaf(leaf) => write!(f, "Missing leaf entry: {}", leaf),
+        }
+    }
+}
+
+impl Error for MerkleError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AnyhowError(error) => Some(error.as_ref()),
+            Self::CRHError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for MerkleError {
+    fn from(error: anyhow::Error) -> Self {
+        MerkleError::AnyhowError(error)
+    }
+}
+
+impl From<crate::CRHError> for MerkleError {
+    fn from(error: crate::CRHError) -> Self {
+        MerkleError::CRHError(error)
+    }
+}
+
+impl From<io::Error> for MerkleError {
+    fn from(error: io::Error) -> Self {
         MerkleError::Crate("std::io", format!("{:?}", error))
     }
 }
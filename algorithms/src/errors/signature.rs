@@ -14,31 +14,60 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::io::{Error, ErrorKind};
+use snarkvm_utilities::{error::Error, format, io, string::String};
 
-#[derive(Debug, Error)]
-pub enum SignatureError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
+use core::fmt;
 
-    #[error("{}: {}", _0, _1)]
+#[derive(Debug)]
+pub enum SignatureError {
+    AnyhowError(anyhow::Error),
     Crate(&'static str, String),
+    EncryptionError(crate::EncryptionError),
+    Message(String),
+}
 
-    #[error("{}", _0)]
-    EncryptionError(#[from] crate::EncryptionError),
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{}", error),
+            Self::Crate(crate_name, message) => write!(f, "{}: {}", crate_name, message),
+            Self::EncryptionError(error) => write!(f, "{}", error),
+            Self::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
 
-    #[error("{}", _0)]
-    Message(String),
+impl Error for SignatureError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AnyhowError(error) => Some(error.as_ref()),
+            Self::EncryptionError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for SignatureError {
+    fn from(error: anyhow::Error) -> Self {
+        SignatureError::AnyhowError(error)
+    }
+}
+
+impl From<crate::EncryptionError> for SignatureError {
+    fn from(error: crate::EncryptionError) -> Self {
+        SignatureError::EncryptionError(error)
+    }
 }
 
-impl From<Error> for SignatureError {
-    fn from(error: Error) -> Self {
+impl From<io::Error> for SignatureError {
+    fn from(error: io::Error) -> Self {
         SignatureError::Crate("std::io", format!("{:?}", error))
     }
 }
 
-impl From<SignatureError> for Error {
-    fn from(error: SignatureError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+impl From<SignatureError> for io::Error {
+    fn from(error: SignatureError) -> Self {
+        snarkvm_utilities::error(format!("{}", error))
     }
 }
@@ -16,36 +16,68 @@
 
 use snarkvm_fields::ConstraintFieldError;
 use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::{error::Error, format, io, string::String};
 
-#[derive(Debug, Error)]
-pub enum SNARKError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
-
-    #[error("{}", _0)]
-    ConstraintFieldError(#[from] ConstraintFieldError),
+use core::fmt;
 
-    #[error("{}: {}", _0, _1)]
+#[derive(Debug)]
+pub enum SNARKError {
+    AnyhowError(anyhow::Error),
+    ConstraintFieldError(ConstraintFieldError),
     Crate(&'static str, String),
-
-    #[error("Expected a circuit-specific SRS in SNARK")]
     ExpectedCircuitSpecificSRS,
-
-    #[error("{}", _0)]
     Message(String),
-
-    #[error("{}", _0)]
     SynthesisError(SynthesisError),
-
-    #[error("Batch size was zero; must be at least 1")]
     EmptyBatch,
-
-    #[error("terminated")]
     Terminated,
 }
 
+impl fmt::Display for SNARKError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{}", error),
+            Self::ConstraintFieldError(error) => write!(f, "{}", error),
+            Self::Crate(crate_name, message) => write!(f, "{}: {}", crate_name, message),
+            Self::ExpectedCircuitSpecificSRS => write!(f, "Expected a circuit-specific SRS in SNARK"),
+            Self::Message(message) => write!(f, "{}", message),
+            Self::SynthesisError(error) => write!(f, "{}", error),
+            Self::EmptyBatch => write!(f, "Batch size was zero; must be at least 1"),
+            Self::Terminated => write!(f, "terminated"),
+        }
+    }
+}
+
+impl Error for SNARKError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AnyhowError(error) => Some(error.as_ref()),
+            Self::ConstraintFieldError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for SNARKError {
+    fn from(error: anyhow::Error) -> Self {
+        SNARKError::AnyhowError(error)
+    }
+}
+
+impl From<ConstraintFieldError> for SNARKError {
+    fn from(error: ConstraintFieldError) -> Self {
+        SNARKError::ConstraintFieldError(error)
+    }
+}
+
 impl From<SynthesisError> for SNARKError {
     fn from(error: SynthesisError) -> Self {
         SNARKError::SynthesisError(error)
     }
 }
+
+impl From<io::Error> for SNARKError {
+    fn from(error: io::Error) -> Self {
+        SNARKError::Crate("std::io", format!("{:?}", error))
+    }
+}
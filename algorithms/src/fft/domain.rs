@@ -25,6 +25,11 @@
 //! roots to be the powers of a 2^n root of unity in the field.
 //! This allows us to perform polynomial operations in O(n)
 //! by performing an O(n log n) FFT over such a domain.
+//!
+//! For fields that also define a small multiplicative subgroup of order
+//! `q^k` (see `FftParameters::SMALL_SUBGROUP_BASE`), domains of mixed
+//! size `2^a * q^b` are supported as well, which lets callers avoid
+//! rounding a size that is slightly over a power of two up to the next one.
 
 use crate::{
     cfg_chunks_mut,
@@ -36,10 +41,10 @@ use crate::{
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(feature = "parallel")]
 use snarkvm_utilities::max_available_threads;
-use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
+use snarkvm_utilities::{borrow::Cow, execute_with_max_available_threads, serialize::*, vec};
 
+use core::fmt;
 use rand::Rng;
-use std::{borrow::Cow, fmt};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -77,12 +82,12 @@ const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 7;
 
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
-/// a power-of-2.
+/// a power-of-2, optionally multiplied by a power of a small subgroup base.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
-    /// `log_2(self.size)`.
+    /// `log_2(self.size)` for radix-2 domains, and the two-adicity of `self.size` otherwise.
     pub log_size_of_group: u32,
     /// Size of the domain as a field element.
     pub size_as_field_element: F,
@@ -124,11 +129,31 @@ impl<F: FftField> EvaluationDomain<F> {
             return None;
         }
 
+        Self::new_with_size(size as usize)
+    }
+
+    /// Construct the smallest domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, and that contains the domain of size `multiple_of`
+    /// as a subgroup.
+    ///
+    /// The size of the domain is of the form `2^a * q^b`, where `q` is the small subgroup base
+    /// of the field. If the field does not define a small subgroup, this returns a radix-2 domain.
+    pub fn new_mixed_radix(num_coeffs: usize, multiple_of: usize) -> Option<Self> {
+        let size = Self::compute_size_of_mixed_radix_domain(num_coeffs, multiple_of)?;
+        Self::new_with_size(size)
+    }
+
+    /// Construct a domain of exactly `size` elements, or return `None` if the field
+    /// has no multiplicative subgroup of that order.
+    pub fn new_with_size(size: usize) -> Option<Self> {
+        let size = size as u64;
+        let log_size_of_group = size.trailing_zeros();
+
         // Compute the generator for the multiplicative subgroup.
-        // It should be the 2^(log_size_of_group) root of unity.
+        // It should be a primitive `size`-th root of unity.
         let group_gen = F::get_root_of_unity(size as usize)?;
 
-        // Check that it is indeed the 2^(log_size_of_group) root of unity.
+        // Check that it is indeed a `size`-th root of unity.
         debug_assert_eq!(group_gen.pow([size]), F::one());
 
         let size_as_field_element = F::from(size);
@@ -152,6 +177,56 @@ impl<F: FftField> EvaluationDomain<F> {
         if size.trailing_zeros() <= F::FftParameters::TWO_ADICITY { Some(size) } else { None }
     }
 
+    /// Return the size of the smallest domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, and that contains the domain of size `multiple_of`.
+    ///
+    /// Returns `None` if `multiple_of` is not a valid domain size, or if no such domain exists.
+    pub fn compute_size_of_mixed_radix_domain(num_coeffs: usize, multiple_of: usize) -> Option<usize> {
+        let num_coeffs = num_coeffs.max(multiple_of).max(1);
+        let two_adicity = multiple_of.max(1).trailing_zeros();
+        let odd_part = multiple_of.max(1) >> two_adicity;
+
+        let (q, max_q_adicity) =
+            match (F::FftParameters::SMALL_SUBGROUP_BASE, F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY) {
+                (Some(q), Some(q_adicity)) => (q as usize, q_adicity),
+                _ => {
+                    // Without a small subgroup, only radix-2 domains exist.
+                    return match odd_part {
+                        1 => Self::compute_size_of_domain(num_coeffs),
+                        _ => None,
+                    };
+                }
+            };
+
+        // Decompose the odd part of `multiple_of` into a power of `q`.
+        let mut min_q_adicity = 0;
+        let mut odd_part = odd_part;
+        while odd_part % q == 0 {
+            odd_part /= q;
+            min_q_adicity += 1;
+        }
+        if odd_part != 1 || min_q_adicity > max_q_adicity || two_adicity > F::FftParameters::TWO_ADICITY {
+            return None;
+        }
+
+        // Find the smallest `2^a * q^b >= num_coeffs` with `a >= two_adicity` and `b >= min_q_adicity`.
+        (min_q_adicity..=max_q_adicity)
+            .filter_map(|q_adicity| {
+                let q_part = q.checked_pow(q_adicity)?;
+                let two_part = ((num_coeffs + q_part - 1) / q_part).checked_next_power_of_two()?.max(1 << two_adicity);
+                match two_part.trailing_zeros() <= F::FftParameters::TWO_ADICITY {
+                    true => two_part.checked_mul(q_part),
+                    false => None,
+                }
+            })
+            .min()
+    }
+
+    /// Returns `true` if the size of `self` is a power of two.
+    pub fn is_radix_2(&self) -> bool {
+        self.size.is_power_of_two()
+    }
+
     /// Return the size of `self`.
     pub fn size(&self) -> usize {
         self.size as usize
@@ -358,18 +433,31 @@ impl<F: FftField> EvaluationDomain<F> {
 impl<F: FftField> EvaluationDomain<F> {
     pub fn precompute_fft(&self) -> FFTPrecomputation<F> {
         execute_with_max_available_threads(|| FFTPrecomputation {
-            roots: self.roots_of_unity(self.group_gen),
+            roots: self.precomputed_roots_of_unity(self.group_gen),
             domain: *self,
         })
     }
 
     pub fn precompute_ifft(&self) -> IFFTPrecomputation<F> {
         execute_with_max_available_threads(|| IFFTPrecomputation {
-            inverse_roots: self.roots_of_unity(self.group_gen_inv),
+            inverse_roots: self.precomputed_roots_of_unity(self.group_gen_inv),
             domain: *self,
         })
     }
 
+    /// Returns the powers of `root` needed to perform an FFT over `self`.
+    /// Radix-2 domains only need the first `self.size / 2` powers, while
+    /// mixed-radix domains need all `self.size` of them.
+    fn precomputed_roots_of_unity(&self, root: F) -> Vec<F> {
+        if self.is_radix_2() {
+            self.roots_of_unity(root)
+        } else {
+            let mut roots = vec![F::one(); self.size()];
+            Self::distribute_powers(&mut roots, root);
+            roots
+        }
+    }
+
     pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         let pc = self.precompute_fft();
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc)
@@ -443,6 +531,11 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        // Mixed-radix FFTs always produce in-order outputs.
+        if !self.is_radix_2() {
+            return self.mixed_radix_fft_in_place(x_s, &pc.roots);
+        }
+
         let log_len = log2(x_s.len());
 
         if ord == OI {
@@ -468,6 +561,11 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        // Mixed-radix IFFTs always take in-order inputs.
+        if !self.is_radix_2() {
+            return self.mixed_radix_fft_in_place(x_s, &pc.inverse_roots);
+        }
+
         let log_len = log2(x_s.len());
 
         if ord == II {
@@ -481,6 +579,50 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Computes an in-order (unnormalized) DFT of `x_s` over a mixed-radix domain of size
+    /// `n = n_1 * n_2`, where `n_1 = 2^a` and `n_2 = q^b` are coprime, and `roots` holds all
+    /// `n` powers of the chosen generator.
+    ///
+    /// This uses the Good-Thomas (prime-factor) mapping, which turns the DFT into `n_2`
+    /// radix-2 FFTs of size `n_1`, followed by `n_1` DFTs of size `n_2` without twiddle factors.
+    /// The latter are computed directly, as `n_2` is a power of the small subgroup base.
+    fn mixed_radix_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], roots: &[F]) {
+        let n = x_s.len();
+        debug_assert_eq!(n, self.size());
+        debug_assert_eq!(roots.len(), n);
+        let n_1 = 1usize << n.trailing_zeros();
+        let n_2 = n / n_1;
+
+        // Arrange the input into `n_2` rows of length `n_1`, where
+        // `row j_2` holds `x[(j_1 * n_2 + j_2 * n_1) mod n]` at column `j_1`.
+        let mut rows = vec![T::zero(); n];
+        cfg_chunks_mut!(rows, n_1).enumerate().for_each(|(j_2, row)| {
+            row.iter_mut().enumerate().for_each(|(j_1, x)| *x = x_s[(j_1 * n_2 + j_2 * n_1) % n]);
+        });
+
+        // Perform a radix-2 FFT of each row, using the generator `roots[n_2]` of order `n_1`.
+        if n_1 > 1 {
+            let row_roots = roots.iter().step_by(n_2).take(n_1 / 2).copied().collect::<Vec<_>>();
+            let log_n_1 = log2(n_1);
+            cfg_chunks_mut!(rows, n_1).for_each(|row| {
+                self.io_helper_with_roots(row, &row_roots);
+                derange_helper(row, log_n_1);
+            });
+        }
+
+        // Combine the columns with a DFT using the generator `roots[n_1]` of order `n_2`,
+        // and write the output at the index `k` congruent to `k_1 mod n_1` and `k_2 mod n_2`.
+        cfg_iter_mut!(x_s).enumerate().for_each(|(k, x)| {
+            let (k_1, k_2) = (k % n_1, k % n_2);
+            *x = T::zero();
+            for j_2 in 0..n_2 {
+                let mut term = rows[j_2 * n_1 + k_1];
+                term *= roots[n_1 * ((j_2 * k_2) % n_2)];
+                *x += term;
+            }
+        });
+    }
+
     /// Computes the first `self.size / 2` roots of unity for the entire domain.
     /// e.g. for the domain [1, g, g^2, ..., g^{n - 1}], it computes
     // [1, g, g^2, ..., g^{(n/2) - 1}]
@@ -588,7 +730,7 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     fn io_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F]) {
-        let mut roots = std::borrow::Cow::Borrowed(roots);
+        let mut roots = Cow::Borrowed(roots);
 
         let mut step = 1;
         let mut first = true;
@@ -797,9 +939,10 @@ impl<F: FftField> FFTPrecomputation<F> {
         }
         if &self.domain == domain {
             Some(Cow::Borrowed(self))
-        } else if domain.size() < self.domain.size() {
+        } else if domain.size() < self.domain.size() && self.domain.size() % domain.size() == 0 {
             let size_ratio = self.domain.size() / domain.size();
-            let roots = self.roots.iter().step_by(size_ratio).copied().collect();
+            let num_roots = if domain.is_radix_2() { domain.size() / 2 } else { domain.size() };
+            let roots = self.roots.iter().step_by(size_ratio).take(num_roots).copied().collect();
             Some(Cow::Owned(Self { roots, domain: *domain }))
         } else {
             None
@@ -821,9 +964,10 @@ impl<F: FftField> IFFTPrecomputation<F> {
         }
         if &self.domain == domain {
             Some(Cow::Borrowed(self))
-        } else if domain.size() < self.domain.size() {
+        } else if domain.size() < self.domain.size() && self.domain.size() % domain.size() == 0 {
             let size_ratio = self.domain.size() / domain.size();
-            let inverse_roots = self.inverse_roots.iter().step_by(size_ratio).copied().collect();
+            let num_roots = if domain.is_radix_2() { domain.size() / 2 } else { domain.size() };
+            let inverse_roots = self.inverse_roots.iter().step_by(size_ratio).take(num_roots).copied().collect();
             Some(Cow::Owned(Self { inverse_roots, domain: *domain }))
         } else {
             None
@@ -1021,6 +1165,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mixed_radix_domain_sizes() {
+        // BLS12-377's scalar field has a small subgroup of order 3.
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(0, 1), Some(1));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(3, 1), Some(3));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(5, 1), Some(6));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(7, 1), Some(8));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(1025, 1), Some(1536));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(1537, 1), Some(2048));
+        // The domain must contain the subgroup of size `multiple_of`.
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(5, 4), Some(8));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(5, 3), Some(6));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(2, 6), Some(6));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(5, 9), None);
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(5, 5), None);
+
+        for num_coeffs in 1..100 {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(num_coeffs, 1).unwrap();
+            assert!(domain.size() >= num_coeffs);
+            assert!(domain.size() <= EvaluationDomain::<Fr>::new(num_coeffs).unwrap().size());
+            assert_eq!(domain.group_gen.pow([domain.size]), Fr::one());
+            assert_eq!(domain.elements().count(), domain.size());
+            assert_eq!(domain.is_radix_2(), domain.size().is_power_of_two());
+        }
+    }
+
+    #[test]
+    fn mixed_radix_vanishing_polynomial() {
+        let rng = &mut thread_rng();
+        for size in [3, 6, 12, 96] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(size, 1).unwrap();
+            assert_eq!(domain.size(), size);
+            let z = domain.vanishing_polynomial();
+            for point in domain.elements() {
+                assert!(z.evaluate(point).is_zero())
+            }
+            for _ in 0..10 {
+                let point = rng.gen();
+                assert_eq!(z.evaluate(point), domain.evaluate_vanishing_polynomial(point))
+            }
+        }
+    }
+
+    /// Tests that the FFTs output the correct result over mixed-radix domains.
+    #[test]
+    fn test_mixed_radix_fft_correctness() {
+        let rng = &mut thread_rng();
+        for size in [3, 6, 12, 24, 48, 96, 768] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(size, 1).unwrap();
+            assert_eq!(domain.size(), size);
+            assert!(!domain.is_radix_2());
+
+            let random_polynomial = DensePolynomial::<Fr>::rand(size - 1, rng);
+            let polynomial_evaluations = domain.fft(&random_polynomial.coeffs);
+            let polynomial_coset_evaluations = domain.coset_fft(&random_polynomial.coeffs);
+            for (i, x) in domain.elements().enumerate() {
+                let coset_x = Fr::multiplicative_generator() * x;
+
+                assert_eq!(polynomial_evaluations[i], random_polynomial.evaluate(x));
+                assert_eq!(polynomial_coset_evaluations[i], random_polynomial.evaluate(coset_x));
+            }
+
+            let random_polynomial_from_subgroup =
+                DensePolynomial::from_coefficients_vec(domain.ifft(&polynomial_evaluations));
+            let random_polynomial_from_coset =
+                DensePolynomial::from_coefficients_vec(domain.coset_ifft(&polynomial_coset_evaluations));
+            assert_eq!(random_polynomial, random_polynomial_from_subgroup, "domain size = {}", size);
+            assert_eq!(random_polynomial, random_polynomial_from_coset, "domain size = {}", size);
+        }
+    }
+
+    /// Tests that mixed-radix FFT precomputation is correctly subdomained.
+    #[test]
+    fn test_mixed_radix_fft_precomputation() {
+        let big_domain = EvaluationDomain::<Fr>::new_mixed_radix(3 << 6, 1).unwrap();
+        let pc = big_domain.precompute_fft();
+        let ifft_pc = big_domain.precompute_ifft();
+        assert_eq!(ifft_pc, pc.to_ifft_precomputation());
+        for size in [2, 3, 4, 6, 8, 12, 16, 24, 32, 48, 64, 96] {
+            let small_domain = EvaluationDomain::<Fr>::new_mixed_radix(size, 1).unwrap();
+            assert_eq!(small_domain.size(), size);
+            assert_eq!(
+                pc.precomputation_for_subdomain(&small_domain).unwrap().as_ref(),
+                &small_domain.precompute_fft()
+            );
+            assert_eq!(
+                ifft_pc.precomputation_for_subdomain(&small_domain).unwrap().as_ref(),
+                &small_domain.precompute_ifft()
+            );
+        }
+        // A mixed-radix domain is not a subgroup of a radix-2 domain.
+        let radix_2_pc = EvaluationDomain::<Fr>::new(1 << 8).unwrap().precompute_fft();
+        let small_domain = EvaluationDomain::<Fr>::new_mixed_radix(3, 1).unwrap();
+        assert!(radix_2_pc.precomputation_for_subdomain(&small_domain).is_none());
+    }
+
     /// Tests that IFFT precomputation can be correctly computed from
     /// FFT precomputation
     #[test]
@@ -25,7 +25,7 @@ use rayon::prelude::*;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{cfg_iter_mut, serialize::*};
 
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use super::domain::IFFTPrecomputation;
 
@@ -77,7 +77,7 @@ impl<F: PrimeField> Evaluations<F> {
     }
 }
 
-impl<F: PrimeField> std::ops::Index<usize> for Evaluations<F> {
+impl<F: PrimeField> core::ops::Index<usize> for Evaluations<F> {
     type Output = F;
 
     fn index(&self, index: usize) -> &F {
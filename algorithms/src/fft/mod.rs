@@ -24,7 +24,17 @@ pub mod evaluations;
 pub use evaluations::Evaluations;
 
 pub mod polynomial;
-pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
+pub use polynomial::{
+    DenseMultilinearExtension,
+    DensePolynomial,
+    MultilinearExtension,
+    Polynomial,
+    SparseMultilinearExtension,
+    SparsePolynomial,
+};
+
+#[cfg(feature = "snark")]
+pub mod sumcheck;
 
 #[cfg(test)]
 mod tests;
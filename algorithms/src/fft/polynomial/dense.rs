@@ -18,13 +18,13 @@
 
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*};
+use snarkvm_utilities::{borrow::ToOwned, cfg_iter_mut, serialize::*, vec};
 
-use rand::Rng;
-use std::{
+use core::{
     fmt,
     ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use rand::Rng;
 
 #[cfg(not(feature = "parallel"))]
 use itertools::Itertools;
@@ -34,6 +34,9 @@ use rayon::prelude::*;
 
 use super::PolyMultiplier;
 
+/// The degree below which division falls back to long division, as it is faster for small inputs.
+const FAST_DIVISION_THRESHOLD: usize = 64;
+
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
 #[must_use]
@@ -162,6 +165,61 @@ impl<F: PrimeField> DensePolynomial<F> {
         self_poly.divide_with_q_and_r(&vanishing_poly)
     }
 
+    /// Divide `self` by `divisor`, and return the quotient and remainder, or `None` if `divisor` is zero.
+    ///
+    /// For large inputs, this computes the quotient by inverting the reversed divisor with Newton
+    /// iteration, so that the division costs a constant number of FFT-based multiplications.
+    pub fn divide_with_q_and_r(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        } else if self.is_zero() {
+            return Some((Self::zero(), Self::zero()));
+        } else if self.degree() < divisor.degree() {
+            return Some((Self::zero(), self.clone()));
+        }
+
+        let quotient_len = self.degree() - divisor.degree() + 1;
+        if divisor.degree() < FAST_DIVISION_THRESHOLD || quotient_len < FAST_DIVISION_THRESHOLD {
+            return Polynomial::from(self).divide_with_q_and_r(&divisor.into());
+        }
+
+        // Writing `rev_k(p) = x^k * p(1/x)`, we have `rev(self) = rev(q) * rev(divisor) mod x^quotient_len`,
+        // and `rev(divisor)` is invertible modulo `x^quotient_len` as its constant coefficient is non-zero.
+        let reversed_divisor = Self::from_coefficients_vec(divisor.coeffs.iter().rev().copied().collect());
+        let reversed_divisor_inverse = reversed_divisor.inverse_mod_x_pow(quotient_len)?;
+        let reversed_dividend =
+            Self::from_coefficients_vec(self.coeffs.iter().rev().take(quotient_len).copied().collect());
+        let mut quotient = (&reversed_dividend * &reversed_divisor_inverse).coeffs;
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+        let quotient = Self::from_coefficients_vec(quotient);
+
+        // The remainder has degree less than that of the divisor, so only the low coefficients are needed.
+        let product = divisor * &quotient;
+        let mut remainder = self.coeffs[..divisor.degree()].to_vec();
+        cfg_iter_mut!(remainder).zip(&product.coeffs).for_each(|(r, p)| *r -= p);
+        Some((quotient, Self::from_coefficients_vec(remainder)))
+    }
+
+    /// Returns the inverse of `self` modulo `x^k`, or `None` if the constant coefficient of `self` is zero.
+    fn inverse_mod_x_pow(&self, k: usize) -> Option<Self> {
+        let mut inverse = Self::from_coefficients_vec(vec![self.coeffs.first()?.inverse()?]);
+        let mut precision = 1;
+        while precision < k {
+            precision = (2 * precision).min(k);
+            // Each step doubles the precision: `g <- g * (2 - self * g) mod x^precision`.
+            let truncated = Self::from_coefficients_slice(&self.coeffs[..precision.min(self.coeffs.len())]);
+            let mut correction = (&truncated * &inverse).coeffs;
+            correction.truncate(precision);
+            cfg_iter_mut!(correction).for_each(|c| *c = -*c);
+            correction[0] += F::one().double();
+            let mut next = (&inverse * &Self::from_coefficients_vec(correction)).coeffs;
+            next.truncate(precision);
+            inverse = Self::from_coefficients_vec(next);
+        }
+        Some(inverse)
+    }
+
     /// Evaluate `self` over `domain`.
     pub fn evaluate_over_domain_by_ref(&self, domain: EvaluationDomain<F>) -> Evaluations<F> {
         let poly: Polynomial<'_, F> = self.into();
@@ -391,6 +449,8 @@ impl<'a, F: Field> Sub<&'a super::SparsePolynomial<F>> for DensePolynomial<F> {
     }
 }
 
+/// Performs long division of polynomials over any field.
+/// Over FFT-friendly prime fields, use [`DensePolynomial::divide_with_q_and_r`], which is asymptotically faster.
 impl<'a, 'b, F: Field> Div<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
@@ -451,7 +511,7 @@ impl<F: Field> MulAssign<F> for DensePolynomial<F> {
 }
 
 /// Multiplies `self` by `other: F`.
-impl<F: Field> std::iter::Sum for DensePolynomial<F> {
+impl<F: Field> core::iter::Sum for DensePolynomial<F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(DensePolynomial::zero(), |a, b| &a + &b)
     }
@@ -569,6 +629,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fast_divide_polynomials_random() {
+        let rng = &mut thread_rng();
+
+        for (a_degree, b_degree) in [(64, 64), (200, 64), (200, 100), (500, 300), (1000, 17), (1023, 512)] {
+            let dividend = DensePolynomial::<Fr>::rand(a_degree, rng);
+            let divisor = DensePolynomial::<Fr>::rand(b_degree, rng);
+            let (quotient, remainder) = dividend.divide_with_q_and_r(&divisor).unwrap();
+            let expected = Polynomial::divide_with_q_and_r(&(&dividend).into(), &(&divisor).into()).unwrap();
+            assert_eq!((quotient.clone(), remainder.clone()), expected);
+            assert!(remainder.is_zero() || remainder.degree() < divisor.degree());
+            assert_eq!(dividend, &(&divisor * &quotient) + &remainder);
+        }
+        assert!(DensePolynomial::<Fr>::rand(5, rng).divide_with_q_and_r(&DensePolynomial::zero()).is_none());
+    }
+
     #[test]
     fn evaluate_polynomials() {
         let rng = &mut thread_rng();
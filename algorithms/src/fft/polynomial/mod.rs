@@ -18,9 +18,16 @@
 
 use crate::fft::{EvaluationDomain, Evaluations};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*, SerializationError};
+use snarkvm_utilities::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    cfg_iter_mut,
+    serialize::*,
+    vec,
+    SerializationError,
+};
 
-use std::{borrow::Cow, convert::TryInto};
+use core::convert::TryInto;
 
 use Polynomial::*;
 
@@ -36,6 +43,12 @@ pub use sparse::SparsePolynomial;
 mod multiplier;
 pub use multiplier::*;
 
+mod multilinear;
+pub use multilinear::*;
+
+mod subproduct_tree;
+pub use subproduct_tree::*;
+
 /// Represents either a sparse polynomial or a dense one.
 #[derive(Clone, Debug)]
 pub enum Polynomial<'a, F: Field> {
@@ -0,0 +1,623 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Multilinear polynomials represented by their evaluations over the boolean hypercube.
+//!
+//! The evaluation at the point `(x_0, ..., x_{n-1}) ∈ {0, 1}^n` is stored at index
+//! `x_0 + 2 * x_1 + ... + 2^{n-1} * x_{n-1}`, so `x_0` is the least significant variable.
+
+use snarkvm_fields::Field;
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, collections::BTreeMap, serialize::*, vec};
+
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use rand::Rng;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A multilinear polynomial, given by its evaluations over the boolean hypercube.
+pub trait MultilinearExtension<F: Field>:
+    Sized + Clone + fmt::Debug + PartialEq + Eq + CanonicalSerialize + CanonicalDeserialize
+{
+    /// Returns the number of variables of `self`.
+    fn num_vars(&self) -> usize;
+
+    /// Evaluates `self` at the given `point`, or returns `None` if the point has the wrong number of variables.
+    fn evaluate(&self, point: &[F]) -> Option<F>;
+
+    /// Fixes the first `partial_point.len()` variables of `self` to `partial_point`,
+    /// and returns the multilinear polynomial in the remaining variables.
+    ///
+    /// Panics if `partial_point` has more coordinates than `self` has variables.
+    fn fix_variables(&self, partial_point: &[F]) -> Self;
+
+    /// Returns the evaluations of `self` over the boolean hypercube, in index order.
+    fn to_evaluations(&self) -> Vec<F>;
+}
+
+/// Stores a multilinear polynomial in dense evaluation form.
+#[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize)]
+pub struct DenseMultilinearExtension<F: Field> {
+    /// The evaluation at the point with binary representation `i` is stored at location `i`.
+    evaluations: Vec<F>,
+    /// The number of variables.
+    num_vars: usize,
+}
+
+impl<F: Field> DenseMultilinearExtension<F> {
+    /// Returns the zero polynomial in `num_vars` variables.
+    pub fn zero(num_vars: usize) -> Self {
+        Self { evaluations: vec![F::zero(); 1 << num_vars], num_vars }
+    }
+
+    /// Constructs a new polynomial from its evaluations over the boolean hypercube.
+    ///
+    /// Panics if the number of evaluations is not `2^num_vars`.
+    pub fn from_evaluations_slice(num_vars: usize, evaluations: &[F]) -> Self {
+        Self::from_evaluations_vec(num_vars, evaluations.to_vec())
+    }
+
+    /// Constructs a new polynomial from its evaluations over the boolean hypercube.
+    ///
+    /// Panics if the number of evaluations is not `2^num_vars`.
+    pub fn from_evaluations_vec(num_vars: usize, evaluations: Vec<F>) -> Self {
+        assert_eq!(evaluations.len(), 1 << num_vars, "The number of evaluations must be 2^num_vars");
+        Self { evaluations, num_vars }
+    }
+
+    /// Returns the polynomial `eq(x, point) = ∏_i (x_i * point_i + (1 - x_i) * (1 - point_i))`,
+    /// which is one at `point` and zero everywhere else on the boolean hypercube.
+    pub fn eq(point: &[F]) -> Self {
+        let mut evaluations = vec![F::one()];
+        for r in point.iter().rev() {
+            // Each step prepends the variable `r` as the new least significant variable.
+            evaluations = evaluations
+                .iter()
+                .flat_map(|e| {
+                    let hi = *e * r;
+                    [*e - hi, hi]
+                })
+                .collect();
+        }
+        Self { evaluations, num_vars: point.len() }
+    }
+
+    /// Outputs a polynomial in `num_vars` variables whose evaluations are sampled uniformly at random.
+    pub fn rand<R: Rng>(num_vars: usize, rng: &mut R) -> Self {
+        Self::from_evaluations_vec(num_vars, (0..(1 << num_vars)).map(|_| F::rand(rng)).collect())
+    }
+
+    /// Returns the evaluations of `self` over the boolean hypercube.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Fixes the first variable of `self` to `r`, in place.
+    pub fn fix_first_variable_in_place(&mut self, r: F) {
+        assert!(self.num_vars > 0, "Cannot fix a variable of a constant polynomial");
+        let half = 1 << (self.num_vars - 1);
+        let evaluations = cfg_into_iter!(0..half)
+            .map(|b| {
+                let (lo, hi) = (self.evaluations[b << 1], self.evaluations[(b << 1) + 1]);
+                lo + r * (hi - lo)
+            })
+            .collect();
+        self.evaluations = evaluations;
+        self.num_vars -= 1;
+    }
+}
+
+impl<F: Field> MultilinearExtension<F> for DenseMultilinearExtension<F> {
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn evaluate(&self, point: &[F]) -> Option<F> {
+        (point.len() == self.num_vars).then(|| self.fix_variables(point).evaluations[0])
+    }
+
+    fn fix_variables(&self, partial_point: &[F]) -> Self {
+        assert!(partial_point.len() <= self.num_vars, "The partial point has too many coordinates");
+        let mut result = self.clone();
+        for r in partial_point {
+            result.fix_first_variable_in_place(*r);
+        }
+        result
+    }
+
+    fn to_evaluations(&self) -> Vec<F> {
+        self.evaluations.clone()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for DenseMultilinearExtension<F> {
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let evaluations = Vec::<F>::deserialize(reader)?;
+        let num_vars = usize::deserialize(reader)?;
+        Self::from_parts(evaluations, num_vars)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let evaluations = Vec::<F>::deserialize_uncompressed(reader)?;
+        let num_vars = usize::deserialize_uncompressed(reader)?;
+        Self::from_parts(evaluations, num_vars)
+    }
+}
+
+impl<F: Field> DenseMultilinearExtension<F> {
+    /// Checks that the deserialized `evaluations` cover the boolean hypercube in `num_vars` variables.
+    fn from_parts(evaluations: Vec<F>, num_vars: usize) -> Result<Self, SerializationError> {
+        match hypercube_size(num_vars) {
+            Some(size) if size == evaluations.len() => Ok(Self { evaluations, num_vars }),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<F: Field> fmt::Debug for DenseMultilinearExtension<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "DenseMultilinearExtension({} variables, [", self.num_vars)?;
+        for (i, evaluation) in self.evaluations.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", evaluation)?;
+        }
+        write!(f, "])")
+    }
+}
+
+impl<'a, F: Field> Add<&'a DenseMultilinearExtension<F>> for &DenseMultilinearExtension<F> {
+    type Output = DenseMultilinearExtension<F>;
+
+    fn add(self, other: &'a DenseMultilinearExtension<F>) -> DenseMultilinearExtension<F> {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<'a, F: Field> AddAssign<&'a DenseMultilinearExtension<F>> for DenseMultilinearExtension<F> {
+    fn add_assign(&mut self, other: &'a DenseMultilinearExtension<F>) {
+        assert_eq!(self.num_vars, other.num_vars, "Polynomials must have the same number of variables");
+        cfg_iter_mut!(self.evaluations).zip(cfg_iter!(other.evaluations)).for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<'a, F: Field> AddAssign<(F, &'a DenseMultilinearExtension<F>)> for DenseMultilinearExtension<F> {
+    fn add_assign(&mut self, (f, other): (F, &'a DenseMultilinearExtension<F>)) {
+        assert_eq!(self.num_vars, other.num_vars, "Polynomials must have the same number of variables");
+        cfg_iter_mut!(self.evaluations).zip(cfg_iter!(other.evaluations)).for_each(|(a, b)| *a += f * b);
+    }
+}
+
+impl<F: Field> Neg for DenseMultilinearExtension<F> {
+    type Output = DenseMultilinearExtension<F>;
+
+    fn neg(mut self) -> DenseMultilinearExtension<F> {
+        cfg_iter_mut!(self.evaluations).for_each(|e| *e = -*e);
+        self
+    }
+}
+
+impl<'a, F: Field> Sub<&'a DenseMultilinearExtension<F>> for &DenseMultilinearExtension<F> {
+    type Output = DenseMultilinearExtension<F>;
+
+    fn sub(self, other: &'a DenseMultilinearExtension<F>) -> DenseMultilinearExtension<F> {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<'a, F: Field> SubAssign<&'a DenseMultilinearExtension<F>> for DenseMultilinearExtension<F> {
+    fn sub_assign(&mut self, other: &'a DenseMultilinearExtension<F>) {
+        assert_eq!(self.num_vars, other.num_vars, "Polynomials must have the same number of variables");
+        cfg_iter_mut!(self.evaluations).zip(cfg_iter!(other.evaluations)).for_each(|(a, b)| *a -= b);
+    }
+}
+
+impl<F: Field> Mul<F> for &DenseMultilinearExtension<F> {
+    type Output = DenseMultilinearExtension<F>;
+
+    fn mul(self, elem: F) -> DenseMultilinearExtension<F> {
+        let mut result = self.clone();
+        result *= elem;
+        result
+    }
+}
+
+impl<F: Field> MulAssign<F> for DenseMultilinearExtension<F> {
+    fn mul_assign(&mut self, elem: F) {
+        cfg_iter_mut!(self.evaluations).for_each(|e| *e *= elem);
+    }
+}
+
+/// Stores a multilinear polynomial in sparse evaluation form.
+#[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize)]
+pub struct SparseMultilinearExtension<F: Field> {
+    /// The non-zero evaluations, keyed by the binary representation of their point.
+    evaluations: BTreeMap<usize, F>,
+    /// The number of variables.
+    num_vars: usize,
+}
+
+impl<F: Field> SparseMultilinearExtension<F> {
+    /// Returns the zero polynomial in `num_vars` variables.
+    pub fn zero(num_vars: usize) -> Self {
+        Self { evaluations: BTreeMap::new(), num_vars }
+    }
+
+    /// Constructs a new polynomial from its non-zero evaluations over the boolean hypercube.
+    ///
+    /// Panics if an index is out of range for `num_vars` variables.
+    pub fn from_evaluations(num_vars: usize, evaluations: impl IntoIterator<Item = (usize, F)>) -> Self {
+        let mut result = Self::zero(num_vars);
+        for (i, e) in evaluations {
+            assert!(i < (1 << num_vars), "The evaluation index is out of range");
+            *result.evaluations.entry(i).or_insert_with(F::zero) += e;
+        }
+        result.evaluations.retain(|_, e| !e.is_zero());
+        result
+    }
+
+    /// Outputs a polynomial in `num_vars` variables with `num_nonzero` evaluations,
+    /// sampled uniformly at random at random points.
+    pub fn rand<R: Rng>(num_vars: usize, num_nonzero: usize, rng: &mut R) -> Self {
+        assert!(num_nonzero <= (1 << num_vars), "Too many non-zero evaluations");
+        let mut evaluations = BTreeMap::new();
+        while evaluations.len() < num_nonzero {
+            let e = F::rand(rng);
+            if !e.is_zero() {
+                evaluations.insert(rng.gen_range(0..(1 << num_vars)), e);
+            }
+        }
+        Self { evaluations, num_vars }
+    }
+
+    /// Returns the non-zero evaluations of `self` over the boolean hypercube.
+    pub fn evaluations(&self) -> impl Iterator<Item = (&usize, &F)> {
+        self.evaluations.iter()
+    }
+
+    /// Converts `self` into dense evaluation form.
+    pub fn to_dense(&self) -> DenseMultilinearExtension<F> {
+        DenseMultilinearExtension::from_evaluations_vec(self.num_vars, self.to_evaluations())
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for SparseMultilinearExtension<F> {
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let evaluations = BTreeMap::<usize, F>::deserialize(reader)?;
+        let num_vars = usize::deserialize(reader)?;
+        Self::from_parts(evaluations, num_vars)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let evaluations = BTreeMap::<usize, F>::deserialize_uncompressed(reader)?;
+        let num_vars = usize::deserialize_uncompressed(reader)?;
+        Self::from_parts(evaluations, num_vars)
+    }
+}
+
+impl<F: Field> SparseMultilinearExtension<F> {
+    /// Checks that the deserialized `evaluations` are non-zero, at points of the boolean hypercube in `num_vars` variables.
+    fn from_parts(evaluations: BTreeMap<usize, F>, num_vars: usize) -> Result<Self, SerializationError> {
+        let size = hypercube_size(num_vars).ok_or(SerializationError::InvalidData)?;
+        match evaluations.iter().all(|(i, e)| *i < size && !e.is_zero()) {
+            true => Ok(Self { evaluations, num_vars }),
+            false => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<F: Field> MultilinearExtension<F> for SparseMultilinearExtension<F> {
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn evaluate(&self, point: &[F]) -> Option<F> {
+        (point.len() == self.num_vars).then(|| {
+            self.evaluations
+                .iter()
+                .map(|(i, e)| {
+                    point.iter().enumerate().fold(
+                        *e,
+                        |acc, (j, r)| {
+                            if (i >> j) & 1 == 1 {
+                                acc * r
+                            } else {
+                                acc * (F::one() - r)
+                            }
+                        },
+                    )
+                })
+                .sum()
+        })
+    }
+
+    fn fix_variables(&self, partial_point: &[F]) -> Self {
+        assert!(partial_point.len() <= self.num_vars, "The partial point has too many coordinates");
+        let mut evaluations = self.evaluations.clone();
+        for r in partial_point {
+            let one_minus_r = F::one() - r;
+            let mut next = BTreeMap::new();
+            for (i, e) in evaluations {
+                let weight = if i & 1 == 1 { *r } else { one_minus_r };
+                *next.entry(i >> 1).or_insert_with(F::zero) += e * weight;
+            }
+            evaluations = next;
+        }
+        evaluations.retain(|_, e| !e.is_zero());
+        Self { evaluations, num_vars: self.num_vars - partial_point.len() }
+    }
+
+    fn to_evaluations(&self) -> Vec<F> {
+        let mut evaluations = vec![F::zero(); 1 << self.num_vars];
+        for (i, e) in &self.evaluations {
+            evaluations[*i] = *e;
+        }
+        evaluations
+    }
+}
+
+impl<F: Field> fmt::Debug for SparseMultilinearExtension<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "SparseMultilinearExtension({} variables, [", self.num_vars)?;
+        for (j, (i, evaluation)) in self.evaluations.iter().enumerate() {
+            if j > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {:?}", i, evaluation)?;
+        }
+        write!(f, "])")
+    }
+}
+
+impl<F: Field> From<SparseMultilinearExtension<F>> for DenseMultilinearExtension<F> {
+    fn from(other: SparseMultilinearExtension<F>) -> Self {
+        other.to_dense()
+    }
+}
+
+impl<'a, F: Field> Add<&'a SparseMultilinearExtension<F>> for &SparseMultilinearExtension<F> {
+    type Output = SparseMultilinearExtension<F>;
+
+    fn add(self, other: &'a SparseMultilinearExtension<F>) -> SparseMultilinearExtension<F> {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<'a, F: Field> AddAssign<&'a SparseMultilinearExtension<F>> for SparseMultilinearExtension<F> {
+    fn add_assign(&mut self, other: &'a SparseMultilinearExtension<F>) {
+        assert_eq!(self.num_vars, other.num_vars, "Polynomials must have the same number of variables");
+        for (i, e) in &other.evaluations {
+            *self.evaluations.entry(*i).or_insert_with(F::zero) += e;
+        }
+        self.evaluations.retain(|_, e| !e.is_zero());
+    }
+}
+
+impl<F: Field> Neg for SparseMultilinearExtension<F> {
+    type Output = SparseMultilinearExtension<F>;
+
+    fn neg(mut self) -> SparseMultilinearExtension<F> {
+        self.evaluations.values_mut().for_each(|e| *e = -*e);
+        self
+    }
+}
+
+impl<'a, F: Field> Sub<&'a SparseMultilinearExtension<F>> for &SparseMultilinearExtension<F> {
+    type Output = SparseMultilinearExtension<F>;
+
+    fn sub(self, other: &'a SparseMultilinearExtension<F>) -> SparseMultilinearExtension<F> {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<'a, F: Field> SubAssign<&'a SparseMultilinearExtension<F>> for SparseMultilinearExtension<F> {
+    fn sub_assign(&mut self, other: &'a SparseMultilinearExtension<F>) {
+        assert_eq!(self.num_vars, other.num_vars, "Polynomials must have the same number of variables");
+        for (i, e) in &other.evaluations {
+            *self.evaluations.entry(*i).or_insert_with(F::zero) -= e;
+        }
+        self.evaluations.retain(|_, e| !e.is_zero());
+    }
+}
+
+impl<F: Field> MulAssign<F> for SparseMultilinearExtension<F> {
+    fn mul_assign(&mut self, elem: F) {
+        if elem.is_zero() {
+            self.evaluations.clear();
+        } else {
+            self.evaluations.values_mut().for_each(|e| *e *= elem);
+        }
+    }
+}
+
+/// Returns the number of points of the boolean hypercube in `num_vars` variables, or `None` if it overflows.
+fn hypercube_size(num_vars: usize) -> Option<usize> {
+    u32::try_from(num_vars).ok().and_then(|num_vars| 1usize.checked_shl(num_vars))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fft::polynomial::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::UniformRand;
+
+    use rand::thread_rng;
+
+    /// Evaluates the multilinear extension of `evaluations` at `point` directly from its definition.
+    fn naive_evaluate(evaluations: &[Fr], point: &[Fr]) -> Fr {
+        evaluations
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                point.iter().enumerate().fold(
+                    *e,
+                    |acc, (j, r)| {
+                        if (i >> j) & 1 == 1 {
+                            acc * r
+                        } else {
+                            acc * (Fr::one() - r)
+                        }
+                    },
+                )
+            })
+            .sum()
+    }
+
+    #[test]
+    fn evaluate_dense_and_sparse() {
+        let rng = &mut thread_rng();
+        for num_vars in 0..8 {
+            let point: Vec<_> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+
+            let dense = DenseMultilinearExtension::<Fr>::rand(num_vars, rng);
+            let expected = naive_evaluate(dense.evaluations(), &point);
+            assert_eq!(dense.evaluate(&point), Some(expected));
+
+            let sparse = SparseMultilinearExtension::<Fr>::rand(num_vars, 1 << (num_vars / 2), rng);
+            let expected = naive_evaluate(&sparse.to_evaluations(), &point);
+            assert_eq!(sparse.evaluate(&point), Some(expected));
+            assert_eq!(sparse.to_dense().evaluate(&point), Some(expected));
+
+            assert_eq!(dense.evaluate(&[Fr::one(); 9]), None);
+        }
+    }
+
+    #[test]
+    fn serialization() {
+        use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize};
+
+        let rng = &mut thread_rng();
+        let dense = DenseMultilinearExtension::<Fr>::rand(4, rng);
+        let sparse = SparseMultilinearExtension::<Fr>::rand(4, 5, rng);
+
+        let mut bytes = Vec::new();
+        dense.serialize(&mut bytes).unwrap();
+        assert_eq!(dense, DenseMultilinearExtension::deserialize(&mut &bytes[..]).unwrap());
+        let mut bytes = Vec::new();
+        sparse.serialize(&mut bytes).unwrap();
+        assert_eq!(sparse, SparseMultilinearExtension::deserialize(&mut &bytes[..]).unwrap());
+
+        // The evaluations must cover the boolean hypercube in `num_vars` variables.
+        let mut bytes = Vec::new();
+        (dense.evaluations().to_vec(), 5usize).serialize(&mut bytes).unwrap();
+        assert!(DenseMultilinearExtension::<Fr>::deserialize(&mut &bytes[..]).is_err());
+        let mut bytes = Vec::new();
+        (dense.evaluations().to_vec(), 64usize).serialize(&mut bytes).unwrap();
+        assert!(DenseMultilinearExtension::<Fr>::deserialize(&mut &bytes[..]).is_err());
+
+        // The sparse evaluations must be non-zero, and lie on the boolean hypercube.
+        for evaluations in [[(16usize, Fr::one())], [(0, Fr::zero())]] {
+            let mut bytes = Vec::new();
+            (evaluations.into_iter().collect::<std::collections::BTreeMap<_, _>>(), 4usize)
+                .serialize(&mut bytes)
+                .unwrap();
+            assert!(SparseMultilinearExtension::<Fr>::deserialize(&mut &bytes[..]).is_err());
+        }
+    }
+
+    #[test]
+    fn evaluate_on_hypercube() {
+        let rng = &mut thread_rng();
+        let dense = DenseMultilinearExtension::<Fr>::rand(5, rng);
+        for i in 0..32 {
+            let point: Vec<_> = (0..5).map(|j| if (i >> j) & 1 == 1 { Fr::one() } else { Fr::zero() }).collect();
+            assert_eq!(dense.evaluate(&point), Some(dense.evaluations()[i]));
+        }
+    }
+
+    #[test]
+    fn fix_variables() {
+        let rng = &mut thread_rng();
+        for num_vars in 1..8 {
+            let point: Vec<_> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+            let dense = DenseMultilinearExtension::<Fr>::rand(num_vars, rng);
+            let sparse = SparseMultilinearExtension::<Fr>::rand(num_vars, num_vars, rng);
+
+            for fixed in 0..=num_vars {
+                let (partial_point, rest) = point.split_at(fixed);
+
+                let partial = dense.fix_variables(partial_point);
+                assert_eq!(partial.num_vars(), num_vars - fixed);
+                assert_eq!(partial.evaluate(rest), dense.evaluate(&point));
+
+                let partial = sparse.fix_variables(partial_point);
+                assert_eq!(partial.num_vars(), num_vars - fixed);
+                assert_eq!(partial.evaluate(rest), sparse.evaluate(&point));
+                assert_eq!(partial.to_dense(), sparse.to_dense().fix_variables(partial_point));
+            }
+        }
+    }
+
+    #[test]
+    fn eq_polynomial() {
+        let rng = &mut thread_rng();
+        let r: Vec<_> = (0..6).map(|_| Fr::rand(rng)).collect();
+        let eq = DenseMultilinearExtension::eq(&r);
+
+        // `eq(x, r)` is symmetric in `x` and `r`, and evaluates any multilinear polynomial at `r`.
+        let x: Vec<_> = (0..6).map(|_| Fr::rand(rng)).collect();
+        assert_eq!(eq.evaluate(&x), DenseMultilinearExtension::eq(&x).evaluate(&r));
+
+        let p = DenseMultilinearExtension::<Fr>::rand(6, rng);
+        let inner_product: Fr = p.evaluations().iter().zip(eq.evaluations()).map(|(a, b)| *a * b).sum();
+        assert_eq!(p.evaluate(&r), Some(inner_product));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let rng = &mut thread_rng();
+        for num_vars in 0..8 {
+            let point: Vec<_> = (0..num_vars).map(|_| Fr::rand(rng)).collect();
+            let scalar = Fr::rand(rng);
+
+            let p = DenseMultilinearExtension::<Fr>::rand(num_vars, rng);
+            let q = DenseMultilinearExtension::<Fr>::rand(num_vars, rng);
+            let (p_eval, q_eval) = (p.evaluate(&point).unwrap(), q.evaluate(&point).unwrap());
+
+            assert_eq!((&p + &q).evaluate(&point), Some(p_eval + q_eval));
+            assert_eq!((&p - &q).evaluate(&point), Some(p_eval - q_eval));
+            assert_eq!((-p.clone()).evaluate(&point), Some(-p_eval));
+            assert_eq!((&p * scalar).evaluate(&point), Some(p_eval * scalar));
+            let mut r = p.clone();
+            r += (scalar, &q);
+            assert_eq!(r.evaluate(&point), Some(p_eval + scalar * q_eval));
+            assert_eq!(&p - &p, DenseMultilinearExtension::zero(num_vars));
+
+            let p = SparseMultilinearExtension::<Fr>::rand(num_vars, 1 << (num_vars / 2), rng);
+            let q = SparseMultilinearExtension::<Fr>::rand(num_vars, 1 << (num_vars / 2), rng);
+            let (p_eval, q_eval) = (p.evaluate(&point).unwrap(), q.evaluate(&point).unwrap());
+
+            assert_eq!((&p + &q).evaluate(&point), Some(p_eval + q_eval));
+            assert_eq!((&p - &q).evaluate(&point), Some(p_eval - q_eval));
+            assert_eq!((-p.clone()).evaluate(&point), Some(-p_eval));
+            assert_eq!(&p - &p, SparseMultilinearExtension::zero(num_vars));
+            assert_eq!((&p + &q).to_dense(), &p.to_dense() + &q.to_dense());
+        }
+    }
+}
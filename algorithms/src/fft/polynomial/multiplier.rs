@@ -14,13 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{borrow::Borrow, collections::BTreeMap};
+use core::borrow::Borrow;
 
 use crate::fft::domain::{FFTPrecomputation, IFFTPrecomputation};
 
 /// A struct that helps multiply a batch of polynomials
 use super::*;
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, ExecutionPool};
+use snarkvm_utilities::{
+    borrow::ToOwned,
+    cfg_iter,
+    cfg_iter_mut,
+    collections::BTreeMap,
+    string::{String, ToString},
+    ExecutionPool,
+};
 
 #[derive(Default)]
 pub struct PolyMultiplier<'a, F: PrimeField> {
@@ -152,7 +159,10 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
             pool.add_job(move || {
                 let mut e = e.to_owned().into_owned().evaluations;
                 e.resize(domain.size(), F::zero());
-                crate::fft::domain::derange(&mut e);
+                // Mixed-radix FFTs are always in order, so only radix-2 evaluations need reordering.
+                if domain.is_radix_2() {
+                    crate::fft::domain::derange(&mut e);
+                }
                 (l, e)
             })
         }
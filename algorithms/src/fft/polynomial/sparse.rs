@@ -18,9 +18,9 @@
 
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::serialize::*;
+use snarkvm_utilities::{collections::BTreeMap, serialize::*};
 
-use std::{collections::BTreeMap, fmt};
+use core::fmt;
 
 /// Stores a sparse polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
@@ -100,7 +100,7 @@ impl<F: Field> SparsePolynomial<F> {
         if self.is_zero() || other.is_zero() {
             SparsePolynomial::zero()
         } else {
-            let mut result = std::collections::BTreeMap::new();
+            let mut result = BTreeMap::new();
             for (i, self_coeff) in self.coeffs.iter() {
                 for (j, other_coeff) in other.coeffs.iter() {
                     let cur_coeff = result.entry(i + j).or_insert_with(F::zero);
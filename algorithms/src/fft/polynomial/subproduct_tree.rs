@@ -0,0 +1,274 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Fast multipoint evaluation and interpolation using a subproduct tree.
+
+use crate::fft::DensePolynomial;
+use snarkvm_fields::{batch_inversion, PrimeField};
+use snarkvm_utilities::{boxed::Box, cfg_iter_mut, vec, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of points below which a subtree evaluates a polynomial directly,
+/// as this is faster than reducing it further.
+const DIRECT_EVALUATION_THRESHOLD: usize = 8;
+
+/// A subproduct tree over a list of points `x_0, ..., x_{n-1}`.
+///
+/// The leaves of the tree are the linear polynomials `x - x_i`, and each internal node
+/// is the product of its children, so that the root is `∏_i (x - x_i)`.
+#[derive(Clone, Debug)]
+pub struct SubproductTree<F: PrimeField> {
+    /// The points the tree is built over.
+    points: Vec<F>,
+    /// The root of the tree.
+    root: SubproductTreeNode<F>,
+}
+
+#[derive(Clone, Debug)]
+struct SubproductTreeNode<F: PrimeField> {
+    /// The product `∏_i (x - x_i)` over the points under this node.
+    product: DensePolynomial<F>,
+    /// The subtrees over the first and second halves of the points under this node, or `None` for a leaf.
+    children: Option<Box<(SubproductTreeNode<F>, SubproductTreeNode<F>)>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Builds the subproduct tree over the given `points`, or returns `None` if there are no points.
+    pub fn new(points: &[F]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let timer = start_timer!(|| format!("Building a subproduct tree over {} points", points.len()));
+        let root = SubproductTreeNode::new(points);
+        end_timer!(timer);
+        Some(Self { points: points.to_vec(), root })
+    }
+
+    /// Returns the points the tree is built over.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// Returns the vanishing polynomial `∏_i (x - x_i)` of the points.
+    pub fn vanishing_polynomial(&self) -> &DensePolynomial<F> {
+        &self.root.product
+    }
+
+    /// Evaluates `polynomial` at each of the points.
+    pub fn evaluate(&self, polynomial: &DensePolynomial<F>) -> Vec<F> {
+        let timer = start_timer!(|| format!("Evaluating a polynomial at {} points", self.points.len()));
+        let mut evaluations = vec![F::zero(); self.points.len()];
+        let remainder = reduce(polynomial, &self.root.product);
+        self.root.evaluate(&remainder, &self.points, &mut evaluations);
+        end_timer!(timer);
+        evaluations
+    }
+
+    /// Returns the unique polynomial of degree less than the number of points that takes the
+    /// given `values` at the points, or `None` if the number of values is incorrect or the
+    /// points are not distinct.
+    pub fn interpolate(&self, values: &[F]) -> Option<DensePolynomial<F>> {
+        if values.len() != self.points.len() {
+            return None;
+        }
+        let timer = start_timer!(|| format!("Interpolating a polynomial over {} points", self.points.len()));
+
+        // The Lagrange basis polynomial for `x_i` is `Z(x) / ((x - x_i) * Z'(x_i))`, where `Z` is the vanishing polynomial.
+        let mut weights = self.evaluate(&derivative(&self.root.product));
+        if weights.iter().any(|weight| weight.is_zero()) {
+            end_timer!(timer);
+            return None;
+        }
+        batch_inversion(&mut weights);
+        cfg_iter_mut!(weights).zip(values).for_each(|(weight, value)| *weight *= value);
+        let result = self.root.linear_combination(&weights);
+
+        end_timer!(timer);
+        Some(result)
+    }
+}
+
+impl<F: PrimeField> SubproductTreeNode<F> {
+    fn new(points: &[F]) -> Self {
+        match points {
+            [point] => {
+                Self { product: DensePolynomial::from_coefficients_vec(vec![-*point, F::one()]), children: None }
+            }
+            _ => {
+                let (left, right) = points.split_at(points.len() / 2);
+                let (left, right) = join(|| Self::new(left), || Self::new(right));
+                let product = &left.product * &right.product;
+                Self { product, children: Some(Box::new((left, right))) }
+            }
+        }
+    }
+
+    /// Returns the number of points under this node.
+    fn num_points(&self) -> usize {
+        self.product.degree()
+    }
+
+    /// Writes the evaluations of `polynomial` at `points` into `evaluations`,
+    /// assuming that `polynomial` has already been reduced modulo `self.product`.
+    fn evaluate(&self, polynomial: &DensePolynomial<F>, points: &[F], evaluations: &mut [F]) {
+        match &self.children {
+            Some(children) if points.len() > DIRECT_EVALUATION_THRESHOLD => {
+                let (left, right) = children.as_ref();
+                let (left_points, right_points) = points.split_at(left.num_points());
+                let (left_evaluations, right_evaluations) = evaluations.split_at_mut(left.num_points());
+                join(
+                    || left.evaluate(&reduce(polynomial, &left.product), left_points, left_evaluations),
+                    || right.evaluate(&reduce(polynomial, &right.product), right_points, right_evaluations),
+                );
+            }
+            _ => {
+                cfg_iter_mut!(evaluations).zip(points).for_each(|(evaluation, point)| {
+                    *evaluation = polynomial.evaluate(*point);
+                });
+            }
+        }
+    }
+
+    /// Returns `∑_i weights[i] * self.product / (x - x_i)` over the points under this node.
+    fn linear_combination(&self, weights: &[F]) -> DensePolynomial<F> {
+        match &self.children {
+            Some(children) => {
+                let (left, right) = children.as_ref();
+                let (left_weights, right_weights) = weights.split_at(left.num_points());
+                let (left_combination, right_combination) =
+                    join(|| left.linear_combination(left_weights), || right.linear_combination(right_weights));
+                let (left_term, right_term) =
+                    join(|| &left_combination * &right.product, || &right_combination * &left.product);
+                &left_term + &right_term
+            }
+            None => DensePolynomial::from_coefficients_vec(weights.to_vec()),
+        }
+    }
+}
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Evaluates `self` at each of the given `points`.
+    ///
+    /// This uses a subproduct tree over the points, and runs in `O(n log^2 n)` time
+    /// for `n` points and a polynomial of degree `O(n)`.
+    pub fn multi_evaluate(&self, points: &[F]) -> Vec<F> {
+        match SubproductTree::new(points) {
+            Some(tree) => tree.evaluate(self),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the unique polynomial of degree less than `points.len()` that takes the given `values`
+    /// at the given `points`, or `None` if the lengths differ or the points are not distinct.
+    pub fn interpolate(points: &[F], values: &[F]) -> Option<Self> {
+        match SubproductTree::new(points) {
+            Some(tree) => tree.interpolate(values),
+            None => values.is_empty().then(Self::zero),
+        }
+    }
+}
+
+/// Returns the remainder of `polynomial` modulo `divisor`.
+fn reduce<F: PrimeField>(polynomial: &DensePolynomial<F>, divisor: &DensePolynomial<F>) -> DensePolynomial<F> {
+    match polynomial.degree() < divisor.degree() {
+        true => polynomial.clone(),
+        false => polynomial.divide_with_q_and_r(divisor).expect("the divisor is non-zero").1,
+    }
+}
+
+/// Returns the formal derivative of `polynomial`.
+fn derivative<F: PrimeField>(polynomial: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let coeffs = polynomial.coeffs.iter().enumerate().skip(1).map(|(i, c)| F::from(i as u64) * c).collect();
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+#[cfg(feature = "parallel")]
+fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    rayon::join(a, b)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA,
+    B: FnOnce() -> RB,
+{
+    (a(), b())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    #[test]
+    fn test_multi_evaluate() {
+        let rng = &mut test_rng();
+        for num_points in [1, 2, 3, 7, 8, 9, 31, 64, 100, 257] {
+            let points: Vec<Fr> = (0..num_points).map(|_| Fr::rand(rng)).collect();
+            for degree in [0, num_points / 2, num_points, 3 * num_points] {
+                let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+                let expected: Vec<_> = points.iter().map(|point| polynomial.evaluate(*point)).collect();
+                assert_eq!(polynomial.multi_evaluate(&points), expected);
+            }
+        }
+        assert!(DensePolynomial::<Fr>::rand(5, rng).multi_evaluate(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let rng = &mut test_rng();
+        for num_points in [1, 2, 3, 7, 8, 9, 31, 64, 100, 257] {
+            let points: Vec<Fr> = (0..num_points).map(|_| Fr::rand(rng)).collect();
+            let polynomial = DensePolynomial::<Fr>::rand(num_points - 1, rng);
+            let values: Vec<_> = points.iter().map(|point| polynomial.evaluate(*point)).collect();
+            assert_eq!(DensePolynomial::interpolate(&points, &values).unwrap(), polynomial);
+        }
+        assert_eq!(DensePolynomial::<Fr>::interpolate(&[], &[]).unwrap(), DensePolynomial::zero());
+    }
+
+    #[test]
+    fn test_interpolate_fails_on_invalid_inputs() {
+        let rng = &mut test_rng();
+        let points: Vec<Fr> = (0..10).map(|_| Fr::rand(rng)).collect();
+        let values: Vec<Fr> = (0..10).map(|_| Fr::rand(rng)).collect();
+        assert!(DensePolynomial::interpolate(&points, &values[..9]).is_none());
+
+        let mut repeated_points = points;
+        repeated_points[3] = repeated_points[7];
+        assert!(DensePolynomial::interpolate(&repeated_points, &values).is_none());
+    }
+
+    #[test]
+    fn test_vanishing_polynomial() {
+        let rng = &mut test_rng();
+        let points: Vec<Fr> = (0..50).map(|_| Fr::rand(rng)).collect();
+        let tree = SubproductTree::new(&points).unwrap();
+        assert_eq!(tree.vanishing_polynomial().degree(), points.len());
+        assert_eq!(tree.vanishing_polynomial().coeffs.last(), Some(&Fr::one()));
+        assert!(tree.evaluate(tree.vanishing_polynomial()).iter().all(|e| e.is_zero()));
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::snark::marlin::FiatShamirError;
+use snarkvm_utilities::error::Error;
+
+use core::fmt;
+
+#[derive(Debug)]
+pub enum SumcheckError {
+    FiatShamirError(FiatShamirError),
+    InconsistentRound(usize),
+    InvalidMessageLength(usize, usize, usize),
+    InvalidNumberOfRounds(usize, usize),
+}
+
+impl fmt::Display for SumcheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FiatShamirError(error) => write!(f, "{}", error),
+            Self::InconsistentRound(round) => {
+                write!(f, "The prover message in round {} is inconsistent with the claimed sum", round)
+            }
+            Self::InvalidMessageLength(round, expected, found) => {
+                write!(f, "Invalid prover message length in round {}: expected {}, found {}", round, expected, found)
+            }
+            Self::InvalidNumberOfRounds(expected, found) => {
+                write!(f, "Invalid number of rounds: expected {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for SumcheckError {}
+
+impl From<FiatShamirError> for SumcheckError {
+    fn from(error: FiatShamirError) -> Self {
+        SumcheckError::FiatShamirError(error)
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A non-interactive sumcheck protocol for sums of products of multilinear polynomials,
+//! made non-interactive with a Fiat-Shamir RNG.
+//!
+//! In round `i`, the prover sends the evaluations at `0, 1, ..., d` of the univariate polynomial
+//! obtained by summing over the remaining variables after fixing the first `i` variables to the
+//! previous challenges, where `d` is the maximum number of multiplicands in a product.
+//! The verifier checks each message against the running claim, and reduces the claimed sum
+//! to a single evaluation of the polynomial at a random point, which the caller must check.
+
+mod errors;
+pub use errors::*;
+
+mod polynomial;
+pub use polynomial::*;
+
+use crate::{
+    fft::DenseMultilinearExtension,
+    snark::marlin::{params::OptimizationType, FiatShamirRng},
+};
+use snarkvm_fields::{Field, PrimeField};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter_mut, serialize::*, vec};
+
+use core::marker::PhantomData;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The messages sent by the sumcheck prover, one per variable.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SumcheckProof<F: Field> {
+    /// The evaluations at `0, 1, ..., d` of the round polynomial, for each round.
+    pub round_evaluations: Vec<Vec<F>>,
+}
+
+/// The claim that the sumcheck verifier reduces the claimed sum to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubClaim<F: Field> {
+    /// The point at which the polynomial must be evaluated.
+    pub point: Vec<F>,
+    /// The expected evaluation of the polynomial at `point`.
+    pub expected_evaluation: F,
+}
+
+/// The sumcheck protocol over the target field `F` of the Fiat-Shamir RNG `FS`.
+pub struct Sumcheck<F: PrimeField, CF: PrimeField, FS: FiatShamirRng<F, CF>>(PhantomData<(F, CF, FS)>);
+
+impl<F: PrimeField, CF: PrimeField, FS: FiatShamirRng<F, CF>> Sumcheck<F, CF, FS> {
+    /// Proves the sum of `polynomial` over the boolean hypercube, and returns the proof,
+    /// together with the point at which the verifier will need an evaluation of `polynomial`.
+    pub fn prove(
+        polynomial: &ListOfProductsOfPolynomials<F>,
+        fs_rng: &mut FS,
+    ) -> Result<(SumcheckProof<F>, Vec<F>), SumcheckError> {
+        let prover_time = start_timer!(|| "Sumcheck::Prover");
+        Self::absorb_info(&polynomial.info(), fs_rng);
+
+        let degree = polynomial.max_multiplicands;
+        let mut tables: Vec<DenseMultilinearExtension<F>> =
+            polynomial.flattened_ml_extensions.iter().map(|p| p.as_ref().clone()).collect();
+        let mut round_evaluations = Vec::with_capacity(polynomial.num_variables);
+        let mut point = Vec::with_capacity(polynomial.num_variables);
+
+        for round in 0..polynomial.num_variables {
+            if let Some(r) = point.last() {
+                cfg_iter_mut!(tables).for_each(|table| table.fix_first_variable_in_place(*r));
+            }
+
+            let half = 1 << (polynomial.num_variables - round - 1);
+            let tables = &tables;
+            let sums = cfg_into_iter!(0..half).map(|b| {
+                let mut sums = vec![F::zero(); degree + 1];
+                for (coefficient, indices) in &polynomial.products {
+                    let mut product = vec![*coefficient; degree + 1];
+                    for i in indices {
+                        // The table is linear in the current variable, so step through `0, 1, ..., d`.
+                        let table = tables[*i].evaluations();
+                        let (lo, hi) = (table[b << 1], table[(b << 1) + 1]);
+                        let step = hi - lo;
+                        let mut value = lo;
+                        for p in product.iter_mut() {
+                            *p *= value;
+                            value += step;
+                        }
+                    }
+                    sums.iter_mut().zip(product).for_each(|(s, p)| *s += p);
+                }
+                sums
+            });
+            let evaluations = crate::cfg_reduce!(sums, || vec![F::zero(); degree + 1], |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a
+            });
+
+            fs_rng.absorb_nonnative_field_elements(evaluations.iter().copied(), OptimizationType::Weight);
+            point.push(fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?[0]);
+            round_evaluations.push(evaluations);
+        }
+        end_timer!(prover_time);
+
+        Ok((SumcheckProof { round_evaluations }, point))
+    }
+
+    /// Verifies that a polynomial of the shape `info` sums to `claimed_sum` over the boolean hypercube,
+    /// and returns the evaluation claim that the caller must check against the polynomial.
+    pub fn verify(
+        info: &PolynomialInfo,
+        claimed_sum: F,
+        proof: &SumcheckProof<F>,
+        fs_rng: &mut FS,
+    ) -> Result<SubClaim<F>, SumcheckError> {
+        let verifier_time = start_timer!(|| "Sumcheck::Verify");
+        if proof.round_evaluations.len() != info.num_variables {
+            return Err(SumcheckError::InvalidNumberOfRounds(info.num_variables, proof.round_evaluations.len()));
+        }
+        Self::absorb_info(info, fs_rng);
+
+        let mut expected_evaluation = claimed_sum;
+        let mut point = Vec::with_capacity(info.num_variables);
+        for (round, evaluations) in proof.round_evaluations.iter().enumerate() {
+            if evaluations.len() != info.max_multiplicands + 1 {
+                return Err(SumcheckError::InvalidMessageLength(round, info.max_multiplicands + 1, evaluations.len()));
+            }
+            // The round polynomial must sum to the running claim over `{0, 1}`.
+            let sum = evaluations[0] + evaluations.get(1).copied().unwrap_or(evaluations[0]);
+            if sum != expected_evaluation {
+                return Err(SumcheckError::InconsistentRound(round));
+            }
+
+            fs_rng.absorb_nonnative_field_elements(evaluations.iter().copied(), OptimizationType::Weight);
+            let r = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?[0];
+            expected_evaluation = interpolate_at(evaluations, r);
+            point.push(r);
+        }
+        end_timer!(verifier_time);
+
+        Ok(SubClaim { point, expected_evaluation })
+    }
+
+    /// Binds the transcript to the shape of the polynomial.
+    fn absorb_info(info: &PolynomialInfo, fs_rng: &mut FS) {
+        fs_rng.absorb_bytes(&(info.num_variables as u64).to_le_bytes());
+        fs_rng.absorb_bytes(&(info.max_multiplicands as u64).to_le_bytes());
+    }
+}
+
+/// Evaluates at `point` the polynomial of degree `evaluations.len() - 1`
+/// whose evaluations at `0, 1, ..., evaluations.len() - 1` are `evaluations`.
+pub fn interpolate_at<F: Field>(evaluations: &[F], point: F) -> F {
+    let degree = evaluations.len() - 1;
+    let nodes: Vec<F> = (0..=degree).map(|i| F::from(i as u64)).collect();
+
+    // The Lagrange basis polynomial `L_i` is `∏_{j ≠ i} (x - j) / ∏_{j ≠ i} (i - j)`, where the
+    // numerator is split into prefix and suffix products, and the denominator is `(-1)^(d - i) i! (d - i)!`.
+    let mut suffix = vec![F::one(); degree + 1];
+    for i in (0..degree).rev() {
+        suffix[i] = suffix[i + 1] * (point - nodes[i + 1]);
+    }
+    let mut factorials = vec![F::one(); degree + 1];
+    for i in 1..=degree {
+        factorials[i] = factorials[i - 1] * nodes[i];
+    }
+
+    let mut result = F::zero();
+    let mut prefix = F::one();
+    for (i, evaluation) in evaluations.iter().enumerate() {
+        let mut denominator = factorials[i] * factorials[degree - i];
+        if (degree - i) % 2 == 1 {
+            denominator = -denominator;
+        }
+        result += *evaluation * prefix * suffix[i] * denominator.inverse().unwrap();
+        prefix *= point - nodes[i];
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fft::MultilinearExtension, snark::marlin::FiatShamirChaChaRng};
+    use snarkvm_curves::bls12_377::{Fq, Fr};
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::UniformRand;
+
+    use blake2::Blake2s256;
+    use rand::thread_rng;
+    use std::sync::Arc;
+
+    type FS = FiatShamirChaChaRng<Fr, Fq, Blake2s256>;
+    type TestSumcheck = Sumcheck<Fr, Fq, FS>;
+
+    /// Samples `num_products` products of up to `max_multiplicands` random polynomials, reusing some of them.
+    fn random_polynomial(num_variables: usize, max_multiplicands: usize) -> ListOfProductsOfPolynomials<Fr> {
+        let rng = &mut thread_rng();
+        let pool: Vec<_> =
+            (0..max_multiplicands).map(|_| Arc::new(DenseMultilinearExtension::rand(num_variables, rng))).collect();
+
+        let mut polynomial = ListOfProductsOfPolynomials::new(num_variables);
+        for num_multiplicands in 1..=max_multiplicands {
+            let product = pool.iter().take(num_multiplicands).cloned();
+            polynomial.add_product(product, Fr::rand(rng));
+        }
+        polynomial.add_product([Arc::new(DenseMultilinearExtension::rand(num_variables, rng))], Fr::one());
+        polynomial
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        for num_variables in 0..8 {
+            for max_multiplicands in 1..5 {
+                let polynomial = random_polynomial(num_variables, max_multiplicands);
+                assert_eq!(polynomial.flattened_ml_extensions.len(), max_multiplicands + 1);
+                let claimed_sum = polynomial.sum_over_boolean_hypercube();
+
+                let (proof, prover_point) = TestSumcheck::prove(&polynomial, &mut FS::new()).unwrap();
+                let subclaim = TestSumcheck::verify(&polynomial.info(), claimed_sum, &proof, &mut FS::new()).unwrap();
+                assert_eq!(subclaim.point, prover_point);
+                assert_eq!(polynomial.evaluate(&subclaim.point), Some(subclaim.expected_evaluation));
+
+                // The proof must survive serialization.
+                let mut bytes = vec![];
+                proof.serialize(&mut bytes).unwrap();
+                assert_eq!(proof, SumcheckProof::deserialize(&mut &bytes[..]).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn reject_wrong_sum() {
+        let polynomial = random_polynomial(5, 3);
+        let claimed_sum = polynomial.sum_over_boolean_hypercube();
+        let (proof, _) = TestSumcheck::prove(&polynomial, &mut FS::new()).unwrap();
+
+        let wrong_sum = claimed_sum + Fr::one();
+        assert!(matches!(
+            TestSumcheck::verify(&polynomial.info(), wrong_sum, &proof, &mut FS::new()),
+            Err(SumcheckError::InconsistentRound(0))
+        ));
+
+        // A prover who tampers with a later round is caught by the final evaluation check.
+        let mut tampered = proof.clone();
+        tampered.round_evaluations[4][2] += Fr::one();
+        let subclaim = TestSumcheck::verify(&polynomial.info(), claimed_sum, &tampered, &mut FS::new()).unwrap();
+        assert_ne!(polynomial.evaluate(&subclaim.point), Some(subclaim.expected_evaluation));
+
+        let mut truncated = proof;
+        truncated.round_evaluations.pop();
+        assert!(matches!(
+            TestSumcheck::verify(&polynomial.info(), claimed_sum, &truncated, &mut FS::new()),
+            Err(SumcheckError::InvalidNumberOfRounds(5, 4))
+        ));
+    }
+
+    #[test]
+    fn interpolation() {
+        let rng = &mut thread_rng();
+        for degree in 0..6 {
+            let coefficients: Vec<_> = (0..=degree).map(|_| Fr::rand(rng)).collect();
+            let evaluate = |x: Fr| coefficients.iter().rev().fold(Fr::zero(), |acc, c| acc * x + c);
+            let evaluations: Vec<_> = (0..=degree).map(|i| evaluate(Fr::from(i as u64))).collect();
+
+            let point = Fr::rand(rng);
+            assert_eq!(interpolate_at(&evaluations, point), evaluate(point));
+            assert_eq!(interpolate_at(&evaluations, Fr::from(2u64)), evaluate(Fr::from(2u64)));
+        }
+    }
+
+    #[test]
+    fn product_of_distinct_polynomials() {
+        let rng = &mut thread_rng();
+        let f = DenseMultilinearExtension::<Fr>::rand(4, rng);
+        let g = DenseMultilinearExtension::<Fr>::rand(4, rng);
+        let expected: Fr = f.evaluations().iter().zip(g.evaluations()).map(|(a, b)| *a * b).sum();
+
+        let mut polynomial = ListOfProductsOfPolynomials::new(4);
+        polynomial.add_product([Arc::new(f.clone()), Arc::new(g.clone())], Fr::one());
+        assert_eq!(polynomial.sum_over_boolean_hypercube(), expected);
+
+        let (proof, _) = TestSumcheck::prove(&polynomial, &mut FS::new()).unwrap();
+        let subclaim = TestSumcheck::verify(&polynomial.info(), expected, &proof, &mut FS::new()).unwrap();
+        let f_eval = f.evaluate(&subclaim.point).unwrap();
+        let g_eval = g.evaluate(&subclaim.point).unwrap();
+        assert_eq!(f_eval * g_eval, subclaim.expected_evaluation);
+    }
+}
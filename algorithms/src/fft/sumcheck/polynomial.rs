@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::fft::{DenseMultilinearExtension, MultilinearExtension};
+use snarkvm_fields::Field;
+use snarkvm_utilities::{sync::Arc, vec::Vec};
+
+/// The shape of a [`ListOfProductsOfPolynomials`], which is all the sumcheck verifier needs to know.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PolynomialInfo {
+    /// The maximum number of multilinear polynomials in a product.
+    pub max_multiplicands: usize,
+    /// The number of variables of the polynomial.
+    pub num_variables: usize,
+}
+
+/// A sum of products of multilinear polynomials, `∑_j c_j * ∏_k f_{j, k}(x)`,
+/// which is the polynomial whose sum over the boolean hypercube is proven by the sumcheck protocol.
+///
+/// Each multilinear polynomial is stored once, so a polynomial that appears in several products
+/// is only fixed once per round by the prover.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListOfProductsOfPolynomials<F: Field> {
+    /// The maximum number of multilinear polynomials in a product.
+    pub max_multiplicands: usize,
+    /// The number of variables of the polynomial.
+    pub num_variables: usize,
+    /// The coefficient of each product, and the indices of its polynomials in `flattened_ml_extensions`.
+    pub products: Vec<(F, Vec<usize>)>,
+    /// The distinct multilinear polynomials appearing in the products.
+    pub flattened_ml_extensions: Vec<Arc<DenseMultilinearExtension<F>>>,
+}
+
+impl<F: Field> ListOfProductsOfPolynomials<F> {
+    /// Returns the empty sum of products in `num_variables` variables.
+    pub fn new(num_variables: usize) -> Self {
+        Self { max_multiplicands: 0, num_variables, products: Vec::new(), flattened_ml_extensions: Vec::new() }
+    }
+
+    /// Returns the shape of `self`.
+    pub fn info(&self) -> PolynomialInfo {
+        PolynomialInfo { max_multiplicands: self.max_multiplicands, num_variables: self.num_variables }
+    }
+
+    /// Adds the product `coefficient * ∏_k product[k]` to `self`.
+    ///
+    /// Panics if the product is empty or a polynomial has the wrong number of variables.
+    pub fn add_product(
+        &mut self,
+        product: impl IntoIterator<Item = Arc<DenseMultilinearExtension<F>>>,
+        coefficient: F,
+    ) {
+        let product: Vec<_> = product.into_iter().collect();
+        assert!(!product.is_empty(), "A product must have at least one multiplicand");
+        self.max_multiplicands = self.max_multiplicands.max(product.len());
+
+        let mut indices = Vec::with_capacity(product.len());
+        for polynomial in product {
+            assert_eq!(polynomial.num_vars(), self.num_variables, "The product has the wrong number of variables");
+            let index = match self.flattened_ml_extensions.iter().position(|p| Arc::ptr_eq(p, &polynomial)) {
+                Some(index) => index,
+                None => {
+                    self.flattened_ml_extensions.push(polynomial);
+                    self.flattened_ml_extensions.len() - 1
+                }
+            };
+            indices.push(index);
+        }
+        self.products.push((coefficient, indices));
+    }
+
+    /// Evaluates `self` at the given `point`, or returns `None` if the point has the wrong number of variables.
+    pub fn evaluate(&self, point: &[F]) -> Option<F> {
+        let evaluations = self.flattened_ml_extensions.iter().map(|p| p.evaluate(point)).collect::<Option<Vec<_>>>()?;
+        Some(
+            self.products
+                .iter()
+                .map(|(coefficient, indices)| indices.iter().fold(*coefficient, |acc, i| acc * evaluations[*i]))
+                .sum(),
+        )
+    }
+
+    /// Returns the sum of `self` over the boolean hypercube.
+    pub fn sum_over_boolean_hypercube(&self) -> F {
+        (0..(1 << self.num_variables))
+            .map(|x| {
+                self.products
+                    .iter()
+                    .map(|(coefficient, indices)| {
+                        indices
+                            .iter()
+                            .fold(*coefficient, |acc, i| acc * self.flattened_ml_extensions[*i].evaluations()[x])
+                    })
+                    .sum::<F>()
+            })
+            .sum()
+    }
+}
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::module_inception)]
 #![allow(clippy::type_complexity)]
 
@@ -24,8 +25,6 @@ extern crate alloc;
 #[allow(unused_imports)]
 #[macro_use]
 extern crate aleo_std;
-#[macro_use]
-extern crate thiserror;
 
 pub use snarkvm_utilities::{cfg_chunks, cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut, cfg_reduce};
 
@@ -18,15 +18,23 @@ use crate::{
     errors::MerkleError,
     traits::{MerkleParameters, CRH},
 };
-use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
-
-use anyhow::Result;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::{
+use snarkvm_utilities::{
+    error,
+    format,
     io::{Read, Result as IoResult, Write},
+    string::String,
     sync::Arc,
+    vec,
+    vec::Vec,
+    FromBytes,
+    FromBytesDeserializer,
+    ToBytes,
+    ToBytesSerializer,
 };
 
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 pub type MerkleTreeDigest<P> = <<P as MerkleParameters>::TwoToOneCRH as CRH>::Output;
 
 /// Stores the hashes of a particular path (in order) from leaf to root.
@@ -19,8 +19,7 @@ use crate::{
     merkle_tree::{MerklePath, MerkleTreeDigest},
     traits::{MerkleParameters, CRH},
 };
-use snarkvm_utilities::ToBytes;
-use std::sync::Arc;
+use snarkvm_utilities::{sync::Arc, vec, vec::Vec, ToBytes};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
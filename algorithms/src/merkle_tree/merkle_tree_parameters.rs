@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{MerkleParameters, CRH};
+use snarkvm_utilities::string::String;
 
 /// Defines a Merkle tree using the provided hash and depth.
 #[derive(Clone, PartialEq, Eq, Debug)]
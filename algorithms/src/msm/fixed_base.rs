@@ -16,7 +16,7 @@
 
 use snarkvm_curves::traits::ProjectiveCurve;
 use snarkvm_fields::{FieldParameters, PrimeField};
-use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, ToBits};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, vec, vec::Vec, ToBits};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -16,7 +16,7 @@
 
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_into_iter, BigInteger};
+use snarkvm_utilities::{cfg_into_iter, vec, vec::Vec, BigInteger};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -364,14 +364,21 @@ fn batched_window<G: AffineCurve>(
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+    msm_with_num_bits(bases, scalars, <G::ScalarField as PrimeField>::size_in_bits())
+}
+
+/// Performs variable base MSM for scalars with at most `num_bits` bits.
+pub(super) fn msm_with_num_bits<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    num_bits: usize,
+) -> G::Projective {
     // Determine the bucket size `c` (chosen empirically).
     let c = match scalars.len() < 32 {
         true => 1,
         false => crate::msm::ln_without_floats(scalars.len()) + 2,
     };
 
-    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
-
     // Each window is of size `c`.
     // We divide up the bits 0..num_bits into windows of size `c`, and
     // in parallel process each such window.
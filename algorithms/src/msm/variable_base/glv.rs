@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::batched;
+use snarkvm_curves::{
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::GLVParameters,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_iter, vec::Vec, BigInteger};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Performs variable base MSM using the GLV endomorphism.
+///
+/// Each scalar `k` is split into `k1 + k2 * λ`, so that the MSM becomes one over twice as many bases,
+/// `±P` and `±φ(P)`, with scalars of half the bit length.
+pub fn msm<P: GLVParameters>(
+    bases: &[Affine<P>],
+    scalars: &[<P::ScalarField as PrimeField>::BigInteger],
+) -> Projective<P> {
+    // The decomposition requires canonical scalars, so non-canonical ones are handled by the batched method.
+    let field_scalars = match cfg_iter!(scalars).map(|s| P::ScalarField::from_repr(*s)).collect::<Option<Vec<_>>>() {
+        Some(field_scalars) => field_scalars,
+        None => return batched::msm(bases, scalars),
+    };
+
+    let decomposed: Vec<_> = cfg_iter!(bases)
+        .zip(field_scalars)
+        .map(|(base, scalar)| {
+            let ((k1_is_negative, k1), (k2_is_negative, k2)) = P::scalar_decomposition(&scalar);
+            let endomorphism = P::endomorphism_affine(base);
+            [
+                (if k1_is_negative { -*base } else { *base }, k1.to_repr()),
+                (if k2_is_negative { -endomorphism } else { endomorphism }, k2.to_repr()),
+            ]
+        })
+        .collect();
+    let (glv_bases, glv_scalars): (Vec<_>, Vec<_>) = decomposed.into_iter().flatten().unzip();
+
+    let num_bits = cfg_iter!(glv_scalars).map(|scalar| scalar.num_bits() as usize).max().unwrap_or(0);
+    match num_bits {
+        0 => Projective::zero(),
+        _ => batched::msm_with_num_bits(&glv_bases, &glv_scalars, num_bits),
+    }
+}
@@ -15,18 +15,29 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod batched;
+mod glv;
 mod standard;
 
+mod precomputed;
+pub use precomputed::*;
+
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 mod cuda;
 
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
+use snarkvm_curves::{
+    bls12_377::{Fr, G1Affine, G1Projective},
+    traits::AffineCurve,
+};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{cfg_iter, vec::Vec};
+
+use core::any::{Any, TypeId};
 
-use core::any::TypeId;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 use core::sync::atomic::{AtomicBool, Ordering};
@@ -38,7 +49,7 @@ pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-        // For BLS12-377, we perform variable base MSM using a batched addition technique.
+        // For BLS12-377, we perform variable base MSM using the GLV endomorphism and a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
             if !HAS_CUDA_FAILED.load(Ordering::SeqCst) {
@@ -50,7 +61,14 @@ impl VariableBase {
                     }
                 }
             }
-            batched::msm(bases, scalars)
+            // Only the bases with a matching scalar contribute, so skip copying the rest.
+            let bases = &bases[..core::cmp::min(bases.len(), scalars.len())];
+            // `G` is `G1Affine`, as checked above, so the bases, scalars and result downcast to their BLS12-377 types.
+            let bases: Vec<G1Affine> = cfg_iter!(bases).map(|base| *(base as &dyn Any).downcast_ref().unwrap()).collect();
+            let scalars: Vec<<Fr as PrimeField>::BigInteger> =
+                cfg_iter!(scalars).map(|scalar| *(scalar as &dyn Any).downcast_ref().unwrap()).collect();
+            let result: G1Projective = glv::msm(&bases, &scalars);
+            *(&result as &dyn Any).downcast_ref::<G::Projective>().unwrap()
         }
         // For all other curves, we perform variable base MSM using Pippenger's algorithm.
         else {
@@ -110,6 +128,24 @@ mod tests {
 
         let candidate = batched::msm(bases.as_slice(), scalars.as_slice());
         assert_eq!(naive_a, candidate);
+
+        let candidate = glv::msm(bases.as_slice(), scalars.as_slice());
+        assert_eq!(naive_a, candidate);
+    }
+
+    #[test]
+    fn test_glv_msm() {
+        let mut rng = test_rng();
+        for size in [1, 2, 17, 300] {
+            let (bases, mut scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, size);
+            let expected = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice());
+            assert_eq!(glv::msm(bases.as_slice(), scalars.as_slice()), expected);
+
+            // Small and zero scalars.
+            scalars.iter_mut().enumerate().for_each(|(i, s)| *s = Fr::from(i as u64).to_repr());
+            let expected = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice());
+            assert_eq!(glv::msm(bases.as_slice(), scalars.as_slice()), expected);
+        }
     }
 
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, serialize::*, vec, BigInteger, Read, SerializationError, Write};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The largest supported window size, which bounds the number of buckets to `2^MAX_WINDOW_SIZE`.
+const MAX_WINDOW_SIZE: usize = 24;
+
+/// Precomputed multiples of a fixed list of bases, for variable-base MSMs that are repeatedly
+/// computed over the same bases (such as commitments with a fixed SRS).
+///
+/// For a window size `c`, the table stores `2^(c * j) * base` for every base and every window `j`
+/// of a scalar. An MSM then adds each base multiple into the bucket of its window digit,
+/// which removes the doublings and the per-window bucket aggregation of Pippenger's algorithm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrecomputedBases<G: AffineCurve> {
+    /// The window size `c`, in bits.
+    window_size: usize,
+    /// The number of windows of size `c` a scalar is split into.
+    num_windows: usize,
+    /// The multiples `2^(c * j) * base` for `j` in `0..num_windows`, stored base by base.
+    multiples: Vec<G>,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Returns the window size used by `PrecomputedBases::new` for the given number of bases.
+    pub fn get_window_size(num_bases: usize) -> usize {
+        match num_bases < 32 {
+            true => 3,
+            false => (crate::msm::ln_without_floats(num_bases) + 2).min(MAX_WINDOW_SIZE),
+        }
+    }
+
+    /// Precomputes the multiples of the given `bases`, with a window size chosen for their number.
+    pub fn new(bases: &[G]) -> Self {
+        Self::with_window_size(bases, Self::get_window_size(bases.len()))
+    }
+
+    /// Precomputes the multiples of the given `bases` for the given window size.
+    ///
+    /// Panics if the window size is zero or larger than the maximum supported window size.
+    pub fn with_window_size(bases: &[G], window_size: usize) -> Self {
+        assert!((1..=MAX_WINDOW_SIZE).contains(&window_size), "Invalid window size {}", window_size);
+        let num_windows = Self::num_windows(window_size);
+
+        let precompute_time =
+            start_timer!(|| format!("Precomputing {} multiples of {} bases", num_windows, bases.len()));
+        let multiples = cfg_iter!(bases)
+            .flat_map(|base| {
+                let mut multiple = base.to_projective();
+                let mut multiples = Vec::with_capacity(num_windows);
+                for _ in 0..num_windows {
+                    multiples.push(multiple);
+                    for _ in 0..window_size {
+                        multiple.double_in_place();
+                    }
+                }
+                G::Projective::batch_normalization_into_affine(multiples)
+            })
+            .collect();
+        end_timer!(precompute_time);
+
+        Self { window_size, num_windows, multiples }
+    }
+
+    /// Returns the number of windows a scalar is split into for the given window size.
+    fn num_windows(window_size: usize) -> usize {
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        (num_bits + window_size - 1) / window_size
+    }
+
+    /// Returns the window size, in bits.
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Returns the number of bases in the table.
+    pub fn num_bases(&self) -> usize {
+        self.multiples.len() / self.num_windows
+    }
+
+    /// Returns `∑_i scalars[i] * bases[offset + i]`.
+    ///
+    /// Panics if there are fewer than `offset + scalars.len()` bases in the table.
+    pub fn msm(&self, offset: usize, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        assert!(offset + scalars.len() <= self.num_bases(), "Not enough precomputed bases for the MSM");
+        let multiples = &self.multiples[offset * self.num_windows..(offset + scalars.len()) * self.num_windows];
+
+        // Each chunk accumulates into its own buckets, so a chunk should contain enough scalars
+        // to amortize the final aggregation of its buckets.
+        #[cfg(feature = "parallel")]
+        let max_num_chunks = snarkvm_utilities::parallel::max_available_threads();
+        #[cfg(not(feature = "parallel"))]
+        let max_num_chunks = 1;
+        let num_chunks = (scalars.len() >> self.window_size).clamp(1, max_num_chunks);
+        let chunk_size = (scalars.len() + num_chunks - 1) / num_chunks;
+
+        cfg_into_iter!(0..num_chunks)
+            .map(|i| {
+                let start = (i * chunk_size).min(scalars.len());
+                let end = ((i + 1) * chunk_size).min(scalars.len());
+                self.msm_chunk(&multiples[start * self.num_windows..end * self.num_windows], &scalars[start..end])
+            })
+            .sum()
+    }
+
+    fn msm_chunk(&self, multiples: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
+        let mut buckets = vec![G::Projective::zero(); (1 << self.window_size) - 1];
+        for (scalar, multiples) in scalars.iter().zip(multiples.chunks(self.num_windows)) {
+            let mut scalar = *scalar;
+            for multiple in multiples {
+                let digit = scalar.as_ref()[0] % (1 << self.window_size);
+                if digit != 0 {
+                    buckets[(digit - 1) as usize].add_assign_mixed(multiple);
+                }
+                scalar.divn(self.window_size as u32);
+            }
+        }
+
+        let mut res = G::Projective::zero();
+        for running_sum in buckets.into_iter().rev().scan(G::Projective::zero(), |sum, b| {
+            *sum += b;
+            Some(*sum)
+        }) {
+            res += running_sum;
+        }
+        res
+    }
+}
+
+impl<G: AffineCurve> CanonicalSerialize for PrecomputedBases<G> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        (self.window_size as u32).serialize(writer)?;
+        self.multiples.serialize(writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        (self.window_size as u32).serialized_size() + self.multiples.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        (self.window_size as u32).serialize_uncompressed(writer)?;
+        self.multiples.serialize_uncompressed(writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        (self.window_size as u32).uncompressed_size() + self.multiples.uncompressed_size()
+    }
+}
+
+impl<G: AffineCurve> CanonicalDeserialize for PrecomputedBases<G> {
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let window_size = u32::deserialize(reader)? as usize;
+        let multiples = Vec::<G>::deserialize(reader)?;
+        Self::from_parts(window_size, multiples)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let window_size = u32::deserialize_uncompressed(reader)? as usize;
+        let multiples = Vec::<G>::deserialize_uncompressed(reader)?;
+        Self::from_parts(window_size, multiples)
+    }
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Checks that the deserialized `multiples` form a table for the given window size.
+    fn from_parts(window_size: usize, multiples: Vec<G>) -> Result<Self, SerializationError> {
+        if !(1..=MAX_WINDOW_SIZE).contains(&window_size) {
+            return Err(SerializationError::InvalidData);
+        }
+        let num_windows = Self::num_windows(window_size);
+        if multiples.len() % num_windows != 0 {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self { window_size, num_windows, multiples })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msm::VariableBase;
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    #[test]
+    fn test_precomputed_msm() {
+        let mut rng = test_rng();
+        let bases = (0..300).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+        let scalars = (0..300).map(|_| Fr::rand(&mut rng).to_repr()).collect::<Vec<_>>();
+
+        for window_size in [1, 3, 8, 13] {
+            let table = PrecomputedBases::with_window_size(&bases, window_size);
+            assert_eq!(table.num_bases(), bases.len());
+            for (offset, len) in [(0, 300), (0, 1), (17, 200), (299, 1)] {
+                let expected = VariableBase::msm(&bases[offset..offset + len], &scalars[..len]);
+                assert_eq!(table.msm(offset, &scalars[..len]), expected);
+            }
+            assert!(table.msm(300, &[]).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_precomputed_bases_serialization() {
+        let mut rng = test_rng();
+        let bases = (0..20).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+        let table = PrecomputedBases::new(&bases);
+
+        let mut bytes = Vec::new();
+        table.serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), table.serialized_size());
+        assert_eq!(PrecomputedBases::<G1Affine>::deserialize(&mut &bytes[..]).unwrap(), table);
+
+        let mut bytes = Vec::new();
+        table.serialize_uncompressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), table.uncompressed_size());
+        assert_eq!(PrecomputedBases::<G1Affine>::deserialize_uncompressed(&mut &bytes[..]).unwrap(), table);
+
+        // A table whose size does not match its window size is rejected.
+        let mut bytes = Vec::new();
+        PrecomputedBases { window_size: 3, num_windows: 1, multiples: bases }.serialize(&mut bytes).unwrap();
+        assert!(PrecomputedBases::<G1Affine>::deserialize(&mut &bytes[..]).is_err());
+    }
+}
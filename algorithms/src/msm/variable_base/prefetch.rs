@@ -43,7 +43,7 @@ macro_rules! prefetch_slice_write {
 }
 
 const fn n_lines<T>() -> isize {
-    ((std::mem::size_of::<T>() - 1) / 64 + 1) as isize
+    ((core::mem::size_of::<T>() - 1) / 64 + 1) as isize
 }
 
 #[macro_export]
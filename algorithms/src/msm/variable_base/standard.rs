@@ -16,7 +16,7 @@
 
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_into_iter, BigInteger};
+use snarkvm_utilities::{cfg_into_iter, vec, vec::Vec, BigInteger};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
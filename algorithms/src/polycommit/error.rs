@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::snark::marlin::FiatShamirError;
+use snarkvm_utilities::string::String;
 
 /// The error type for `PolynomialCommitment`.
 #[derive(Debug)]
@@ -70,6 +71,14 @@ pub enum PCError {
     /// The degree provided to `trim` was too large.
     TrimmingDegreeTooLarge,
 
+    /// The universal parameters could not be extended to the required degree.
+    UnavailableDegree {
+        /// The required degree.
+        degree: usize,
+        /// The reason the degree is unavailable.
+        reason: String,
+    },
+
     /// The provided equation contained multiple polynomials, of which least one
     /// had a strict degree bound.
     EquationHasDegreeBounds(String),
@@ -133,6 +142,9 @@ impl core::fmt::Display for PCError {
                 hiding_poly_degree, num_powers
             ),
             PCError::TrimmingDegreeTooLarge => write!(f, "the degree provided to `trim` was too large"),
+            PCError::UnavailableDegree { degree, reason } => {
+                write!(f, "the universal parameters could not be extended to degree {}: {}", degree, reason)
+            }
             PCError::EquationHasDegreeBounds(e) => {
                 write!(f, "the eqaution \"{}\" contained degree-bounded polynomials", e)
             }
@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    polycommit::{PCProof, PCRandomness},
+    Prepare,
+};
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField, Zero};
+use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes, ToMinimalBits};
+
+/// `UniversalParams` are the universal parameters for the inner-product-argument scheme.
+/// They consist only of group elements sampled transparently by hashing to the curve.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct UniversalParams<G: AffineCurve> {
+    /// The generators used to commit to the coefficients of a polynomial.
+    pub comm_key: Vec<G>,
+    /// The generator used to hide commitments.
+    pub h: G,
+    /// The generator used to bind the inner product in evaluation proofs.
+    pub s: G,
+}
+
+impl<G: AffineCurve> FromBytes for UniversalParams<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error("could not deserialize UniversalParams"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for UniversalParams<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut writer).map_err(|_| error("could not serialize UniversalParams"))
+    }
+}
+
+impl<G: AffineCurve> UniversalParams<G> {
+    /// Returns the maximum degree of polynomials that can be committed to.
+    pub fn max_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+/// `CommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKey<G: AffineCurve> {
+    /// The generators used to commit to the coefficients of a polynomial.
+    /// The number of generators is always a power of two.
+    pub comm_key: Vec<G>,
+    /// The generator used to hide commitments.
+    pub h: G,
+    /// The generator used to bind the inner product in evaluation proofs.
+    pub s: G,
+    /// The maximum degree supported by the `UniversalParams` from which `self` was derived.
+    pub max_degree: usize,
+}
+
+impl<G: AffineCurve> FromBytes for CommitterKey<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error("could not deserialize CommitterKey"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for CommitterKey<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut writer).map_err(|_| error("could not serialize CommitterKey"))
+    }
+}
+
+impl<G: AffineCurve> CommitterKey<G> {
+    /// Returns the maximum degree of polynomials that can be committed to with `self`.
+    /// Shifted polynomials, used to enforce degree bounds, are aligned to this degree.
+    pub fn supported_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+/// `VerifierKey` is used to check evaluation proofs for a given commitment.
+/// The verifier recomputes the folded generators, so it holds the same key as the committer.
+pub type VerifierKey<G> = CommitterKey<G>;
+
+/// `PreparedVerifierKey` is the verifier key, prepared for checking evaluation proofs.
+pub type PreparedVerifierKey<G> = CommitterKey<G>;
+
+impl<G: AffineCurve> Prepare for CommitterKey<G> {
+    type Prepared = Self;
+
+    /// The verifier key requires no preparation.
+    fn prepare(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// `Commitment` commits to a polynomial. It is output by `InnerProductArgPC::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<G: AffineCurve> {
+    /// The Pedersen commitment to the coefficients of the polynomial.
+    pub comm: G,
+    /// The commitment to the polynomial shifted up to the maximum supported degree.
+    /// This is `None` if the polynomial has no degree bound.
+    pub shifted_comm: Option<G>,
+}
+
+impl<G: AffineCurve> FromBytes for Commitment<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error("could not deserialize Commitment"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for Commitment<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut writer).map_err(|_| error("could not serialize Commitment"))
+    }
+}
+
+impl<G: AffineCurve> ToMinimalBits for Commitment<G> {
+    fn to_minimal_bits(&self) -> Vec<bool> {
+        let mut bits = self.comm.to_minimal_bits();
+        if let Some(shifted_comm) = &self.shifted_comm {
+            bits.extend(shifted_comm.to_minimal_bits());
+        }
+        bits
+    }
+}
+
+impl<F: Field, G: AffineCurve + ToConstraintField<F>> ToConstraintField<F> for Commitment<G> {
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        let mut elements = self.comm.to_field_elements()?;
+        if let Some(shifted_comm) = &self.shifted_comm {
+            elements.extend(shifted_comm.to_field_elements()?);
+        }
+        Ok(elements)
+    }
+}
+
+/// `PreparedCommitment` is the commitment, prepared for checking evaluation proofs.
+pub type PreparedCommitment<G> = Commitment<G>;
+
+impl<G: AffineCurve> Prepare for Commitment<G> {
+    type Prepared = Self;
+
+    /// The commitment requires no preparation.
+    fn prepare(&self) -> Self {
+        *self
+    }
+}
+
+/// `Randomness` hides the polynomial inside a commitment. It is output by `InnerProductArgPC::commit`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Randomness<G: AffineCurve> {
+    /// The randomness for the commitment to the polynomial.
+    pub rand: G::ScalarField,
+    /// The randomness for the commitment to the shifted polynomial.
+    /// This is `None` if the polynomial has no degree bound.
+    pub shifted_rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> PCRandomness for Randomness<G> {
+    fn empty() -> Self {
+        Self { rand: G::ScalarField::zero(), shifted_rand: None }
+    }
+}
+
+/// `Proof` is an evaluation proof that is output by `InnerProductArgPC::batch_open`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<G: AffineCurve> {
+    /// The left commitments of each round of the inner-product argument.
+    pub l_vec: Vec<G>,
+    /// The right commitments of each round of the inner-product argument.
+    pub r_vec: Vec<G>,
+    /// The final, folded coefficient of the combined polynomial.
+    pub c: G::ScalarField,
+    /// The commitment to the polynomial that hides the combined polynomial.
+    /// This is `None` if none of the opened polynomials are hiding.
+    pub hiding_comm: Option<G>,
+    /// The randomness of the combined commitment, including the hiding polynomial.
+    /// This is `None` if none of the opened polynomials are hiding.
+    pub rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> Proof<G> {
+    /// Returns `true` if the proof hides the evaluated polynomials.
+    pub fn is_hiding(&self) -> bool {
+        self.hiding_comm.is_some()
+    }
+}
+
+/// Evaluation proof at a query set.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<G: AffineCurve>(pub(crate) Vec<Proof<G>>);
+
+impl<G: AffineCurve> PCProof for BatchProof<G> {
+    fn is_hiding(&self) -> bool {
+        self.0.iter().any(|p| p.is_hiding())
+    }
+}
+
+impl<G: AffineCurve> FromBytes for BatchProof<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error("could not deserialize BatchProof"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for BatchProof<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut writer).map_err(|_| error("could not serialize BatchProof"))
+    }
+}
+
+/// A proof of satisfaction of linear combinations.
+/// The linear combinations are opened as a batch of combined commitments.
+pub type BatchLCProof<G> = BatchProof<G>;
@@ -0,0 +1,868 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::{hash_to_curve, sha256::sha256},
+    fft::DensePolynomial,
+    msm::VariableBase,
+    polycommit::{
+        optional_rng::OptionalRng,
+        sonic_pc::{
+            Evaluations,
+            LabeledCommitment,
+            LabeledPolynomial,
+            LabeledPolynomialWithBasis,
+            LinearCombination,
+            PolynomialWithBasis,
+            QuerySet,
+        },
+        PCError,
+        PCRandomness,
+        PolynomialCommitment,
+    },
+    snark::marlin::{params::OptimizationType, FiatShamirRng},
+};
+use itertools::Itertools;
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{
+    borrow::ToOwned,
+    cfg_into_iter,
+    cfg_iter,
+    cfg_iter_mut,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    to_bytes_le,
+    vec,
+    vec::Vec,
+    ToBytes,
+    UniformRand,
+};
+
+use core::{
+    convert::TryInto,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use rand::{rngs::StdRng, SeedableRng};
+use rand_core::RngCore;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// Polynomial commitment based on the inner-product argument of [[BCCGP16]][bccgp],
+/// in the form used by [[BGH19, "Halo"]][halo] to open Pedersen commitments to polynomials.
+/// The scheme has a transparent setup, since its parameters are obtained by hashing to the curve,
+/// and it does not require a pairing. Evaluation proofs are logarithmic in the degree,
+/// while checking them requires a multi-scalar multiplication that is linear in the degree.
+///
+/// Degree bounds are enforced by committing to the polynomial shifted up to the maximum
+/// supported degree, and the (optional) hiding property of evaluation proofs follows
+/// the approach described in [[BCMS20, "PCD"]][pcdas].
+///
+/// [bccgp]: https://eprint.iacr.org/2016/263
+/// [halo]: https://eprint.iacr.org/2019/1021
+/// [pcdas]: https://eprint.iacr.org/2020/499
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InnerProductArgPC<G: AffineCurve> {
+    _curve: PhantomData<G>,
+}
+
+impl<G: AffineCurve> InnerProductArgPC<G> {
+    /// The personalization string for this protocol.
+    /// Used to derive the generators of the universal parameters.
+    pub const PROTOCOL_NAME: &'static str = "AleoInnerProductArgPC0";
+}
+
+impl<G> PolynomialCommitment<G::ScalarField, G::BaseField> for InnerProductArgPC<G>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+{
+    type BatchLCProof = BatchLCProof<G>;
+    type BatchProof = BatchProof<G>;
+    type Commitment = Commitment<G>;
+    type CommitterKey = CommitterKey<G>;
+    type PreparedCommitment = PreparedCommitment<G>;
+    type PreparedVerifierKey = PreparedVerifierKey<G>;
+    type Randomness = Randomness<G>;
+    type UniversalParams = UniversalParams<G>;
+    type VerifierKey = VerifierKey<G>;
+
+    /// Samples the generators of the universal parameters by hashing to the curve.
+    /// The setup is transparent, so `rng` is not used.
+    fn setup<R: RngCore>(max_degree: usize, _rng: &mut R) -> Result<UniversalParams<G>, PCError> {
+        if max_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let setup_time = start_timer!(|| format!("IPA-PC::Setup with max_degree {}", max_degree));
+
+        let num_generators = (max_degree + 1).next_power_of_two();
+        let mut comm_key = Self::sample_generators(num_generators + 2);
+        let s = comm_key.pop().unwrap();
+        let h = comm_key.pop().unwrap();
+
+        end_timer!(setup_time);
+        Ok(UniversalParams { comm_key, h, s })
+    }
+
+    fn trim(
+        pp: &UniversalParams<G>,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        _supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(CommitterKey<G>, VerifierKey<G>), PCError> {
+        let trim_time = start_timer!(|| "Trimming public parameters");
+        if supported_degree > pp.max_degree() {
+            return Err(PCError::TrimmingDegreeTooLarge);
+        }
+
+        // The inner-product argument halves the committer key in each round,
+        // so the number of generators is rounded up to a power of two.
+        let num_generators = (supported_degree + 1).next_power_of_two();
+
+        if let Some(&highest_enforced_degree_bound) = enforced_degree_bounds.and_then(|bounds| bounds.iter().max()) {
+            if highest_enforced_degree_bound >= num_generators {
+                return Err(PCError::UnsupportedDegreeBound(highest_enforced_degree_bound));
+            }
+        }
+
+        // Polynomials in Lagrange basis are interpolated before committing to them,
+        // so it suffices to check that their domains fit in the committer key.
+        for size in supported_lagrange_sizes {
+            if crate::fft::EvaluationDomain::<G::ScalarField>::new_with_size(size).is_none() {
+                return Err(PCError::UnsupportedLagrangeBasisSize(size));
+            }
+            if size > num_generators {
+                return Err(PCError::LagrangeBasisSizeIsTooLarge);
+            }
+        }
+
+        let ck = CommitterKey {
+            comm_key: pp.comm_key[..num_generators].to_vec(),
+            h: pp.h,
+            s: pp.s,
+            max_degree: pp.max_degree(),
+        };
+        let vk = ck.clone();
+
+        end_timer!(trim_time);
+        Ok((ck, vk))
+    }
+
+    fn is_committer_key_for(ck: &CommitterKey<G>, vk: &VerifierKey<G>) -> bool {
+        ck.supported_degree() == vk.supported_degree() && ck.max_degree == vk.max_degree
+    }
+
+    /// Outputs a commitments to `polynomials`. If `polynomials[i].is_hiding()`,
+    /// then the `i`-th commitment is hiding, and `rng` must not be `None`.
+    ///
+    /// If for some `i`, `polynomials[i].is_hiding() == false`, then the
+    /// corresponding randomness is `Randomness<G>::empty()`.
+    ///
+    /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
+    /// polynomial will have the corresponding degree bound enforced.
+    fn commit_with_terminator<'a>(
+        ck: &CommitterKey<G>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'a, G::ScalarField>>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<G>>>, Vec<Randomness<G>>), PCError> {
+        let rng = &mut OptionalRng(rng);
+        let commit_time = start_timer!(|| "Committing to polynomials");
+        let mut labeled_comms = Vec::new();
+        let mut randomness = Vec::new();
+
+        for p in polynomials {
+            if terminator.load(Ordering::Relaxed) {
+                return Err(PCError::Terminated);
+            }
+            let label = p.label().to_string();
+            let degree_bound = p.degree_bound();
+            let polynomial = Self::into_monomial_basis(&p);
+            Self::check_degrees_and_bounds(ck.supported_degree(), &label, polynomial.degree(), degree_bound)?;
+
+            add_to_trace!(|| "PC::Commit", || format!(
+                "Polynomial {} of degree {}, degree bound {:?}, and hiding bound {:?}",
+                label,
+                polynomial.degree(),
+                degree_bound,
+                p.hiding_bound(),
+            ));
+
+            let mut sample_rand = || -> Result<_, PCError> {
+                let rng = rng.0.as_mut().ok_or(PCError::MissingRng)?;
+                Ok(G::ScalarField::rand(rng))
+            };
+            let rand = if p.is_hiding() {
+                let rand = sample_rand()?;
+                let shifted_rand = degree_bound.map(|_| sample_rand()).transpose()?;
+                Randomness { rand, shifted_rand }
+            } else {
+                Randomness::empty()
+            };
+
+            let comm = Self::cm_commit(&ck.comm_key, &polynomial.coeffs, Some((ck.h, rand.rand)));
+            let shifted_comm = degree_bound.map(|degree_bound| {
+                let shift = ck.supported_degree() - degree_bound;
+                let shifted_rand = rand.shifted_rand.unwrap_or_else(G::ScalarField::zero);
+                Self::cm_commit(&ck.comm_key[shift..], &polynomial.coeffs, Some((ck.h, shifted_rand)))
+            });
+
+            let commitment = Commitment { comm: comm.to_affine(), shifted_comm: shifted_comm.map(|c| c.to_affine()) };
+            labeled_comms.push(LabeledCommitment::new(label, commitment, degree_bound));
+            randomness.push(rand);
+        }
+
+        end_timer!(commit_time);
+        Ok((labeled_comms, randomness))
+    }
+
+    /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
+    /// of the polynomials at the points in the query set.
+    fn batch_open<'a, S: FiatShamirRng<G::ScalarField, G::BaseField>>(
+        ck: &CommitterKey<G>,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        rands: impl IntoIterator<Item = &'a Randomness<G>>,
+        fs_rng: &mut S,
+    ) -> Result<BatchProof<G>, PCError> {
+        let poly_rand: BTreeMap<_, _> = labeled_polynomials
+            .into_iter()
+            .zip_eq(rands)
+            .zip_eq(commitments)
+            .map(|((poly, r), _comm)| (poly.label(), (poly, r)))
+            .collect();
+
+        let open_time = start_timer!(|| format!(
+            "Opening {} polynomials at query set of size {}",
+            poly_rand.len(),
+            query_set.len(),
+        ));
+
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        let mut proofs = Vec::with_capacity(query_to_labels_map.len());
+        for (_point_name, (&query, labels)) in query_to_labels_map.into_iter() {
+            let mut query_polys = Vec::with_capacity(labels.len());
+            let mut query_rands = Vec::with_capacity(labels.len());
+
+            for label in labels {
+                let (polynomial, rand) =
+                    poly_rand.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                query_polys.push(*polynomial);
+                query_rands.push(*rand);
+            }
+
+            let proof_time = start_timer!(|| "Creating proof");
+            proofs.push(Self::open_at_point(ck, query_polys, query_rands, query, fs_rng)?);
+            end_timer!(proof_time);
+        }
+        end_timer!(open_time);
+
+        Ok(BatchProof(proofs))
+    }
+
+    fn batch_check<'a, S: FiatShamirRng<G::ScalarField, G::BaseField>>(
+        vk: &VerifierKey<G>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        values: &Evaluations<G::ScalarField>,
+        proof: &BatchProof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError> {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        if proof.0.len() != query_to_labels_map.len() {
+            return Ok(false);
+        }
+
+        let check_time = start_timer!(|| format!("Checking {} evaluation proofs", proof.0.len()));
+        for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
+            let mut comms_to_combine = Vec::with_capacity(labels.len());
+            let mut values_to_combine = Vec::with_capacity(labels.len());
+            for label in labels.into_iter() {
+                let commitment =
+                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                let v_i = values
+                    .get(&(label.clone(), *query))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                comms_to_combine.push(*commitment);
+                values_to_combine.push(*v_i);
+            }
+
+            if !Self::check_at_point(vk, comms_to_combine, values_to_combine, *query, p, fs_rng)? {
+                end_timer!(check_time);
+                return Ok(false);
+            }
+        }
+        end_timer!(check_time);
+
+        Ok(true)
+    }
+
+    fn open_combinations<'a, S: FiatShamirRng<G::ScalarField, G::BaseField>>(
+        ck: &CommitterKey<G>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<G::ScalarField>>,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        rands: impl IntoIterator<Item = &'a Randomness<G>>,
+        fs_rng: &mut S,
+    ) -> Result<BatchLCProof<G>, PCError> {
+        let label_map = polynomials
+            .into_iter()
+            .zip_eq(rands)
+            .zip_eq(commitments)
+            .map(|((p, r), c)| (p.label(), (p, r, c)))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut lc_polynomials = Vec::new();
+        let mut lc_randomness = Vec::new();
+        let mut lc_commitments = Vec::new();
+
+        for lc in linear_combinations {
+            let lc_label = lc.label().to_string();
+            let mut poly = DensePolynomial::zero();
+            let mut degree_bound = None;
+            let mut hiding_bound = None;
+
+            let mut randomness = Randomness::empty();
+            let mut coeffs_and_comms = Vec::new();
+
+            let num_polys = lc.len();
+            for (coeff, label) in lc.iter().filter(|(_, l)| !l.is_one()) {
+                let label: &String = label.try_into().expect("cannot be one!");
+                let &(cur_poly, cur_rand, cur_comm) =
+                    label_map.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                if num_polys == 1 && cur_poly.degree_bound().is_some() {
+                    assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
+                    degree_bound = cur_poly.degree_bound();
+                } else if cur_poly.degree_bound().is_some() {
+                    return Err(PCError::EquationHasDegreeBounds(lc_label));
+                }
+                // Some(_) > None, always.
+                hiding_bound = core::cmp::max(hiding_bound, cur_poly.hiding_bound());
+                poly += (*coeff, cur_poly.polynomial());
+                Self::accumulate_randomness(&mut randomness, *coeff, cur_rand);
+                coeffs_and_comms.push((*coeff, cur_comm.commitment()));
+            }
+
+            let lc_commitment = Self::combine_commitments(coeffs_and_comms);
+            lc_polynomials.push(LabeledPolynomial::new(lc_label.clone(), poly, degree_bound, hiding_bound));
+            lc_randomness.push(randomness);
+            lc_commitments.push(LabeledCommitment::new(lc_label, lc_commitment, degree_bound));
+        }
+
+        Self::batch_open(ck, lc_polynomials.iter(), lc_commitments.iter(), query_set, lc_randomness.iter(), fs_rng)
+    }
+
+    /// Checks that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `labeled_commitments`.
+    fn check_combinations<'a, S: FiatShamirRng<G::ScalarField, G::BaseField>>(
+        vk: &VerifierKey<G>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        evaluations: &Evaluations<G::ScalarField>,
+        proof: &BatchLCProof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError> {
+        let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
+
+        let mut lc_commitments = Vec::new();
+        let mut evaluations = evaluations.clone();
+
+        let lc_processing_time = start_timer!(|| "Combining commitments");
+        for lc in linear_combinations {
+            let lc_label = lc.label().to_string();
+            let num_polys = lc.len();
+
+            let mut degree_bound = None;
+            let mut coeffs_and_comms = Vec::new();
+
+            for (coeff, label) in lc.iter() {
+                if label.is_one() {
+                    for ((label, _), eval) in evaluations.iter_mut() {
+                        if label == &lc_label {
+                            *eval -= coeff;
+                        }
+                    }
+                } else {
+                    let label: &String = label.try_into().unwrap();
+                    let &cur_comm = label_comm_map
+                        .get(label as &str)
+                        .ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                    if num_polys == 1 && cur_comm.degree_bound().is_some() {
+                        assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
+                        degree_bound = cur_comm.degree_bound();
+                    } else if cur_comm.degree_bound().is_some() {
+                        return Err(PCError::EquationHasDegreeBounds(lc_label));
+                    }
+                    coeffs_and_comms.push((*coeff, cur_comm.commitment()));
+                }
+            }
+            let lc_commitment = Self::combine_commitments(coeffs_and_comms);
+            lc_commitments.push(LabeledCommitment::new(lc_label, lc_commitment, degree_bound));
+        }
+        end_timer!(lc_processing_time);
+
+        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+}
+
+impl<G> InnerProductArgPC<G>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+{
+    /// Returns `num_generators` independent generators, by hashing to the curve.
+    fn sample_generators(num_generators: usize) -> Vec<G> {
+        let generators_time = start_timer!(|| format!("Sampling {} generators", num_generators));
+        let generators = cfg_into_iter!(0..num_generators)
+            .map(|i| hash_to_curve::<G>(&format!("{} generator {}", Self::PROTOCOL_NAME, i)).0)
+            .collect();
+        end_timer!(generators_time);
+        generators
+    }
+
+    /// Outputs the Pedersen commitment to `scalars` under the first `scalars.len()` elements of `comm_key`,
+    /// hidden by `hiding_generator * randomness` if `hiding` is provided.
+    fn cm_commit(comm_key: &[G], scalars: &[G::ScalarField], hiding: Option<(G, G::ScalarField)>) -> G::Projective {
+        let scalars_bigint = cfg_iter!(scalars).map(|s| s.to_repr()).collect::<Vec<_>>();
+        let mut comm = VariableBase::msm(&comm_key[..scalars.len()], &scalars_bigint);
+        if let Some((hiding_generator, randomness)) = hiding {
+            comm += hiding_generator.mul(randomness);
+        }
+        comm
+    }
+
+    fn inner_product(l: &[G::ScalarField], r: &[G::ScalarField]) -> G::ScalarField {
+        l.iter().zip_eq(r).map(|(l, r)| *l * r).sum()
+    }
+
+    /// Collapses the terms of `p` into a single polynomial in monomial basis.
+    fn into_monomial_basis(p: &LabeledPolynomialWithBasis<G::ScalarField>) -> DensePolynomial<G::ScalarField> {
+        let mut polynomial = DensePolynomial::zero();
+        for (coeff, term) in &p.polynomial {
+            let term = match term {
+                PolynomialWithBasis::Lagrange { evaluations } => evaluations.interpolate_by_ref(),
+                PolynomialWithBasis::Monomial { polynomial, .. } => polynomial.into_dense(),
+            };
+            polynomial += (*coeff, &term);
+        }
+        polynomial
+    }
+
+    /// Returns `x^shift * p`.
+    fn shift(p: &DensePolynomial<G::ScalarField>, shift: usize) -> DensePolynomial<G::ScalarField> {
+        let mut coeffs = vec![G::ScalarField::zero(); shift];
+        coeffs.extend_from_slice(&p.coeffs);
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    fn check_degrees_and_bounds(
+        supported_degree: usize,
+        label: &str,
+        degree: usize,
+        degree_bound: Option<usize>,
+    ) -> Result<(), PCError> {
+        if degree > supported_degree {
+            return Err(PCError::TooManyCoefficients {
+                num_coefficients: degree + 1,
+                num_powers: supported_degree + 1,
+            });
+        }
+        match degree_bound {
+            Some(bound) if bound < degree || bound > supported_degree => Err(PCError::IncorrectDegreeBound {
+                poly_degree: degree,
+                degree_bound: bound,
+                supported_degree,
+                label: label.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn accumulate_randomness(randomness: &mut Randomness<G>, coeff: G::ScalarField, other: &Randomness<G>) {
+        randomness.rand += coeff * other.rand;
+        if let Some(other_shifted_rand) = other.shifted_rand {
+            *randomness.shifted_rand.get_or_insert_with(G::ScalarField::zero) += coeff * other_shifted_rand;
+        }
+    }
+
+    /// Linearly combines `commitments` with `coeffs`.
+    fn combine_commitments<'a>(
+        coeffs_and_comms: impl IntoIterator<Item = (G::ScalarField, &'a Commitment<G>)>,
+    ) -> Commitment<G> {
+        let mut comm = G::Projective::zero();
+        let mut shifted_comm = None;
+        for (coeff, c) in coeffs_and_comms {
+            comm += c.comm.mul(coeff);
+            if let Some(c) = c.shifted_comm {
+                *shifted_comm.get_or_insert_with(G::Projective::zero) += c.mul(coeff);
+            }
+        }
+        Commitment { comm: comm.to_affine(), shifted_comm: shifted_comm.map(|c| c.to_affine()) }
+    }
+
+    /// Derives the randomness of the hiding polynomial from the secret randomness of the
+    /// combined commitment, so that evaluation proofs can be hidden without an `rng`.
+    fn hiding_rng(combined_rand: G::ScalarField, point: G::ScalarField) -> StdRng {
+        let mut input = Self::PROTOCOL_NAME.as_bytes().to_vec();
+        input.extend(to_bytes_le![combined_rand, point].unwrap());
+        StdRng::from_seed(sha256(&input))
+    }
+
+    /// Outputs a proof that the random linear combination of `polynomials` evaluates correctly at `point`.
+    fn open_at_point<S: FiatShamirRng<G::ScalarField, G::BaseField>>(
+        ck: &CommitterKey<G>,
+        polynomials: Vec<&LabeledPolynomial<G::ScalarField>>,
+        rands: Vec<&Randomness<G>>,
+        point: G::ScalarField,
+        fs_rng: &mut S,
+    ) -> Result<Proof<G>, PCError> {
+        let supported_degree = ck.supported_degree();
+
+        // Combine the polynomials, and their shifts, into a single polynomial.
+        let mut combined_polynomial = DensePolynomial::zero();
+        let mut combined_rand = G::ScalarField::zero();
+        let mut has_hiding = false;
+        for (p, rand) in polynomials.into_iter().zip_eq(rands) {
+            let degree_bound = p.degree_bound();
+            Self::check_degrees_and_bounds(supported_degree, p.label(), p.degree(), degree_bound)?;
+            has_hiding |= p.is_hiding();
+
+            let polynomial = p.polynomial().into_dense();
+            let challenge = fs_rng.squeeze_short_nonnative_field_element()?;
+            combined_polynomial += (challenge, &polynomial);
+            combined_rand += challenge * rand.rand;
+
+            if let Some(degree_bound) = degree_bound {
+                let shifted_polynomial = Self::shift(&polynomial, supported_degree - degree_bound);
+                let challenge = fs_rng.squeeze_short_nonnative_field_element()?;
+                combined_polynomial += (challenge, &shifted_polynomial);
+                combined_rand += challenge * rand.shifted_rand.unwrap_or_else(G::ScalarField::zero);
+            }
+        }
+
+        // Mask the combined polynomial with a random polynomial that vanishes at `point`.
+        let (hiding_comm, rand) = if has_hiding {
+            let rng = &mut Self::hiding_rng(combined_rand, point);
+            let mut hiding_polynomial = DensePolynomial::rand(supported_degree, rng);
+            let hiding_evaluation = hiding_polynomial.evaluate(point);
+            hiding_polynomial.coeffs[0] -= hiding_evaluation;
+            let hiding_rand = G::ScalarField::rand(rng);
+
+            let hiding_comm = Self::cm_commit(&ck.comm_key, &hiding_polynomial.coeffs, Some((ck.h, hiding_rand)));
+            let hiding_comm = hiding_comm.to_affine();
+            fs_rng.absorb_native_field_elements(&[hiding_comm]);
+            let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element()?;
+
+            combined_polynomial += (hiding_challenge, &hiding_polynomial);
+            combined_rand += hiding_challenge * hiding_rand;
+            (Some(hiding_comm), Some(combined_rand))
+        } else {
+            (None, None)
+        };
+
+        // Bind the statement to the transcript, and derive the generator for the inner product.
+        let value = combined_polynomial.evaluate(point);
+        let combined_commitment = Self::cm_commit(&ck.comm_key, &combined_polynomial.coeffs, None).to_affine();
+        fs_rng.absorb_native_field_elements(&[combined_commitment]);
+        fs_rng.absorb_nonnative_field_elements([point, value], OptimizationType::Weight);
+        let h_prime = ck.s.mul(fs_rng.squeeze_short_nonnative_field_element()?).to_affine();
+
+        let mut num_coeffs = ck.comm_key.len();
+        let mut coeffs = combined_polynomial.coeffs;
+        coeffs.resize(num_coeffs, G::ScalarField::zero());
+
+        let mut point_powers = Vec::with_capacity(num_coeffs);
+        let mut cur = G::ScalarField::one();
+        for _ in 0..num_coeffs {
+            point_powers.push(cur);
+            cur *= point;
+        }
+
+        let mut comm_key = ck.comm_key.clone();
+        let mut l_vec = Vec::new();
+        let mut r_vec = Vec::new();
+        while num_coeffs > 1 {
+            let half = num_coeffs / 2;
+            let (coeffs_l, coeffs_r) = coeffs.split_at_mut(half);
+            let (point_powers_l, point_powers_r) = point_powers.split_at_mut(half);
+            let (key_l, key_r) = comm_key.split_at(half);
+
+            let l = Self::cm_commit(key_r, coeffs_l, Some((h_prime, Self::inner_product(coeffs_l, point_powers_r))));
+            let r = Self::cm_commit(key_l, coeffs_r, Some((h_prime, Self::inner_product(coeffs_r, point_powers_l))));
+            let lr = G::Projective::batch_normalization_into_affine(vec![l, r]);
+            fs_rng.absorb_native_field_elements(&lr);
+
+            let round_challenge = fs_rng.squeeze_short_nonnative_field_element()?;
+            let round_challenge_inv = round_challenge.inverse().unwrap();
+
+            cfg_iter_mut!(coeffs_l).zip(coeffs_r).for_each(|(l, r)| *l += round_challenge_inv * *r);
+            cfg_iter_mut!(point_powers_l).zip(point_powers_r).for_each(|(l, r)| *l += round_challenge * *r);
+            let folded_key =
+                cfg_iter!(key_l).zip(key_r).map(|(l, r)| l.to_projective() + r.mul(round_challenge)).collect();
+            comm_key = G::Projective::batch_normalization_into_affine(folded_key);
+
+            coeffs.truncate(half);
+            point_powers.truncate(half);
+            l_vec.push(lr[0]);
+            r_vec.push(lr[1]);
+            num_coeffs = half;
+        }
+
+        Ok(Proof { l_vec, r_vec, c: coeffs[0], hiding_comm, rand })
+    }
+
+    /// Checks that `values` are the evaluations at `point` of the polynomials committed in `commitments`.
+    fn check_at_point<S: FiatShamirRng<G::ScalarField, G::BaseField>>(
+        vk: &VerifierKey<G>,
+        commitments: Vec<&LabeledCommitment<Commitment<G>>>,
+        values: Vec<G::ScalarField>,
+        point: G::ScalarField,
+        proof: &Proof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError> {
+        let supported_degree = vk.supported_degree();
+        let num_rounds = vk.comm_key.len().trailing_zeros() as usize;
+        if proof.l_vec.len() != num_rounds || proof.r_vec.len() != num_rounds {
+            return Ok(false);
+        }
+
+        // Combine the commitments, and their shifts, in the same way as the prover.
+        let mut combined_commitment = G::Projective::zero();
+        let mut combined_value = G::ScalarField::zero();
+        for (labeled_comm, value) in commitments.into_iter().zip_eq(values) {
+            let commitment = labeled_comm.commitment();
+            let challenge = fs_rng.squeeze_short_nonnative_field_element()?;
+            combined_commitment += commitment.comm.mul(challenge);
+            combined_value += challenge * value;
+
+            if let Some(degree_bound) = labeled_comm.degree_bound() {
+                if degree_bound > supported_degree {
+                    return Err(PCError::UnsupportedDegreeBound(degree_bound));
+                }
+                let shifted_comm = match commitment.shifted_comm {
+                    Some(shifted_comm) => shifted_comm,
+                    None => return Ok(false),
+                };
+                let shift = point.pow([(supported_degree - degree_bound) as u64]);
+                let challenge = fs_rng.squeeze_short_nonnative_field_element()?;
+                combined_commitment += shifted_comm.mul(challenge);
+                combined_value += challenge * shift * value;
+            }
+        }
+
+        match (proof.hiding_comm, proof.rand) {
+            (Some(hiding_comm), Some(rand)) => {
+                fs_rng.absorb_native_field_elements(&[hiding_comm]);
+                let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element()?;
+                combined_commitment += hiding_comm.mul(hiding_challenge);
+                combined_commitment -= vk.h.mul(rand);
+            }
+            (None, None) => {}
+            _ => return Ok(false),
+        }
+
+        fs_rng.absorb_native_field_elements(&[combined_commitment.to_affine()]);
+        fs_rng.absorb_nonnative_field_elements([point, combined_value], OptimizationType::Weight);
+        let h_prime = vk.s.mul(fs_rng.squeeze_short_nonnative_field_element()?).to_affine();
+
+        // Fold the commitment with the commitments of each round.
+        let mut round_commitment = combined_commitment + h_prime.mul(combined_value);
+        let mut round_challenges = Vec::with_capacity(num_rounds);
+        for (l, r) in proof.l_vec.iter().zip_eq(&proof.r_vec) {
+            fs_rng.absorb_native_field_elements(&[*l, *r]);
+            let round_challenge = fs_rng.squeeze_short_nonnative_field_element()?;
+            let round_challenge_inv = match round_challenge.inverse() {
+                Some(round_challenge_inv) => round_challenge_inv,
+                None => return Ok(false),
+            };
+            round_commitment += l.mul(round_challenge) + r.mul(round_challenge_inv);
+            round_challenges.push(round_challenge);
+        }
+
+        // Compute the folded evaluation vector, which is the evaluation of a sparse polynomial.
+        let mut b_final = G::ScalarField::one();
+        let mut point_power = point;
+        for round_challenge in round_challenges.iter().rev() {
+            b_final *= G::ScalarField::one() + *round_challenge * point_power;
+            point_power.square_in_place();
+        }
+
+        // Compute the folded generator, as a multi-scalar multiplication over the committer key.
+        let mut s = Vec::with_capacity(vk.comm_key.len());
+        s.push(G::ScalarField::one());
+        for round_challenge in round_challenges.iter().rev() {
+            let shifted = s.iter().map(|s| *s * round_challenge).collect::<Vec<_>>();
+            s.extend(shifted);
+        }
+        let g_final = Self::cm_commit(&vk.comm_key, &s, None);
+
+        Ok(round_commitment == g_final * proof.c + h_prime.mul(proof.c * b_final))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+
+    use super::{CommitterKey, InnerProductArgPC};
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        polycommit::{test_templates::*, PolynomialCommitment},
+        snark::marlin::FiatShamirAlgebraicSpongeRng,
+    };
+    use snarkvm_curves::{
+        bls12_377::{Fq, Fr, G1Affine},
+        pasta::{Fp as PallasFq, Fq as PallasFr, PallasAffine},
+    };
+    use snarkvm_utilities::{rand::test_rng, FromBytes, ToBytes};
+
+    type Sponge = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
+    type PC_G1 = InnerProductArgPC<G1Affine>;
+
+    type PallasSponge = FiatShamirAlgebraicSpongeRng<PallasFr, PallasFq, PoseidonSponge<PallasFq, 6, 1>>;
+    type PC_Pallas = InnerProductArgPC<PallasAffine>;
+
+    #[test]
+    fn test_committer_key_serialization() {
+        let rng = &mut test_rng();
+        let pp = PC_G1::setup(31, rng).unwrap();
+        let (ck, vk) = PC_G1::trim(&pp, 20, [16], 0, None).unwrap();
+        assert_eq!(ck.comm_key.len(), 32);
+        assert_eq!(ck, vk);
+
+        let ck_bytes = ck.to_bytes_le().unwrap();
+        let ck_recovered: CommitterKey<G1Affine> = FromBytes::read_le(&ck_bytes[..]).unwrap();
+        assert_eq!(ck, ck_recovered);
+    }
+
+    #[test]
+    fn test_setup_is_deterministic() {
+        let rng = &mut test_rng();
+        let pp_a = PC_G1::setup(15, rng).unwrap();
+        let pp_b = PC_G1::setup(15, rng).unwrap();
+        assert_eq!(pp_a, pp_b);
+    }
+
+    #[test]
+    fn test_single_poly() {
+        single_poly_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_quadratic_poly_degree_bound_multiple_queries() {
+        quadratic_poly_degree_bound_multiple_queries_test::<Fr, Fq, PC_G1, Sponge>()
+            .expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_linear_poly_degree_bound() {
+        linear_poly_degree_bound_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_single_poly_degree_bound() {
+        single_poly_degree_bound_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_single_poly_degree_bound_multiple_queries() {
+        single_poly_degree_bound_multiple_queries_test::<Fr, Fq, PC_G1, Sponge>()
+            .expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_two_polys_degree_bound_single_query() {
+        two_polys_degree_bound_single_query_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_full_end_to_end() {
+        full_end_to_end_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_single_equation() {
+        single_equation_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_two_equation() {
+        two_equation_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_two_equation_degree_bound() {
+        two_equation_degree_bound_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_full_end_to_end_equation() {
+        full_end_to_end_equation_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bad_degree_bound() {
+        bad_degree_bound_test::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_lagrange_commitment() {
+        lagrange_test_template::<Fr, Fq, PC_G1, Sponge>().expect("test failed for bls12-377 G1");
+    }
+
+    #[test]
+    fn test_full_end_to_end_pallas() {
+        full_end_to_end_test::<PallasFr, PallasFq, PC_Pallas, PallasSponge>().expect("test failed for pallas");
+    }
+
+    #[test]
+    fn test_full_end_to_end_equation_pallas() {
+        full_end_to_end_equation_test::<PallasFr, PallasFq, PC_Pallas, PallasSponge>().expect("test failed for pallas");
+    }
+
+    #[test]
+    fn test_lagrange_commitment_pallas() {
+        lagrange_test_template::<PallasFr, PallasFq, PC_Pallas, PallasSponge>().expect("test failed for pallas");
+    }
+}
@@ -0,0 +1,521 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A powers-of-tau style ceremony for the universal parameters of [`KZG10`](super::KZG10).
+//!
+//! Each participant re-randomizes the accumulated powers of `beta` with a secret `tau`,
+//! and the hiding generator `gamma G` with a secret `gamma`, and publishes a Schnorr
+//! proof of knowledge of both exponents. As long as one participant discards their
+//! secrets, the final `beta` and `gamma` are unknown to everyone.
+
+use super::{KZG10DegreeBoundsConfig, UniversalParams};
+use crate::{msm::VariableBase, polycommit::PCError};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_parameters::testnet3::PowersOfG;
+use snarkvm_utilities::{
+    cfg_iter,
+    cfg_iter_mut,
+    collections::BTreeMap,
+    error,
+    format,
+    io,
+    io::{Read, Write},
+    rand::UniformRand,
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    sync::Arc,
+    vec,
+    vec::Vec,
+    FromBytes,
+    ToBytes,
+};
+
+use core::ops::Mul;
+use parking_lot::RwLock;
+use rand_core::RngCore;
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The domain separator for the Fiat-Shamir challenges of the ceremony.
+const CEREMONY_DOMAIN: &[u8] = b"AleoUniversalSRSCeremony0";
+
+/// `KnowledgeProof` is a Schnorr proof of knowledge of `s`, such that `target = s * base`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KnowledgeProof<G: AffineCurve> {
+    /// The commitment `k * base` to the prover nonce `k`.
+    pub commitment: G,
+    /// The response `k + c * s` to the challenge `c`.
+    pub response: G::ScalarField,
+}
+
+impl<G: AffineCurve> KnowledgeProof<G> {
+    /// Proves knowledge of `secret`, such that `target = secret * base`.
+    fn prove<R: RngCore>(index: usize, base: &G, target: &G, secret: G::ScalarField, rng: &mut R) -> Self {
+        let nonce = G::ScalarField::rand(rng);
+        let commitment = base.mul(nonce).to_affine();
+        let challenge = Self::challenge(index, base, target, &commitment);
+        Self { commitment, response: nonce + challenge * secret }
+    }
+
+    /// Returns `true` if `self` proves knowledge of the discrete log of `target` with respect to `base`.
+    fn verify(&self, index: usize, base: &G, target: &G) -> bool {
+        let challenge = Self::challenge(index, base, target, &self.commitment);
+        base.mul(self.response) == self.commitment.to_projective() + target.mul(challenge)
+    }
+
+    /// Returns the Fiat-Shamir challenge for the `index`-th contribution.
+    fn challenge(index: usize, base: &G, target: &G, commitment: &G) -> G::ScalarField {
+        let mut hasher = Sha256::new();
+        hasher.update(CEREMONY_DOMAIN);
+        hasher.update((index as u64).to_le_bytes());
+        for element in [base, target, commitment] {
+            hasher.update(element.to_bytes_le().expect("failed to serialize a group element"));
+        }
+        G::ScalarField::from_bytes_le_mod_order(&hasher.finalize())
+    }
+}
+
+/// `PowersAccumulator` holds the current state of the ceremony, which is a valid set of
+/// universal parameters for the (unknown) product of all contributed secrets.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PowersAccumulator<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub powers_of_beta_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `max_degree + 1`.
+    pub powers_of_beta_times_gamma_g: Vec<E::G1Affine>,
+    /// The generator of G2.
+    pub h: E::G2Affine,
+    /// \beta times the above generator of G2.
+    pub beta_h: E::G2Affine,
+    /// Group elements of the form `{ \beta^{max_degree - i} H }^{-1}`, where `i` is a supported degree bound.
+    pub inverse_neg_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+}
+
+impl<E: PairingEngine> PowersAccumulator<E> {
+    /// Returns the accumulator for `\beta = \gamma = 1`, which is the starting point of the ceremony.
+    fn new(max_degree: usize, supported_degree_bounds_config: &KZG10DegreeBoundsConfig) -> Self {
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        let inverse_neg_powers_of_beta_h =
+            supported_degree_bounds_config.get_list::<E::Fr>(max_degree).into_iter().map(|i| (i, h)).collect();
+
+        Self {
+            powers_of_beta_g: vec![g; max_degree + 1],
+            powers_of_beta_times_gamma_g: vec![g; max_degree + 2],
+            h,
+            beta_h: h,
+            inverse_neg_powers_of_beta_h,
+        }
+    }
+
+    /// Returns the maximum degree supported by the accumulated powers.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_beta_g.len() - 1
+    }
+
+    /// Returns the generator of G1.
+    pub fn g(&self) -> E::G1Affine {
+        self.powers_of_beta_g[0]
+    }
+
+    /// Returns the generator of G1 that is used for making a commitment hiding.
+    pub fn gamma_g(&self) -> E::G1Affine {
+        self.powers_of_beta_times_gamma_g[0]
+    }
+
+    /// Multiplies `\beta` by `tau` and `\gamma` by `gamma` in every accumulated element.
+    fn rerandomize(&mut self, tau: E::Fr, gamma: E::Fr) {
+        let max_degree = self.max_degree();
+
+        // Compute `tau^i`, where `i` ranges from 0 to `max_degree + 1`.
+        let mut powers_of_tau = Vec::with_capacity(max_degree + 2);
+        let mut cur = E::Fr::one();
+        for _ in 0..max_degree + 2 {
+            powers_of_tau.push(cur);
+            cur *= &tau;
+        }
+
+        let powers_of_beta_g = cfg_iter!(self.powers_of_beta_g)
+            .zip(&powers_of_tau)
+            .map(|(power, tau_i)| power.mul(*tau_i))
+            .collect::<Vec<_>>();
+        let powers_of_beta_times_gamma_g = cfg_iter!(self.powers_of_beta_times_gamma_g)
+            .zip(&powers_of_tau)
+            .map(|(power, tau_i)| power.mul(*tau_i * gamma))
+            .collect::<Vec<_>>();
+        self.powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(powers_of_beta_g);
+        self.powers_of_beta_times_gamma_g =
+            E::G1Projective::batch_normalization_into_affine(powers_of_beta_times_gamma_g);
+
+        self.beta_h = self.beta_h.mul(tau).to_affine();
+        cfg_iter_mut!(self.inverse_neg_powers_of_beta_h).for_each(|(i, power)| {
+            let exponent = powers_of_tau[max_degree - *i].inverse().expect("tau must be non-zero");
+            *power = power.mul(exponent).to_affine();
+        });
+    }
+
+    /// Returns `true` if the accumulated elements are consistent with `beta_h`,
+    /// that is, if every element is the expected power of `\beta` of its generator.
+    pub fn check_consistency<R: RngCore>(&self, rng: &mut R) -> bool {
+        let max_degree = self.max_degree();
+        if self.powers_of_beta_times_gamma_g.len() != max_degree + 2 {
+            return false;
+        }
+        if self.g().is_zero() || self.gamma_g().is_zero() || self.h.is_zero() || self.beta_h.is_zero() {
+            return false;
+        }
+        if self.inverse_neg_powers_of_beta_h.keys().any(|i| *i > max_degree) {
+            return false;
+        }
+
+        let check_time = start_timer!(|| format!("Checking the consistency of {} powers", max_degree + 1));
+        let (prepared_h, prepared_beta_h) = (self.h.prepare(), self.beta_h.prepare());
+        let result = check_powers_of_beta::<E, _>(&self.powers_of_beta_g, &prepared_h, &prepared_beta_h, rng)
+            && check_powers_of_beta::<E, _>(&self.powers_of_beta_times_gamma_g, &prepared_h, &prepared_beta_h, rng)
+            && self.check_inverse_neg_powers_of_beta_h();
+        end_timer!(check_time);
+        result
+    }
+
+    /// Returns `true` if `e(\beta^{max_degree - i} G, \beta^{-(max_degree - i)} H) = e(G, H)`
+    /// for every supported degree bound `i`.
+    fn check_inverse_neg_powers_of_beta_h(&self) -> bool {
+        let max_degree = self.max_degree();
+        let prepared_h = self.h.prepare();
+        let prepared_neg_g = (-self.g()).prepare();
+        self.inverse_neg_powers_of_beta_h.iter().all(|(i, inverse_neg_power)| {
+            let prepared_power = self.powers_of_beta_g[max_degree - *i].prepare();
+            let prepared_inverse_neg_power = inverse_neg_power.prepare();
+            E::product_of_pairings_is_one([
+                (&prepared_power, &prepared_inverse_neg_power),
+                (&prepared_neg_g, &prepared_h),
+            ])
+        })
+    }
+}
+
+/// `Contribution` records the public part of one participant's contribution to the ceremony.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Contribution<E: PairingEngine> {
+    /// \beta times the generator of G2, after this contribution.
+    pub beta_h: E::G2Affine,
+    /// The hiding generator of G1, after this contribution.
+    pub gamma_g: E::G1Affine,
+    /// A proof of knowledge of `tau`, such that `beta_h = tau * previous_beta_h`.
+    pub beta_proof: KnowledgeProof<E::G2Affine>,
+    /// A proof of knowledge of `gamma`, such that `gamma_g = gamma * previous_gamma_g`.
+    pub gamma_proof: KnowledgeProof<E::G1Affine>,
+}
+
+/// `CeremonyTranscript` is the public record of a ceremony for the universal parameters.
+///
+/// The transcript is serialized as the current [`PowersAccumulator`], followed by the
+/// list of [`Contribution`]s in the order in which they were made.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CeremonyTranscript<E: PairingEngine> {
+    /// The accumulated powers, after the last contribution.
+    pub accumulator: PowersAccumulator<E>,
+    /// The contributions made so far, in order.
+    pub contributions: Vec<Contribution<E>>,
+}
+
+impl<E: PairingEngine> CeremonyTranscript<E> {
+    /// Initializes a ceremony for universal parameters of maximum degree `max_degree`.
+    pub fn new(max_degree: usize, supported_degree_bounds_config: &KZG10DegreeBoundsConfig) -> Result<Self, PCError> {
+        if max_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        Ok(Self {
+            accumulator: PowersAccumulator::new(max_degree, supported_degree_bounds_config),
+            contributions: vec![],
+        })
+    }
+
+    /// Returns the maximum degree supported by the ceremony.
+    pub fn max_degree(&self) -> usize {
+        self.accumulator.max_degree()
+    }
+
+    /// Re-randomizes the accumulated powers with fresh secrets sampled from `rng`,
+    /// and appends a proof of knowledge of the secrets to the transcript.
+    ///
+    /// The secrets are dropped on return; `rng` must be a cryptographically secure RNG.
+    pub fn contribute<R: RngCore>(&mut self, rng: &mut R) {
+        let contribute_time = start_timer!(|| format!("Contributing to ceremony of degree {}", self.max_degree()));
+        let index = self.contributions.len();
+        let previous_beta_h = self.accumulator.beta_h;
+        let previous_gamma_g = self.accumulator.gamma_g();
+
+        let tau = sample_nonzero::<E::Fr, _>(rng);
+        let gamma = sample_nonzero::<E::Fr, _>(rng);
+        self.accumulator.rerandomize(tau, gamma);
+
+        let beta_h = self.accumulator.beta_h;
+        let gamma_g = self.accumulator.gamma_g();
+        let beta_proof = KnowledgeProof::prove(index, &previous_beta_h, &beta_h, tau, rng);
+        let gamma_proof = KnowledgeProof::prove(index, &previous_gamma_g, &gamma_g, gamma, rng);
+
+        self.contributions.push(Contribution { beta_h, gamma_g, beta_proof, gamma_proof });
+        end_timer!(contribute_time);
+    }
+
+    /// Returns `true` if the transcript contains at least one contribution, every contribution
+    /// carries valid proofs of knowledge, and the accumulated powers are consistent with the
+    /// last contribution.
+    pub fn verify_transcript<R: RngCore>(&self, rng: &mut R) -> bool {
+        let verify_time = start_timer!(|| format!("Verifying {} contributions", self.contributions.len()));
+        let result = self.verify_contributions() && self.accumulator.check_consistency(rng);
+        end_timer!(verify_time, || format!("Result: {}", result));
+        result
+    }
+
+    /// Returns `true` if the chain of contributions starts from the fixed generators
+    /// and ends at the current accumulator.
+    fn verify_contributions(&self) -> bool {
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        if self.contributions.is_empty() || self.accumulator.g() != g || self.accumulator.h != h {
+            return false;
+        }
+
+        let (mut previous_beta_h, mut previous_gamma_g) = (h, g);
+        for (index, contribution) in self.contributions.iter().enumerate() {
+            if contribution.beta_h.is_zero() || contribution.gamma_g.is_zero() {
+                return false;
+            }
+            if !contribution.beta_proof.verify(index, &previous_beta_h, &contribution.beta_h)
+                || !contribution.gamma_proof.verify(index, &previous_gamma_g, &contribution.gamma_g)
+            {
+                return false;
+            }
+            previous_beta_h = contribution.beta_h;
+            previous_gamma_g = contribution.gamma_g;
+        }
+
+        self.accumulator.beta_h == previous_beta_h && self.accumulator.gamma_g() == previous_gamma_g
+    }
+
+    /// Converts the accumulated powers into universal parameters for `KZG10`.
+    pub fn into_universal_params(self) -> UniversalParams<E> {
+        let PowersAccumulator {
+            powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            h,
+            beta_h,
+            inverse_neg_powers_of_beta_h,
+        } = self.accumulator;
+
+        let supported_degree_bounds = inverse_neg_powers_of_beta_h.keys().copied().collect();
+        let powers_of_beta_times_gamma_g = powers_of_beta_times_gamma_g.into_iter().enumerate().collect();
+        let powers: PowersOfG<E> = (powers_of_beta_g, powers_of_beta_times_gamma_g).into();
+
+        UniversalParams {
+            powers: Arc::new(RwLock::new(powers)),
+            h,
+            beta_h,
+            supported_degree_bounds,
+            inverse_neg_powers_of_beta_h,
+            prepared_h: h.prepare(),
+            prepared_beta_h: beta_h.prepare(),
+        }
+    }
+}
+
+impl<E: PairingEngine> FromBytes for CeremonyTranscript<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error("could not deserialize CeremonyTranscript"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for CeremonyTranscript<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut writer).map_err(|_| error("could not serialize CeremonyTranscript"))
+    }
+}
+
+impl<E: PairingEngine> UniversalParams<E> {
+    /// Returns `true` if the powers of `\beta` in `self`, up to `max_degree`, are consistent
+    /// with `beta_h`. This allows auditing parameters that were not produced by a local ceremony.
+    pub fn check_consistency<R: RngCore>(&self, rng: &mut R) -> bool {
+        let max_degree = self.max_degree();
+        let check_time = start_timer!(|| format!("Checking the consistency of {} powers", max_degree + 1));
+
+        // Check the powers of beta times G.
+        let powers_of_beta_g = self.powers_of_beta_g(0, max_degree + 1);
+        let mut result = check_powers_of_beta::<E, _>(&powers_of_beta_g, &self.prepared_h, &self.prepared_beta_h, rng);
+
+        // Check the powers of beta times gamma G. Only a subset of these powers is stored,
+        // so we check every pair of consecutive powers that is present.
+        let powers_of_beta_times_gamma_g = self.get_powers_times_gamma_g();
+        for (i, power) in &powers_of_beta_times_gamma_g {
+            if let Some(next_power) = powers_of_beta_times_gamma_g.get(&(i + 1)) {
+                result &=
+                    check_powers_of_beta::<E, _>(&[*power, *next_power], &self.prepared_h, &self.prepared_beta_h, rng);
+            }
+        }
+
+        // Check the inverse negative powers of beta times H.
+        let prepared_neg_g = (-powers_of_beta_g[0]).prepare();
+        for (i, inverse_neg_power) in &self.inverse_neg_powers_of_beta_h {
+            result &= *i <= max_degree
+                && E::product_of_pairings_is_one([
+                    (&powers_of_beta_g[max_degree - *i].prepare(), &inverse_neg_power.prepare()),
+                    (&prepared_neg_g, &self.prepared_h),
+                ]);
+        }
+
+        end_timer!(check_time, || format!("Result: {}", result));
+        result
+    }
+}
+
+/// Returns `true` if `powers[i + 1] = \beta * powers[i]` for every `i`, where `beta_h = \beta * h`,
+/// given `h` and `beta_h` prepared for use in pairings.
+///
+/// The check is performed on a random linear combination of the powers, so that it requires
+/// two multi-scalar multiplications and a single product of pairings.
+fn check_powers_of_beta<E: PairingEngine, R: RngCore>(
+    powers: &[E::G1Affine],
+    prepared_h: &<E::G2Affine as PairingCurve>::Prepared,
+    prepared_beta_h: &<E::G2Affine as PairingCurve>::Prepared,
+    rng: &mut R,
+) -> bool {
+    if powers.len() < 2 {
+        return true;
+    }
+    if powers.iter().any(|power| power.is_zero()) {
+        return false;
+    }
+
+    // We don't need to sample randomizers from the full field, only from 128-bit strings.
+    let randomizers = (0..powers.len() - 1).map(|_| E::Fr::from(u128::rand(rng)).to_repr()).collect::<Vec<_>>();
+    let lhs = VariableBase::msm(&powers[..powers.len() - 1], &randomizers);
+    let rhs = VariableBase::msm(&powers[1..], &randomizers);
+
+    let affine_points = E::G1Projective::batch_normalization_into_affine(vec![lhs, -rhs]);
+    E::product_of_pairings_is_one([
+        (&affine_points[0].prepare(), prepared_beta_h),
+        (&affine_points[1].prepare(), prepared_h),
+    ])
+}
+
+/// Samples a non-zero field element from `rng`.
+fn sample_nonzero<F: Field, R: RngCore>(rng: &mut R) -> F {
+    loop {
+        let element = F::rand(rng);
+        if !element.is_zero() {
+            return element;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fft::DensePolynomial, polycommit::kzg10::KZG10};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr, G1Affine};
+    use snarkvm_utilities::rand::test_rng;
+
+    use core::sync::atomic::AtomicBool;
+
+    type Transcript = CeremonyTranscript<Bls12_377>;
+
+    #[test]
+    fn test_ceremony_end_to_end() {
+        let rng = &mut test_rng();
+
+        let max_degree = 32;
+        let mut transcript = Transcript::new(max_degree, &KZG10DegreeBoundsConfig::MARLIN).unwrap();
+        assert!(!transcript.verify_transcript(rng));
+
+        for _ in 0..3 {
+            transcript.contribute(rng);
+            assert!(transcript.verify_transcript(rng));
+        }
+
+        let pp = transcript.into_universal_params();
+        assert_eq!(pp.max_degree(), max_degree);
+        assert!(pp.check_consistency(rng));
+
+        let (ck, vk) = KZG10::trim(&pp, max_degree);
+        let p = DensePolynomial::<Fr>::rand(max_degree, rng);
+        let (comm, rand) = KZG10::commit(&ck, &(&p).into(), Some(1), &AtomicBool::new(false), Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let proof = KZG10::open(&ck, &p, point, &rand).unwrap();
+        assert!(KZG10::check(&vk, &comm, point, p.evaluate(point), &proof).unwrap());
+    }
+
+    #[test]
+    fn test_ceremony_rejects_tampered_powers() {
+        let rng = &mut test_rng();
+
+        let mut transcript = Transcript::new(16, &KZG10DegreeBoundsConfig::MARLIN).unwrap();
+        transcript.contribute(rng);
+        transcript.contribute(rng);
+        assert!(transcript.verify_transcript(rng));
+
+        let mut tampered = transcript.clone();
+        tampered.accumulator.powers_of_beta_g[7] = G1Affine::rand(rng);
+        assert!(!tampered.verify_transcript(rng));
+
+        let mut tampered = transcript.clone();
+        tampered.accumulator.powers_of_beta_times_gamma_g[3] = G1Affine::rand(rng);
+        assert!(!tampered.verify_transcript(rng));
+
+        let mut tampered = transcript.clone();
+        let (_, power) = tampered.accumulator.inverse_neg_powers_of_beta_h.iter_mut().next().unwrap();
+        *power = (*power).mul(Fr::rand(rng)).to_affine();
+        assert!(!tampered.verify_transcript(rng));
+    }
+
+    #[test]
+    fn test_ceremony_rejects_invalid_proof_of_knowledge() {
+        let rng = &mut test_rng();
+
+        let mut transcript = Transcript::new(16, &KZG10DegreeBoundsConfig::NONE).unwrap();
+        transcript.contribute(rng);
+        transcript.contribute(rng);
+
+        // Drop the first contribution, so that the chain no longer starts at the generator.
+        let mut tampered = transcript.clone();
+        tampered.contributions.remove(0);
+        assert!(!tampered.verify_transcript(rng));
+
+        // Swap in a proof for a different contribution.
+        let mut tampered = transcript.clone();
+        tampered.contributions[1].beta_proof = tampered.contributions[0].beta_proof;
+        assert!(!tampered.verify_transcript(rng));
+
+        let mut tampered = transcript;
+        tampered.contributions[1].gamma_proof.response += Fr::one();
+        assert!(!tampered.verify_transcript(rng));
+    }
+
+    #[test]
+    fn test_ceremony_transcript_serialization() {
+        let rng = &mut test_rng();
+
+        let mut transcript = Transcript::new(8, &KZG10DegreeBoundsConfig::MARLIN).unwrap();
+        transcript.contribute(rng);
+
+        let bytes = transcript.to_bytes_le().unwrap();
+        let recovered = Transcript::read_le(&bytes[..]).unwrap();
+        assert_eq!(transcript, recovered);
+        assert!(recovered.verify_transcript(rng));
+    }
+}
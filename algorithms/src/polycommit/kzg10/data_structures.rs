@@ -16,6 +16,7 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain},
+    msm::{PrecomputedBases, VariableBase},
     snark::marlin::{params::OptimizationType, FiatShamirError, FiatShamirRng},
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -23,9 +24,13 @@ use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField, Zero};
 use snarkvm_parameters::testnet3::PowersOfG;
 use snarkvm_utilities::{
     borrow::Cow,
+    collections::BTreeMap,
     error,
+    io,
     io::{Read, Write},
     serialize::{CanonicalDeserialize, CanonicalSerialize},
+    sync::Arc,
+    vec::Vec,
     FromBytes,
     SerializationError,
     ToBytes,
@@ -36,7 +41,6 @@ use anyhow::Result;
 use core::ops::{Add, AddAssign};
 use parking_lot::RwLock;
 use rand_core::RngCore;
-use std::{collections::BTreeMap, io, sync::Arc};
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
 #[derive(Clone, Debug)]
@@ -231,6 +235,8 @@ pub struct Powers<'a, E: PairingEngine> {
     pub powers_of_beta_g: Cow<'a, [E::G1Affine]>,
     /// Group elements of the form `β^i γG`, for different values of `i`.
     pub powers_of_beta_times_gamma_g: Cow<'a, [E::G1Affine]>,
+    /// Precomputed multiples of `powers_of_beta_g`, which speed up commitments if present.
+    pub precomputed_powers_of_beta_g: Option<Cow<'a, PrecomputedBases<E::G1Affine>>>,
 }
 
 impl<E: PairingEngine> Powers<'_, E> {
@@ -238,6 +244,19 @@ impl<E: PairingEngine> Powers<'_, E> {
     pub fn size(&self) -> usize {
         self.powers_of_beta_g.len()
     }
+
+    /// Returns `∑_i scalars[i] * powers_of_beta_g[offset + i]`,
+    /// using the precomputed multiples of `powers_of_beta_g` if they are present.
+    pub(crate) fn msm_powers_of_beta_g(
+        &self,
+        offset: usize,
+        scalars: &[<E::Fr as PrimeField>::BigInteger],
+    ) -> E::G1Projective {
+        match &self.precomputed_powers_of_beta_g {
+            Some(precomputed) if offset + scalars.len() <= precomputed.num_bases() => precomputed.msm(offset, scalars),
+            _ => VariableBase::msm(&self.powers_of_beta_g[offset..], scalars),
+        }
+    }
 }
 /// `LagrangeBasis` is used to commit to and create evaluation proofs for a given polynomial.
 #[derive(Clone, Debug, Hash)]
@@ -27,9 +27,19 @@ use crate::{
     polycommit::PCError,
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_fields::{FftParameters, Field, One, PrimeField, Zero};
 use snarkvm_parameters::testnet3::PowersOfG;
-use snarkvm_utilities::{cfg_iter, rand::UniformRand, BitIteratorBE};
+use snarkvm_utilities::{
+    cfg_iter,
+    collections::BTreeMap,
+    format,
+    rand::UniformRand,
+    string::ToString,
+    sync::Arc,
+    vec,
+    vec::Vec,
+    BitIteratorBE,
+};
 
 use core::{
     marker::PhantomData,
@@ -39,11 +49,13 @@ use core::{
 use itertools::Itertools;
 use parking_lot::RwLock;
 use rand_core::RngCore;
-use std::{collections::BTreeMap, sync::Arc};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+mod ceremony;
+pub use ceremony::*;
+
 mod data_structures;
 pub use data_structures::*;
 
@@ -66,21 +78,32 @@ impl KZG10DegreeBoundsConfig {
             KZG10DegreeBoundsConfig::ALL => (0..max_degree).collect(),
             KZG10DegreeBoundsConfig::MARLIN => {
                 // In Marlin, the degree bounds are all of the forms `domain_size - 2`.
-                // Consider that we are using radix-2 FFT,
-                // there are only a few possible domain sizes and therefore degree bounds.
-                //
-                // We do not consider mixed-radix FFT for simplicity, as the curves that we
-                // are using have very high two-arity.
-
-                let mut radix_2_possible_domain_sizes = vec![];
-
-                let mut cur = 2usize;
-                while cur - 2 <= max_degree {
-                    radix_2_possible_domain_sizes.push(cur - 2);
-                    cur *= 2;
+                // The domain sizes are of the form `2^a * q^b`, where `q` is the small subgroup base
+                // of the field (if any), so there are only a few possible degree bounds.
+                let (q, max_q_adicity) =
+                    match (F::FftParameters::SMALL_SUBGROUP_BASE, F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY) {
+                        (Some(q), Some(q_adicity)) => (q as usize, q_adicity),
+                        _ => (1, 0),
+                    };
+
+                let mut possible_domain_sizes = vec![];
+
+                let mut q_part = Some(1usize);
+                for _ in 0..=max_q_adicity {
+                    let mut cur = match q_part {
+                        Some(q_part) => Some(q_part.max(2)),
+                        None => break,
+                    };
+                    while let Some(size) = cur.filter(|size| size - 2 <= max_degree) {
+                        possible_domain_sizes.push(size - 2);
+                        cur = size.checked_mul(2);
+                    }
+                    q_part = q_part.and_then(|q_part| q_part.checked_mul(q));
                 }
 
-                radix_2_possible_domain_sizes
+                possible_domain_sizes.sort_unstable();
+                possible_domain_sizes.dedup();
+                possible_domain_sizes
             }
             KZG10DegreeBoundsConfig::LIST(v) => v.clone(),
             KZG10DegreeBoundsConfig::NONE => vec![],
@@ -233,7 +256,7 @@ impl<E: PairingEngine> KZG10<E> {
                 let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
 
                 let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-                let commitment = VariableBase::msm(&powers.powers_of_beta_g[num_leading_zeros..], &plain_coeffs);
+                let commitment = powers.msm_powers_of_beta_g(num_leading_zeros, &plain_coeffs);
                 end_timer!(msm_time);
 
                 if terminator.load(Ordering::Relaxed) {
@@ -288,7 +311,7 @@ impl<E: PairingEngine> KZG10<E> {
         rng: Option<&mut dyn RngCore>,
     ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
         Self::check_degree_is_too_large(evaluations.len() - 1, lagrange_basis.size())?;
-        assert_eq!(evaluations.len().next_power_of_two(), lagrange_basis.size());
+        assert_eq!(lagrange_basis.domain.size(), lagrange_basis.size());
 
         let commit_time = start_timer!(|| format!(
             "Committing to polynomial of degree {} with hiding_bound: {:?}",
@@ -377,7 +400,7 @@ impl<E: PairingEngine> KZG10<E> {
         let (num_leading_zeros, witness_coeffs) = skip_leading_zeros_and_convert_to_bigints(witness_polynomial);
 
         let witness_comm_time = start_timer!(|| "Computing commitment to witness polynomial");
-        let mut w = VariableBase::msm(&powers.powers_of_beta_g[num_leading_zeros..], &witness_coeffs);
+        let mut w = powers.msm_powers_of_beta_g(num_leading_zeros, &witness_coeffs);
         end_timer!(witness_comm_time);
 
         let random_v = if let Some(hiding_witness_polynomial) = hiding_witness_polynomial {
@@ -429,17 +452,22 @@ impl<E: PairingEngine> KZG10<E> {
         proof: &Proof<E>,
     ) -> Result<bool, PCError> {
         let check_time = start_timer!(|| "Checking evaluation");
+        // The check `e(commitment - value * g - random_v * gamma_g, h) = e(w, beta_h - point * h)`
+        // is rearranged so that it only pairs with the prepared `h` and `beta_h`.
         let mut inner = commitment.0.to_projective() - vk.g.to_projective().mul(value);
         if let Some(random_v) = proof.random_v {
             inner -= &vk.gamma_g.mul(random_v);
         }
-        let lhs = E::pairing(inner, vk.h);
+        inner += &proof.w.mul(point);
 
-        let inner = vk.beta_h.to_projective() - vk.h.mul(point);
-        let rhs = E::pairing(proof.w, inner);
+        let affine_points = E::G1Projective::batch_normalization_into_affine(vec![-proof.w.to_projective(), inner]);
+        let result = E::product_of_pairings_is_one([
+            (&affine_points[0].prepare(), &vk.prepared_beta_h),
+            (&affine_points[1].prepare(), &vk.prepared_h),
+        ]);
 
-        end_timer!(check_time, || format!("Result: {}", lhs == rhs));
-        Ok(lhs == rhs)
+        end_timer!(check_time, || format!("Result: {}", result));
+        Ok(result)
     }
 
     /// Check that each `proof_i` in `proofs` is a valid proof of evaluation for
@@ -490,10 +518,10 @@ impl<E: PairingEngine> KZG10<E> {
         end_timer!(to_affine_time);
 
         let pairing_time = start_timer!(|| "Performing product of pairings");
-        let result = E::product_of_pairings(
-            [(&total_w.prepare(), &vk.prepared_beta_h), (&total_c.prepare(), &vk.prepared_h)].iter().copied(),
-        )
-        .is_one();
+        let result = E::product_of_pairings_is_one([
+            (&total_w.prepare(), &vk.prepared_beta_h),
+            (&total_c.prepare(), &vk.prepared_h),
+        ]);
         end_timer!(pairing_time);
         end_timer!(check_time, || format!("Result: {}", result));
         Ok(result)
@@ -594,6 +622,7 @@ mod tests {
             let powers = Powers {
                 powers_of_beta_g: Cow::Owned(powers_of_beta_g),
                 powers_of_beta_times_gamma_g: Cow::Owned(powers_of_beta_times_gamma_g),
+                precomputed_powers_of_beta_g: None,
             };
             let vk = VerifierKey {
                 g: pp.power_of_beta_g(0),
@@ -20,6 +20,10 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::type_complexity)]
 
+/// The trait describing polynomial commitment schemes.
+pub mod traits;
+pub use traits::*;
+
 /// The core [\[KZG10\]][kzg] construction.
 ///
 /// [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
@@ -37,6 +41,13 @@ pub mod kzg10;
 /// [marlin]: https://eprint.iacr.org/2019/1047
 pub mod sonic_pc;
 
+/// Polynomial commitment scheme based on the inner-product argument of [\[BCCGP16\]][bccgp],
+/// as used in [[BGH19, “Halo”]][halo]. It has a transparent setup and requires no pairing.
+///
+/// [bccgp]: https://eprint.iacr.org/2016/263
+/// [halo]: https://eprint.iacr.org/2019/1021
+pub mod ipa_pc;
+
 /// Errors pertaining to query sets.
 pub mod error;
 pub use error::*;
@@ -17,18 +17,26 @@
 use crate::{
     crypto_hash::sha256::sha256,
     fft::EvaluationDomain,
-    polycommit::kzg10,
+    msm::PrecomputedBases,
+    polycommit::{kzg10, PCProof, PCRandomness},
     snark::marlin::{FiatShamirError, FiatShamirRng},
     Prepare,
 };
 use hashbrown::HashMap;
 use snarkvm_curves::{PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
-use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
-
-use std::{
-    borrow::{Borrow, Cow},
+use snarkvm_utilities::{
+    borrow::Cow,
     collections::{BTreeMap, BTreeSet},
+    error,
+    serialize::*,
+    string::{String, ToString},
+    FromBytes,
+    ToBytes,
+};
+
+use core::{
+    borrow::Borrow,
     fmt,
     ops::{AddAssign, MulAssign, SubAssign},
 };
@@ -47,6 +55,12 @@ pub type Commitment<E> = kzg10::Commitment<E>;
 /// `PreparedCommitment` is the prepared commitment for the KZG10 scheme.
 pub type PreparedCommitment<E> = kzg10::PreparedCommitment<E>;
 
+impl<E: PairingEngine> PCRandomness for Randomness<E> {
+    fn empty() -> Self {
+        kzg10::Randomness::empty()
+    }
+}
+
 impl<E: PairingEngine> Prepare for Commitment<E> {
     type Prepared = PreparedCommitment<E>;
 
@@ -90,6 +104,12 @@ pub struct CommitterKey<E: PairingEngine> {
 
     /// The maximum degree supported by the `UniversalParams` from which `self` was derived
     pub max_degree: usize,
+
+    /// Precomputed multiples of `powers_of_beta_g`, which speed up commitments if present.
+    /// This is `None` unless `self.precompute_powers_of_beta_g()` has been called.
+    /// It is kept by `CanonicalSerialize`, but omitted by the `ToBytes` encoding of `self`,
+    /// so it must be precomputed again after reading `self` with `FromBytes`.
+    pub precomputed_powers_of_beta_g: Option<PrecomputedBases<E::G1Affine>>,
 }
 
 impl<E: PairingEngine> FromBytes for CommitterKey<E> {
@@ -224,6 +244,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
             shifted_powers_of_beta_times_gamma_g,
             enforced_degree_bounds,
             max_degree: max_degree as usize,
+            precomputed_powers_of_beta_g: None,
         })
     }
 }
@@ -323,10 +344,22 @@ impl<E: PairingEngine> CommitterKey<E> {
         kzg10::Powers {
             powers_of_beta_g: self.powers_of_beta_g.as_slice().into(),
             powers_of_beta_times_gamma_g: self.powers_of_beta_times_gamma_g.as_slice().into(),
+            precomputed_powers_of_beta_g: self.precomputed_powers_of_beta_g.as_ref().map(Cow::Borrowed),
+        }
+    }
+
+    /// Precomputes multiples of `powers_of_beta_g`, which are then reused by every commitment with `self`.
+    ///
+    /// Only `powers_of_beta_g` is precomputed, so commitments to shifted polynomials
+    /// (see `Self::shifted_powers_of_beta_g`) are not sped up.
+    pub fn precompute_powers_of_beta_g(&mut self) {
+        if self.precomputed_powers_of_beta_g.is_none() {
+            self.precomputed_powers_of_beta_g = Some(PrecomputedBases::new(&self.powers_of_beta_g));
         }
     }
 
     /// Obtain powers for committing to shifted polynomials.
+    /// These powers never carry precomputed multiples, even if `self` does.
     pub fn shifted_powers_of_beta_g(&self, degree_bound: impl Into<Option<usize>>) -> Option<kzg10::Powers<E>> {
         match (&self.shifted_powers_of_beta_g, &self.shifted_powers_of_beta_times_gamma_g) {
             (Some(shifted_powers_of_beta_g), Some(shifted_powers_of_beta_times_gamma_g)) => {
@@ -341,6 +374,7 @@ impl<E: PairingEngine> CommitterKey<E> {
                 let ck = kzg10::Powers {
                     powers_of_beta_g: shifted_powers_of_beta_g[powers_range].into(),
                     powers_of_beta_times_gamma_g: shifted_powers_of_beta_times_gamma_g[&bound].clone().into(),
+                    precomputed_powers_of_beta_g: None,
                 };
 
                 Some(ck)
@@ -413,10 +447,11 @@ impl<E: PairingEngine> VerifierKey<E> {
             .and_then(|v| v.binary_search_by(|(d, _)| d.cmp(&degree_bound)).ok().map(|i| v[i].1))
     }
 
-    pub fn get_prepared_shift_power(&self, degree_bound: usize) -> Option<<E::G2Affine as PairingCurve>::Prepared> {
+    /// Find the appropriate prepared shift for the degree bound.
+    pub fn get_prepared_shift_power(&self, degree_bound: usize) -> Option<&<E::G2Affine as PairingCurve>::Prepared> {
         self.degree_bounds_and_prepared_neg_powers_of_h
             .as_ref()
-            .and_then(|v| v.binary_search_by(|(d, _)| d.cmp(&degree_bound)).ok().map(|i| v[i].1.clone()))
+            .and_then(|v| v.binary_search_by(|(d, _)| d.cmp(&degree_bound)).ok().map(|i| &v[i].1))
     }
 }
 
@@ -466,10 +501,10 @@ pub struct PreparedVerifierKey<E: PairingEngine> {
 
 impl<E: PairingEngine> PreparedVerifierKey<E> {
     /// Find the appropriate shift for the degree bound.
-    pub fn get_prepared_shift_power(&self, bound: usize) -> Option<<E::G2Affine as PairingCurve>::Prepared> {
+    pub fn get_prepared_shift_power(&self, bound: usize) -> Option<&<E::G2Affine as PairingCurve>::Prepared> {
         self.degree_bounds_and_prepared_neg_powers_of_h
             .as_ref()
-            .and_then(|v| v.binary_search_by(|(d, _)| d.cmp(&bound)).ok().map(|i| v[i].1.clone()))
+            .and_then(|v| v.binary_search_by(|(d, _)| d.cmp(&bound)).ok().map(|i| &v[i].1))
     }
 }
 
@@ -493,8 +528,8 @@ impl<E: PairingEngine> Prepare for VerifierKey<E> {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BatchProof<E: PairingEngine>(pub(crate) Vec<kzg10::Proof<E>>);
 
-impl<E: PairingEngine> BatchProof<E> {
-    pub fn is_hiding(&self) -> bool {
+impl<E: PairingEngine> PCProof for BatchProof<E> {
+    fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
     }
 }
@@ -779,8 +814,8 @@ pub struct BatchLCProof<E: PairingEngine> {
     pub evaluations: Option<Vec<E::Fr>>,
 }
 
-impl<E: PairingEngine> BatchLCProof<E> {
-    pub fn is_hiding(&self) -> bool {
+impl<E: PairingEngine> PCProof for BatchLCProof<E> {
+    fn is_hiding(&self) -> bool {
         self.proof.is_hiding()
     }
 }
@@ -16,13 +16,20 @@
 
 use crate::{
     fft::DensePolynomial,
-    polycommit::{kzg10, optional_rng::OptionalRng, PCError},
+    polycommit::{kzg10, optional_rng::OptionalRng, PCError, PolynomialCommitment},
     snark::marlin::{params::OptimizationType, FiatShamirRng},
 };
 use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use core::{
     convert::TryInto,
@@ -31,7 +38,6 @@ use core::{
     sync::atomic::{AtomicBool, Ordering},
 };
 use rand_core::{RngCore, SeedableRng};
-use std::collections::{BTreeMap, BTreeSet};
 
 mod data_structures;
 pub use data_structures::*;
@@ -49,17 +55,27 @@ pub use polynomial::*;
 /// [sonic]: https://eprint.iacr.org/2019/099
 /// [al]: https://eprint.iacr.org/2019/601
 /// [marlin]: https://eprint.iacr.org/2019/1047
-#[derive(Clone, Debug)]
-pub struct SonicKZG10<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> {
-    _engine: PhantomData<(E, S)>,
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SonicKZG10<E: PairingEngine> {
+    _engine: PhantomData<E>,
 }
 
-impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
-    pub fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<UniversalParams<E>, PCError> {
+impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
+    type BatchLCProof = BatchLCProof<E>;
+    type BatchProof = BatchProof<E>;
+    type Commitment = Commitment<E>;
+    type CommitterKey = CommitterKey<E>;
+    type PreparedCommitment = PreparedCommitment<E>;
+    type PreparedVerifierKey = PreparedVerifierKey<E>;
+    type Randomness = Randomness<E>;
+    type UniversalParams = UniversalParams<E>;
+    type VerifierKey = VerifierKey<E>;
+
+    fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<UniversalParams<E>, PCError> {
         kzg10::KZG10::setup(max_degree, &kzg10::KZG10DegreeBoundsConfig::MARLIN, true, rng).map_err(Into::into)
     }
 
-    pub fn trim(
+    fn trim(
         pp: &UniversalParams<E>,
         supported_degree: usize,
         supported_lagrange_sizes: impl IntoIterator<Item = usize>,
@@ -69,7 +85,8 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         let trim_time = start_timer!(|| "Trimming public parameters");
         let mut max_degree = pp.max_degree();
         if supported_degree > max_degree {
-            pp.download_up_to(supported_degree).map_err(|_| PCError::TrimmingDegreeTooLarge)?;
+            pp.download_up_to(supported_degree)
+                .map_err(|e| PCError::UnavailableDegree { degree: supported_degree, reason: e.to_string() })?;
             max_degree = pp.max_degree();
         }
 
@@ -129,15 +146,13 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         let mut lagrange_bases_at_beta_g = BTreeMap::new();
         for size in supported_lagrange_sizes {
             let lagrange_time = start_timer!(|| format!("Constructing `lagrange_bases` of size {size}"));
-            if !size.is_power_of_two() {
-                return Err(PCError::LagrangeBasisSizeIsNotPowerOfTwo);
-            }
+            let domain =
+                crate::fft::EvaluationDomain::new_with_size(size).ok_or(PCError::UnsupportedLagrangeBasisSize(size))?;
             if size > pp.max_degree() + 1 {
                 return Err(PCError::LagrangeBasisSizeIsTooLarge);
             }
-            let domain = crate::fft::EvaluationDomain::new(size).unwrap();
             let lagrange_basis_at_beta_g = pp.lagrange_basis(domain);
-            assert!(lagrange_basis_at_beta_g.len().is_power_of_two());
+            assert_eq!(lagrange_basis_at_beta_g.len(), size);
             lagrange_bases_at_beta_g.insert(domain.size(), lagrange_basis_at_beta_g);
             end_timer!(lagrange_time);
         }
@@ -150,6 +165,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             shifted_powers_of_beta_times_gamma_g,
             enforced_degree_bounds,
             max_degree,
+            precomputed_powers_of_beta_g: None,
         };
 
         let g = pp.power_of_beta_g(0);
@@ -192,6 +208,18 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         Ok((ck, vk))
     }
 
+    fn precompute_committer_key(ck: &mut CommitterKey<E>) {
+        ck.precompute_powers_of_beta_g();
+    }
+
+    fn is_committer_key_precomputed(ck: &CommitterKey<E>) -> bool {
+        ck.precomputed_powers_of_beta_g.is_some()
+    }
+
+    fn is_committer_key_for(ck: &CommitterKey<E>, vk: &VerifierKey<E>) -> bool {
+        ck.supported_degree() == vk.supported_degree() && ck.max_degree() == vk.max_degree()
+    }
+
     /// Outputs a commitments to `polynomials`. If `polynomials[i].is_hiding()`,
     /// then the `i`-th commitment is hiding up to `polynomials.hiding_bound()` queries.
     /// `rng` should not be `None` if `polynomials[i].is_hiding() == true` for any `i`.
@@ -202,18 +230,8 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
     /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
     /// polynomial will have the corresponding degree bound enforced.
     #[allow(clippy::type_complexity)]
-    pub fn commit<'b>(
-        ck: &CommitterKey<E>,
-        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'b, E::Fr>>,
-        rng: Option<&mut dyn RngCore>,
-    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError> {
-        Self::commit_with_terminator(ck, polynomials, &AtomicBool::new(false), rng)
-    }
-
-    /// Outputs a commitment to `polynomial`.
-    #[allow(clippy::type_complexity)]
     #[allow(clippy::format_push_string)]
-    pub fn commit_with_terminator<'a>(
+    fn commit_with_terminator<'a>(
         ck: &CommitterKey<E>,
         polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'a, E::Fr>>,
         terminator: &AtomicBool,
@@ -262,12 +280,11 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
                         let rng_ref = rng.as_mut().map(|s| s as _);
                         match p {
                             PolynomialWithBasis::Lagrange { evaluations } => {
-                                let domain = crate::fft::EvaluationDomain::new(evaluations.evaluations.len()).unwrap();
+                                let domain = evaluations.domain();
                                 let lagrange_basis = ck
                                     .lagrange_basis(domain)
                                     .ok_or(PCError::UnsupportedLagrangeBasisSize(domain.size()))?;
-                                assert!(domain.size().is_power_of_two());
-                                assert!(lagrange_basis.size().is_power_of_two());
+                                assert_eq!(lagrange_basis.size(), domain.size());
                                 kzg10::KZG10::commit_lagrange(
                                     &lagrange_basis,
                                     &evaluations.evaluations,
@@ -310,45 +327,16 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         Ok((labeled_comms, randomness))
     }
 
-    pub fn combine_for_open<'a>(
-        ck: &CommitterKey<E>,
-        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
-        rands: impl IntoIterator<Item = &'a Randomness<E>>,
-        fs_rng: &mut S,
-    ) -> Result<(DensePolynomial<E::Fr>, Randomness<E>), PCError>
-    where
-        Randomness<E>: 'a,
-        Commitment<E>: 'a,
-    {
-        Ok(Self::combine_polynomials(labeled_polynomials.into_iter().zip_eq(rands).map(|(p, r)| {
-            let enforced_degree_bounds: Option<&[usize]> = ck.enforced_degree_bounds.as_deref();
-
-            kzg10::KZG10::<E>::check_degrees_and_bounds(
-                ck.supported_degree(),
-                ck.max_degree,
-                enforced_degree_bounds,
-                p,
-            )
-            .unwrap();
-            let challenge = fs_rng.squeeze_short_nonnative_field_element().unwrap();
-            (challenge, p.polynomial().as_dense().unwrap(), r)
-        })))
-    }
-
     /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
     /// of the polynomials at the points in the query set.
-    pub fn batch_open<'a>(
+    fn batch_open<'a, S: FiatShamirRng<E::Fr, E::Fq>>(
         ck: &CommitterKey<E>,
         labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
         query_set: &QuerySet<E::Fr>,
         rands: impl IntoIterator<Item = &'a Randomness<E>>,
         fs_rng: &mut S,
-    ) -> Result<BatchProof<E>, PCError>
-    where
-        Randomness<E>: 'a,
-        Commitment<E>: 'a,
-    {
+    ) -> Result<BatchProof<E>, PCError> {
         let poly_rand_comm: HashMap<_, _> = labeled_polynomials
             .into_iter()
             .zip_eq(rands)
@@ -398,17 +386,14 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         batch_proof
     }
 
-    pub fn batch_check<'a>(
+    fn batch_check<'a, S: FiatShamirRng<E::Fr, E::Fq>>(
         vk: &VerifierKey<E>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
         query_set: &QuerySet<E::Fr>,
         values: &Evaluations<E::Fr>,
         proof: &BatchProof<E>,
         fs_rng: &mut S,
-    ) -> Result<bool, PCError>
-    where
-        Commitment<E>: 'a,
-    {
+    ) -> Result<bool, PCError> {
         let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
         let mut query_to_labels_map = BTreeMap::new();
 
@@ -465,7 +450,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk)
     }
 
-    pub fn open_combinations<'a>(
+    fn open_combinations<'a, S: FiatShamirRng<E::Fr, E::Fq>>(
         ck: &CommitterKey<E>,
         linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
         polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
@@ -473,11 +458,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         query_set: &QuerySet<E::Fr>,
         rands: impl IntoIterator<Item = &'a Randomness<E>>,
         fs_rng: &mut S,
-    ) -> Result<BatchLCProof<E>, PCError>
-    where
-        Randomness<E>: 'a,
-        Commitment<E>: 'a,
-    {
+    ) -> Result<BatchLCProof<E>, PCError> {
         let label_map = polynomials
             .into_iter()
             .zip_eq(rands)
@@ -545,7 +526,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
 
     /// Checks that `values` are the true evaluations at `query_set` of the polynomials
     /// committed in `labeled_commitments`.
-    pub fn check_combinations<'a>(
+    fn check_combinations<'a, S: FiatShamirRng<E::Fr, E::Fq>>(
         vk: &VerifierKey<E>,
         linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
@@ -553,10 +534,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         evaluations: &Evaluations<E::Fr>,
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
-    ) -> Result<bool, PCError>
-    where
-        Commitment<E>: 'a,
-    {
+    ) -> Result<bool, PCError> {
         let BatchLCProof { proof, .. } = proof;
         let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
 
@@ -613,7 +591,28 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
     }
 }
 
-impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
+impl<E: PairingEngine> SonicKZG10<E> {
+    pub fn combine_for_open<'a, S: FiatShamirRng<E::Fr, E::Fq>>(
+        ck: &CommitterKey<E>,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        rands: impl IntoIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+    ) -> Result<(DensePolynomial<E::Fr>, Randomness<E>), PCError> {
+        Ok(Self::combine_polynomials(labeled_polynomials.into_iter().zip_eq(rands).map(|(p, r)| {
+            let enforced_degree_bounds: Option<&[usize]> = ck.enforced_degree_bounds.as_deref();
+
+            kzg10::KZG10::<E>::check_degrees_and_bounds(
+                ck.supported_degree(),
+                ck.max_degree,
+                enforced_degree_bounds,
+                p,
+            )
+            .unwrap();
+            let challenge = fs_rng.squeeze_short_nonnative_field_element().unwrap();
+            (challenge, p.polynomial().as_dense().unwrap(), r)
+        })))
+    }
+
     fn combine_polynomials<'a>(
         coeffs_polys_rands: impl IntoIterator<Item = (E::Fr, &'a DensePolynomial<E::Fr>, &'a Randomness<E>)>,
     ) -> (DensePolynomial<E::Fr>, Randomness<E>) {
@@ -652,9 +651,9 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
     }
 }
 
-impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
+impl<E: PairingEngine> SonicKZG10<E> {
     #[allow(clippy::too_many_arguments)]
-    fn accumulate_elems<'a>(
+    fn accumulate_elems<'a, S: FiatShamirRng<E::Fr, E::Fq>>(
         combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
         combined_witness: &mut E::G1Projective,
         combined_adjusted_witness: &mut E::G1Projective,
@@ -718,30 +717,34 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
 
+        // The commitments without a degree bound and the adjusted witness are all paired with `h`,
+        // so they are combined first to save a miller loop.
+        let mut h_elem = -combined_adjusted_witness;
         for (degree_bound, comm) in combined_comms.into_iter() {
-            let shift_power = if let Some(degree_bound) = degree_bound {
-                vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
-            } else {
-                vk.vk.prepared_h.clone()
-            };
-
-            g1_projective_elems.push(comm);
-            g2_prepared_elems.push(shift_power);
+            match degree_bound {
+                Some(degree_bound) => {
+                    let shift_power = vk
+                        .get_prepared_shift_power(degree_bound)
+                        .ok_or(PCError::UnsupportedDegreeBound(degree_bound))?;
+                    g1_projective_elems.push(comm);
+                    g2_prepared_elems.push(shift_power);
+                }
+                None => h_elem += comm,
+            }
         }
 
-        g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+        g1_projective_elems.push(h_elem);
+        g2_prepared_elems.push(&vk.vk.prepared_h);
 
         g1_projective_elems.push(-combined_witness);
-        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+        g2_prepared_elems.push(&vk.vk.prepared_beta_h);
 
-        let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
+        let g1_prepared_elems = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
             .into_iter()
             .map(|a| a.prepare())
             .collect::<Vec<_>>();
 
-        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems.iter());
-        let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
+        let is_one = E::product_of_pairings_is_one(g1_prepared_elems.iter().zip_eq(g2_prepared_elems));
         end_timer!(check_time);
         Ok(is_one)
     }
@@ -754,7 +757,7 @@ mod tests {
     use super::{CommitterKey, SonicKZG10};
     use crate::{
         crypto_hash::PoseidonSponge,
-        polycommit::test_templates::*,
+        polycommit::{test_templates::*, PolynomialCommitment},
         snark::marlin::FiatShamirAlgebraicSpongeRng,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
@@ -763,7 +766,7 @@ mod tests {
     use rand::distributions::Distribution;
 
     type Sponge = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
-    type PC_Bls12_377 = SonicKZG10<Bls12_377, Sponge>;
+    type PC_Bls12_377 = SonicKZG10<Bls12_377>;
 
     #[test]
     fn test_committer_key_serialization() {
@@ -784,76 +787,129 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_precomputed_powers_of_beta_g() {
+        use crate::{fft::DensePolynomial, polycommit::kzg10::KZG10};
+        use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize};
+        use std::sync::atomic::AtomicBool;
+
+        let rng = &mut test_rng();
+        let pp = PC_Bls12_377::setup(64, rng).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 64, [], 0, None).unwrap();
+        let mut precomputed_ck = ck.clone();
+        precomputed_ck.precompute_powers_of_beta_g();
+
+        for degree in [0, 1, 17, 64] {
+            let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+            let commit = |ck: &CommitterKey<Bls12_377>| {
+                KZG10::commit(&ck.powers(), &(&polynomial).into(), None, &AtomicBool::new(false), None).unwrap().0
+            };
+            assert_eq!(commit(&ck), commit(&precomputed_ck));
+        }
+
+        // The precomputation is omitted by `ToBytes`, but kept by `CanonicalSerialize`.
+        assert_eq!(ck.to_bytes_le().unwrap(), precomputed_ck.to_bytes_le().unwrap());
+        let mut bytes = Vec::new();
+        precomputed_ck.serialize(&mut bytes).unwrap();
+        let recovered = CommitterKey::<Bls12_377>::deserialize(&mut &bytes[..]).unwrap();
+        assert_eq!(recovered.precomputed_powers_of_beta_g, precomputed_ck.precomputed_powers_of_beta_g);
+    }
+
+    #[test]
+    fn test_verifier_key_caches_prepared_g2() {
+        use snarkvm_curves::traits::PairingCurve;
+
+        let rng = &mut test_rng();
+        let pp = PC_Bls12_377::setup(64, rng).unwrap();
+        let (_ck, vk) = PC_Bls12_377::trim(&pp, 64, [], 0, None).unwrap();
+
+        // The G2 elements of every pairing check are prepared once, when the key is trimmed.
+        assert_eq!(vk.vk.prepared_h, vk.vk.h.prepare());
+        assert_eq!(vk.vk.prepared_beta_h, vk.vk.beta_h.prepare());
+        let degree_bounds_and_neg_powers_of_h = vk.degree_bounds_and_neg_powers_of_h.as_ref().unwrap();
+        assert!(!degree_bounds_and_neg_powers_of_h.is_empty());
+        for (degree_bound, shift_power) in degree_bounds_and_neg_powers_of_h {
+            assert_eq!(vk.get_prepared_shift_power(*degree_bound), Some(&shift_power.prepare()));
+        }
+
+        // The prepared elements are kept by serialization, so a deserialized key does not prepare them again.
+        let vk_bytes = vk.to_bytes_le().unwrap();
+        let vk_recovered: super::VerifierKey<Bls12_377> = FromBytes::read_le(&vk_bytes[..]).unwrap();
+        assert_eq!(vk_recovered, vk);
+    }
+
     #[test]
     fn test_single_poly() {
-        single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        single_poly_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
     }
 
     #[test]
     fn test_quadratic_poly_degree_bound_multiple_queries() {
-        quadratic_poly_degree_bound_multiple_queries_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        quadratic_poly_degree_bound_multiple_queries_test::<Fr, Fq, PC_Bls12_377, Sponge>()
+            .expect("test failed for bls12-377");
     }
 
     #[test]
     fn test_linear_poly_degree_bound() {
-        linear_poly_degree_bound_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        linear_poly_degree_bound_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
     }
 
     #[test]
     fn test_single_poly_degree_bound() {
-        single_poly_degree_bound_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        single_poly_degree_bound_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
     }
 
     #[test]
     fn test_single_poly_degree_bound_multiple_queries() {
-        single_poly_degree_bound_multiple_queries_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        single_poly_degree_bound_multiple_queries_test::<Fr, Fq, PC_Bls12_377, Sponge>()
+            .expect("test failed for bls12-377");
     }
 
     #[test]
     fn test_two_polys_degree_bound_single_query() {
-        two_polys_degree_bound_single_query_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        two_polys_degree_bound_single_query_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
     }
 
     #[test]
     fn test_full_end_to_end() {
-        full_end_to_end_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        full_end_to_end_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
         println!("Finished bls12-377");
     }
 
     #[test]
     fn test_single_equation() {
-        single_equation_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        single_equation_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
         println!("Finished bls12-377");
     }
 
     #[test]
     fn test_two_equation() {
-        two_equation_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        two_equation_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
         println!("Finished bls12-377");
     }
 
     #[test]
     fn test_two_equation_degree_bound() {
-        two_equation_degree_bound_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        two_equation_degree_bound_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
         println!("Finished bls12-377");
     }
 
     #[test]
     fn test_full_end_to_end_equation() {
-        full_end_to_end_equation_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        full_end_to_end_equation_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
         println!("Finished bls12-377");
     }
 
     #[test]
     #[should_panic]
     fn test_bad_degree_bound() {
-        bad_degree_bound_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        bad_degree_bound_test::<Fr, Fq, PC_Bls12_377, Sponge>().expect("test failed for bls12-377");
         println!("Finished bls12-377");
     }
 
     #[test]
     fn test_lagrange_commitment() {
-        crate::polycommit::test_templates::lagrange_test_template::<Bls12_377, Sponge>()
+        crate::polycommit::test_templates::lagrange_test_template::<Fr, Fq, PC_Bls12_377, Sponge>()
             .expect("test failed for bls12-377");
         println!("Finished bls12-377");
     }
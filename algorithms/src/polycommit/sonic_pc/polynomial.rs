@@ -17,10 +17,17 @@
 use super::PolynomialLabel;
 use crate::fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, Polynomial, SparsePolynomial};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, CanonicalDeserialize, CanonicalSerialize};
+use snarkvm_utilities::{
+    borrow::{Cow, ToOwned},
+    cfg_iter,
+    cfg_iter_mut,
+    vec,
+    vec::Vec,
+    CanonicalDeserialize,
+    CanonicalSerialize,
+};
 
 use hashbrown::HashMap;
-use std::borrow::Cow;
 
 #[cfg(not(feature = "parallel"))]
 use itertools::Itertools;
@@ -260,7 +267,7 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
             lagrange_polys
                 .into_iter()
                 .map(|(k, v)| {
-                    let domain = EvaluationDomain::new(k).unwrap();
+                    let domain = EvaluationDomain::new_with_size(k).unwrap();
                     Lagrange { evaluations: Cow::Owned(EvaluationsOnDomain::from_vec_and_domain(v, domain)) }
                 })
                 .chain({
@@ -16,29 +16,22 @@
 
 use std::marker::PhantomData;
 
-use super::sonic_pc::{
-    BatchLCProof,
-    BatchProof,
-    Commitment,
-    Evaluations,
-    LabeledCommitment,
-    QuerySet,
-    Randomness,
-    SonicKZG10,
-    VerifierKey,
-};
+use super::sonic_pc::{Evaluations, LabeledCommitment, QuerySet};
 use crate::{
     fft::DensePolynomial,
     polycommit::{
+        ipa_pc,
+        kzg10,
         sonic_pc::{LabeledPolynomial, LabeledPolynomialWithBasis, LinearCombination},
         PCError,
+        PolynomialCommitment,
     },
     snark::marlin::FiatShamirRng,
 };
 use itertools::Itertools;
-use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::{rand::test_rng, UniformRand};
+use snarkvm_curves::{AffineCurve, PairingEngine};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::rand::test_rng;
 
 use rand::{
     distributions::{self, Distribution},
@@ -56,21 +49,43 @@ struct TestInfo {
     num_equations: Option<usize>,
 }
 
-pub struct TestComponents<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> {
-    pub verification_key: VerifierKey<E>,
-    pub commitments: Vec<LabeledCommitment<Commitment<E>>>,
-    pub query_set: QuerySet<'static, E::Fr>,
-    pub evaluations: Evaluations<'static, E::Fr>,
-    pub batch_lc_proof: Option<BatchLCProof<E>>,
-    pub batch_proof: Option<BatchProof<E>>,
-    pub randomness: Vec<Randomness<E>>,
-    _sponge: PhantomData<S>,
+/// The degree bounds that the universal parameters of a scheme can enforce.
+pub trait SupportedDegreeBounds {
+    fn supported_degree_bounds(&self) -> Vec<usize>;
+}
+
+impl<E: PairingEngine> SupportedDegreeBounds for kzg10::UniversalParams<E> {
+    fn supported_degree_bounds(&self) -> Vec<usize> {
+        self.supported_degree_bounds().to_vec()
+    }
+}
+
+impl<G: AffineCurve> SupportedDegreeBounds for ipa_pc::UniversalParams<G> {
+    fn supported_degree_bounds(&self) -> Vec<usize> {
+        (1..=self.max_degree()).collect()
+    }
+}
+
+pub struct TestComponents<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, S: FiatShamirRng<F, CF>> {
+    pub verification_key: PC::VerifierKey,
+    pub commitments: Vec<LabeledCommitment<PC::Commitment>>,
+    pub query_set: QuerySet<'static, F>,
+    pub evaluations: Evaluations<'static, F>,
+    pub batch_lc_proof: Option<PC::BatchLCProof>,
+    pub batch_proof: Option<PC::BatchProof>,
+    pub randomness: Vec<PC::Randomness>,
+    _sponge: PhantomData<(CF, S)>,
 }
 
-pub fn bad_degree_bound_test<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>() -> Result<(), PCError> {
+pub fn bad_degree_bound_test<
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    S: FiatShamirRng<F, CF>,
+>() -> Result<(), PCError> {
     let rng = &mut test_rng();
     let max_degree = 100;
-    let pp = SonicKZG10::<E, S>::setup(max_degree, rng)?;
+    let pp = PC::setup(max_degree, rng)?;
 
     for _ in 0..10 {
         let supported_degree = distributions::Uniform::from(1..=max_degree).sample(rng);
@@ -93,15 +108,14 @@ pub fn bad_degree_bound_test<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>()
         }
 
         println!("supported degree: {:?}", supported_degree);
-        let (ck, vk) =
-            SonicKZG10::<E, S>::trim(&pp, supported_degree, None, supported_degree, Some(degree_bounds.as_slice()))?;
+        let (ck, vk) = PC::trim(&pp, supported_degree, None, supported_degree, Some(degree_bounds.as_slice()))?;
         println!("Trimmed");
 
-        let (comms, rands) = SonicKZG10::<E, S>::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
+        let (comms, rands) = PC::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
 
         let mut query_set = QuerySet::new();
         let mut values = Evaluations::new();
-        let point = E::Fr::rand(rng);
+        let point = F::rand(rng);
         for (i, label) in labels.iter().enumerate() {
             query_set.insert((label.clone(), ("rand".into(), point)));
             let value = polynomials[i].evaluate(point);
@@ -110,16 +124,20 @@ pub fn bad_degree_bound_test<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>()
         println!("Generated query set");
 
         let mut sponge_for_open = S::new();
-        let proof = SonicKZG10::batch_open(&ck, &polynomials, &comms, &query_set, &rands, &mut sponge_for_open)?;
+        let proof = PC::batch_open(&ck, &polynomials, &comms, &query_set, &rands, &mut sponge_for_open)?;
         let mut sponge_for_check = S::new();
-        let result = SonicKZG10::batch_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
+        let result = PC::batch_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
         assert!(result, "proof was incorrect, Query set: {:#?}", query_set);
     }
     Ok(())
 }
 
-pub fn lagrange_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>()
--> Result<Vec<TestComponents<E, S>>, PCError> {
+pub fn lagrange_test_template<
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    S: FiatShamirRng<F, CF>,
+>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError> {
     let num_iters = 10usize;
     let max_degree = 256usize;
     let supported_degree = 127usize;
@@ -129,7 +147,7 @@ pub fn lagrange_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
     let mut test_components = Vec::new();
 
     let rng = &mut test_rng();
-    let pp = SonicKZG10::<E, S>::setup(max_degree, rng)?;
+    let pp = PC::setup(max_degree, rng)?;
 
     for _ in 0..num_iters {
         assert!(max_degree >= supported_degree, "max_degree < supported_degree");
@@ -147,9 +165,9 @@ pub fn lagrange_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
             let label = format!("Test{}", i);
             labels.push(label.clone());
             let eval_size: usize = distributions::Uniform::from(1..eval_size).sample(rng).next_power_of_two();
-            let mut evals = vec![E::Fr::zero(); eval_size];
+            let mut evals = vec![F::zero(); eval_size];
             for e in &mut evals {
-                *e = E::Fr::rand(rng);
+                *e = F::rand(rng);
             }
             let domain = crate::fft::EvaluationDomain::new(evals.len()).unwrap();
             let evals = crate::fft::Evaluations::from_vec_and_domain(evals, domain);
@@ -167,23 +185,18 @@ pub fn lagrange_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
         println!("supported degree: {:?}", supported_degree);
         println!("supported hiding bound: {:?}", supported_hiding_bound);
         println!("num_points_in_query_set: {:?}", num_points_in_query_set);
-        let (ck, vk) = SonicKZG10::<E, S>::trim(
-            &pp,
-            supported_degree,
-            supported_lagrange_sizes,
-            supported_hiding_bound,
-            degree_bounds,
-        )?;
+        let (ck, vk) =
+            PC::trim(&pp, supported_degree, supported_lagrange_sizes, supported_hiding_bound, degree_bounds)?;
         println!("Trimmed");
 
-        let (comms, rands) = SonicKZG10::<E, S>::commit(&ck, lagrange_polynomials, Some(rng)).unwrap();
+        let (comms, rands) = PC::commit(&ck, lagrange_polynomials, Some(rng)).unwrap();
 
         // Construct query set
         let mut query_set = QuerySet::new();
         let mut values = Evaluations::new();
-        // let mut point = E::Fr::one();
+        // let mut point = F::one();
         for point_id in 0..num_points_in_query_set {
-            let point = E::Fr::rand(rng);
+            let point = F::rand(rng);
             for (polynomial, label) in polynomials.iter().zip_eq(labels.iter()) {
                 query_set.insert((label.clone(), (format!("rand_{}", point_id), point)));
                 let value = polynomial.evaluate(point);
@@ -193,9 +206,9 @@ pub fn lagrange_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
         println!("Generated query set");
 
         let mut sponge_for_open = S::new();
-        let proof = SonicKZG10::batch_open(&ck, &polynomials, &comms, &query_set, &rands, &mut sponge_for_open)?;
+        let proof = PC::batch_open(&ck, &polynomials, &comms, &query_set, &rands, &mut sponge_for_open)?;
         let mut sponge_for_check = S::new();
-        let result = SonicKZG10::batch_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
+        let result = PC::batch_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
         if !result {
             println!(
                 "Failed with {} polynomials, num_points_in_query_set: {:?}",
@@ -222,10 +235,13 @@ pub fn lagrange_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
     Ok(test_components)
 }
 
-fn test_template<E, S>(info: TestInfo) -> Result<Vec<TestComponents<E, S>>, PCError>
+fn test_template<F, CF, PC, S>(info: TestInfo) -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let TestInfo {
         num_iters,
@@ -241,7 +257,7 @@ where
 
     let rng = &mut test_rng();
     let max_degree = max_degree.unwrap_or_else(|| distributions::Uniform::from(8..=64).sample(rng));
-    let pp = SonicKZG10::<E, S>::setup(max_degree, rng)?;
+    let pp = PC::setup(max_degree, rng)?;
     let supported_degree_bounds = pp.supported_degree_bounds();
 
     for _ in 0..num_iters {
@@ -293,18 +309,17 @@ where
         println!("supported degree: {:?}", supported_degree);
         println!("supported hiding bound: {:?}", supported_hiding_bound);
         println!("num_points_in_query_set: {:?}", num_points_in_query_set);
-        let (ck, vk) =
-            SonicKZG10::<E, S>::trim(&pp, supported_degree, None, supported_hiding_bound, degree_bounds.as_deref())?;
+        let (ck, vk) = PC::trim(&pp, supported_degree, None, supported_hiding_bound, degree_bounds.as_deref())?;
         println!("Trimmed");
 
-        let (comms, rands) = SonicKZG10::<E, S>::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
+        let (comms, rands) = PC::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
 
         // Construct query set
         let mut query_set = QuerySet::new();
         let mut values = Evaluations::new();
-        // let mut point = E::Fr::one();
+        // let mut point = F::one();
         for point_id in 0..num_points_in_query_set {
-            let point = E::Fr::rand(rng);
+            let point = F::rand(rng);
             for (polynomial, label) in polynomials.iter().zip_eq(labels.iter()) {
                 query_set.insert((label.clone(), (format!("rand_{}", point_id), point)));
                 let value = polynomial.evaluate(point);
@@ -314,9 +329,9 @@ where
         println!("Generated query set");
 
         let mut sponge_for_open = S::new();
-        let proof = SonicKZG10::batch_open(&ck, &polynomials, &comms, &query_set, &rands, &mut sponge_for_open)?;
+        let proof = PC::batch_open(&ck, &polynomials, &comms, &query_set, &rands, &mut sponge_for_open)?;
         let mut sponge_for_check = S::new();
-        let result = SonicKZG10::batch_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
+        let result = PC::batch_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
         if !result {
             println!(
                 "Failed with {} polynomials, num_points_in_query_set: {:?}",
@@ -343,9 +358,14 @@ where
     Ok(test_components)
 }
 
-fn equation_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
-    info: TestInfo,
-) -> Result<Vec<TestComponents<E, S>>, PCError> {
+fn equation_test_template<F, CF, PC, S>(info: TestInfo) -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
+where
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
+{
     let TestInfo {
         num_iters,
         max_degree,
@@ -360,7 +380,7 @@ fn equation_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
 
     let rng = &mut test_rng();
     let max_degree = max_degree.unwrap_or_else(|| distributions::Uniform::from(8..=64).sample(rng));
-    let pp = SonicKZG10::<E, S>::setup(max_degree, rng)?;
+    let pp = PC::setup(max_degree, rng)?;
     let supported_degree_bounds = pp.supported_degree_bounds();
 
     for _ in 0..num_iters {
@@ -416,28 +436,27 @@ fn equation_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
         println!("{}", num_polynomials);
         println!("{}", enforce_degree_bounds);
 
-        let (ck, vk) =
-            SonicKZG10::<E, S>::trim(&pp, supported_degree, None, supported_hiding_bound, degree_bounds.as_deref())?;
+        let (ck, vk) = PC::trim(&pp, supported_degree, None, supported_hiding_bound, degree_bounds.as_deref())?;
         println!("Trimmed");
 
-        let (comms, rands) = SonicKZG10::<E, S>::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
+        let (comms, rands) = PC::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
 
         // Let's construct our equations
         let mut linear_combinations = Vec::new();
         let mut query_set = QuerySet::new();
         let mut values = Evaluations::new();
         for i in 0..num_points_in_query_set {
-            let point = E::Fr::rand(rng);
+            let point = F::rand(rng);
             for j in 0..num_equations.unwrap() {
                 let label = format!("query {} eqn {}", i, j);
                 let mut lc = LinearCombination::empty(label.clone());
 
-                let mut value = E::Fr::zero();
+                let mut value = F::zero();
                 let should_have_degree_bounds: bool = rng.gen();
                 for (k, label) in labels.iter().enumerate() {
                     if should_have_degree_bounds {
                         value += &polynomials[k].evaluate(point);
-                        lc.add(E::Fr::one(), label.clone());
+                        lc.add(F::one(), label.clone());
                         break;
                     } else {
                         let poly = &polynomials[k];
@@ -445,7 +464,7 @@ fn equation_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
                             continue;
                         } else {
                             assert!(poly.degree_bound().is_none());
-                            let coeff = E::Fr::rand(rng);
+                            let coeff = F::rand(rng);
                             value += &(coeff * poly.evaluate(point));
                             lc.add(coeff, label.clone());
                         }
@@ -466,7 +485,7 @@ fn equation_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
         println!("Linear combinations: {:?}", linear_combinations);
 
         let mut sponge_for_open = S::new();
-        let proof = SonicKZG10::open_combinations(
+        let proof = PC::open_combinations(
             &ck,
             &linear_combinations,
             &polynomials,
@@ -477,7 +496,7 @@ fn equation_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
         )?;
         println!("Generated proof");
         let mut sponge_for_check = S::new();
-        let result = SonicKZG10::check_combinations(
+        let result = PC::check_combinations(
             &vk,
             &linear_combinations,
             &comms,
@@ -512,10 +531,13 @@ fn equation_test_template<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>>(
     Ok(test_components)
 }
 
-pub fn single_poly_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn single_poly_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -526,13 +548,16 @@ where
         max_num_queries: 1,
         ..Default::default()
     };
-    test_template::<E, S>(info)
+    test_template::<F, CF, PC, S>(info)
 }
 
-pub fn linear_poly_degree_bound_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn linear_poly_degree_bound_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -543,13 +568,16 @@ where
         max_num_queries: 1,
         ..Default::default()
     };
-    test_template::<E, S>(info)
+    test_template::<F, CF, PC, S>(info)
 }
 
-pub fn single_poly_degree_bound_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn single_poly_degree_bound_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -560,13 +588,17 @@ where
         max_num_queries: 1,
         ..Default::default()
     };
-    test_template::<E, S>(info)
+    test_template::<F, CF, PC, S>(info)
 }
 
-pub fn quadratic_poly_degree_bound_multiple_queries_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn quadratic_poly_degree_bound_multiple_queries_test<F, CF, PC, S>(
+) -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -577,13 +609,17 @@ where
         max_num_queries: 2,
         ..Default::default()
     };
-    test_template::<E, S>(info)
+    test_template::<F, CF, PC, S>(info)
 }
 
-pub fn single_poly_degree_bound_multiple_queries_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn single_poly_degree_bound_multiple_queries_test<F, CF, PC, S>(
+) -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -594,13 +630,16 @@ where
         max_num_queries: 2,
         ..Default::default()
     };
-    test_template::<E, S>(info)
+    test_template::<F, CF, PC, S>(info)
 }
 
-pub fn two_polys_degree_bound_single_query_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn two_polys_degree_bound_single_query_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -611,13 +650,16 @@ where
         max_num_queries: 1,
         ..Default::default()
     };
-    test_template::<E, S>(info)
+    test_template::<F, CF, PC, S>(info)
 }
 
-pub fn full_end_to_end_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn full_end_to_end_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -628,13 +670,16 @@ where
         max_num_queries: 5,
         ..Default::default()
     };
-    test_template::<E, S>(info)
+    test_template::<F, CF, PC, S>(info)
 }
 
-pub fn full_end_to_end_equation_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn full_end_to_end_equation_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -645,13 +690,16 @@ where
         max_num_queries: 5,
         num_equations: Some(10),
     };
-    equation_test_template::<E, S>(info)
+    equation_test_template::<F, CF, PC, S>(info)
 }
 
-pub fn single_equation_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn single_equation_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -662,13 +710,16 @@ where
         max_num_queries: 1,
         num_equations: Some(1),
     };
-    equation_test_template::<E, S>(info)
+    equation_test_template::<F, CF, PC, S>(info)
 }
 
-pub fn two_equation_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn two_equation_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -679,13 +730,16 @@ where
         max_num_queries: 1,
         num_equations: Some(2),
     };
-    equation_test_template::<E, S>(info)
+    equation_test_template::<F, CF, PC, S>(info)
 }
 
-pub fn two_equation_degree_bound_test<E, S>() -> Result<Vec<TestComponents<E, S>>, PCError>
+pub fn two_equation_degree_bound_test<F, CF, PC, S>() -> Result<Vec<TestComponents<F, CF, PC, S>>, PCError>
 where
-    E: PairingEngine,
-    S: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    PC::UniversalParams: SupportedDegreeBounds,
+    S: FiatShamirRng<F, CF>,
 {
     let info = TestInfo {
         num_iters: 100,
@@ -696,5 +750,5 @@ where
         max_num_queries: 1,
         num_equations: Some(2),
     };
-    equation_test_template::<E, S>(info)
+    equation_test_template::<F, CF, PC, S>(info)
 }
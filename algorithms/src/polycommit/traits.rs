@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    polycommit::{
+        sonic_pc::{
+            Evaluations,
+            LabeledCommitment,
+            LabeledPolynomial,
+            LabeledPolynomialWithBasis,
+            LinearCombination,
+            QuerySet,
+        },
+        PCError,
+    },
+    snark::marlin::FiatShamirRng,
+    Prepare,
+};
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_utilities::{serialize::*, string::ToString, FromBytes, ToBytes, ToMinimalBits};
+
+use core::{fmt::Debug, sync::atomic::AtomicBool};
+use rand_core::RngCore;
+
+/// The randomness used to hide a polynomial commitment.
+pub trait PCRandomness: Clone {
+    /// Outputs the randomness of a commitment that does not hide its polynomial.
+    fn empty() -> Self;
+}
+
+/// An evaluation proof output by a polynomial commitment scheme.
+pub trait PCProof {
+    /// Returns `true` if the proof hides the evaluated polynomials.
+    fn is_hiding(&self) -> bool;
+}
+
+/// Describes a polynomial commitment scheme over the scalar field `F`, whose commitments
+/// are absorbed into Fiat-Shamir sponges over the base field `CF`.
+///
+/// Polynomials are committed in batches, and opened at the points of a [`QuerySet`],
+/// either directly or through [`LinearCombination`]s of the committed polynomials.
+/// The Fiat-Shamir RNG used to batch the openings is provided by the caller, so that
+/// the openings can be bound to the transcript of an enclosing protocol.
+pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>:
+    Sized + Clone + Debug + PartialEq + Eq + Send + Sync
+{
+    /// The universal parameters of the scheme, from which the keys are trimmed.
+    type UniversalParams: Clone + Debug + FromBytes + ToBytes + Send + Sync;
+    /// The key used to commit to polynomials, and to open them.
+    type CommitterKey: Clone + Debug + FromBytes + ToBytes + Send + Sync;
+    /// The key used to check evaluation proofs.
+    type VerifierKey: Clone
+        + Debug
+        + PartialEq
+        + Eq
+        + CanonicalSerialize
+        + CanonicalDeserialize
+        + FromBytes
+        + ToBytes
+        + Prepare<Prepared = Self::PreparedVerifierKey>
+        + Send
+        + Sync;
+    /// The verifier key, prepared for checking evaluation proofs.
+    type PreparedVerifierKey: Clone + Send + Sync;
+    /// A commitment to a polynomial.
+    type Commitment: Copy
+        + Debug
+        + PartialEq
+        + Eq
+        + CanonicalSerialize
+        + CanonicalDeserialize
+        + ToConstraintField<CF>
+        + ToMinimalBits
+        + Prepare<Prepared = Self::PreparedCommitment>
+        + Send
+        + Sync
+        + 'static;
+    /// A commitment, prepared for checking evaluation proofs.
+    type PreparedCommitment: Clone + Send + Sync;
+    /// The randomness used to hide a commitment.
+    type Randomness: PCRandomness
+        + Debug
+        + PartialEq
+        + Eq
+        + CanonicalSerialize
+        + CanonicalDeserialize
+        + Send
+        + Sync
+        + 'static;
+    /// An evaluation proof for the polynomials of a query set.
+    type BatchProof: PCProof + Clone + Debug + PartialEq + Eq + CanonicalSerialize + CanonicalDeserialize + Send + Sync;
+    /// An evaluation proof for the linear combinations of a query set.
+    type BatchLCProof: PCProof
+        + Clone
+        + Debug
+        + PartialEq
+        + Eq
+        + CanonicalSerialize
+        + CanonicalDeserialize
+        + FromBytes
+        + ToBytes
+        + Send
+        + Sync;
+
+    /// Outputs universal parameters for committing to polynomials of degree up to `max_degree`.
+    fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<Self::UniversalParams, PCError>;
+
+    /// Specializes the universal parameters for polynomials of degree up to `supported_degree`,
+    /// with the given Lagrange bases, hiding bound, and enforced degree bounds.
+    fn trim(
+        pp: &Self::UniversalParams,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), PCError>;
+
+    /// Precomputes tables that speed up every commitment and evaluation proof with `ck`, at the cost of memory.
+    /// Schemes without such tables leave `ck` unchanged.
+    fn precompute_committer_key(_ck: &mut Self::CommitterKey) {}
+
+    /// Returns `true` if the tables of [`Self::precompute_committer_key`] are present in `ck`.
+    /// Schemes without such tables always return `true`.
+    fn is_committer_key_precomputed(_ck: &Self::CommitterKey) -> bool {
+        true
+    }
+
+    /// Returns `true` if `ck` supports the same degrees as `vk`, as it does when both are trimmed together.
+    fn is_committer_key_for(ck: &Self::CommitterKey, vk: &Self::VerifierKey) -> bool;
+
+    /// Outputs commitments to `polynomials`. If `polynomials[i].is_hiding()`,
+    /// then the `i`-th commitment is hiding, and `rng` must not be `None`.
+    fn commit<'a>(
+        ck: &Self::CommitterKey,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'a, F>>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Self::Commitment>>, Vec<Self::Randomness>), PCError> {
+        Self::commit_with_terminator(ck, polynomials, &AtomicBool::new(false), rng)
+    }
+
+    /// Outputs commitments to `polynomials`, stopping early if `terminator` is set.
+    fn commit_with_terminator<'a>(
+        ck: &Self::CommitterKey,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'a, F>>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Self::Commitment>>, Vec<Self::Randomness>), PCError>;
+
+    /// Outputs a proof of evaluation of `labeled_polynomials` at `point`.
+    fn open<'a, S: FiatShamirRng<F, CF>>(
+        ck: &Self::CommitterKey,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: F,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+        fs_rng: &mut S,
+    ) -> Result<Self::BatchProof, PCError> {
+        let labeled_polynomials = labeled_polynomials.into_iter().collect::<Vec<_>>();
+        let query_set = labeled_polynomials
+            .iter()
+            .map(|p| (p.label().to_string(), ("point".to_string(), point)))
+            .collect::<QuerySet<F>>();
+        Self::batch_open(ck, labeled_polynomials, commitments, &query_set, rands, fs_rng)
+    }
+
+    /// Checks that `values` are the evaluations at `point` of the polynomials committed in `commitments`.
+    fn check<'a, S: FiatShamirRng<F, CF>>(
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: F,
+        values: impl IntoIterator<Item = F>,
+        proof: &Self::BatchProof,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError> {
+        let commitments = commitments.into_iter().collect::<Vec<_>>();
+        let mut query_set = QuerySet::new();
+        let mut evaluations = Evaluations::new();
+        for (commitment, value) in commitments.iter().zip(values) {
+            query_set.insert((commitment.label().to_string(), ("point".to_string(), point)));
+            evaluations.insert((commitment.label().to_string(), point), value);
+        }
+        Self::batch_check(vk, commitments, &query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Outputs a proof of evaluation of `labeled_polynomials` at the points in `query_set`.
+    fn batch_open<'a, S: FiatShamirRng<F, CF>>(
+        ck: &Self::CommitterKey,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        query_set: &QuerySet<F>,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+        fs_rng: &mut S,
+    ) -> Result<Self::BatchProof, PCError>;
+
+    /// Checks that `values` are the evaluations at the points in `query_set`
+    /// of the polynomials committed in `commitments`.
+    fn batch_check<'a, S: FiatShamirRng<F, CF>>(
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        query_set: &QuerySet<F>,
+        values: &Evaluations<F>,
+        proof: &Self::BatchProof,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError>;
+
+    /// Outputs a proof of evaluation of `linear_combinations` of `polynomials`
+    /// at the points in `query_set`.
+    fn open_combinations<'a, S: FiatShamirRng<F, CF>>(
+        ck: &Self::CommitterKey,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<F>>,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        query_set: &QuerySet<F>,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+        fs_rng: &mut S,
+    ) -> Result<Self::BatchLCProof, PCError>;
+
+    /// Checks that `evaluations` are the evaluations at the points in `query_set`
+    /// of `linear_combinations` of the polynomials committed in `commitments`.
+    fn check_combinations<'a, S: FiatShamirRng<F, CF>>(
+        vk: &Self::VerifierKey,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<F>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        query_set: &QuerySet<F>,
+        evaluations: &Evaluations<F>,
+        proof: &Self::BatchLCProof,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError>;
+}
@@ -16,8 +16,9 @@
 
 use crate::{crypto_hash::Poseidon, PRF};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{vec, vec::Vec};
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 #[derive(Clone)]
 pub struct PoseidonPRF<F: PrimeField, const RATE: usize, const OPTIMIZED_FOR_WEIGHTS: bool>(PhantomData<F>);
@@ -21,7 +21,7 @@ use crate::{
     SignatureSchemeOperations,
 };
 use snarkvm_curves::{
-    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    templates::twisted_edwards_extended::{Affine as TEAffine, CombTable, Projective as TEProjective},
     AffineCurve,
     ProjectiveCurve,
     TwistedEdwardsParameters,
@@ -31,6 +31,7 @@ use snarkvm_utilities::{
     io::{Read, Result as IoResult, Write},
     rand::UniformRand,
     serialize::*,
+    vec,
     FromBits,
     FromBytes,
     ToBits,
@@ -38,7 +39,6 @@ use snarkvm_utilities::{
 };
 
 use anyhow::Result;
-use itertools::Itertools;
 use rand::{CryptoRng, Rng};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
@@ -50,6 +50,16 @@ pub struct AleoSignature<TE: TwistedEdwardsParameters> {
 }
 
 impl<TE: TwistedEdwardsParameters> AleoSignature<TE> {
+    /// Initializes a signature from its components.
+    pub(crate) fn new(
+        prover_response: TE::ScalarField,
+        verifier_challenge: TE::ScalarField,
+        root_public_key: TE::BaseField,
+        root_randomizer: TE::BaseField,
+    ) -> Self {
+        Self { prover_response, verifier_challenge, root_public_key, root_randomizer }
+    }
+
     #[inline]
     pub fn size() -> usize {
         2 * TE::ScalarField::SERIALIZED_SIZE + 2 * TE::BaseField::SERIALIZED_SIZE
@@ -118,6 +128,7 @@ where
     TE::BaseField: PrimeField,
 {
     g_bases: Vec<TEProjective<TE>>,
+    g_table: CombTable<TE>,
     poseidon: Poseidon<TE::BaseField, 4, false>,
 }
 
@@ -133,10 +144,10 @@ where
     fn setup(message: &str) -> Self {
         assert!(TE::ScalarField::size_in_data_bits() < TE::BaseField::size_in_data_bits());
 
+        let (base, _, _) = hash_to_curve::<TEAffine<TE>>(message);
+
         // Compute the powers of G.
         let g_bases = {
-            let (base, _, _) = hash_to_curve::<TEAffine<TE>>(message);
-
             let mut g = base.to_projective();
             let mut g_bases = Vec::with_capacity(TE::ScalarField::size_in_bits());
             for _ in 0..TE::ScalarField::size_in_bits() {
@@ -146,9 +157,12 @@ where
             g_bases
         };
 
+        // Compute the comb table of G.
+        let g_table = CombTable::new(&base);
+
         let crypto_hash = Poseidon::<TE::BaseField, 4, false>::setup();
 
-        Self { g_bases, poseidon: crypto_hash }
+        Self { g_bases, g_table, poseidon: crypto_hash }
     }
 
     fn parameters(&self) -> &Self::Parameters {
@@ -170,10 +184,10 @@ where
         let (sk_sig, r_sig) = private_key;
 
         // Compute G^sk_sig.
-        let g_sk_sig = self.g_scalar_multiply(sk_sig);
+        let g_sk_sig = self.g_scalar_multiply_ct(sk_sig);
 
         // Compute G^r_sig.
-        let g_r_sig = self.g_scalar_multiply(r_sig);
+        let g_r_sig = self.g_scalar_multiply_ct(r_sig);
 
         // We do a batch inversion to save one inversion.
         let mut to_invert = [g_sk_sig, g_r_sig];
@@ -184,7 +198,7 @@ where
         let sk_prf = self.hash_to_scalar_field(&[g_sk_sig_affine.to_x_coordinate(), g_r_sig_affine.to_x_coordinate()]);
 
         // Compute G^sk_prf.
-        let g_sk_prf = self.g_scalar_multiply(&sk_prf);
+        let g_sk_prf = self.g_scalar_multiply_ct(&sk_prf);
 
         // Compute G^sk_sig G^r_sig G^sk_prf.
         (g_sk_sig + g_r_sig + g_sk_prf).into()
@@ -205,16 +219,16 @@ where
         let r = TE::ScalarField::rand(rng);
 
         // Compute G^r.
-        let g_r = self.g_scalar_multiply(&r);
+        let g_r = self.g_scalar_multiply_ct(&r);
 
         // Extract (sk_sig, r_sig).
         let (sk_sig, r_sig) = private_key;
 
         // Compute G^sk_sig.
-        let g_sk_sig = self.g_scalar_multiply(sk_sig);
+        let g_sk_sig = self.g_scalar_multiply_ct(sk_sig);
 
         // Compute G^r_sig.
-        let g_r_sig = self.g_scalar_multiply(r_sig);
+        let g_r_sig = self.g_scalar_multiply_ct(r_sig);
 
         let mut to_invert = [g_sk_sig, g_r_sig, g_r];
         TEProjective::<TE>::batch_normalization(&mut to_invert);
@@ -224,33 +238,23 @@ where
         let sk_prf = self.hash_to_scalar_field(&[g_sk_sig_affine.to_x_coordinate(), g_r_sig_affine.to_x_coordinate()]);
 
         // Compute G^sk_prf.
-        let g_sk_prf = self.g_scalar_multiply(&sk_prf);
+        let g_sk_prf = self.g_scalar_multiply_ct(&sk_prf);
 
         // Compute G^sk_sig G^r_sig G^sk_prf.
         let public_key = (g_sk_sig + g_r_sig + g_sk_prf).to_affine();
 
         // Compute the verifier challenge.
-        let verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.to_x_coordinate().to_field_elements()?);
-            preimage.extend_from_slice(&g_r_affine.to_x_coordinate().to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let verifier_challenge = self.compute_verifier_challenge(&public_key, &g_r_affine, message)?;
 
         // Compute the prover response.
         let prover_response = r - (verifier_challenge * sk_sig);
 
-        Ok(AleoSignature {
+        Ok(AleoSignature::new(
             prover_response,
             verifier_challenge,
-            root_public_key: g_sk_sig_affine.to_x_coordinate(),
-            root_randomizer: g_r_sig_affine.to_x_coordinate(),
-        })
+            g_sk_sig_affine.to_x_coordinate(),
+            g_r_sig_affine.to_x_coordinate(),
+        ))
     }
 
     ///
@@ -271,17 +275,7 @@ where
         let g_r = (self.g_scalar_multiply(prover_response) + g_sk_sig_c).to_affine();
 
         // Compute the candidate verifier challenge.
-        let candidate_verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.to_x_coordinate().to_field_elements()?);
-            preimage.extend_from_slice(&g_r.to_x_coordinate().to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let candidate_verifier_challenge = self.compute_verifier_challenge(public_key, &g_r, message)?;
 
         // Recover G^r_sig.
         let g_r_sig = Self::recover_from_x_coordinate(root_randomizer)?;
@@ -320,14 +314,11 @@ where
     }
 
     fn g_scalar_multiply(&self, scalar: &Self::ScalarField) -> <Self::AffineCurve as AffineCurve>::Projective {
-        self.g_bases
-            .iter()
-            .zip_eq(&scalar.to_bits_le())
-            .filter_map(|(base, bit)| match bit {
-                true => Some(base),
-                false => None,
-            })
-            .sum::<TEProjective<TE>>()
+        self.g_table.mul(scalar)
+    }
+
+    fn g_scalar_multiply_ct(&self, scalar: &Self::ScalarField) -> <Self::AffineCurve as AffineCurve>::Projective {
+        self.g_table.mul_ct(scalar)
     }
 
     fn hash_to_scalar_field(&self, input: &[Self::BaseField]) -> Self::ScalarField {
@@ -356,6 +347,26 @@ where
         base * *scalar
     }
 
+    ///
+    /// Returns the verifier challenge c := Hash(G^sk_sig G^r_sig G^sk_prf, G^r, message).
+    ///
+    pub(crate) fn compute_verifier_challenge(
+        &self,
+        public_key: &TEAffine<TE>,
+        g_r: &TEAffine<TE>,
+        message: &[bool],
+    ) -> Result<TE::ScalarField> {
+        // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
+        let mut preimage = vec![];
+        preimage.extend_from_slice(&public_key.to_x_coordinate().to_field_elements()?);
+        preimage.extend_from_slice(&g_r.to_x_coordinate().to_field_elements()?);
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+
+        // Hash to derive the verifier challenge.
+        Ok(self.hash_to_scalar_field(&preimage))
+    }
+
     fn recover_from_x_coordinate(x_coordinate: &TE::BaseField) -> Result<TEAffine<TE>> {
         if let Some(element) = TEAffine::<TE>::from_x_coordinate(*x_coordinate, true) {
             if element.is_in_correct_subgroup_assuming_on_curve() {
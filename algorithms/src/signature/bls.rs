@@ -0,0 +1,317 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{crypto_hash::HashToCurve, SignatureError, SignatureScheme};
+use snarkvm_curves::{templates::bls12::ZcashEncoding, AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{ExtensionField, Zero};
+use snarkvm_utilities::{bytes_from_bits_le, format, rand::UniformRand, string::String, vec::Vec};
+
+use anyhow::{ensure, Result};
+use core::marker::PhantomData;
+use hashbrown::HashSet;
+use rand::{CryptoRng, Rng};
+
+/// The BLS signature scheme of `draft-irtf-cfrg-bls-signature-05`, with public keys in G1 and signatures in G2.
+///
+/// This is the proof-of-possession ciphersuite: signatures on the same message may be aggregated
+/// and checked against the sum of the public keys, provided that every public key comes with a
+/// valid proof of possession of its private key, which rules out rogue key attacks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BLSSignatureScheme<E: PairingEngine>
+where
+    E::G1Affine: ZcashEncoding,
+    E::G2Affine: HashToCurve,
+    E::Fqe: ExtensionField,
+{
+    ciphersuite_id: String,
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> SignatureScheme for BLSSignatureScheme<E>
+where
+    E::G1Affine: ZcashEncoding,
+    E::G2Affine: HashToCurve,
+    E::Fqe: ExtensionField,
+{
+    type Parameters = String;
+    type PrivateKey = E::Fr;
+    type PublicKey = E::G1Affine;
+    type Signature = E::G2Affine;
+
+    /// Returns the proof-of-possession ciphersuite.
+    ///
+    /// The ciphersuite identifier is fixed by the hash-to-curve suite, so the given message is unused.
+    fn setup(_message: &str) -> Self {
+        Self { ciphersuite_id: format!("BLS_SIG_{}POP_", E::G2Affine::SUITE_ID), _engine: PhantomData }
+    }
+
+    /// Returns the ciphersuite identifier, which is the domain separation tag of signatures.
+    fn parameters(&self) -> &Self::Parameters {
+        &self.ciphersuite_id
+    }
+
+    ///
+    /// Returns a uniformly random non-zero private key.
+    ///
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::PrivateKey {
+        loop {
+            let private_key = E::Fr::rand(rng);
+            if !private_key.is_zero() {
+                return private_key;
+            }
+        }
+    }
+
+    ///
+    /// Returns the public key `sk * G1`.
+    ///
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        (E::G1Affine::prime_subgroup_generator() * *private_key).to_affine()
+    }
+
+    ///
+    /// Returns the signature `sk * H(message)`, where the message is given as little-endian bits of bytes.
+    ///
+    fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        message: &[bool],
+        _rng: &mut R,
+    ) -> Result<Self::Signature> {
+        self.sign_bytes(private_key, &Self::message_to_bytes(message)?)
+    }
+
+    ///
+    /// Verifies `e(pk, H(message)) = e(G1, signature)`, where the message is given as little-endian bits of bytes.
+    ///
+    fn verify(&self, public_key: &Self::PublicKey, message: &[bool], signature: &Self::Signature) -> Result<bool> {
+        self.verify_bytes(public_key, &Self::message_to_bytes(message)?, signature)
+    }
+}
+
+impl<E: PairingEngine> BLSSignatureScheme<E>
+where
+    E::G1Affine: ZcashEncoding,
+    E::G2Affine: HashToCurve,
+    E::Fqe: ExtensionField,
+{
+    /// Returns the signature `sk * H(message)`.
+    pub fn sign_bytes(&self, private_key: &E::Fr, message: &[u8]) -> Result<E::G2Affine> {
+        Ok((E::G2Affine::hash_to_curve(message, self.ciphersuite_id.as_bytes())? * *private_key).to_affine())
+    }
+
+    /// Verifies `e(pk, H(message)) = e(G1, signature)`.
+    pub fn verify_bytes(&self, public_key: &E::G1Affine, message: &[u8], signature: &E::G2Affine) -> Result<bool> {
+        self.aggregate_verify(&[*public_key], &[message], signature)
+    }
+
+    /// Returns the sum of the given signatures, which may be on the same or on different messages.
+    pub fn aggregate_signatures(signatures: &[E::G2Affine]) -> E::G2Affine {
+        signatures.iter().map(|signature| signature.to_projective()).sum::<E::G2Projective>().to_affine()
+    }
+
+    /// Returns the sum of the given public keys.
+    pub fn aggregate_public_keys(public_keys: &[E::G1Affine]) -> E::G1Affine {
+        public_keys.iter().map(|public_key| public_key.to_projective()).sum::<E::G1Projective>().to_affine()
+    }
+
+    ///
+    /// Verifies an aggregate signature on distinct messages, one for each public key, by checking
+    /// `e(pk_1, H(message_1)) * ... * e(pk_n, H(message_n)) = e(G1, signature)`.
+    ///
+    /// Returns `false` if the messages are not distinct, as the check is only sound for distinct messages
+    /// unless all public keys come with a proof of possession, in which case `fast_aggregate_verify` applies.
+    ///
+    pub fn aggregate_verify(
+        &self,
+        public_keys: &[E::G1Affine],
+        messages: &[&[u8]],
+        signature: &E::G2Affine,
+    ) -> Result<bool> {
+        ensure!(public_keys.len() == messages.len(), "Mismatched number of public keys and messages");
+        if public_keys.is_empty() || !Self::is_valid_signature(signature) {
+            return Ok(false);
+        }
+        if public_keys.iter().any(|public_key| !Self::is_valid_public_key(public_key)) {
+            return Ok(false);
+        }
+        if messages.iter().collect::<HashSet<_>>().len() != messages.len() {
+            return Ok(false);
+        }
+
+        let mut g1_elements = public_keys.iter().map(|public_key| public_key.prepare()).collect::<Vec<_>>();
+        let mut g2_elements = Vec::with_capacity(messages.len() + 1);
+        for message in messages {
+            g2_elements.push(E::G2Affine::hash_to_curve(message, self.ciphersuite_id.as_bytes())?.prepare());
+        }
+        g1_elements.push((-E::G1Affine::prime_subgroup_generator()).prepare());
+        g2_elements.push(signature.prepare());
+
+        Ok(E::product_of_pairings_is_one(g1_elements.iter().zip(g2_elements.iter())))
+    }
+
+    ///
+    /// Verifies an aggregate signature on a single message by the given public keys,
+    /// each of which must have been checked with `verify_possession`.
+    ///
+    pub fn fast_aggregate_verify(
+        &self,
+        public_keys: &[E::G1Affine],
+        message: &[u8],
+        signature: &E::G2Affine,
+    ) -> Result<bool> {
+        if public_keys.iter().any(|public_key| !Self::is_valid_public_key(public_key)) {
+            return Ok(false);
+        }
+        self.verify_bytes(&Self::aggregate_public_keys(public_keys), message, signature)
+    }
+
+    /// Returns a proof of possession of the private key, which is a signature on the compressed encoding
+    /// of the public key under a domain separation tag distinct from that of signatures.
+    pub fn prove_possession(&self, private_key: &E::Fr) -> Result<E::G2Affine> {
+        let public_key = self.generate_public_key(private_key).to_compressed();
+        Ok((E::G2Affine::hash_to_curve(&public_key, self.possession_dst().as_bytes())? * *private_key).to_affine())
+    }
+
+    /// Verifies a proof of possession of the private key of the given public key.
+    pub fn verify_possession(&self, public_key: &E::G1Affine, proof: &E::G2Affine) -> Result<bool> {
+        if !Self::is_valid_public_key(public_key) || !Self::is_valid_signature(proof) {
+            return Ok(false);
+        }
+        let point = E::G2Affine::hash_to_curve(&public_key.to_compressed(), self.possession_dst().as_bytes())?;
+        Ok(E::product_of_pairings_is_one([
+            (&public_key.prepare(), &point.prepare()),
+            (&(-E::G1Affine::prime_subgroup_generator()).prepare(), &proof.prepare()),
+        ]))
+    }
+
+    /// Returns the domain separation tag of proofs of possession.
+    fn possession_dst(&self) -> String {
+        self.ciphersuite_id.replacen("BLS_SIG_", "BLS_POP_", 1)
+    }
+
+    /// Returns `true` if the public key is a non-zero element of the prime-order subgroup.
+    fn is_valid_public_key(public_key: &E::G1Affine) -> bool {
+        !public_key.is_zero() && public_key.is_on_curve() && public_key.is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    /// Returns `true` if the signature is an element of the prime-order subgroup.
+    fn is_valid_signature(signature: &E::G2Affine) -> bool {
+        signature.is_on_curve() && signature.is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    /// Returns the bytes of a message given as little-endian bits of bytes.
+    fn message_to_bytes(message: &[bool]) -> Result<Vec<u8>> {
+        if message.len() % 8 != 0 {
+            return Err(SignatureError::Message("BLS signatures are on messages of whole bytes".into()).into());
+        }
+        Ok(bytes_from_bits_le(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, G2Affine};
+    use snarkvm_utilities::{test_crypto_rng, ToBits};
+
+    type TestSignature = BLSSignatureScheme<Bls12_377>;
+
+    #[test]
+    fn test_bls_signature_on_bls12_377() {
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = TestSignature::setup("test_bls_signature_on_bls12_377");
+        assert_eq!(signature_scheme.parameters(), "BLS_SIG_BLS12377G2_XMD:SHA-256_SSWU_RO_POP_");
+
+        let private_key = signature_scheme.generate_private_key(rng);
+        let public_key = signature_scheme.generate_public_key(&private_key);
+        let message = "Hi, I am a BLS signature!".as_bytes().to_bits_le();
+        let signature = signature_scheme.sign(&private_key, &message, rng).unwrap();
+        assert!(signature_scheme.verify(&public_key, &message, &signature).unwrap());
+        assert!(!signature_scheme.verify(&public_key, &b"Bad message".to_bits_le(), &signature).unwrap());
+
+        let other_public_key = signature_scheme.generate_public_key(&signature_scheme.generate_private_key(rng));
+        assert!(!signature_scheme.verify(&other_public_key, &message, &signature).unwrap());
+
+        // Signatures are deterministic, and messages must consist of whole bytes.
+        assert_eq!(signature_scheme.sign(&private_key, &message, rng).unwrap(), signature);
+        assert!(signature_scheme.sign(&private_key, &message[1..], rng).is_err());
+    }
+
+    #[test]
+    fn test_bls_aggregate_verify() {
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = TestSignature::setup("test_bls_aggregate_verify");
+
+        let private_keys = (0..4).map(|_| signature_scheme.generate_private_key(rng)).collect::<Vec<_>>();
+        let public_keys = private_keys.iter().map(|sk| signature_scheme.generate_public_key(sk)).collect::<Vec<_>>();
+        let messages = [&b"message 0"[..], b"message 1", b"message 2", b"message 3"];
+        let signatures = private_keys
+            .iter()
+            .zip(messages.iter())
+            .map(|(sk, message)| signature_scheme.sign_bytes(sk, message).unwrap())
+            .collect::<Vec<_>>();
+        let aggregate = TestSignature::aggregate_signatures(&signatures);
+        assert!(signature_scheme.aggregate_verify(&public_keys, &messages, &aggregate).unwrap());
+
+        // The messages must match the public keys.
+        let swapped = [messages[1], messages[0], messages[2], messages[3]];
+        assert!(!signature_scheme.aggregate_verify(&public_keys, &swapped, &aggregate).unwrap());
+        assert!(!signature_scheme.aggregate_verify(&public_keys[..3], &messages[..3], &aggregate).unwrap());
+        assert!(signature_scheme.aggregate_verify(&public_keys[..3], &messages, &aggregate).is_err());
+
+        // The messages must be distinct.
+        let repeated = [messages[0], messages[0]];
+        let signatures = [
+            signature_scheme.sign_bytes(&private_keys[0], messages[0]).unwrap(),
+            signature_scheme.sign_bytes(&private_keys[1], messages[0]).unwrap(),
+        ];
+        let aggregate = TestSignature::aggregate_signatures(&signatures);
+        assert!(!signature_scheme.aggregate_verify(&public_keys[..2], &repeated, &aggregate).unwrap());
+    }
+
+    #[test]
+    fn test_bls_proof_of_possession() {
+        let rng = &mut test_crypto_rng();
+        let signature_scheme = TestSignature::setup("test_bls_proof_of_possession");
+
+        let private_keys = (0..4).map(|_| signature_scheme.generate_private_key(rng)).collect::<Vec<_>>();
+        let public_keys = private_keys.iter().map(|sk| signature_scheme.generate_public_key(sk)).collect::<Vec<_>>();
+        for (private_key, public_key) in private_keys.iter().zip(public_keys.iter()) {
+            let proof = signature_scheme.prove_possession(private_key).unwrap();
+            assert!(signature_scheme.verify_possession(public_key, &proof).unwrap());
+            assert!(!signature_scheme.verify_possession(&public_keys[0], &-proof).unwrap());
+
+            // The proof of possession signs the compressed public key, under its own domain separation tag.
+            let dst = "BLS_POP_BLS12377G2_XMD:SHA-256_SSWU_RO_POP_";
+            let point = G2Affine::hash_to_curve(&public_key.to_compressed(), dst.as_bytes()).unwrap();
+            assert_eq!(proof, (point * *private_key).to_affine());
+
+            // A signature on the public key is not a proof of possession.
+            let signature = signature_scheme.sign_bytes(private_key, &public_key.to_compressed()).unwrap();
+            assert!(!signature_scheme.verify_possession(public_key, &signature).unwrap());
+        }
+
+        // Signatures on a common message are checked against the aggregate public key.
+        let message = b"attestation";
+        let signatures =
+            private_keys.iter().map(|sk| signature_scheme.sign_bytes(sk, message).unwrap()).collect::<Vec<_>>();
+        let aggregate = TestSignature::aggregate_signatures(&signatures);
+        assert!(signature_scheme.fast_aggregate_verify(&public_keys, message, &aggregate).unwrap());
+        assert!(!signature_scheme.fast_aggregate_verify(&public_keys[1..], message, &aggregate).unwrap());
+        assert!(!signature_scheme.fast_aggregate_verify(&public_keys, b"another message", &aggregate).unwrap());
+    }
+}
@@ -16,3 +16,9 @@
 
 pub mod aleo;
 pub use aleo::*;
+
+pub mod bls;
+pub use bls::*;
+
+pub mod threshold;
+pub use threshold::*;
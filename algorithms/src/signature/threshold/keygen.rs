@@ -0,0 +1,563 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{signature::AleoSignatureScheme, SignatureSchemeOperations};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    ProjectiveCurve,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    string::ToString,
+    vec,
+    vec::Vec,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::{bail, ensure, Result};
+use rand::{CryptoRng, Rng};
+
+/// The domain separator of the challenges of proofs of knowledge in key generation.
+const KEYGEN_DOMAIN: &[u8] = b"AleoThresholdKeyGen0";
+
+/// The threshold `t` and the number of participants `n` of a `t`-of-`n` threshold signature scheme.
+/// Participants are indexed from `1` to `n`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdParameters {
+    threshold: u16,
+    num_participants: u16,
+}
+
+impl ThresholdParameters {
+    /// Initializes the parameters of a `threshold`-of-`num_participants` scheme.
+    pub fn new(threshold: u16, num_participants: u16) -> Result<Self> {
+        ensure!(
+            0 < threshold && threshold <= num_participants,
+            "Invalid threshold {} for {} participants",
+            threshold,
+            num_participants
+        );
+        Ok(Self { threshold, num_participants })
+    }
+
+    /// Returns the minimum number of participants needed to sign.
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the number of participants.
+    pub fn num_participants(&self) -> u16 {
+        self.num_participants
+    }
+
+    /// Returns `true` if `index` is the index of a participant.
+    pub(super) fn is_participant(&self, index: u16) -> bool {
+        0 < index && index <= self.num_participants
+    }
+}
+
+impl FromBytes for ThresholdParameters {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let threshold = u16::read_le(&mut reader)?;
+        let num_participants = u16::read_le(&mut reader)?;
+        Self::new(threshold, num_participants).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl ToBytes for ThresholdParameters {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.threshold.write_le(&mut writer)?;
+        self.num_participants.write_le(&mut writer)
+    }
+}
+
+/// A Schnorr proof of knowledge of the discrete logarithm of a group element, bound to a participant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ProofOfKnowledge<TE: TwistedEdwardsParameters> {
+    commitment: TEAffine<TE>,
+    response: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> ProofOfKnowledge<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    ///
+    /// Returns a proof (G^k, k + c * secret), where c := Hash(participant, G^secret, G^k).
+    ///
+    fn prove<R: Rng + CryptoRng>(
+        scheme: &AleoSignatureScheme<TE>,
+        participant: u16,
+        element: &TEAffine<TE>,
+        secret: &TE::ScalarField,
+        rng: &mut R,
+    ) -> Self {
+        let k = TE::ScalarField::rand(rng);
+        let commitment = scheme.g_scalar_multiply_ct(&k).to_affine();
+        let challenge = Self::challenge(scheme, participant, element, &commitment);
+        Self { commitment, response: k + challenge * secret }
+    }
+
+    ///
+    /// Verifies G^response == G^k * element^c, where c := Hash(participant, element, G^k).
+    ///
+    fn verify(&self, scheme: &AleoSignatureScheme<TE>, participant: u16, element: &TEAffine<TE>) -> bool {
+        if !is_valid_element(&self.commitment) {
+            return false;
+        }
+        let challenge = Self::challenge(scheme, participant, element, &self.commitment);
+        scheme.g_scalar_multiply(&self.response) == self.commitment.to_projective() + *element * challenge
+    }
+
+    fn challenge(
+        scheme: &AleoSignatureScheme<TE>,
+        participant: u16,
+        element: &TEAffine<TE>,
+        commitment: &TEAffine<TE>,
+    ) -> TE::ScalarField {
+        scheme.hash_to_scalar_field(&[
+            TE::BaseField::from_bytes_le_mod_order(KEYGEN_DOMAIN),
+            TE::BaseField::from(participant as u128),
+            element.to_x_coordinate(),
+            commitment.to_x_coordinate(),
+        ])
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ProofOfKnowledge<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let commitment = read_element(&mut reader)?;
+        let response = FromBytes::read_le(&mut reader)?;
+        Ok(Self { commitment, response })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ProofOfKnowledge<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.commitment.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+/// The message that a participant broadcasts to all participants in the first round of key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyGenRound1Message<TE: TwistedEdwardsParameters> {
+    sender: u16,
+    /// The commitments G^a_k to the coefficients of the sender's secret polynomial.
+    coefficient_commitments: Vec<TEAffine<TE>>,
+    /// The sender's contribution G^r_i to the signature root randomizer G^r_sig.
+    randomizer_commitment: TEAffine<TE>,
+    /// The proof of knowledge of the constant coefficient a_0.
+    coefficient_proof: ProofOfKnowledge<TE>,
+    /// The proof of knowledge of r_i.
+    randomizer_proof: ProofOfKnowledge<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> KeyGenRound1Message<TE> {
+    /// Returns the index of the sender.
+    pub fn sender(&self) -> u16 {
+        self.sender
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for KeyGenRound1Message<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let sender = u16::read_le(&mut reader)?;
+        let num_commitments = u16::read_le(&mut reader)?;
+        let mut coefficient_commitments = Vec::with_capacity(num_commitments as usize);
+        for _ in 0..num_commitments {
+            coefficient_commitments.push(read_element(&mut reader)?);
+        }
+        let randomizer_commitment = read_element(&mut reader)?;
+        let coefficient_proof = FromBytes::read_le(&mut reader)?;
+        let randomizer_proof = FromBytes::read_le(&mut reader)?;
+        Ok(Self { sender, coefficient_commitments, randomizer_commitment, coefficient_proof, randomizer_proof })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for KeyGenRound1Message<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.sender.write_le(&mut writer)?;
+        (self.coefficient_commitments.len() as u16).write_le(&mut writer)?;
+        self.coefficient_commitments.write_le(&mut writer)?;
+        self.randomizer_commitment.write_le(&mut writer)?;
+        self.coefficient_proof.write_le(&mut writer)?;
+        self.randomizer_proof.write_le(&mut writer)
+    }
+}
+
+/// The message that a participant sends to another participant in the second round of key generation.
+///
+/// The message contains a share of the sender's secret, and must be sent over a confidential and authenticated channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyGenRound2Message<TE: TwistedEdwardsParameters> {
+    sender: u16,
+    receiver: u16,
+    /// The evaluation f_sender(receiver) of the sender's secret polynomial.
+    pub(super) share: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> KeyGenRound2Message<TE> {
+    /// Returns the index of the sender.
+    pub fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    /// Returns the index of the receiver.
+    pub fn receiver(&self) -> u16 {
+        self.receiver
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for KeyGenRound2Message<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let sender = u16::read_le(&mut reader)?;
+        let receiver = u16::read_le(&mut reader)?;
+        let share = FromBytes::read_le(&mut reader)?;
+        Ok(Self { sender, receiver, share })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for KeyGenRound2Message<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.sender.write_le(&mut writer)?;
+        self.receiver.write_le(&mut writer)?;
+        self.share.write_le(&mut writer)
+    }
+}
+
+///
+/// A participant in the distributed key generation of a threshold key, following the
+/// Pedersen key generation with proofs of knowledge of FROST (RFC 9591, appendix C).
+///
+/// In the first round, each participant i samples a secret polynomial f_i of degree t - 1 and a
+/// randomizer r_i, and broadcasts commitments to them. In the second round, each participant sends
+/// f_i(j) to each participant j. The joint secret sk_sig := sum_i f_i(0) is shared among the participants,
+/// while the joint randomizer r_sig := sum_i r_i is not known to anyone, as signing only requires G^r_sig.
+///
+pub struct KeyGenParticipant<TE: TwistedEdwardsParameters> {
+    parameters: ThresholdParameters,
+    index: u16,
+    coefficients: Vec<TE::ScalarField>,
+    round1_messages: Vec<KeyGenRound1Message<TE>>,
+}
+
+impl<TE: TwistedEdwardsParameters> KeyGenParticipant<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    /// Starts the key generation for the participant `index`, and returns its first round message.
+    pub fn new<R: Rng + CryptoRng>(
+        scheme: &AleoSignatureScheme<TE>,
+        parameters: ThresholdParameters,
+        index: u16,
+        rng: &mut R,
+    ) -> Result<(Self, KeyGenRound1Message<TE>)> {
+        ensure!(parameters.is_participant(index), "Invalid participant index {}", index);
+
+        let coefficients = (0..parameters.threshold).map(|_| TE::ScalarField::rand(rng)).collect::<Vec<_>>();
+        let randomizer = TE::ScalarField::rand(rng);
+
+        let mut commitments =
+            coefficients.iter().chain([randomizer].iter()).map(|c| scheme.g_scalar_multiply_ct(c)).collect::<Vec<_>>();
+        TEProjective::<TE>::batch_normalization(&mut commitments);
+        let mut coefficient_commitments = commitments.iter().map(|c| c.to_affine()).collect::<Vec<_>>();
+        let randomizer_commitment = coefficient_commitments.pop().unwrap();
+
+        let coefficient_proof =
+            ProofOfKnowledge::prove(scheme, index, &coefficient_commitments[0], &coefficients[0], rng);
+        let randomizer_proof = ProofOfKnowledge::prove(scheme, index, &randomizer_commitment, &randomizer, rng);
+
+        let message = KeyGenRound1Message {
+            sender: index,
+            coefficient_commitments,
+            randomizer_commitment,
+            coefficient_proof,
+            randomizer_proof,
+        };
+        Ok((Self { parameters, index, coefficients, round1_messages: vec![] }, message))
+    }
+
+    /// Verifies the first round messages of all participants, including its own,
+    /// and returns the second round messages for the other participants.
+    pub fn round_two(
+        &mut self,
+        scheme: &AleoSignatureScheme<TE>,
+        round1_messages: &[KeyGenRound1Message<TE>],
+    ) -> Result<Vec<KeyGenRound2Message<TE>>> {
+        ensure!(self.round1_messages.is_empty(), "The second round of key generation has already started");
+        ensure!(
+            round1_messages.len() == self.parameters.num_participants as usize,
+            "Expected {} first round messages, found {}",
+            self.parameters.num_participants,
+            round1_messages.len()
+        );
+
+        let mut round1_messages = round1_messages.to_vec();
+        round1_messages.sort_by_key(|message| message.sender);
+        for (i, message) in round1_messages.iter().enumerate() {
+            ensure!(message.sender as usize == i + 1, "Missing the first round message of participant {}", i + 1);
+            ensure!(
+                message.coefficient_commitments.len() == self.parameters.threshold as usize,
+                "Participant {} committed to {} coefficients",
+                message.sender,
+                message.coefficient_commitments.len()
+            );
+            ensure!(
+                message
+                    .coefficient_commitments
+                    .iter()
+                    .chain([message.randomizer_commitment].iter())
+                    .all(is_valid_element),
+                "Participant {} sent an invalid commitment",
+                message.sender
+            );
+            ensure!(
+                message.coefficient_proof.verify(scheme, message.sender, &message.coefficient_commitments[0])
+                    && message.randomizer_proof.verify(scheme, message.sender, &message.randomizer_commitment),
+                "Participant {} sent an invalid proof of knowledge",
+                message.sender
+            );
+        }
+
+        self.round1_messages = round1_messages;
+        Ok((1..=self.parameters.num_participants)
+            .filter(|receiver| *receiver != self.index)
+            .map(|receiver| KeyGenRound2Message {
+                sender: self.index,
+                receiver,
+                share: evaluate(&self.coefficients, receiver),
+            })
+            .collect())
+    }
+
+    /// Verifies the second round messages sent to this participant, and returns its key share.
+    pub fn finalize(
+        self,
+        scheme: &AleoSignatureScheme<TE>,
+        round2_messages: &[KeyGenRound2Message<TE>],
+    ) -> Result<ThresholdKeyShare<TE>> {
+        ensure!(!self.round1_messages.is_empty(), "The second round of key generation has not started");
+        ensure!(
+            round2_messages.len() + 1 == self.parameters.num_participants as usize,
+            "Expected {} second round messages, found {}",
+            self.parameters.num_participants - 1,
+            round2_messages.len()
+        );
+
+        let mut secret_share = evaluate(&self.coefficients, self.index);
+        let mut senders = vec![false; self.parameters.num_participants as usize + 1];
+        for message in round2_messages {
+            ensure!(message.receiver == self.index, "Received a share for participant {}", message.receiver);
+            ensure!(
+                self.parameters.is_participant(message.sender)
+                    && message.sender != self.index
+                    && !senders[message.sender as usize],
+                "Unexpected share from participant {}",
+                message.sender
+            );
+            senders[message.sender as usize] = true;
+
+            // Check G^f_i(j) == prod_k (G^a_k)^(j^k).
+            let commitments = &self.round1_messages[message.sender as usize - 1].coefficient_commitments;
+            if scheme.g_scalar_multiply(&message.share) != evaluate_in_exponent(commitments, self.index) {
+                bail!("Participant {} sent an invalid share", message.sender);
+            }
+            secret_share += message.share;
+        }
+
+        // Compute the joint commitments to the coefficients of the shared polynomial, and the joint randomizer.
+        let mut joint_commitments = vec![TEProjective::<TE>::zero(); self.parameters.threshold as usize];
+        let mut pr_sig = TEProjective::<TE>::zero();
+        for message in &self.round1_messages {
+            for (joint, commitment) in joint_commitments.iter_mut().zip(&message.coefficient_commitments) {
+                joint.add_assign_mixed(commitment);
+            }
+            pr_sig.add_assign_mixed(&message.randomizer_commitment);
+        }
+        let joint_commitments = joint_commitments.iter().map(|c| c.to_affine()).collect::<Vec<_>>();
+
+        let mut verification_shares = (1..=self.parameters.num_participants)
+            .map(|j| evaluate_in_exponent(&joint_commitments, j))
+            .collect::<Vec<_>>();
+        TEProjective::<TE>::batch_normalization(&mut verification_shares);
+        let verification_shares = verification_shares.iter().map(|c| c.to_affine()).collect::<Vec<_>>();
+
+        let public_key = ThresholdPublicKey {
+            parameters: self.parameters,
+            pk_sig: joint_commitments[0],
+            pr_sig: pr_sig.to_affine(),
+            verification_shares,
+        };
+        Ok(ThresholdKeyShare { index: self.index, secret_share, public_key })
+    }
+}
+
+/// The public key of a threshold key, with the verification shares G^s_j of the secret shares s_j of sk_sig.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdPublicKey<TE: TwistedEdwardsParameters> {
+    parameters: ThresholdParameters,
+    /// pk_sig := G^sk_sig.
+    pk_sig: TEAffine<TE>,
+    /// pr_sig := G^r_sig.
+    pr_sig: TEAffine<TE>,
+    verification_shares: Vec<TEAffine<TE>>,
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdPublicKey<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    /// Returns the threshold parameters.
+    pub fn parameters(&self) -> &ThresholdParameters {
+        &self.parameters
+    }
+
+    /// Returns the signature root public key G^sk_sig.
+    pub fn pk_sig(&self) -> &TEAffine<TE> {
+        &self.pk_sig
+    }
+
+    /// Returns the signature root randomizer G^r_sig.
+    pub fn pr_sig(&self) -> &TEAffine<TE> {
+        &self.pr_sig
+    }
+
+    /// Returns the verification share G^s_j of the participant `index`.
+    pub fn verification_share(&self, index: u16) -> Option<&TEAffine<TE>> {
+        match self.parameters.is_participant(index) {
+            true => self.verification_shares.get(index as usize - 1),
+            false => None,
+        }
+    }
+
+    ///
+    /// Returns the public key (G^sk_sig G^r_sig G^sk_prf) of `AleoSignatureScheme`, where sk_prf := RO(G^sk_sig || G^r_sig).
+    ///
+    pub fn to_public_key(&self, scheme: &AleoSignatureScheme<TE>) -> TEAffine<TE> {
+        let sk_prf = scheme.hash_to_scalar_field(&[self.pk_sig.to_x_coordinate(), self.pr_sig.to_x_coordinate()]);
+        (self.pk_sig.to_projective() + self.pr_sig.to_projective() + scheme.g_scalar_multiply(&sk_prf)).to_affine()
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ThresholdPublicKey<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let parameters: ThresholdParameters = FromBytes::read_le(&mut reader)?;
+        let pk_sig = read_element(&mut reader)?;
+        let pr_sig = read_element(&mut reader)?;
+        let mut verification_shares = Vec::with_capacity(parameters.num_participants as usize);
+        for _ in 0..parameters.num_participants {
+            verification_shares.push(read_element(&mut reader)?);
+        }
+        Ok(Self { parameters, pk_sig, pr_sig, verification_shares })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ThresholdPublicKey<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.parameters.write_le(&mut writer)?;
+        self.pk_sig.write_le(&mut writer)?;
+        self.pr_sig.write_le(&mut writer)?;
+        self.verification_shares.write_le(&mut writer)
+    }
+}
+
+/// The key share of a participant, with the secret share s_j of sk_sig and the threshold public key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdKeyShare<TE: TwistedEdwardsParameters> {
+    pub(super) index: u16,
+    pub(super) secret_share: TE::ScalarField,
+    pub(super) public_key: ThresholdPublicKey<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdKeyShare<TE> {
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the threshold public key.
+    pub fn public_key(&self) -> &ThresholdPublicKey<TE> {
+        &self.public_key
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for ThresholdKeyShare<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = u16::read_le(&mut reader)?;
+        let secret_share = FromBytes::read_le(&mut reader)?;
+        let public_key = FromBytes::read_le(&mut reader)?;
+        Ok(Self { index, secret_share, public_key })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for ThresholdKeyShare<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.secret_share.write_le(&mut writer)?;
+        self.public_key.write_le(&mut writer)
+    }
+}
+
+/// Returns `true` if the element is a non-zero element of the prime-order subgroup.
+pub(super) fn is_valid_element<TE: TwistedEdwardsParameters>(element: &TEAffine<TE>) -> bool {
+    !element.is_zero() && element.is_on_curve() && element.is_in_correct_subgroup_assuming_on_curve()
+}
+
+/// Reads an element, and ensures it is a non-zero element of the prime-order subgroup.
+pub(super) fn read_element<TE: TwistedEdwardsParameters, R: Read>(reader: R) -> IoResult<TEAffine<TE>> {
+    let element = TEAffine::<TE>::read_le(reader)?;
+    match is_valid_element(&element) {
+        true => Ok(element),
+        false => Err(error("Invalid group element")),
+    }
+}
+
+/// Returns f(x) for the polynomial f with the given coefficients, lowest degree first.
+fn evaluate<F: PrimeField>(coefficients: &[F], x: u16) -> F {
+    let x = F::from(x as u128);
+    coefficients.iter().rev().fold(F::zero(), |acc, c| acc * x + c)
+}
+
+/// Returns G^f(x) given the commitments G^a_k to the coefficients of f, lowest degree first.
+fn evaluate_in_exponent<TE: TwistedEdwardsParameters>(commitments: &[TEAffine<TE>], x: u16) -> TEProjective<TE> {
+    let x = TE::ScalarField::from(x as u128);
+    let mut power = TE::ScalarField::one();
+    let mut result = TEProjective::<TE>::zero();
+    for commitment in commitments {
+        result += *commitment * power;
+        power *= x;
+    }
+    result
+}
@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A `t`-of-`n` threshold variant of `AleoSignatureScheme`, following FROST (RFC 9591).
+//!
+//! The participants jointly generate a key with `KeyGenParticipant`, and any `t` of them can then
+//! sign a message in two rounds, by exchanging `SigningCommitment`s and `SignatureShare`s.
+//! The aggregated signature is an `AleoSignature`, which verifies under the public key
+//! returned by `ThresholdPublicKey::to_public_key`.
+
+pub mod keygen;
+pub use keygen::*;
+
+pub mod signing;
+pub use signing::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{is_valid_element, read_element, ThresholdKeyShare, ThresholdPublicKey};
+use crate::{
+    signature::{AleoSignature, AleoSignatureScheme},
+    SignatureSchemeOperations,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    ProjectiveCurve,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    vec,
+    vec::Vec,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use rand::{CryptoRng, Rng};
+
+/// The domain separator of the binding factors in signing.
+const BINDING_DOMAIN: &[u8] = b"AleoThresholdBinding0";
+
+/// The secret nonces of a signer for a single signing session.
+///
+/// The nonces are consumed when signing, as reusing them for two messages reveals the secret share.
+pub struct SigningNonces<TE: TwistedEdwardsParameters> {
+    hiding: TE::ScalarField,
+    binding: TE::ScalarField,
+    commitment: SigningCommitment<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> SigningNonces<TE> {
+    /// Returns the commitment to the nonces, to be sent to the coordinator.
+    pub fn commitment(&self) -> &SigningCommitment<TE> {
+        &self.commitment
+    }
+}
+
+/// The commitment (G^d_i, G^e_i) of a signer to its nonces, sent in the first round of signing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SigningCommitment<TE: TwistedEdwardsParameters> {
+    signer: u16,
+    hiding: TEAffine<TE>,
+    binding: TEAffine<TE>,
+}
+
+impl<TE: TwistedEdwardsParameters> SigningCommitment<TE> {
+    /// Returns the index of the signer.
+    pub fn signer(&self) -> u16 {
+        self.signer
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for SigningCommitment<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let signer = u16::read_le(&mut reader)?;
+        let hiding = read_element(&mut reader)?;
+        let binding = read_element(&mut reader)?;
+        Ok(Self { signer, hiding, binding })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for SigningCommitment<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.signer.write_le(&mut writer)?;
+        self.hiding.write_le(&mut writer)?;
+        self.binding.write_le(&mut writer)
+    }
+}
+
+/// The signature share z_i of a signer, sent in the second round of signing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SignatureShare<TE: TwistedEdwardsParameters> {
+    pub(super) signer: u16,
+    pub(super) share: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> SignatureShare<TE> {
+    /// Returns the index of the signer.
+    pub fn signer(&self) -> u16 {
+        self.signer
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for SignatureShare<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let signer = u16::read_le(&mut reader)?;
+        let share = FromBytes::read_le(&mut reader)?;
+        Ok(Self { signer, share })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for SignatureShare<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.signer.write_le(&mut writer)?;
+        self.share.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdKeyShare<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    /// Samples the nonces (d_i, e_i) for a signing session, and commits to them.
+    pub fn commit<R: Rng + CryptoRng>(&self, scheme: &AleoSignatureScheme<TE>, rng: &mut R) -> SigningNonces<TE> {
+        let hiding = TE::ScalarField::rand(rng);
+        let binding = TE::ScalarField::rand(rng);
+
+        let mut commitments = [scheme.g_scalar_multiply_ct(&hiding), scheme.g_scalar_multiply_ct(&binding)];
+        TEProjective::<TE>::batch_normalization(&mut commitments);
+        let [hiding_commitment, binding_commitment] = commitments.map(|c| c.to_affine());
+
+        SigningNonces {
+            hiding,
+            binding,
+            commitment: SigningCommitment {
+                signer: self.index,
+                hiding: hiding_commitment,
+                binding: binding_commitment,
+            },
+        }
+    }
+
+    ///
+    /// Returns the signature share z_i := d_i + e_i * rho_i - lambda_i * c * s_i, where:
+    ///     rho_i := Hash(pk, message, commitments, i)
+    ///     R := prod_j G^d_j (G^e_j)^rho_j
+    ///     c := Hash(pk, R, message)
+    ///
+    pub fn sign(
+        &self,
+        scheme: &AleoSignatureScheme<TE>,
+        nonces: SigningNonces<TE>,
+        message: &[bool],
+        commitments: &[SigningCommitment<TE>],
+    ) -> Result<SignatureShare<TE>> {
+        ensure!(
+            commitments.contains(&nonces.commitment),
+            "The signing commitments do not include the commitment of signer {}",
+            self.index
+        );
+        let session = SigningSession::new(scheme, &self.public_key, message, commitments)?;
+        let (lagrange_coefficient, binding_factor) = session.signer(self.index)?;
+
+        let share = nonces.hiding + nonces.binding * binding_factor
+            - lagrange_coefficient * session.challenge * self.secret_share;
+        Ok(SignatureShare { signer: self.index, share })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ThresholdPublicKey<TE>
+where
+    TE::BaseField: PrimeField,
+{
+    ///
+    /// Verifies each signature share, and returns the signature (sum_i z_i, c, G^sk_sig, G^r_sig),
+    /// which verifies under `AleoSignatureScheme` with the public key `self.to_public_key()`.
+    ///
+    pub fn aggregate(
+        &self,
+        scheme: &AleoSignatureScheme<TE>,
+        message: &[bool],
+        commitments: &[SigningCommitment<TE>],
+        shares: &[SignatureShare<TE>],
+    ) -> Result<AleoSignature<TE>> {
+        let session = SigningSession::new(scheme, self, message, commitments)?;
+        ensure!(
+            shares.len() == commitments.len(),
+            "Expected {} signature shares, found {}",
+            commitments.len(),
+            shares.len()
+        );
+
+        let mut prover_response = TE::ScalarField::zero();
+        for (commitment, share) in commitments.iter().zip(shares) {
+            ensure!(
+                commitment.signer == share.signer,
+                "The signature shares must be in the order of the signing commitments"
+            );
+            let (lagrange_coefficient, binding_factor) = session.signer(share.signer)?;
+            let verification_share = self
+                .verification_share(share.signer)
+                .ok_or_else(|| anyhow!("Missing the verification share of signer {}", share.signer))?;
+
+            // Check G^z_i (G^s_i)^(lambda_i * c) == G^d_i (G^e_i)^rho_i.
+            let candidate = scheme.g_scalar_multiply(&share.share)
+                + *verification_share * (lagrange_coefficient * session.challenge);
+            if candidate != commitment.hiding.to_projective() + commitment.binding * binding_factor {
+                bail!("Signer {} sent an invalid signature share", share.signer);
+            }
+            prover_response += share.share;
+        }
+
+        Ok(AleoSignature::new(
+            prover_response,
+            session.challenge,
+            self.pk_sig().to_x_coordinate(),
+            self.pr_sig().to_x_coordinate(),
+        ))
+    }
+}
+
+/// The values derived from the commitments of the signers, which are shared by all signers of a session.
+struct SigningSession<'a, TE: TwistedEdwardsParameters> {
+    commitments: &'a [SigningCommitment<TE>],
+    binding_factors: Vec<TE::ScalarField>,
+    challenge: TE::ScalarField,
+}
+
+impl<'a, TE: TwistedEdwardsParameters> SigningSession<'a, TE>
+where
+    TE::BaseField: PrimeField,
+{
+    fn new(
+        scheme: &AleoSignatureScheme<TE>,
+        public_key: &ThresholdPublicKey<TE>,
+        message: &[bool],
+        commitments: &'a [SigningCommitment<TE>],
+    ) -> Result<Self> {
+        let parameters = public_key.parameters();
+        ensure!(
+            commitments.len() >= parameters.threshold() as usize,
+            "Expected at least {} signers, found {}",
+            parameters.threshold(),
+            commitments.len()
+        );
+        ensure!(
+            commitments.windows(2).all(|pair| pair[0].signer < pair[1].signer),
+            "The signing commitments must be sorted by signer, without duplicates"
+        );
+        for commitment in commitments {
+            ensure!(parameters.is_participant(commitment.signer), "Invalid signer index {}", commitment.signer);
+            ensure!(
+                is_valid_element(&commitment.hiding) && is_valid_element(&commitment.binding),
+                "Signer {} sent an invalid commitment",
+                commitment.signer
+            );
+        }
+
+        // Construct the hash input (pk, message, commitments) shared by the binding factors.
+        let aleo_public_key = public_key.to_public_key(scheme);
+        let mut preimage = vec![TE::BaseField::from_bytes_le_mod_order(BINDING_DOMAIN)];
+        preimage.push(aleo_public_key.to_x_coordinate());
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+        for commitment in commitments {
+            preimage.push(TE::BaseField::from(commitment.signer as u128));
+            preimage.push(commitment.hiding.to_x_coordinate());
+            preimage.push(commitment.binding.to_x_coordinate());
+        }
+
+        // Compute the binding factors rho_i, and the group commitment R.
+        let mut binding_factors = Vec::with_capacity(commitments.len());
+        let mut g_r = TEProjective::<TE>::zero();
+        for commitment in commitments {
+            preimage.push(TE::BaseField::from(commitment.signer as u128));
+            let binding_factor = scheme.hash_to_scalar_field(&preimage);
+            preimage.pop();
+
+            g_r.add_assign_mixed(&commitment.hiding);
+            g_r += commitment.binding * binding_factor;
+            binding_factors.push(binding_factor);
+        }
+
+        let challenge = scheme.compute_verifier_challenge(&aleo_public_key, &g_r.to_affine(), message)?;
+        Ok(Self { commitments, binding_factors, challenge })
+    }
+
+    /// Returns the Lagrange coefficient lambda_i and the binding factor rho_i of the signer `index`.
+    fn signer(&self, index: u16) -> Result<(TE::ScalarField, TE::ScalarField)> {
+        let position = self
+            .commitments
+            .iter()
+            .position(|commitment| commitment.signer == index)
+            .ok_or_else(|| anyhow!("Signer {} is not part of the signing session", index))?;
+
+        // Compute lambda_i := prod_{j != i} j / (j - i).
+        let i = TE::ScalarField::from(index as u128);
+        let (numerator, denominator) = self
+            .commitments
+            .iter()
+            .filter(|commitment| commitment.signer != index)
+            .map(|commitment| TE::ScalarField::from(commitment.signer as u128))
+            .fold((TE::ScalarField::one(), TE::ScalarField::one()), |(num, den), j| (num * j, den * (j - i)));
+        let lagrange_coefficient = numerator * denominator.inverse().ok_or_else(|| anyhow!("Duplicate signer"))?;
+
+        Ok((lagrange_coefficient, self.binding_factors[position]))
+    }
+}
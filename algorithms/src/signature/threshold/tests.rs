@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{signature::AleoSignatureScheme, SignatureScheme};
+use snarkvm_curves::{
+    edwards_bls12::{EdwardsParameters as EdwardsBls12, Fq, Fr},
+    templates::twisted_edwards_extended::Affine as TEAffine,
+};
+use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::{test_crypto_rng, FromBytes, ToBits, ToBytes};
+
+type Scheme = AleoSignatureScheme<EdwardsBls12>;
+
+/// Runs the distributed key generation among all participants, and returns their key shares.
+fn keygen(scheme: &Scheme, threshold: u16, num_participants: u16) -> Vec<ThresholdKeyShare<EdwardsBls12>> {
+    let rng = &mut test_crypto_rng();
+    let parameters = ThresholdParameters::new(threshold, num_participants).unwrap();
+
+    let (mut participants, round1_messages): (Vec<_>, Vec<_>) =
+        (1..=num_participants).map(|i| KeyGenParticipant::new(scheme, parameters, i, rng).unwrap()).unzip();
+    let round2_messages = participants
+        .iter_mut()
+        .flat_map(|participant| participant.round_two(scheme, &round1_messages).unwrap())
+        .collect::<Vec<_>>();
+
+    participants
+        .into_iter()
+        .enumerate()
+        .map(|(i, participant)| {
+            let received =
+                round2_messages.iter().filter(|m| m.receiver() as usize == i + 1).cloned().collect::<Vec<_>>();
+            participant.finalize(scheme, &received).unwrap()
+        })
+        .collect()
+}
+
+/// Signs the message with the given signers, and returns the aggregated signature.
+fn threshold_sign(
+    scheme: &Scheme,
+    key_shares: &[ThresholdKeyShare<EdwardsBls12>],
+    signers: &[u16],
+    message: &[bool],
+) -> anyhow::Result<crate::signature::AleoSignature<EdwardsBls12>> {
+    let rng = &mut test_crypto_rng();
+    let signers = signers.iter().map(|i| &key_shares[*i as usize - 1]).collect::<Vec<_>>();
+
+    let nonces = signers.iter().map(|signer| signer.commit(scheme, rng)).collect::<Vec<_>>();
+    let commitments = nonces.iter().map(|nonces| *nonces.commitment()).collect::<Vec<_>>();
+    let shares = signers
+        .iter()
+        .zip(nonces)
+        .map(|(signer, nonces)| signer.sign(scheme, nonces, message, &commitments))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    key_shares[0].public_key().aggregate(scheme, message, &commitments, &shares)
+}
+
+#[test]
+fn test_threshold_sign_and_verify() {
+    let scheme = Scheme::setup("test_threshold_sign_and_verify");
+    let key_shares = keygen(&scheme, 2, 3);
+    let public_key = key_shares[0].public_key().to_public_key(&scheme);
+    for key_share in &key_shares {
+        assert_eq!(key_share.public_key().to_public_key(&scheme), public_key);
+    }
+
+    let message = "Hi, I am a threshold Aleo signature!".as_bytes().to_bits_le();
+    for signers in [&[1, 3][..], &[2, 3], &[1, 2, 3]] {
+        let signature = threshold_sign(&scheme, &key_shares, signers, &message).unwrap();
+        assert!(scheme.verify(&public_key, &message, &signature).unwrap());
+        assert!(!scheme.verify(&public_key, &b"Bad message".to_bits_le(), &signature).unwrap());
+    }
+
+    // Fewer than `threshold` signers cannot sign.
+    assert!(threshold_sign(&scheme, &key_shares, &[2], &message).is_err());
+}
+
+#[test]
+fn test_threshold_rejects_invalid_messages() {
+    let rng = &mut test_crypto_rng();
+    let scheme = Scheme::setup("test_threshold_rejects_invalid_messages");
+    let parameters = ThresholdParameters::new(2, 3).unwrap();
+    assert!(ThresholdParameters::new(0, 3).is_err());
+    assert!(ThresholdParameters::new(4, 3).is_err());
+    assert!(KeyGenParticipant::new(&scheme, parameters, 4, rng).is_err());
+
+    // A participant rejects a share that does not match the commitments of its sender.
+    let (mut participants, round1_messages): (Vec<_>, Vec<_>) =
+        (1..=3).map(|i| KeyGenParticipant::new(&scheme, parameters, i, rng).unwrap()).unzip();
+    let round2_messages = participants
+        .iter_mut()
+        .flat_map(|participant| participant.round_two(&scheme, &round1_messages).unwrap())
+        .collect::<Vec<_>>();
+    let mut received = round2_messages.into_iter().filter(|m| m.receiver() == 1).collect::<Vec<_>>();
+    received[0].share += Fr::one();
+    assert!(participants.remove(0).finalize(&scheme, &received).is_err());
+
+    // The coordinator rejects an invalid signature share.
+    let key_shares = keygen(&scheme, 2, 3);
+    let message = b"message".to_bits_le();
+    let nonces = key_shares[..2].iter().map(|signer| signer.commit(&scheme, rng)).collect::<Vec<_>>();
+    let commitments = nonces.iter().map(|nonces| *nonces.commitment()).collect::<Vec<_>>();
+    let mut shares = key_shares[..2]
+        .iter()
+        .zip(nonces)
+        .map(|(signer, nonces)| signer.sign(&scheme, nonces, &message, &commitments).unwrap())
+        .collect::<Vec<_>>();
+    assert!(key_shares[0].public_key().aggregate(&scheme, &message, &commitments, &shares).is_ok());
+    shares[1].share += Fr::one();
+    assert!(key_shares[0].public_key().aggregate(&scheme, &message, &commitments, &shares).is_err());
+}
+
+#[test]
+fn test_threshold_serialization() {
+    let rng = &mut test_crypto_rng();
+    let scheme = Scheme::setup("test_threshold_serialization");
+    let parameters = ThresholdParameters::new(2, 3).unwrap();
+
+    let (mut participant, round1_message) = KeyGenParticipant::new(&scheme, parameters, 1, rng).unwrap();
+    let round1_messages = (2..=3)
+        .map(|i| KeyGenParticipant::new(&scheme, parameters, i, rng).unwrap().1)
+        .chain([round1_message.clone()])
+        .collect::<Vec<_>>();
+    let round2_message = participant.round_two(&scheme, &round1_messages).unwrap().remove(0);
+
+    let key_share = keygen(&scheme, 2, 3).remove(0);
+    let nonces = key_share.commit(&scheme, rng);
+    let commitment = *nonces.commitment();
+    let share = SignatureShare { signer: 1, share: key_share.secret_share };
+
+    macro_rules! check_round_trip {
+        ($value:expr, $ty:ty) => {
+            let bytes = $value.to_bytes_le().unwrap();
+            assert_eq!($value, <$ty>::from_bytes_le(&bytes).unwrap());
+        };
+    }
+    check_round_trip!(parameters, ThresholdParameters);
+    check_round_trip!(round1_message, KeyGenRound1Message<EdwardsBls12>);
+    check_round_trip!(round2_message, KeyGenRound2Message<EdwardsBls12>);
+    check_round_trip!(key_share, ThresholdKeyShare<EdwardsBls12>);
+    check_round_trip!(key_share.public_key().clone(), ThresholdPublicKey<EdwardsBls12>);
+    check_round_trip!(commitment, SigningCommitment<EdwardsBls12>);
+    check_round_trip!(share, SignatureShare<EdwardsBls12>);
+
+    // Elements that are not on the curve, or not in the prime-order subgroup, are rejected.
+    let bytes = commitment.to_bytes_le().unwrap();
+    let low_order = TEAffine::<EdwardsBls12>::new(Fq::zero(), -Fq::one()).to_bytes_le().unwrap();
+    let mut off_curve = bytes.clone();
+    off_curve[2 + low_order.len() - 1] ^= 1;
+    assert!(SigningCommitment::<EdwardsBls12>::from_bytes_le(&off_curve).is_err());
+    let mut not_in_subgroup = bytes;
+    not_in_subgroup[2..2 + low_order.len()].copy_from_slice(&low_order);
+    assert!(SigningCommitment::<EdwardsBls12>::from_bytes_le(&not_in_subgroup).is_err());
+}
@@ -28,9 +28,15 @@ use crate::{
 };
 use itertools::Itertools;
 use snarkvm_fields::{Field, PrimeField};
+use snarkvm_utilities::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 use core::{borrow::Borrow, marker::PhantomData};
-use std::collections::BTreeMap;
 
 /// The algebraic holographic proof defined in [CHMMVW19](https://eprint.iacr.org/2019/1047).
 /// Currently, this AHP only supports inputs of size one
@@ -93,22 +99,51 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     /// Get all the strict degree bounds enforced in the AHP.
     pub fn get_degree_bounds(info: &CircuitInfo<F>) -> [usize; 4] {
-        let num_constraints = info.num_constraints;
-        let num_non_zero_a = info.num_non_zero_a;
-        let num_non_zero_b = info.num_non_zero_b;
-        let num_non_zero_c = info.num_non_zero_c;
+        let constraint_domain = Self::constraint_domain(info).unwrap();
+        let [non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] = Self::non_zero_domains(info).unwrap();
         [
-            EvaluationDomain::<F>::compute_size_of_domain(num_constraints).unwrap() - 2,
-            EvaluationDomain::<F>::compute_size_of_domain(num_non_zero_a).unwrap() - 2,
-            EvaluationDomain::<F>::compute_size_of_domain(num_non_zero_b).unwrap() - 2,
-            EvaluationDomain::<F>::compute_size_of_domain(num_non_zero_c).unwrap() - 2,
+            constraint_domain.size() - 2,
+            non_zero_a_domain.size() - 2,
+            non_zero_b_domain.size() - 2,
+            non_zero_c_domain.size() - 2,
         ]
     }
 
+    /// Returns the constraint domain `H` of the given circuit.
+    ///
+    /// If `MM::MIXED_RADIX` is set, this is the smallest mixed-radix domain that fits the constraints
+    /// and contains the input domain. Otherwise, it is the smallest radix-2 domain that fits the constraints.
+    pub fn constraint_domain(info: &CircuitInfo<F>) -> Option<EvaluationDomain<F>> {
+        match MM::MIXED_RADIX {
+            true => EvaluationDomain::new_mixed_radix(info.num_constraints, info.num_public_inputs),
+            false => EvaluationDomain::new(info.num_constraints),
+        }
+    }
+
+    /// Returns the non-zero domains `K_A`, `K_B`, and `K_C` of the given circuit.
+    ///
+    /// If `MM::MIXED_RADIX` is set, the largest of these is the smallest mixed-radix domain that fits
+    /// the non-zero entries of every matrix, and the others are chosen as subgroups of it,
+    /// as the prover and verifier relate them via selector polynomials.
+    pub fn non_zero_domains(info: &CircuitInfo<F>) -> Option<[EvaluationDomain<F>; 3]> {
+        let num_non_zero = [info.num_non_zero_a, info.num_non_zero_b, info.num_non_zero_c];
+        let largest_domain = match MM::MIXED_RADIX {
+            true => EvaluationDomain::<F>::new_mixed_radix(num_non_zero.into_iter().max()?, 1)?,
+            false => EvaluationDomain::<F>::new(num_non_zero.into_iter().max()?)?,
+        };
+        let [a, b, c] = num_non_zero.map(|num_non_zero| match largest_domain.is_radix_2() {
+            true => EvaluationDomain::new(num_non_zero),
+            false => {
+                // The odd part of the largest domain is a power of the small subgroup base.
+                let q_part = largest_domain.size() >> largest_domain.log_size_of_group;
+                EvaluationDomain::new_mixed_radix(num_non_zero, q_part)
+            }
+        });
+        Some([a?, b?, c?])
+    }
+
     pub fn max_non_zero_domain(info: &CircuitInfo<F>) -> EvaluationDomain<F> {
-        let non_zero_a_domain = EvaluationDomain::new(info.num_non_zero_a).unwrap();
-        let non_zero_b_domain = EvaluationDomain::new(info.num_non_zero_b).unwrap();
-        let non_zero_c_domain = EvaluationDomain::new(info.num_non_zero_c).unwrap();
+        let [non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] = Self::non_zero_domains(info).unwrap();
         Self::max_non_zero_domain_helper(non_zero_a_domain, non_zero_b_domain, non_zero_c_domain)
     }
 
@@ -126,6 +161,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         non_zero_b_domain_size: usize,
         non_zero_c_domain_size: usize,
     ) -> Option<(FFTPrecomputation<F>, IFFTPrecomputation<F>)> {
+        let domain_sizes =
+            [constraint_domain_size, non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size];
         let largest_domain_size = [
             3 * constraint_domain_size,
             non_zero_a_domain_size * 2,
@@ -134,7 +171,16 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         ]
         .into_iter()
         .max()?;
-        let largest_mul_domain = EvaluationDomain::new(largest_domain_size)?;
+        // The products are computed over radix-2 domains, while the constraint and non-zero domains
+        // may be mixed-radix. The precomputation must cover all of them, so its size is the largest
+        // radix-2 size times the largest odd part (a power of the small subgroup base) of the domain sizes.
+        let largest_odd_part = domain_sizes.into_iter().map(|size| size >> size.trailing_zeros()).max()?;
+        let largest_mul_domain = match largest_odd_part {
+            1 => EvaluationDomain::new(largest_domain_size)?,
+            _ => EvaluationDomain::new_with_size(
+                EvaluationDomain::<F>::compute_size_of_domain(largest_domain_size)?.checked_mul(largest_odd_part)?,
+            )?,
+        };
 
         let fft_precomputation = largest_mul_domain.precompute_fft();
         let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
@@ -390,9 +436,10 @@ impl<F: PrimeField> UnnormalizedBivariateLagrangePoly<F> for EvaluationDomain<F>
         let elements = domain.elements().collect::<Vec<_>>();
 
         let mut denoms = cfg_iter!(elements).map(|e| x - e).collect::<Vec<_>>();
-        if domain.size() <= self.size() {
+        if self.size() % domain.size() == 0 {
+            // `domain` is a subgroup of `self`, so `self` vanishes on all of its elements.
             snarkvm_fields::batch_inversion_and_mul(&mut denoms, &vanish_x);
-        } else {
+        } else if domain.size() % self.size() == 0 {
             snarkvm_fields::batch_inversion(&mut denoms);
             let ratio = domain.size() / self.size();
             let mut numerators = vec![vanish_x; domain.size()];
@@ -402,6 +449,18 @@ impl<F: PrimeField> UnnormalizedBivariateLagrangePoly<F> for EvaluationDomain<F>
                 }
             });
             cfg_iter_mut!(denoms).zip_eq(numerators).for_each(|(d, e)| *d *= e);
+        } else {
+            // Neither domain contains the other, which happens when one of them is mixed-radix.
+            // The vanishing polynomial of `self` at the `i`-th element of `domain` is `g^(i * |self|) - 1`.
+            snarkvm_fields::batch_inversion(&mut denoms);
+            let g = domain.group_gen.pow([self.size]);
+            let mut numerators = vec![vanish_x + F::one(); domain.size()];
+            let mut g_i = F::one();
+            for n in numerators.iter_mut() {
+                *n -= g_i;
+                g_i *= g;
+            }
+            cfg_iter_mut!(denoms).zip_eq(numerators).for_each(|(d, e)| *d *= e);
         }
         denoms
     }
@@ -480,6 +539,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn domain_unnormalized_bivariate_lagrange_poly_over_mixed_radix_domains() {
+        let rng = &mut test_rng();
+        let sizes = [2, 3, 4, 6, 8, 12, 16, 24, 48];
+        for domain_size in sizes {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(domain_size, 1).unwrap();
+            assert_eq!(domain.size(), domain_size);
+
+            let manual: Vec<_> =
+                domain.elements().map(|elem| domain.eval_unnormalized_bivariate_lagrange_poly(elem, elem)).collect();
+            let fast = domain.batch_eval_unnormalized_bivariate_lagrange_poly_with_same_inputs();
+            assert_eq!(fast, manual);
+
+            let x = Fr::rand(rng);
+            for other_domain_size in sizes {
+                let other = EvaluationDomain::<Fr>::new_mixed_radix(other_domain_size, 1).unwrap();
+                let manual: Vec<_> =
+                    other.elements().map(|y| domain.eval_unnormalized_bivariate_lagrange_poly(x, y)).collect();
+                let fast =
+                    domain.batch_eval_unnormalized_bivariate_lagrange_poly_with_diff_inputs_over_domain(x, &other);
+                assert_eq!(fast, manual, "failed for self {:?} and other {:?}", domain, other);
+            }
+        }
+    }
+
     #[test]
     fn test_summation() {
         let rng = &mut test_rng();
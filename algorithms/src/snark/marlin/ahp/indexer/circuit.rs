@@ -17,11 +17,8 @@
 use core::marker::PhantomData;
 
 use crate::{
-    fft::{
-        domain::{FFTPrecomputation, IFFTPrecomputation},
-        EvaluationDomain,
-    },
-    polycommit::sonic_pc::LabeledPolynomial,
+    fft::domain::{FFTPrecomputation, IFFTPrecomputation},
+    polycommit::{sonic_pc::LabeledPolynomial, PCError, PolynomialCommitment},
     snark::marlin::{ahp::matrices::MatrixArithmetization, AHPForR1CS, CircuitInfo, MarlinMode, Matrix},
 };
 use snarkvm_fields::PrimeField;
@@ -65,7 +62,41 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
 
     /// The number of constraints in this R1CS instance.
     pub fn constraint_domain_size(&self) -> usize {
-        crate::fft::EvaluationDomain::<F>::new(self.index_info.num_constraints).unwrap().size()
+        AHPForR1CS::<F, MM>::constraint_domain(&self.index_info).unwrap().size()
+    }
+
+    /// Trims the universal SRS to the committer and verifier keys for this index.
+    /// This is deterministic, so the committer key can always be re-derived from the SRS.
+    pub fn trim_universal_srs<CF: PrimeField, PC: PolynomialCommitment<F, CF>>(
+        &self,
+        universal_srs: &PC::UniversalParams,
+    ) -> Result<(PC::CommitterKey, PC::VerifierKey), PCError> {
+        Self::trim_universal_srs_for_all::<CF, PC>([self], universal_srs)
+    }
+
+    /// Trims the universal SRS to a single pair of committer and verifier keys for all of the given indices,
+    /// so that the indices can be proven together. Like [`Self::trim_universal_srs`], this is deterministic.
+    pub fn trim_universal_srs_for_all<'a, CF: PrimeField, PC: PolynomialCommitment<F, CF>>(
+        circuits: impl IntoIterator<Item = &'a Self>,
+        universal_srs: &PC::UniversalParams,
+    ) -> Result<(PC::CommitterKey, PC::VerifierKey), PCError>
+    where
+        Self: 'a,
+    {
+        let mut max_degree = 0;
+        let mut constraint_domain_sizes = Vec::new();
+        let mut coefficient_support = Vec::new();
+        for circuit in circuits {
+            max_degree = max_degree.max(circuit.max_degree());
+            constraint_domain_sizes.push(circuit.constraint_domain_size());
+            coefficient_support.extend(AHPForR1CS::<F, MM>::get_degree_bounds(&circuit.index_info));
+        }
+        constraint_domain_sizes.sort_unstable();
+        constraint_domain_sizes.dedup();
+
+        // Marlin only needs degree 2 random polynomials.
+        let supported_hiding_bound = 1;
+        PC::trim(universal_srs, max_degree, constraint_domain_sizes, supported_hiding_bound, Some(&coefficient_support))
     }
 
     /// Iterate over the indexed polynomials.
@@ -147,14 +178,12 @@ impl<F: PrimeField, MM: MarlinMode> CanonicalDeserialize for Circuit<F, MM> {
     #[allow(unused_mut, unused_variables)]
     fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         let index_info: CircuitInfo<F> = CanonicalDeserialize::deserialize(reader)?;
-        let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_constraints)
-            .ok_or(SerializationError::InvalidData)?;
-        let non_zero_a_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_a)
-            .ok_or(SerializationError::InvalidData)?;
-        let non_zero_b_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_b)
-            .ok_or(SerializationError::InvalidData)?;
-        let non_zero_c_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_c)
-            .ok_or(SerializationError::InvalidData)?;
+        let constraint_domain_size =
+            AHPForR1CS::<F, MM>::constraint_domain(&index_info).ok_or(SerializationError::InvalidData)?.size();
+        let [non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size] =
+            AHPForR1CS::<F, MM>::non_zero_domains(&index_info)
+                .ok_or(SerializationError::InvalidData)?
+                .map(|d| d.size());
 
         let (fft_precomputation, ifft_precomputation) = AHPForR1CS::<F, MM>::fft_precomputation(
             constraint_domain_size,
@@ -181,14 +210,12 @@ impl<F: PrimeField, MM: MarlinMode> CanonicalDeserialize for Circuit<F, MM> {
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         {
             let index_info: CircuitInfo<F> = CanonicalDeserialize::deserialize_uncompressed(reader)?;
-            let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_constraints)
-                .ok_or(SerializationError::InvalidData)?;
-            let non_zero_a_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_a)
-                .ok_or(SerializationError::InvalidData)?;
-            let non_zero_b_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_b)
-                .ok_or(SerializationError::InvalidData)?;
-            let non_zero_c_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_c)
-                .ok_or(SerializationError::InvalidData)?;
+            let constraint_domain_size =
+                AHPForR1CS::<F, MM>::constraint_domain(&index_info).ok_or(SerializationError::InvalidData)?.size();
+            let [non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size] =
+                AHPForR1CS::<F, MM>::non_zero_domains(&index_info)
+                    .ok_or(SerializationError::InvalidData)?
+                    .map(|d| d.size());
 
             let (fft_precomputation, ifft_precomputation) = AHPForR1CS::<F, MM>::fft_precomputation(
                 constraint_domain_size,
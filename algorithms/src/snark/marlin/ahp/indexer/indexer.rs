@@ -30,12 +30,12 @@ use crate::{
 };
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::{collections::BTreeMap, format};
 
 use core::marker::PhantomData;
-use std::collections::BTreeMap;
 
 #[cfg(not(feature = "std"))]
-use snarkvm_utilities::println;
+use snarkvm_utilities::{eprintln, println};
 
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Generate the index for this constraint system.
@@ -97,17 +97,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             f: PhantomData,
         };
 
-        let constraint_domain =
-            EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let constraint_domain = Self::constraint_domain(&index_info).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
         let input_domain =
             EvaluationDomain::new(num_padded_public_variables).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
-        let non_zero_a_domain =
-            EvaluationDomain::new(num_non_zero_a).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_b_domain =
-            EvaluationDomain::new(num_non_zero_b).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_c_domain =
-            EvaluationDomain::new(num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let [non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] =
+            Self::non_zero_domains(&index_info).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
         let joint_arithmetization_time = start_timer!(|| "Arithmetizing A");
         let a_arith = arithmetize_matrix(&a, "a", non_zero_a_domain, constraint_domain, input_domain);
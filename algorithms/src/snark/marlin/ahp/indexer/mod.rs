@@ -27,6 +27,8 @@ pub(crate) use constraint_system::*;
 
 mod indexer;
 
+use snarkvm_utilities::vec::Vec;
+
 /// Represents a matrix.
 pub(crate) type Matrix<F> = Vec<Vec<(F, usize)>>;
 
@@ -24,12 +24,10 @@ use crate::{
 use itertools::Itertools;
 use snarkvm_fields::{batch_inversion, Field, PrimeField};
 use snarkvm_r1cs::{ConstraintSystem, Index as VarIndex};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, serialize::*};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, collections::BTreeMap, format, serialize::*, string::ToString};
 
 use hashbrown::HashMap;
 
-use std::collections::BTreeMap;
-
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
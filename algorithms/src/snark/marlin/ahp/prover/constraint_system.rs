@@ -17,6 +17,7 @@
 use crate::snark::marlin::ahp::matrices::make_matrices_square;
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
+use snarkvm_utilities::{vec, vec::Vec};
 
 pub(crate) struct ConstraintSystem<F: Field> {
     pub(crate) public_variables: Vec<F>,
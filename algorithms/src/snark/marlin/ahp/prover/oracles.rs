@@ -14,9 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::collections::BTreeMap;
-
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{collections::BTreeMap, vec, vec::Vec};
 
 use crate::polycommit::sonic_pc::{LabeledPolynomial, LabeledPolynomialWithBasis, PolynomialInfo, PolynomialLabel};
 
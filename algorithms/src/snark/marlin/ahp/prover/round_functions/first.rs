@@ -14,7 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{collections::BTreeMap, sync::Arc};
+use snarkvm_utilities::{
+    cfg_into_iter,
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, SparsePolynomial},
@@ -34,7 +41,6 @@ use crate::{
 };
 use itertools::Itertools;
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::cfg_into_iter;
 
 use rand_core::RngCore;
 
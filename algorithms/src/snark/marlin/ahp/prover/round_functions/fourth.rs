@@ -14,8 +14,6 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::collections::BTreeMap;
-
 use crate::{
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::marlin::{
@@ -27,6 +25,7 @@ use crate::{
 
 use rand_core::RngCore;
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::collections::BTreeMap;
 
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Output the fourth round message and the next state.
@@ -15,7 +15,6 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use core::convert::TryInto;
-use std::collections::BTreeMap;
 
 use crate::{
     fft,
@@ -39,7 +38,7 @@ use crate::{
     },
 };
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, ExecutionPool};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, collections::BTreeMap, vec, ExecutionPool};
 
 use itertools::Itertools;
 use rand_core::RngCore;
@@ -55,7 +54,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     /// Output the degree bounds of oracles in the first round.
     pub fn second_round_polynomial_info(info: &CircuitInfo<F>) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints).unwrap();
+        let constraint_domain_size = Self::constraint_domain(info).unwrap().size();
         [
             PolynomialInfo::new("g_1".into(), Some(constraint_domain_size - 2), Self::zk_bound()),
             PolynomialInfo::new("h_1".into(), None, None),
@@ -98,9 +97,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let sumcheck_lhs = Self::calculate_lhs(&state, t, summed_z_m, z, *alpha);
 
-        debug_assert!(
-            sumcheck_lhs.evaluate_over_domain_by_ref(constraint_domain).evaluations.into_iter().sum::<F>().is_zero()
-        );
+        debug_assert!(sumcheck_lhs
+            .evaluate_over_domain_by_ref(constraint_domain)
+            .evaluations
+            .into_iter()
+            .sum::<F>()
+            .is_zero());
 
         let sumcheck_time = start_timer!(|| "Compute sumcheck h and g polys");
         let (h_1, x_g_1) = sumcheck_lhs.divide_by_vanishing_poly(constraint_domain).unwrap();
@@ -15,7 +15,6 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use core::convert::TryInto;
-use std::collections::BTreeMap;
 
 use crate::{
     fft::{
@@ -34,7 +33,7 @@ use crate::{
     },
 };
 use snarkvm_fields::{batch_inversion_and_mul, PrimeField};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, ExecutionPool};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, collections::BTreeMap, string::ToString, vec::Vec, ExecutionPool};
 
 use rand_core::RngCore;
 
@@ -51,9 +50,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     /// Output the degree bounds of oracles in the first round.
     pub fn third_round_polynomial_info(info: &CircuitInfo<F>) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        let non_zero_a_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_a).unwrap();
-        let non_zero_b_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_b).unwrap();
-        let non_zero_c_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_c).unwrap();
+        let [non_zero_a_size, non_zero_b_size, non_zero_c_size] =
+            Self::non_zero_domains(info).unwrap().map(|domain| domain.size());
 
         [
             PolynomialInfo::new("g_a".into(), Some(non_zero_a_size - 2), None),
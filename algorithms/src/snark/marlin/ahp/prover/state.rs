@@ -14,8 +14,6 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::sync::Arc;
-
 use crate::{
     fft::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
@@ -26,11 +24,13 @@ use crate::{
     snark::marlin::{
         ahp::{indexer::Circuit, verifier},
         AHPError,
+        AHPForR1CS,
         MarlinMode,
     },
 };
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::{sync::Arc, vec::Vec};
 
 /// State for the AHP prover.
 pub struct State<'a, F: PrimeField, MM: MarlinMode> {
@@ -97,14 +97,10 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
     ) -> Result<Self, AHPError> {
         let index_info = &index.index_info;
         let constraint_domain =
-            EvaluationDomain::new(index_info.num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-        let non_zero_a_domain =
-            EvaluationDomain::new(index_info.num_non_zero_a).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_b_domain =
-            EvaluationDomain::new(index_info.num_non_zero_b).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_c_domain =
-            EvaluationDomain::new(index_info.num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+            AHPForR1CS::<F, MM>::constraint_domain(index_info).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let [non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] =
+            AHPForR1CS::<F, MM>::non_zero_domains(index_info).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
         let input_domain =
             EvaluationDomain::new(padded_public_input[0].len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
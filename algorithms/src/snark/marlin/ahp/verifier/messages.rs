@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{string::String, vec::Vec};
 
 use crate::snark::marlin::{witness_label, MarlinMode};
 
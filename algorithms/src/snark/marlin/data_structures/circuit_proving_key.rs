@@ -15,31 +15,48 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    polycommit::sonic_pc,
+    polycommit::PolynomialCommitment,
     snark::marlin::{ahp::indexer::*, CircuitVerifyingKey, MarlinMode},
+    SNARKError,
 };
-use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
-    io::{self, Read, Write},
+    error,
+    io,
+    io::{Read, Write},
     serialize::*,
+    sync::Arc,
     FromBytes,
     ToBytes,
 };
 
+/// The version of the compact serialization format for `CircuitProvingKey`.
+pub(super) const COMPACT_FORMAT_VERSION: u8 = 0;
+
+/// The number of sections in the compact serialization format for `CircuitProvingKey`.
+pub(super) const NUM_COMPACT_SECTIONS: usize = 3;
+
+/// The size in bytes of the header of the compact serialization format,
+/// which consists of the version, followed by the length of each section.
+pub(super) const COMPACT_HEADER_SIZE: usize = 1 + 8 * NUM_COMPACT_SECTIONS;
+
 /// Proving key for a specific circuit (i.e., R1CS matrices).
 #[derive(Clone, Debug)]
-pub struct CircuitProvingKey<E: PairingEngine, MM: MarlinMode> {
+pub struct CircuitProvingKey<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> {
     /// The circuit verifying key.
-    pub circuit_verifying_key: CircuitVerifyingKey<E, MM>,
+    pub circuit_verifying_key: CircuitVerifyingKey<F, CF, PC, MM>,
     /// The randomness for the circuit polynomial commitments.
-    pub circuit_commitment_randomness: Vec<sonic_pc::Randomness<E>>,
+    pub circuit_commitment_randomness: Vec<PC::Randomness>,
     /// The circuit itself.
-    pub circuit: Circuit<E::Fr, MM>,
-    /// The committer key for this index, trimmed from the universal SRS.
-    pub committer_key: sonic_pc::CommitterKey<E>,
+    pub circuit: Circuit<F, MM>,
+    /// The committer key for this index, trimmed from the universal SRS, with its tables precomputed.
+    /// It is shared with the proving keys that are loaded from the compact format.
+    pub committer_key: Arc<PC::CommitterKey>,
 }
 
-impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitProvingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> ToBytes
+    for CircuitProvingKey<F, CF, PC, MM>
+{
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
         CanonicalSerialize::serialize(&self.circuit_verifying_key, &mut writer)?;
         CanonicalSerialize::serialize(&self.circuit_commitment_randomness, &mut writer)?;
@@ -49,14 +66,101 @@ impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitProvingKey<E, MM> {
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> FromBytes for CircuitProvingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> FromBytes
+    for CircuitProvingKey<F, CF, PC, MM>
+{
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         let circuit_verifying_key = CanonicalDeserialize::deserialize(&mut reader)?;
         let circuit_commitment_randomness = CanonicalDeserialize::deserialize(&mut reader)?;
         let circuit = CanonicalDeserialize::deserialize(&mut reader)?;
-        let committer_key = FromBytes::read_le(&mut reader)?;
+        let mut committer_key = FromBytes::read_le(&mut reader)?;
+        PC::precompute_committer_key(&mut committer_key);
+        let committer_key = Arc::new(committer_key);
+
+        Ok(Self { circuit_verifying_key, circuit_commitment_randomness, circuit, committer_key })
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> CircuitProvingKey<F, CF, PC, MM> {
+    /// Serializes the proving key in the compact format.
+    ///
+    /// The compact format omits the committer key, which is shared between the proving keys
+    /// of circuits of the same size, and stores all group elements in compressed form.
+    /// It consists of a header with the length of each section, followed by the sections for
+    /// the circuit verifying key, the circuit commitment randomness, and the circuit,
+    /// so that each section can be located and loaded independently.
+    pub fn write_compact<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut sections = [Vec::new(), Vec::new(), Vec::new()];
+        CanonicalSerialize::serialize(&self.circuit_verifying_key, &mut sections[0])?;
+        CanonicalSerialize::serialize(&self.circuit_commitment_randomness, &mut sections[1])?;
+        CanonicalSerialize::serialize(&self.circuit, &mut sections[2])?;
+
+        COMPACT_FORMAT_VERSION.write_le(&mut writer)?;
+        for section in &sections {
+            (section.len() as u64).write_le(&mut writer)?;
+        }
+        for section in &sections {
+            writer.write_all(section)?;
+        }
+        Ok(())
+    }
+
+    /// Deserializes a proving key from the compact format, with the given committer key.
+    ///
+    /// The committer key is shared rather than copied. It must be the key trimmed for this circuit,
+    /// as held by the proving key from [`MarlinSNARK::circuit_setup`](crate::snark::marlin::MarlinSNARK::circuit_setup)
+    /// for any circuit of the same size, or as returned by [`Circuit::trim_universal_srs`].
+    /// In the latter case, call [`PolynomialCommitment::precompute_committer_key`] on it first.
+    /// Returns an error if the committer key does not support the degrees of the circuit verifying key,
+    /// or if its tables are not precomputed.
+    pub fn read_compact<R: Read>(mut reader: R, committer_key: Arc<PC::CommitterKey>) -> Result<Self, SNARKError> {
+        let [vk_length, randomness_length, circuit_length] = read_compact_header(&mut reader)?;
+
+        let circuit_verifying_key = read_compact_section(&mut reader, vk_length)?;
+        check_committer_key(committer_key.as_ref(), &circuit_verifying_key)?;
+        let circuit_commitment_randomness = read_compact_section(&mut reader, randomness_length)?;
+        let circuit = read_compact_section(&mut reader, circuit_length)?;
 
         Ok(Self { circuit_verifying_key, circuit_commitment_randomness, circuit, committer_key })
     }
 }
+
+/// Ensures the committer key supports the degrees of the circuit verifying key, and has its tables precomputed.
+pub(super) fn check_committer_key<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode>(
+    committer_key: &PC::CommitterKey,
+    circuit_verifying_key: &CircuitVerifyingKey<F, CF, PC, MM>,
+) -> Result<(), SNARKError> {
+    if !PC::is_committer_key_for(committer_key, &circuit_verifying_key.verifier_key) {
+        return Err(SNARKError::Message("The committer key does not match the circuit verifying key".into()));
+    }
+    if !PC::is_committer_key_precomputed(committer_key) {
+        return Err(SNARKError::Message("The committer key is not precomputed".into()));
+    }
+    Ok(())
+}
+
+/// Reads the header of the compact format, and returns the length of each section.
+pub(super) fn read_compact_header<R: Read>(mut reader: R) -> io::Result<[usize; NUM_COMPACT_SECTIONS]> {
+    let version = u8::read_le(&mut reader)?;
+    if version != COMPACT_FORMAT_VERSION {
+        return Err(error("unsupported compact proving key version"));
+    }
+
+    let mut lengths = [0usize; NUM_COMPACT_SECTIONS];
+    for length in lengths.iter_mut() {
+        *length = usize::try_from(u64::read_le(&mut reader)?)
+            .map_err(|_| error("could not read the length of a compact proving key section"))?;
+    }
+    Ok(lengths)
+}
+
+/// Deserializes a section of the compact format, and ensures it is exactly `length` bytes.
+pub(super) fn read_compact_section<T: CanonicalDeserialize, R: Read>(reader: R, length: usize) -> io::Result<T> {
+    let mut section = reader.take(length as u64);
+    let value = CanonicalDeserialize::deserialize(&mut section)?;
+    match section.limit() {
+        0 => Ok(value),
+        _ => Err(error("could not deserialize a compact proving key section")),
+    }
+}
@@ -15,13 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    fft::EvaluationDomain,
-    polycommit::sonic_pc,
-    snark::marlin::{ahp::indexer::*, CircuitProvingKey, MarlinMode, PreparedCircuitVerifyingKey},
+    polycommit::PolynomialCommitment,
+    snark::marlin::{ahp::indexer::*, AHPForR1CS, CircuitProvingKey, MarlinMode, PreparedCircuitVerifyingKey},
     Prepare,
 };
-use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField};
 use snarkvm_r1cs::SynthesisError;
 use snarkvm_utilities::{
     error,
@@ -41,37 +39,34 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Verification key for a specific index (i.e., R1CS matrices).
 #[derive(Debug, Clone, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
-pub struct CircuitVerifyingKey<E: PairingEngine, MM: MarlinMode> {
+pub struct CircuitVerifyingKey<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> {
     /// Stores information about the size of the circuit, as well as its defined field.
-    pub circuit_info: CircuitInfo<E::Fr>,
+    pub circuit_info: CircuitInfo<F>,
     /// Commitments to the indexed polynomials.
-    pub circuit_commitments: Vec<sonic_pc::Commitment<E>>,
+    pub circuit_commitments: Vec<PC::Commitment>,
     /// The verifier key for this index, trimmed from the universal SRS.
-    pub verifier_key: sonic_pc::VerifierKey<E>,
+    pub verifier_key: PC::VerifierKey,
     #[doc(hidden)]
-    pub mode: PhantomData<MM>,
+    pub mode: PhantomData<(CF, MM)>,
 }
 
-impl<E: PairingEngine, MM: MarlinMode> Prepare for CircuitVerifyingKey<E, MM> {
-    type Prepared = PreparedCircuitVerifyingKey<E, MM>;
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> Prepare
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
+    type Prepared = PreparedCircuitVerifyingKey<F, CF, PC, MM>;
 
     /// Prepare the circuit verifying key.
     fn prepare(&self) -> Self::Prepared {
-        let mut prepared_index_comms = Vec::<sonic_pc::PreparedCommitment<E>>::new();
+        let mut prepared_index_comms = Vec::<PC::PreparedCommitment>::new();
         for (_, comm) in self.circuit_commitments.iter().enumerate() {
             prepared_index_comms.push(comm.prepare());
         }
 
         let prepared_verifier_key = self.verifier_key.prepare();
 
-        let constraint_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_constraints).unwrap() as u64;
-        let non_zero_a_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_a).unwrap() as u64;
-        let non_zero_b_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_b).unwrap() as u64;
-        let non_zero_c_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_b).unwrap() as u64;
+        let constraint_domain_size = AHPForR1CS::<F, MM>::constraint_domain(&self.circuit_info).unwrap().size as u64;
+        let [non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size] =
+            AHPForR1CS::<F, MM>::non_zero_domains(&self.circuit_info).unwrap().map(|domain| domain.size);
 
         PreparedCircuitVerifyingKey {
             constraint_domain_size,
@@ -85,38 +80,41 @@ impl<E: PairingEngine, MM: MarlinMode> Prepare for CircuitVerifyingKey<E, MM> {
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> From<CircuitProvingKey<E, MM>> for CircuitVerifyingKey<E, MM> {
-    fn from(other: CircuitProvingKey<E, MM>) -> Self {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode>
+    From<CircuitProvingKey<F, CF, PC, MM>> for CircuitVerifyingKey<F, CF, PC, MM>
+{
+    fn from(other: CircuitProvingKey<F, CF, PC, MM>) -> Self {
         other.circuit_verifying_key
     }
 }
 
-impl<'a, E: PairingEngine, MM: MarlinMode> From<&'a CircuitProvingKey<E, MM>> for CircuitVerifyingKey<E, MM> {
-    fn from(other: &'a CircuitProvingKey<E, MM>) -> Self {
+impl<'a, F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode>
+    From<&'a CircuitProvingKey<F, CF, PC, MM>> for CircuitVerifyingKey<F, CF, PC, MM>
+{
+    fn from(other: &'a CircuitProvingKey<F, CF, PC, MM>) -> Self {
         other.circuit_verifying_key.clone()
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> From<PreparedCircuitVerifyingKey<E, MM>> for CircuitVerifyingKey<E, MM> {
-    fn from(other: PreparedCircuitVerifyingKey<E, MM>) -> Self {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode>
+    From<PreparedCircuitVerifyingKey<F, CF, PC, MM>> for CircuitVerifyingKey<F, CF, PC, MM>
+{
+    fn from(other: PreparedCircuitVerifyingKey<F, CF, PC, MM>) -> Self {
         other.orig_vk
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> ToMinimalBits for CircuitVerifyingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> ToMinimalBits
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
     fn to_minimal_bits(&self) -> Vec<bool> {
-        let constraint_domain = EvaluationDomain::<E::Fr>::new(self.circuit_info.num_constraints)
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)
-            .unwrap();
-        let non_zero_domain_a = EvaluationDomain::<E::Fr>::new(self.circuit_info.num_non_zero_a)
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)
-            .unwrap();
-        let non_zero_domain_b = EvaluationDomain::<E::Fr>::new(self.circuit_info.num_non_zero_b)
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)
-            .unwrap();
-        let non_zero_domain_c = EvaluationDomain::<E::Fr>::new(self.circuit_info.num_non_zero_c)
+        let constraint_domain = AHPForR1CS::<F, MM>::constraint_domain(&self.circuit_info)
             .ok_or(SynthesisError::PolynomialDegreeTooLarge)
             .unwrap();
+        let [non_zero_domain_a, non_zero_domain_b, non_zero_domain_c] =
+            AHPForR1CS::<F, MM>::non_zero_domains(&self.circuit_info)
+                .ok_or(SynthesisError::PolynomialDegreeTooLarge)
+                .unwrap();
 
         assert!(constraint_domain.size() < u64::MAX as usize);
         assert!(non_zero_domain_a.size() < u64::MAX as usize);
@@ -162,41 +160,44 @@ impl<E: PairingEngine, MM: MarlinMode> ToMinimalBits for CircuitVerifyingKey<E,
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> FromBytes for CircuitVerifyingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> FromBytes
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
     fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
         CanonicalDeserialize::deserialize(&mut r).map_err(|_| error("could not deserialize CircuitVerifyingKey"))
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitVerifyingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> ToBytes
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
     fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
         CanonicalSerialize::serialize(self, &mut w).map_err(|_| error("could not serialize CircuitVerifyingKey"))
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> CircuitVerifyingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode>
+    CircuitVerifyingKey<F, CF, PC, MM>
+{
     /// Iterate over the commitments to indexed polynomials in `self`.
-    pub fn iter(&self) -> impl Iterator<Item = &sonic_pc::Commitment<E>> {
+    pub fn iter(&self) -> impl Iterator<Item = &PC::Commitment> {
         self.circuit_commitments.iter()
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> ToConstraintField<E::Fq> for CircuitVerifyingKey<E, MM> {
-    fn to_field_elements(&self) -> Result<Vec<E::Fq>, ConstraintFieldError> {
-        let constraint_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_constraints).unwrap() as u128;
-        let non_zero_a_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_a).unwrap() as u128;
-        let non_zero_b_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_b).unwrap() as u128;
-        let non_zero_c_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_c).unwrap() as u128;
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> ToConstraintField<CF>
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
+    fn to_field_elements(&self) -> Result<Vec<CF>, ConstraintFieldError> {
+        let constraint_domain_size = AHPForR1CS::<F, MM>::constraint_domain(&self.circuit_info).unwrap().size as u128;
+        let [non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size] =
+            AHPForR1CS::<F, MM>::non_zero_domains(&self.circuit_info).unwrap().map(|domain| domain.size as u128);
 
         let mut res = Vec::new();
-        res.append(&mut E::Fq::from(constraint_domain_size).to_field_elements()?);
-        res.append(&mut E::Fq::from(non_zero_a_domain_size).to_field_elements()?);
-        res.append(&mut E::Fq::from(non_zero_b_domain_size).to_field_elements()?);
-        res.append(&mut E::Fq::from(non_zero_c_domain_size).to_field_elements()?);
+        res.append(&mut CF::from(constraint_domain_size).to_field_elements()?);
+        res.append(&mut CF::from(non_zero_a_domain_size).to_field_elements()?);
+        res.append(&mut CF::from(non_zero_b_domain_size).to_field_elements()?);
+        res.append(&mut CF::from(non_zero_c_domain_size).to_field_elements()?);
         for comm in self.circuit_commitments.iter() {
             res.append(&mut comm.to_field_elements()?);
         }
@@ -207,7 +208,9 @@ impl<E: PairingEngine, MM: MarlinMode> ToConstraintField<E::Fq> for CircuitVerif
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> FromStr for CircuitVerifyingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> FromStr
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
     type Err = anyhow::Error;
 
     #[inline]
@@ -216,7 +219,9 @@ impl<E: PairingEngine, MM: MarlinMode> FromStr for CircuitVerifyingKey<E, MM> {
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> fmt::Display for CircuitVerifyingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> fmt::Display
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let vk_hex = hex::encode(self.to_bytes_le().expect("Failed to convert verifying key to bytes"));
@@ -224,7 +229,9 @@ impl<E: PairingEngine, MM: MarlinMode> fmt::Display for CircuitVerifyingKey<E, M
     }
 }
 
-impl<E: PairingEngine, MM: MarlinMode> Serialize for CircuitVerifyingKey<E, MM> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> Serialize
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
     #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
@@ -234,7 +241,9 @@ impl<E: PairingEngine, MM: MarlinMode> Serialize for CircuitVerifyingKey<E, MM>
     }
 }
 
-impl<'de, E: PairingEngine, MM: MarlinMode> Deserialize<'de> for CircuitVerifyingKey<E, MM> {
+impl<'de, F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> Deserialize<'de>
+    for CircuitVerifyingKey<F, CF, PC, MM>
+{
     #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::circuit_proving_key::{check_committer_key, read_compact_header, read_compact_section, COMPACT_HEADER_SIZE};
+use crate::{
+    polycommit::PolynomialCommitment,
+    snark::marlin::{ahp::indexer::*, CircuitProvingKey, CircuitVerifyingKey, MarlinMode},
+    SNARKError,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{error, sync::Arc, vec::Vec};
+
+use core::ops::Range;
+
+/// A circuit proving key in the compact format, loaded on demand from its serialized bytes.
+///
+/// Only the circuit verifying key is deserialized up front. The circuit and its commitment randomness
+/// remain serialized until they are needed, and the committer key is shared with the other proving keys
+/// of the same size when the full proving key is loaded. Backed by a memory-mapped file, this allows
+/// a prover to hold keys for many circuits at once, without keeping them all in memory.
+pub struct LazyCircuitProvingKey<
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    MM: MarlinMode,
+    B: AsRef<[u8]>,
+> {
+    /// The circuit verifying key.
+    circuit_verifying_key: CircuitVerifyingKey<F, CF, PC, MM>,
+    /// The proving key, serialized in the compact format.
+    bytes: B,
+    /// The byte range of the circuit commitment randomness.
+    randomness_range: Range<usize>,
+    /// The byte range of the circuit.
+    circuit_range: Range<usize>,
+}
+
+/// A circuit proving key in the compact format, loaded on demand from a memory-mapped file.
+#[cfg(feature = "std")]
+pub type MappedCircuitProvingKey<F, CF, PC, MM> = LazyCircuitProvingKey<F, CF, PC, MM, snarkvm_utilities::MappedFile>;
+
+#[cfg(feature = "std")]
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode>
+    MappedCircuitProvingKey<F, CF, PC, MM>
+{
+    /// Memory-maps the proving key at the given path, which must be in the compact format.
+    /// See [`CircuitProvingKey::write_compact`].
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<Self, SNARKError> {
+        Self::from_bytes(snarkvm_utilities::MappedFile::open(path)?)
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode, B: AsRef<[u8]>>
+    LazyCircuitProvingKey<F, CF, PC, MM, B>
+{
+    /// Returns the proving key for the given bytes, which must be in the compact format.
+    /// See [`CircuitProvingKey::write_compact`].
+    pub fn from_bytes(bytes: B) -> Result<Self, SNARKError> {
+        let size = bytes.as_ref().len();
+        let [vk_length, randomness_length, circuit_length] = read_compact_header(bytes.as_ref())?;
+        let vk_range = Self::section_range(COMPACT_HEADER_SIZE, vk_length, size)?;
+        let randomness_range = Self::section_range(vk_range.end, randomness_length, size)?;
+        let circuit_range = Self::section_range(randomness_range.end, circuit_length, size)?;
+        if circuit_range.end != size {
+            return Err(error("the compact proving key has trailing bytes").into());
+        }
+
+        let circuit_verifying_key = read_compact_section(&bytes.as_ref()[vk_range], vk_length)?;
+
+        Ok(Self { circuit_verifying_key, bytes, randomness_range, circuit_range })
+    }
+
+    /// Returns the circuit verifying key.
+    pub fn circuit_verifying_key(&self) -> &CircuitVerifyingKey<F, CF, PC, MM> {
+        &self.circuit_verifying_key
+    }
+
+    /// Returns the circuit commitment randomness, deserialized on demand.
+    pub fn circuit_commitment_randomness(&self) -> Result<Vec<PC::Randomness>, SNARKError> {
+        let range = self.randomness_range.clone();
+        Ok(read_compact_section(&self.bytes.as_ref()[range.clone()], range.len())?)
+    }
+
+    /// Returns the circuit, deserialized on demand.
+    pub fn circuit(&self) -> Result<Circuit<F, MM>, SNARKError> {
+        let range = self.circuit_range.clone();
+        Ok(read_compact_section(&self.bytes.as_ref()[range.clone()], range.len())?)
+    }
+
+    /// Returns the full proving key, with the given committer key.
+    /// See [`CircuitProvingKey::read_compact`] for the requirements on the committer key.
+    pub fn load(&self, committer_key: Arc<PC::CommitterKey>) -> Result<CircuitProvingKey<F, CF, PC, MM>, SNARKError> {
+        check_committer_key(committer_key.as_ref(), &self.circuit_verifying_key)?;
+        Ok(CircuitProvingKey {
+            circuit_verifying_key: self.circuit_verifying_key.clone(),
+            circuit_commitment_randomness: self.circuit_commitment_randomness()?,
+            circuit: self.circuit()?,
+            committer_key,
+        })
+    }
+
+    /// Returns the byte range of the section with the given start and length,
+    /// ensuring it lies within the given number of bytes.
+    fn section_range(start: usize, length: usize, size: usize) -> Result<Range<usize>, SNARKError> {
+        match start.checked_add(length) {
+            Some(end) if end <= size => Ok(start..end),
+            _ => Err(error("the compact proving key is truncated").into()),
+        }
+    }
+}
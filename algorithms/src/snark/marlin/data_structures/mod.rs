@@ -22,6 +22,10 @@ pub use circuit_proving_key::*;
 pub(super) mod circuit_verifying_key;
 pub use circuit_verifying_key::*;
 
+/// The Marlin circuit proving key, loaded on demand.
+pub(super) mod lazy_circuit_proving_key;
+pub use lazy_circuit_proving_key::*;
+
 /// The Marlin prepared circuit verifying key.
 pub(super) mod prepared_circuit_verifying_key;
 pub use prepared_circuit_verifying_key::*;
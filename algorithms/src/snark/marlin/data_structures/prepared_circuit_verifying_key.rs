@@ -15,15 +15,16 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    polycommit::sonic_pc,
+    polycommit::PolynomialCommitment,
     snark::marlin::{CircuitVerifyingKey, MarlinMode},
 };
-use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::vec::Vec;
 
 /// Verification key, prepared (preprocessed) for use in pairings.
 
 #[derive(Clone)]
-pub struct PreparedCircuitVerifyingKey<E: PairingEngine, MM: MarlinMode> {
+pub struct PreparedCircuitVerifyingKey<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>, MM: MarlinMode> {
     /// Size of the variable domain.
     pub constraint_domain_size: u64,
     /// Size of the domain that represents A.
@@ -33,11 +34,11 @@ pub struct PreparedCircuitVerifyingKey<E: PairingEngine, MM: MarlinMode> {
     /// Size of the domain that represents C.
     pub non_zero_c_domain_size: u64,
     /// Commitments to the index polynomials, prepared.
-    pub prepared_index_comms: Vec<sonic_pc::PreparedCommitment<E>>,
+    pub prepared_index_comms: Vec<PC::PreparedCommitment>,
     /// Prepared version of the poly-commit scheme's verification key.
-    pub prepared_verifier_key: sonic_pc::PreparedVerifierKey<E>,
+    pub prepared_verifier_key: PC::PreparedVerifierKey,
     /// Non-prepared verification key, for use in native "prepared verify" (which
     /// is actually standard verify), as well as in absorbing the original vk into
     /// the Fiat-Shamir sponge.
-    pub orig_vk: CircuitVerifyingKey<E, MM>,
+    pub orig_vk: CircuitVerifyingKey<F, CF, PC, MM>,
 }
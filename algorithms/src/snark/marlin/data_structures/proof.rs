@@ -14,38 +14,43 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{polycommit::sonic_pc, snark::marlin::ahp};
+use crate::{polycommit::PolynomialCommitment, snark::marlin::ahp};
 
-use snarkvm_curves::PairingEngine;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
+    collections::BTreeMap,
     error,
-    io::{self, Read, Write},
+    io,
+    io::{Read, Write},
     serialize::*,
+    string::String,
+    vec,
     FromBytes,
     ToBytes,
 };
 
+use core::marker::PhantomData;
+
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
-pub struct Commitments<E: PairingEngine> {
-    pub witness_commitments: Vec<WitnessCommitments<E>>,
+pub struct Commitments<C: CanonicalSerialize + CanonicalDeserialize> {
+    pub witness_commitments: Vec<WitnessCommitments<C>>,
     /// Commitment to the masking polynomial.
-    pub mask_poly: Option<sonic_pc::Commitment<E>>,
+    pub mask_poly: Option<C>,
     /// Commitment to the `g_1` polynomial.
-    pub g_1: sonic_pc::Commitment<E>,
+    pub g_1: C,
     /// Commitment to the `h_1` polynomial.
-    pub h_1: sonic_pc::Commitment<E>,
+    pub h_1: C,
     /// Commitment to the `g_a` polynomial.
-    pub g_a: sonic_pc::Commitment<E>,
+    pub g_a: C,
     /// Commitment to the `g_b` polynomial.
-    pub g_b: sonic_pc::Commitment<E>,
+    pub g_b: C,
     /// Commitment to the `g_c` polynomial.
-    pub g_c: sonic_pc::Commitment<E>,
+    pub g_c: C,
     /// Commitment to the `h_2` polynomial.
-    pub h_2: sonic_pc::Commitment<E>,
+    pub h_2: C,
 }
 
-impl<E: PairingEngine> Commitments<E> {
+impl<C: CanonicalSerialize + CanonicalDeserialize> Commitments<C> {
     fn serialize<W: snarkvm_utilities::Write>(
         &self,
         writer: &mut W,
@@ -152,13 +157,13 @@ impl<E: PairingEngine> Commitments<E> {
 }
 /// Commitments to the `w`, `z_a`, and `z_b` polynomials.
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
-pub struct WitnessCommitments<E: PairingEngine> {
+pub struct WitnessCommitments<C: CanonicalSerialize + CanonicalDeserialize> {
     /// Commitment to the `w` polynomial.
-    pub w: sonic_pc::Commitment<E>,
+    pub w: C,
     /// Commitment to the `z_a` polynomial.
-    pub z_a: sonic_pc::Commitment<E>,
+    pub z_a: C,
     /// Commitment to the `z_b` polynomial.
-    pub z_b: sonic_pc::Commitment<E>,
+    pub z_b: C,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -264,7 +269,7 @@ impl<F: PrimeField> Evaluations<F> {
 }
 
 impl<F: PrimeField> Evaluations<F> {
-    pub(crate) fn from_map(map: &std::collections::BTreeMap<String, F>, batch_size: usize) -> Self {
+    pub(crate) fn from_map(map: &BTreeMap<String, F>, batch_size: usize) -> Self {
         let z_b_evals = map.iter().filter_map(|(k, v)| k.starts_with("z_b_").then(|| *v)).collect::<Vec<_>>();
         assert_eq!(z_b_evals.len(), batch_size);
         Self { z_b_evals, g_1_eval: map["g_1"], g_a_eval: map["g_a"], g_b_eval: map["g_b"], g_c_eval: map["g_c"] }
@@ -295,37 +300,40 @@ impl<F: PrimeField> Evaluations<F> {
 
 /// A zkSNARK proof.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Proof<E: PairingEngine> {
+pub struct Proof<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> {
     /// The number of instances being proven in this proof.
     batch_size: usize,
 
     /// Commitments to prover polynomials.
-    pub commitments: Commitments<E>,
+    pub commitments: Commitments<PC::Commitment>,
 
     /// Evaluations of some of the committed polynomials.
-    pub evaluations: Evaluations<E::Fr>,
+    pub evaluations: Evaluations<F>,
 
     /// Prover message: sum_a, sum_b, sum_c
-    pub msg: ahp::prover::ThirdMessage<E::Fr>,
+    pub msg: ahp::prover::ThirdMessage<F>,
 
     /// An evaluation proof from the polynomial commitment.
-    pub pc_proof: sonic_pc::BatchLCProof<E>,
+    pub pc_proof: PC::BatchLCProof,
+
+    #[doc(hidden)]
+    _base_field: PhantomData<CF>,
 }
 
-impl<E: PairingEngine> Proof<E> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> Proof<F, CF, PC> {
     /// Construct a new proof.
     pub fn new(
         batch_size: usize,
-        commitments: Commitments<E>,
-        evaluations: Evaluations<E::Fr>,
-        msg: ahp::prover::ThirdMessage<E::Fr>,
-        pc_proof: sonic_pc::BatchLCProof<E>,
+        commitments: Commitments<PC::Commitment>,
+        evaluations: Evaluations<F>,
+        msg: ahp::prover::ThirdMessage<F>,
+        pc_proof: PC::BatchLCProof,
     ) -> Self {
-        Self { batch_size, commitments, evaluations, msg, pc_proof }
+        Self { batch_size, commitments, evaluations, msg, pc_proof, _base_field: PhantomData }
     }
 }
 
-impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> CanonicalSerialize for Proof<F, CF, PC> {
     fn serialize<W: snarkvm_utilities::Write>(
         &self,
         writer: &mut W,
@@ -371,7 +379,7 @@ impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
     }
 }
 
-impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> CanonicalDeserialize for Proof<F, CF, PC> {
     fn deserialize<R: snarkvm_utilities::Read>(reader: &mut R) -> Result<Self, snarkvm_utilities::SerializationError> {
         {
             let batch_size = CanonicalDeserialize::deserialize(reader)?;
@@ -381,6 +389,7 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
                 evaluations: Evaluations::deserialize(batch_size, reader)?,
                 msg: CanonicalDeserialize::deserialize(reader)?,
                 pc_proof: CanonicalDeserialize::deserialize(reader)?,
+                _base_field: PhantomData,
             })
         }
     }
@@ -396,19 +405,189 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
                 evaluations: Evaluations::deserialize_uncompressed(batch_size, reader)?,
                 msg: CanonicalDeserialize::deserialize_uncompressed(reader)?,
                 pc_proof: CanonicalDeserialize::deserialize_uncompressed(reader)?,
+                _base_field: PhantomData,
             })
         }
     }
 }
 
-impl<E: PairingEngine> ToBytes for Proof<E> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> ToBytes for Proof<F, CF, PC> {
     fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
         CanonicalSerialize::serialize(self, &mut w).map_err(|_| error("could not serialize Proof"))
     }
 }
 
-impl<E: PairingEngine> FromBytes for Proof<E> {
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> FromBytes for Proof<F, CF, PC> {
     fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
         CanonicalDeserialize::deserialize(&mut r).map_err(|_| error("could not deserialize Proof"))
     }
 }
+
+/// A zkSNARK proof for several circuits, each with its own batch of instances.
+///
+/// The circuits share a single Fiat-Shamir transcript, and the polynomials of every circuit are opened
+/// together, so the proof carries the commitments, evaluations and prover message of each circuit,
+/// but only one evaluation proof from the polynomial commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofBundle<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> {
+    /// The number of instances of each circuit, in the order in which the circuits were proven.
+    batch_sizes: Vec<usize>,
+
+    /// Commitments to the prover polynomials of each circuit.
+    pub commitments: Vec<Commitments<PC::Commitment>>,
+
+    /// Evaluations of some of the committed polynomials of each circuit.
+    pub evaluations: Vec<Evaluations<F>>,
+
+    /// Prover message of each circuit: sum_a, sum_b, sum_c
+    pub msgs: Vec<ahp::prover::ThirdMessage<F>>,
+
+    /// An evaluation proof from the polynomial commitment, for the polynomials of every circuit.
+    pub pc_proof: PC::BatchLCProof,
+
+    #[doc(hidden)]
+    _base_field: PhantomData<CF>,
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> ProofBundle<F, CF, PC> {
+    /// Construct a new proof bundle.
+    pub fn new(
+        batch_sizes: Vec<usize>,
+        commitments: Vec<Commitments<PC::Commitment>>,
+        evaluations: Vec<Evaluations<F>>,
+        msgs: Vec<ahp::prover::ThirdMessage<F>>,
+        pc_proof: PC::BatchLCProof,
+    ) -> Self {
+        assert_eq!(batch_sizes.len(), commitments.len());
+        assert_eq!(batch_sizes.len(), evaluations.len());
+        assert_eq!(batch_sizes.len(), msgs.len());
+        Self { batch_sizes, commitments, evaluations, msgs, pc_proof, _base_field: PhantomData }
+    }
+
+    /// Returns the number of instances of each circuit.
+    pub fn batch_sizes(&self) -> &[usize] {
+        &self.batch_sizes
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> From<Proof<F, CF, PC>> for ProofBundle<F, CF, PC> {
+    fn from(proof: Proof<F, CF, PC>) -> Self {
+        Self::new(
+            vec![proof.batch_size],
+            vec![proof.commitments],
+            vec![proof.evaluations],
+            vec![proof.msg],
+            proof.pc_proof,
+        )
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> CanonicalSerialize for ProofBundle<F, CF, PC> {
+    fn serialize<W: snarkvm_utilities::Write>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), snarkvm_utilities::SerializationError> {
+        CanonicalSerialize::serialize(&self.batch_sizes, writer)?;
+        for ((commitments, evaluations), msg) in self.commitments.iter().zip(&self.evaluations).zip(&self.msgs) {
+            Commitments::serialize(commitments, writer)?;
+            Evaluations::serialize(evaluations, writer)?;
+            CanonicalSerialize::serialize(msg, writer)?;
+        }
+        CanonicalSerialize::serialize(&self.pc_proof, writer)?;
+        Ok(())
+    }
+
+    fn serialized_size(&self) -> usize {
+        let mut size = 0;
+        size += CanonicalSerialize::serialized_size(&self.batch_sizes);
+        for ((commitments, evaluations), msg) in self.commitments.iter().zip(&self.evaluations).zip(&self.msgs) {
+            size += Commitments::serialized_size(commitments);
+            size += Evaluations::serialized_size(evaluations);
+            size += CanonicalSerialize::serialized_size(msg);
+        }
+        size += CanonicalSerialize::serialized_size(&self.pc_proof);
+        size
+    }
+
+    fn serialize_uncompressed<W: snarkvm_utilities::Write>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), snarkvm_utilities::SerializationError> {
+        CanonicalSerialize::serialize_uncompressed(&self.batch_sizes, writer)?;
+        for ((commitments, evaluations), msg) in self.commitments.iter().zip(&self.evaluations).zip(&self.msgs) {
+            Commitments::serialize_uncompressed(commitments, writer)?;
+            Evaluations::serialize_uncompressed(evaluations, writer)?;
+            CanonicalSerialize::serialize_uncompressed(msg, writer)?;
+        }
+        CanonicalSerialize::serialize_uncompressed(&self.pc_proof, writer)?;
+        Ok(())
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        let mut size = 0;
+        size += CanonicalSerialize::uncompressed_size(&self.batch_sizes);
+        for ((commitments, evaluations), msg) in self.commitments.iter().zip(&self.evaluations).zip(&self.msgs) {
+            size += Commitments::uncompressed_size(commitments);
+            size += Evaluations::uncompressed_size(evaluations);
+            size += CanonicalSerialize::uncompressed_size(msg);
+        }
+        size += CanonicalSerialize::uncompressed_size(&self.pc_proof);
+        size
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> CanonicalDeserialize for ProofBundle<F, CF, PC> {
+    fn deserialize<R: snarkvm_utilities::Read>(reader: &mut R) -> Result<Self, snarkvm_utilities::SerializationError> {
+        let batch_sizes: Vec<usize> = CanonicalDeserialize::deserialize(reader)?;
+        let mut commitments = Vec::with_capacity(batch_sizes.len());
+        let mut evaluations = Vec::with_capacity(batch_sizes.len());
+        let mut msgs = Vec::with_capacity(batch_sizes.len());
+        for &batch_size in &batch_sizes {
+            commitments.push(Commitments::deserialize(batch_size, reader)?);
+            evaluations.push(Evaluations::deserialize(batch_size, reader)?);
+            msgs.push(CanonicalDeserialize::deserialize(reader)?);
+        }
+        Ok(ProofBundle {
+            batch_sizes,
+            commitments,
+            evaluations,
+            msgs,
+            pc_proof: CanonicalDeserialize::deserialize(reader)?,
+            _base_field: PhantomData,
+        })
+    }
+
+    fn deserialize_uncompressed<R: snarkvm_utilities::Read>(
+        reader: &mut R,
+    ) -> Result<Self, snarkvm_utilities::SerializationError> {
+        let batch_sizes: Vec<usize> = CanonicalDeserialize::deserialize_uncompressed(reader)?;
+        let mut commitments = Vec::with_capacity(batch_sizes.len());
+        let mut evaluations = Vec::with_capacity(batch_sizes.len());
+        let mut msgs = Vec::with_capacity(batch_sizes.len());
+        for &batch_size in &batch_sizes {
+            commitments.push(Commitments::deserialize_uncompressed(batch_size, reader)?);
+            evaluations.push(Evaluations::deserialize_uncompressed(batch_size, reader)?);
+            msgs.push(CanonicalDeserialize::deserialize_uncompressed(reader)?);
+        }
+        Ok(ProofBundle {
+            batch_sizes,
+            commitments,
+            evaluations,
+            msgs,
+            pc_proof: CanonicalDeserialize::deserialize_uncompressed(reader)?,
+            _base_field: PhantomData,
+        })
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> ToBytes for ProofBundle<F, CF, PC> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut w).map_err(|_| error("could not serialize ProofBundle"))
+    }
+}
+
+impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> FromBytes for ProofBundle<F, CF, PC> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut r).map_err(|_| error("could not deserialize ProofBundle"))
+    }
+}
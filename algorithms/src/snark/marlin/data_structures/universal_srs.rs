@@ -15,4 +15,4 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 /// The universal public parameters for the argument system.
-pub type UniversalSRS<E> = crate::polycommit::sonic_pc::UniversalParams<E>;
+pub type UniversalSRS<F, CF, PC> = <PC as crate::polycommit::PolynomialCommitment<F, CF>>::UniversalParams;
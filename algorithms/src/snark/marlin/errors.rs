@@ -18,6 +18,7 @@ use crate::{
     snark::marlin::{ahp::AHPError, fiat_shamir::FiatShamirError},
     SNARKError,
 };
+use snarkvm_utilities::format;
 
 use core::fmt::Debug;
 
@@ -34,6 +35,8 @@ pub enum MarlinError {
     R1CSError(snarkvm_r1cs::SynthesisError),
     /// There was an error in the underlying polynomial commitment.
     PolynomialCommitmentError(crate::polycommit::PCError),
+    /// The circuits proven together were not set up with the same committer key.
+    CommitterKeyMismatch,
     Terminated,
 }
 
@@ -17,6 +17,7 @@
 use core::fmt;
 
 use snarkvm_fields::ConstraintFieldError;
+use snarkvm_utilities::string::ToString;
 
 /// A `enum` specifying the possible failure modes of `FiatShamir`.
 #[derive(Debug)]
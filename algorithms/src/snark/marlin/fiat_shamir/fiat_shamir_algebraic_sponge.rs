@@ -23,7 +23,7 @@ use crate::{
     traits::DefaultCapacityAlgebraicSponge,
 };
 use snarkvm_fields::{FieldParameters, PrimeField, ToConstraintField};
-use snarkvm_utilities::{BigInteger, FromBits, ToBits};
+use snarkvm_utilities::{vec, vec::Vec, BigInteger, FromBits, ToBits};
 
 use core::marker::PhantomData;
 use smallvec::SmallVec;
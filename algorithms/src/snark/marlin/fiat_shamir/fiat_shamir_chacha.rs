@@ -17,6 +17,7 @@
 use crate::snark::marlin::{fiat_shamir::FiatShamirRng, params::OptimizationType, FiatShamirError};
 use rand::RngCore;
 use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_utilities::vec::Vec;
 
 use core::{fmt::Debug, marker::PhantomData};
 use digest::Digest;
@@ -16,6 +16,7 @@
 
 use crate::snark::marlin::{fiat_shamir::FiatShamirError, params::OptimizationType};
 use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_utilities::vec::Vec;
 
 use core::fmt::Debug;
 use smallvec::SmallVec;
@@ -16,9 +16,14 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, Randomness, SonicKZG10},
+    polycommit::{
+        sonic_pc::{Evaluations, LCTerm, LabeledCommitment, LabeledPolynomial, LinearCombination, QuerySet},
+        PCProof,
+        PCRandomness,
+        PolynomialCommitment,
+    },
     snark::marlin::{
-        ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
+        ahp::{indexer::Circuit, AHPError, AHPForR1CS, EvaluationsProvider},
         fiat_shamir::traits::FiatShamirRng,
         params::OptimizationType,
         proof,
@@ -29,6 +34,7 @@ use crate::{
         MarlinError,
         MarlinMode,
         Proof,
+        ProofBundle,
         UniversalSRS,
     },
     Prepare,
@@ -38,18 +44,33 @@ use crate::{
 };
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
-use rand_core::RngCore;
-use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
+use rand_chacha::ChaChaRng;
+use rand_core::{RngCore, SeedableRng};
+use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_r1cs::ConstraintSynthesizer;
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{
+    borrow::Cow,
+    cfg_into_iter,
+    cfg_iter,
+    cfg_iter_mut,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    to_bytes_le,
+    vec,
+    vec::Vec,
+    ToBytes,
+};
 
-use std::{borrow::Borrow, sync::Arc};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[cfg(not(feature = "std"))]
-use snarkvm_utilities::println;
+use snarkvm_utilities::{eprintln, println};
 
 use core::{
+    borrow::Borrow,
     marker::PhantomData,
     sync::atomic::{AtomicBool, Ordering},
 };
@@ -57,14 +78,22 @@ use core::{
 /// The Marlin proof system.
 #[derive(Clone, Debug)]
 pub struct MarlinSNARK<
-    E: PairingEngine,
-    FS: FiatShamirRng<E::Fr, E::Fq>,
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    FS: FiatShamirRng<F, CF>,
     MM: MarlinMode,
-    Input: ToConstraintField<E::Fr> + ?Sized,
->(#[doc(hidden)] PhantomData<(E, FS, MM, Input)>);
-
-impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: ToConstraintField<E::Fr> + ?Sized>
-    MarlinSNARK<E, FS, MM, Input>
+    Input: ToConstraintField<F> + ?Sized,
+>(#[doc(hidden)] PhantomData<(F, CF, PC, FS, MM, Input)>);
+
+impl<
+        F: PrimeField,
+        CF: PrimeField,
+        PC: PolynomialCommitment<F, CF>,
+        FS: FiatShamirRng<F, CF>,
+        MM: MarlinMode,
+        Input: ToConstraintField<F> + ?Sized,
+    > MarlinSNARK<F, CF, PC, FS, MM, Input>
 {
     /// The personalization string for this protocol.
     /// Used to personalize the Fiat-Shamir RNG.
@@ -79,10 +108,10 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
     /// In production, one should instead perform a universal setup via [`Self::universal_setup`],
     /// and then deterministically specialize the resulting universal SRS via [`Self::circuit_setup`].
     #[allow(clippy::type_complexity)]
-    pub fn circuit_specific_setup<C: ConstraintSynthesizer<E::Fr>, R: RngCore + CryptoRng>(
+    pub fn circuit_specific_setup<C: ConstraintSynthesizer<F>, R: RngCore + CryptoRng>(
         c: &C,
         rng: &mut R,
-    ) -> Result<(CircuitProvingKey<E, MM>, CircuitVerifyingKey<E, MM>), SNARKError> {
+    ) -> Result<(CircuitProvingKey<F, CF, PC, MM>, CircuitVerifyingKey<F, CF, PC, MM>), SNARKError> {
         let circuit = AHPForR1CS::<_, MM>::index(c)?;
         let srs = Self::universal_setup(&circuit.max_degree(), rng)?;
         Self::circuit_setup(&srs, c)
@@ -91,36 +120,60 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
     /// Generates the circuit proving and verifying keys.
     /// This is a deterministic algorithm that anyone can rerun.
     #[allow(clippy::type_complexity)]
-    pub fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
-        universal_srs: &UniversalSRS<E>,
+    pub fn circuit_setup<C: ConstraintSynthesizer<F>>(
+        universal_srs: &UniversalSRS<F, CF, PC>,
         circuit: &C,
-    ) -> Result<(CircuitProvingKey<E, MM>, CircuitVerifyingKey<E, MM>), SNARKError> {
+    ) -> Result<(CircuitProvingKey<F, CF, PC, MM>, CircuitVerifyingKey<F, CF, PC, MM>), SNARKError> {
         let index_time = start_timer!(|| "Marlin::CircuitSetup");
 
         // TODO: Add check that c is in the correct mode.
-        // Increase the universal SRS size to support the circuit size.
         let index = AHPForR1CS::<_, MM>::index(circuit)?;
-        if universal_srs.max_degree() < index.max_degree() {
-            universal_srs
-                .increase_degree(index.max_degree())
-                .map_err(|_| MarlinError::IndexTooLarge(universal_srs.max_degree(), index.max_degree()))?;
-        }
 
-        let coefficient_support = AHPForR1CS::<_, MM>::get_degree_bounds(&index.index_info);
+        let (mut committer_key, verifier_key) = index.trim_universal_srs::<CF, PC>(universal_srs)?;
+        PC::precompute_committer_key(&mut committer_key);
+        let keys = Self::index_with_keys(index, Arc::new(committer_key), verifier_key)?;
 
-        // Marlin only needs degree 2 random polynomials.
-        let supported_hiding_bound = 1;
-        let (committer_key, verifier_key) = SonicKZG10::<E, FS>::trim(
-            universal_srs,
-            index.max_degree(),
-            [index.constraint_domain_size()],
-            supported_hiding_bound,
-            Some(&coefficient_support),
-        )?;
+        end_timer!(index_time);
+
+        Ok(keys)
+    }
+
+    /// Generates the circuit proving and verifying keys for several circuits, which share a single
+    /// committer key, so that the circuits can be proven together with [`Self::prove_heterogeneous`].
+    /// This is a deterministic algorithm that anyone can rerun.
+    #[allow(clippy::type_complexity)]
+    pub fn batch_circuit_setup<C: ConstraintSynthesizer<F>>(
+        universal_srs: &UniversalSRS<F, CF, PC>,
+        circuits: &[&C],
+    ) -> Result<Vec<(CircuitProvingKey<F, CF, PC, MM>, CircuitVerifyingKey<F, CF, PC, MM>)>, SNARKError> {
+        let index_time = start_timer!(|| "Marlin::BatchCircuitSetup");
+
+        let indices =
+            circuits.iter().map(|circuit| AHPForR1CS::<_, MM>::index(*circuit)).collect::<Result<Vec<_>, _>>()?;
+
+        let (mut committer_key, verifier_key) = Circuit::trim_universal_srs_for_all::<CF, PC>(&indices, universal_srs)?;
+        PC::precompute_committer_key(&mut committer_key);
+        let committer_key = Arc::new(committer_key);
+        let keys = indices
+            .into_iter()
+            .map(|index| Self::index_with_keys(index, committer_key.clone(), verifier_key.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        end_timer!(index_time);
+
+        Ok(keys)
+    }
 
+    /// Commits to the polynomials of the given index, and outputs its proving and verifying keys.
+    #[allow(clippy::type_complexity)]
+    fn index_with_keys(
+        index: Circuit<F, MM>,
+        committer_key: Arc<PC::CommitterKey>,
+        verifier_key: PC::VerifierKey,
+    ) -> Result<(CircuitProvingKey<F, CF, PC, MM>, CircuitVerifyingKey<F, CF, PC, MM>), SNARKError> {
         let commit_time = start_timer!(|| "Commit to index polynomials");
         let (mut circuit_commitments, circuit_commitment_randomness): (_, _) =
-            SonicKZG10::<E, FS>::commit(&committer_key, index.iter().map(Into::into), None)?;
+            PC::commit(&committer_key, index.iter().map(Into::into), None)?;
         end_timer!(commit_time);
 
         circuit_commitments.sort_by(|c1, c2| c1.label().cmp(c2.label()));
@@ -139,169 +192,145 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
             committer_key,
         };
 
-        end_timer!(index_time);
-
         Ok((circuit_proving_key, circuit_verifying_key))
     }
 
-    fn terminate(terminator: &AtomicBool) -> Result<(), MarlinError> {
-        if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
-    }
-
-    fn init_sponge(
-        batch_size: usize,
-        circuit_commitments: &[crate::polycommit::sonic_pc::Commitment<E>],
-        inputs: &[Vec<E::Fr>],
-    ) -> FS {
-        let mut sponge = FS::new();
-        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
-        sponge.absorb_bytes(&batch_size.to_le_bytes());
-        sponge.absorb_native_field_elements(circuit_commitments);
-        for input in inputs {
-            sponge.absorb_nonnative_field_elements(input.iter().copied(), OptimizationType::Weight);
-        }
-        sponge
-    }
-
-    fn absorb_labeled_with_msg(
-        comms: &[LabeledCommitment<Commitment<E>>],
-        message: &prover::ThirdMessage<E::Fr>,
-        sponge: &mut FS,
-    ) {
-        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
-        Self::absorb_with_msg(&commitments, message, sponge)
-    }
-
-    fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
-        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
-        Self::absorb(&commitments, sponge)
-    }
-
-    fn absorb(commitments: &[Commitment<E>], sponge: &mut FS) {
-        sponge.absorb_native_field_elements(commitments);
-    }
-
-    fn absorb_with_msg(commitments: &[Commitment<E>], msg: &prover::ThirdMessage<E::Fr>, sponge: &mut FS) {
-        Self::absorb(commitments, sponge);
-        sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c], OptimizationType::Weight);
-    }
-}
-
-impl<E: PairingEngine, FS, MM, Input> SNARK for MarlinSNARK<E, FS, MM, Input>
-where
-    E::Fr: PrimeField,
-    E::Fq: PrimeField,
-    FS: FiatShamirRng<E::Fr, E::Fq>,
-    MM: MarlinMode,
-    Input: ToConstraintField<E::Fr> + ?Sized,
-{
-    type BaseField = E::Fq;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, MM>;
-    type ScalarField = E::Fr;
-    type UniversalSetupConfig = usize;
-    type UniversalSetupParameters = UniversalSRS<E>;
-    type VerifierInput = Input;
-    type VerifyingKey = CircuitVerifyingKey<E, MM>;
-
-    fn universal_setup<R: Rng + CryptoRng>(
-        max_degree: &Self::UniversalSetupConfig,
-        rng: &mut R,
-    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
-        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {}", max_degree,) });
-
-        let srs = SonicKZG10::<E, FS>::setup(*max_degree, rng).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
-    }
-
-    fn setup<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        circuit: &C,
-        srs: &mut SRS<R, Self::UniversalSetupParameters>,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
-        match srs {
-            SRS::CircuitSpecific(rng) => Self::circuit_specific_setup(circuit, rng),
-            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
-        }
-        .map_err(SNARKError::from)
+    /// Proves several circuits at once, each with its own batch of instances.
+    ///
+    /// The provers for each circuit run in parallel, and share a single Fiat-Shamir transcript.
+    /// The polynomials of every circuit are then opened together, so the resulting proof bundle holds
+    /// a single evaluation proof, and must be verified with [`Self::verify_heterogeneous`], using the
+    /// circuit verifying keys in the same order. For this, the proving keys must share a committer key,
+    /// as output by [`Self::batch_circuit_setup`]. Circuits of different types can be proven together
+    /// by wrapping them in a type that implements `ConstraintSynthesizer`.
+    #[allow(clippy::type_complexity)]
+    pub fn prove_heterogeneous<C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
+        groups: &[(&CircuitProvingKey<F, CF, PC, MM>, &[C])],
+        zk_rng: &mut R,
+    ) -> Result<ProofBundle<F, CF, PC>, SNARKError> {
+        Self::prove_heterogeneous_with_terminator(groups, &AtomicBool::new(false), zk_rng)
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        circuit_proving_key: &CircuitProvingKey<E, MM>,
-        circuits: &[C],
+    /// Proves several circuits at once, each with its own batch of instances,
+    /// stopping early if `terminator` is set. See [`Self::prove_heterogeneous`].
+    #[allow(clippy::type_complexity)]
+    pub fn prove_heterogeneous_with_terminator<C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
+        groups: &[(&CircuitProvingKey<F, CF, PC, MM>, &[C])],
         terminator: &AtomicBool,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof, SNARKError> {
+    ) -> Result<ProofBundle<F, CF, PC>, SNARKError> {
         let prover_time = start_timer!(|| "Marlin::Prover");
-        let batch_size = circuits.len();
-        if batch_size == 0 {
+        if groups.is_empty() || groups.iter().any(|(_, circuits)| circuits.is_empty()) {
             return Err(SNARKError::EmptyBatch);
         }
 
         Self::terminate(terminator)?;
 
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
-        let public_input = prover_state.public_inputs();
-        let padded_public_input = prover_state.padded_public_inputs();
-        assert_eq!(prover_state.batch_size, batch_size);
+        let proving_keys: Vec<_> = groups.iter().map(|(circuit_proving_key, _)| *circuit_proving_key).collect();
+        let verifier_key = &proving_keys[0].circuit_verifying_key.verifier_key;
+        if proving_keys.iter().any(|pk| &pk.circuit_verifying_key.verifier_key != verifier_key) {
+            return Err(MarlinError::CommitterKeyMismatch.into());
+        }
+        let prover_states = groups
+            .iter()
+            .map(|(circuit_proving_key, circuits)| {
+                AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let public_inputs: Vec<_> = prover_states.iter().map(|state| state.public_inputs()).collect();
+        let padded_public_inputs: Vec<_> = prover_states.iter().map(|state| state.padded_public_inputs()).collect();
+        for ((_, circuits), state) in groups.iter().zip_eq(&prover_states) {
+            assert_eq!(state.batch_size, circuits.len());
+        }
 
         let mut sponge = Self::init_sponge(
-            batch_size,
-            &circuit_proving_key.circuit_verifying_key.circuit_commitments,
-            &padded_public_input,
+            proving_keys
+                .iter()
+                .map(|pk| &pk.circuit_verifying_key.circuit_commitments[..])
+                .zip_eq(padded_public_inputs.iter().map(|inputs| &inputs[..])),
         );
 
+        // Each circuit is proven with its own randomness, so that the circuits can be proven in parallel.
+        let mut zk_rngs: Vec<_> = groups.iter().map(|_| ChaChaRng::from_seed(zk_rng.gen())).collect();
+
         // --------------------------------------------------------------------
         // First round
 
         Self::terminate(terminator)?;
-        let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
+        let mut prover_states = cfg_into_iter!(prover_states)
+            .zip(cfg_iter_mut!(zk_rngs))
+            .map(|(state, rng)| AHPForR1CS::<_, MM>::prover_first_round(state, rng))
+            .collect::<Result<Vec<_>, _>>()?;
         Self::terminate(terminator)?;
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
-        let (first_commitments, first_commitment_randomnesses) = {
-            let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
-            SonicKZG10::<E, FS>::commit(
-                &circuit_proving_key.committer_key,
-                first_round_oracles.iter_for_commit(),
-                Some(zk_rng),
-            )?
-        };
+        let first_round = cfg_iter_mut!(prover_states)
+            .zip(cfg_iter_mut!(zk_rngs))
+            .zip(cfg_iter!(proving_keys))
+            .map(|((state, rng), circuit_proving_key)| {
+                let first_round_oracles = Arc::get_mut(state.first_round_oracles.as_mut().unwrap()).unwrap();
+                PC::commit(&circuit_proving_key.committer_key, first_round_oracles.iter_for_commit(), Some(rng))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         end_timer!(first_round_comm_time);
 
-        Self::absorb_labeled(&first_commitments, &mut sponge);
+        for (first_commitments, _) in &first_round {
+            Self::absorb_labeled(first_commitments, &mut sponge);
+        }
         Self::terminate(terminator)?;
 
-        let (verifier_first_message, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
-            circuit_proving_key.circuit_verifying_key.circuit_info,
-            batch_size,
-            &mut sponge,
-        )?;
+        let (verifier_first_messages, verifier_states): (Vec<_>, Vec<_>) = proving_keys
+            .iter()
+            .zip_eq(&padded_public_inputs)
+            .map(|(circuit_proving_key, inputs)| {
+                AHPForR1CS::<_, MM>::verifier_first_round(
+                    circuit_proving_key.circuit_verifying_key.circuit_info,
+                    inputs.len(),
+                    &mut sponge,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
 
         Self::terminate(terminator)?;
-        let (second_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state, zk_rng);
+        let (second_oracles, prover_states): (Vec<_>, Vec<_>) = cfg_into_iter!(prover_states)
+            .zip(cfg_iter!(verifier_first_messages))
+            .zip(cfg_iter_mut!(zk_rngs))
+            .map(|((state, message), rng)| AHPForR1CS::<_, MM>::prover_second_round(message, state, rng))
+            .unzip();
         Self::terminate(terminator)?;
 
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            second_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        let second_round = cfg_iter!(second_oracles)
+            .zip(cfg_iter_mut!(zk_rngs))
+            .zip(cfg_iter!(proving_keys))
+            .map(|((oracles, rng), circuit_proving_key)| {
+                PC::commit_with_terminator(
+                    &circuit_proving_key.committer_key,
+                    oracles.iter().map(Into::into),
+                    terminator,
+                    Some(rng),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         end_timer!(second_round_comm_time);
 
-        Self::absorb_labeled(&second_commitments, &mut sponge);
+        for (second_commitments, _) in &second_round {
+            Self::absorb_labeled(second_commitments, &mut sponge);
+        }
         Self::terminate(terminator)?;
 
-        let (verifier_second_msg, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        let (verifier_second_messages, verifier_states): (Vec<_>, Vec<_>) = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_second_round(state, &mut sponge))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -309,23 +338,42 @@ where
 
         Self::terminate(terminator)?;
 
-        let (prover_third_message, third_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
+        let ((prover_third_messages, third_oracles), prover_states): ((Vec<_>, Vec<_>), Vec<_>) =
+            cfg_into_iter!(prover_states)
+                .zip(cfg_iter!(verifier_second_messages))
+                .zip(cfg_iter_mut!(zk_rngs))
+                .map(|((state, message), rng)| AHPForR1CS::<_, MM>::prover_third_round(message, state, rng))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .map(|(message, oracles, state)| ((message, oracles), state))
+                .unzip();
         Self::terminate(terminator)?;
 
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            third_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        let third_round = cfg_iter!(third_oracles)
+            .zip(cfg_iter_mut!(zk_rngs))
+            .zip(cfg_iter!(proving_keys))
+            .map(|((oracles, rng), circuit_proving_key)| {
+                PC::commit_with_terminator(
+                    &circuit_proving_key.committer_key,
+                    oracles.iter().map(Into::into),
+                    terminator,
+                    Some(rng),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         end_timer!(third_round_comm_time);
 
-        Self::absorb_labeled_with_msg(&third_commitments, &prover_third_message, &mut sponge);
+        for ((third_commitments, _), message) in third_round.iter().zip_eq(&prover_third_messages) {
+            Self::absorb_labeled_with_msg(third_commitments, message, &mut sponge);
+        }
 
-        let (verifier_third_msg, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        let (verifier_third_messages, verifier_states): (Vec<_>, Vec<_>) = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_third_round(state, &mut sponge))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -333,155 +381,232 @@ where
 
         Self::terminate(terminator)?;
 
-        let first_round_oracles = Arc::clone(prover_state.first_round_oracles.as_ref().unwrap());
-        let fourth_oracles = AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_third_msg, prover_state, zk_rng)?;
+        let first_round_oracles: Vec<_> =
+            prover_states.iter().map(|state| Arc::clone(state.first_round_oracles.as_ref().unwrap())).collect();
+        let fourth_oracles = cfg_into_iter!(prover_states)
+            .zip(cfg_iter!(verifier_third_messages))
+            .zip(cfg_iter_mut!(zk_rngs))
+            .map(|((state, message), rng)| AHPForR1CS::<_, MM>::prover_fourth_round(message, state, rng))
+            .collect::<Result<Vec<_>, _>>()?;
         Self::terminate(terminator)?;
 
         let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
-        let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            fourth_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        let fourth_round = cfg_iter!(fourth_oracles)
+            .zip(cfg_iter_mut!(zk_rngs))
+            .zip(cfg_iter!(proving_keys))
+            .map(|((oracles, rng), circuit_proving_key)| {
+                PC::commit_with_terminator(
+                    &circuit_proving_key.committer_key,
+                    oracles.iter().map(Into::into),
+                    terminator,
+                    Some(rng),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         end_timer!(fourth_round_comm_time);
 
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
+        for (fourth_commitments, _) in &fourth_round {
+            Self::absorb_labeled(fourth_commitments, &mut sponge);
+        }
 
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_fourth_round(state, &mut sponge))
+            .collect::<Result<Vec<_>, _>>()?;
         // --------------------------------------------------------------------
 
         Self::terminate(terminator)?;
 
-        // Gather prover polynomials in one vector.
-        let polynomials: Vec<_> = circuit_proving_key
-            .circuit
-            .iter() // 12 items
-            .chain(first_round_oracles.iter_for_open()) // 3 * batch_size + (MM::ZK as usize) items
-            .chain(second_oracles.iter())// 2 items
-            .chain(third_oracles.iter())// 3 items
-            .chain(fourth_oracles.iter())// 1 item
-            .collect();
+        // The polynomials of every circuit are opened together, under labels that are unique to each circuit.
+        let mut batch_sizes = Vec::with_capacity(groups.len());
+        let mut all_commitments = Vec::with_capacity(groups.len());
+        let mut all_evaluations = Vec::with_capacity(groups.len());
+        let mut lc_s = Vec::new();
+        let mut polynomials = Vec::new();
+        let mut labeled_commitments = Vec::new();
+        let mut query_set = QuerySet::new();
+        let mut commitment_randomnesses = Vec::new();
+        for (
+            index,
+            (
+                circuit_proving_key,
+                first_round_oracles,
+                second_oracles,
+                third_oracles,
+                fourth_oracles,
+                (first_commitments, first_commitment_randomnesses),
+                (second_commitments, second_commitment_randomnesses),
+                (third_commitments, third_commitment_randomnesses),
+                (fourth_commitments, fourth_commitment_randomnesses),
+                prover_third_message,
+                verifier_state,
+                public_input,
+            ),
+        ) in itertools::izip!(
+            &proving_keys,
+            &first_round_oracles,
+            &second_oracles,
+            &third_oracles,
+            &fourth_oracles,
+            first_round,
+            second_round,
+            third_round,
+            fourth_round,
+            &prover_third_messages,
+            verifier_states,
+            &public_inputs,
+        )
+        .enumerate()
+        {
+            let batch_size = public_input.len();
+
+            // Gather prover polynomials in one vector.
+            let circuit_polynomials: Vec<_> = circuit_proving_key
+                .circuit
+                .iter() // 12 items
+                .chain(first_round_oracles.iter_for_open()) // 3 * batch_size + (MM::ZK as usize) items
+                .chain(second_oracles.iter())// 2 items
+                .chain(third_oracles.iter())// 3 items
+                .chain(fourth_oracles.iter())// 1 item
+                .collect();
+
+            // Gather commitments in one vector.
+            let witness_commitments = first_commitments.chunks_exact(3);
+            let mask_poly = MM::ZK.then(|| *witness_commitments.remainder()[0].commitment());
+            let witness_commitments = witness_commitments
+                .map(|c| proof::WitnessCommitments {
+                    w: *c[0].commitment(),
+                    z_a: *c[1].commitment(),
+                    z_b: *c[2].commitment(),
+                })
+                .collect();
+            #[rustfmt::skip]
+            let commitments = proof::Commitments {
+                witness_commitments,
+                mask_poly,
 
-        Self::terminate(terminator)?;
+                g_1: *second_commitments[0].commitment(),
+                h_1: *second_commitments[1].commitment(),
 
-        // Gather commitments in one vector.
-        let witness_commitments = first_commitments.chunks_exact(3);
-        let mask_poly = MM::ZK.then(|| *witness_commitments.remainder()[0].commitment());
-        let witness_commitments = witness_commitments
-            .map(|c| proof::WitnessCommitments {
-                w: *c[0].commitment(),
-                z_a: *c[1].commitment(),
-                z_b: *c[2].commitment(),
-            })
-            .collect();
-        #[rustfmt::skip]
-        let commitments = proof::Commitments {
-            witness_commitments,
-            mask_poly,
 
-            g_1: *second_commitments[0].commitment(),
-            h_1: *second_commitments[1].commitment(),
+                g_a: *third_commitments[0].commitment(),
+                g_b: *third_commitments[1].commitment(),
+                g_c: *third_commitments[2].commitment(),
 
+                h_2: *fourth_commitments[0].commitment(),
+            };
 
-            g_a: *third_commitments[0].commitment(),
-            g_b: *third_commitments[1].commitment(),
-            g_c: *third_commitments[2].commitment(),
+            let circuit_labeled_commitments: Vec<_> = circuit_proving_key
+                .circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip_eq(AHPForR1CS::<F, MM>::index_polynomial_info().values())
+                .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+                .chain(first_commitments)
+                .chain(second_commitments)
+                .chain(third_commitments)
+                .chain(fourth_commitments)
+                .collect();
+
+            // Gather commitment randomness together.
+            let circuit_commitment_randomnesses: Vec<PC::Randomness> = circuit_proving_key
+                .circuit_commitment_randomness
+                .clone()
+                .into_iter()
+                .chain(first_commitment_randomnesses)
+                .chain(second_commitment_randomnesses)
+                .chain(third_commitment_randomnesses)
+                .chain(fourth_commitment_randomnesses)
+                .collect();
+
+            if !MM::ZK {
+                let empty_randomness = PC::Randomness::empty();
+                assert!(circuit_commitment_randomnesses.iter().all(|r| r == &empty_randomness));
+            }
 
-            h_2: *fourth_commitments[0].commitment(),
-        };
+            // Compute the AHP verifier's query set.
+            let (circuit_query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+            let circuit_lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+                public_input,
+                &circuit_polynomials,
+                prover_third_message,
+                &verifier_state,
+            )?;
 
-        let labeled_commitments: Vec<_> = circuit_proving_key
-            .circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments.into_iter())
-            .chain(second_commitments.into_iter())
-            .chain(third_commitments.into_iter())
-            .chain(fourth_commitments.into_iter())
-            .collect();
-
-        // Gather commitment randomness together.
-        let commitment_randomnesses: Vec<Randomness<E>> = circuit_proving_key
-            .circuit_commitment_randomness
-            .clone()
-            .into_iter()
-            .chain(first_commitment_randomnesses)
-            .chain(second_commitment_randomnesses)
-            .chain(third_commitment_randomnesses)
-            .chain(fourth_commitment_randomnesses)
-            .collect();
-
-        if !MM::ZK {
-            let empty_randomness = Randomness::<E>::empty();
-            assert!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
-        }
+            Self::terminate(terminator)?;
+
+            let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
+            let circuit_query_set = circuit_query_set.to_set();
+            let mut evaluations = BTreeMap::new();
+            for (label, (_, point)) in &circuit_query_set {
+                if !AHPForR1CS::<F, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+                    let lc = circuit_lc_s.get(label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                    let evaluation = circuit_polynomials.get_lc_eval(lc, *point)?;
+                    evaluations.insert(label.clone(), evaluation);
+                }
+            }
 
-        // Compute the AHP verifier's query set.
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_input,
-            &polynomials,
-            &prover_third_message,
-            &verifier_state,
-        )?;
+            let evaluations = proof::Evaluations::from_map(&evaluations, batch_size);
+            end_timer!(eval_time);
 
-        Self::terminate(terminator)?;
+            lc_s.extend(circuit_lc_s.values().map(|lc| Self::relabel_linear_combination(index, lc)));
+            polynomials.extend(circuit_polynomials.into_iter().map(|p| Self::relabel_polynomial(index, p)));
+            labeled_commitments.extend(circuit_labeled_commitments.iter().map(|c| Self::relabel_commitment(index, c)));
+            query_set.extend(Self::relabel_query_set(index, circuit_query_set));
+            commitment_randomnesses.extend(circuit_commitment_randomnesses);
 
-        let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
-        let mut evaluations = std::collections::BTreeMap::new();
-        for (label, (_, point)) in query_set.to_set() {
-            if !AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
-                let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
-                let evaluation = polynomials.get_lc_eval(lc, point)?;
-                evaluations.insert(label, evaluation);
-            }
+            batch_sizes.push(batch_size);
+            all_commitments.push(commitments);
+            all_evaluations.push(evaluations);
         }
 
-        let evaluations = proof::Evaluations::from_map(&evaluations, batch_size);
-        end_timer!(eval_time);
-
         Self::terminate(terminator)?;
 
-        sponge.absorb_nonnative_field_elements(evaluations.to_field_elements(), OptimizationType::Weight);
+        for evaluations in &all_evaluations {
+            sponge.absorb_nonnative_field_elements(evaluations.to_field_elements(), OptimizationType::Weight);
+        }
 
-        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
-            &circuit_proving_key.committer_key,
-            lc_s.values(),
-            polynomials,
+        let pc_proof = PC::open_combinations(
+            &proving_keys[0].committer_key,
+            &lc_s,
+            polynomials.iter().map(|p| p.as_ref()),
             &labeled_commitments,
-            &query_set.to_set(),
+            &query_set,
             &commitment_randomnesses,
             &mut sponge,
         )?;
+        assert_eq!(pc_proof.is_hiding(), MM::ZK);
 
-        Self::terminate(terminator)?;
-
-        let proof = Proof::<E>::new(batch_size, commitments, evaluations, prover_third_message, pc_proof);
-        assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
         end_timer!(prover_time);
 
-        Ok(proof)
+        Ok(ProofBundle::new(batch_sizes, all_commitments, all_evaluations, prover_third_messages, pc_proof))
     }
 
-    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
-        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
-        public_inputs: &[B],
-        proof: &Self::Proof,
+    /// Verifies a proof bundle for several circuits, each with its own batch of public inputs.
+    /// The circuit verifying keys must be in the same order as the circuits were proven.
+    /// See [`Self::prove_heterogeneous`].
+    pub fn verify_heterogeneous<B: Borrow<Input>>(
+        groups: &[(&CircuitVerifyingKey<F, CF, PC, MM>, &[B])],
+        proof: &ProofBundle<F, CF, PC>,
     ) -> Result<bool, SNARKError> {
-        let circuit_verifying_key = &prepared_verifying_key.orig_vk;
-        if public_inputs.is_empty() {
+        if groups.is_empty() || groups.iter().any(|(_, public_inputs)| public_inputs.is_empty()) {
             return Err(SNARKError::EmptyBatch);
         }
+        let verifier_key = &groups[0].0.verifier_key;
+        if groups.iter().any(|(circuit_verifying_key, _)| &circuit_verifying_key.verifier_key != verifier_key) {
+            return Err(MarlinError::CommitterKeyMismatch.into());
+        }
+        if groups.len() != proof.batch_sizes().len() {
+            eprintln!("Expected proofs for {} circuits, found {}", groups.len(), proof.batch_sizes().len());
+            return Ok(false);
+        }
         let verifier_time = start_timer!(|| "Marlin::Verify");
 
         let comms = &proof.commitments;
         let proof_has_correct_zk_mode = if MM::ZK {
-            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
+            proof.pc_proof.is_hiding() & comms.iter().all(|comms| comms.mask_poly.is_some())
         } else {
-            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
+            !proof.pc_proof.is_hiding() & comms.iter().all(|comms| comms.mask_poly.is_none())
         };
         if !proof_has_correct_zk_mode {
             eprintln!(
@@ -491,135 +616,216 @@ where
             return Ok(false);
         }
 
-        let batch_size = public_inputs.len();
-
-        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
-        let mut first_commitments = comms
-            .witness_commitments
-            .iter()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                [
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
-                ]
-            })
-            .collect::<Vec<_>>();
-        if MM::ZK {
-            first_commitments.push(LabeledCommitment::new_with_info(
-                first_round_info.get("mask_poly").unwrap(),
-                comms.mask_poly.unwrap(),
-            ));
-        }
+        let mut round_commitments = Vec::with_capacity(groups.len());
+        let mut all_public_inputs = Vec::with_capacity(groups.len());
+        let mut all_padded_public_inputs = Vec::with_capacity(groups.len());
+        for ((circuit_verifying_key, public_inputs), comms) in groups.iter().zip_eq(comms) {
+            let batch_size = public_inputs.len();
+            if comms.witness_commitments.len() != batch_size {
+                eprintln!("Expected a proof for {} instances, found {}", batch_size, comms.witness_commitments.len());
+                return Ok(false);
+            }
 
-        let second_round_info =
-            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let second_commitments = [
-            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
-            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
-        ];
-        let third_round_info =
-            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let third_commitments = [
-            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
-            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
-            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
-        ];
-        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
-        let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
-
-        let input_domain =
-            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
-
-        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
-            public_inputs
+            let first_round_info = AHPForR1CS::<F, MM>::first_round_polynomial_info(batch_size);
+            let mut first_commitments = comms
+                .witness_commitments
                 .iter()
-                .map(|input| {
-                    let input = input.borrow().to_field_elements().unwrap();
-                    let mut new_input = vec![E::Fr::one()];
-                    new_input.extend_from_slice(&input);
-                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
-                    if cfg!(debug_assertions) {
-                        println!("Number of padded public variables: {}", new_input.len());
-                    }
-                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
-                    (new_input, unformatted)
+                .enumerate()
+                .flat_map(|(i, c)| {
+                    [
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
+                    ]
                 })
-                .unzip()
-        };
+                .collect::<Vec<_>>();
+            if MM::ZK {
+                first_commitments.push(LabeledCommitment::new_with_info(
+                    first_round_info.get("mask_poly").unwrap(),
+                    comms.mask_poly.unwrap(),
+                ));
+            }
+
+            let second_round_info =
+                AHPForR1CS::<F, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
+            let second_commitments = [
+                LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
+                LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
+            ];
+            let third_round_info =
+                AHPForR1CS::<F, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
+            let third_commitments = [
+                LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
+                LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
+                LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
+            ];
+            let fourth_round_info = AHPForR1CS::<F, MM>::fourth_round_polynomial_info();
+            let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
+
+            let input_domain =
+                EvaluationDomain::<F>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
+
+            let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
+                public_inputs
+                    .iter()
+                    .map(|input| {
+                        let input = input.borrow().to_field_elements().unwrap();
+                        let mut new_input = vec![F::one()];
+                        new_input.extend_from_slice(&input);
+                        new_input.resize(input.len().max(input_domain.size()), F::zero());
+                        if cfg!(debug_assertions) {
+                            println!("Number of padded public variables: {}", new_input.len());
+                        }
+                        let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
+                        (new_input, unformatted)
+                    })
+                    .unzip()
+            };
+
+            round_commitments.push((first_commitments, second_commitments, third_commitments, fourth_commitments));
+            all_public_inputs.push(public_inputs);
+            all_padded_public_inputs.push(padded_public_inputs);
+        }
 
-        let mut sponge =
-            Self::init_sponge(batch_size, &circuit_verifying_key.circuit_commitments, &padded_public_inputs);
+        let mut sponge = Self::init_sponge(
+            groups
+                .iter()
+                .map(|(circuit_verifying_key, _)| &circuit_verifying_key.circuit_commitments[..])
+                .zip_eq(all_padded_public_inputs.iter().map(|inputs| &inputs[..])),
+        );
 
         // --------------------------------------------------------------------
         // First round
-        Self::absorb_labeled(&first_commitments, &mut sponge);
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
+        for (first_commitments, ..) in &round_commitments {
+            Self::absorb_labeled(first_commitments, &mut sponge);
+        }
+        let verifier_states = groups
+            .iter()
+            .map(|(circuit_verifying_key, public_inputs)| {
+                AHPForR1CS::<_, MM>::verifier_first_round(
+                    circuit_verifying_key.circuit_info,
+                    public_inputs.len(),
+                    &mut sponge,
+                )
+                .map(|(_, state)| state)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
-        Self::absorb_labeled(&second_commitments, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        for (_, second_commitments, ..) in &round_commitments {
+            Self::absorb_labeled(second_commitments, &mut sponge);
+        }
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_second_round(state, &mut sponge).map(|(_, state)| state))
+            .collect::<Result<Vec<_>, _>>()?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Third round
-        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        for ((_, _, third_commitments, _), msg) in round_commitments.iter().zip_eq(&proof.msgs) {
+            Self::absorb_labeled_with_msg(third_commitments, msg, &mut sponge);
+        }
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_third_round(state, &mut sponge).map(|(_, state)| state))
+            .collect::<Result<Vec<_>, _>>()?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Fourth round
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        for (.., fourth_commitments) in &round_commitments {
+            Self::absorb_labeled(fourth_commitments, &mut sponge);
+        }
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_fourth_round(state, &mut sponge))
+            .collect::<Result<Vec<_>, _>>()?;
         // --------------------------------------------------------------------
 
-        // Collect degree bounds for commitments. Indexed polynomials have *no*
-        // degree bounds because we know the committed index polynomial has the
-        // correct degree.
-
-        // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments)
-            .chain(second_commitments)
-            .chain(third_commitments)
-            .chain(fourth_commitments)
-            .collect();
-
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-
-        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements(), OptimizationType::Weight);
+        for evaluations in &proof.evaluations {
+            sponge.absorb_nonnative_field_elements(evaluations.to_field_elements(), OptimizationType::Weight);
+        }
 
+        // The polynomials of every circuit are opened together, under labels that are unique to each circuit.
+        let mut lc_s = Vec::new();
+        let mut commitments = Vec::new();
+        let mut query_set = QuerySet::new();
         let mut evaluations = Evaluations::new();
-
-        for (label, (_point_name, q)) in query_set.to_set() {
-            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
-                evaluations.insert((label, q), E::Fr::zero());
-            } else {
-                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
-                evaluations.insert((label, q), eval);
+        for (
+            index,
+            (
+                (circuit_verifying_key, _),
+                proof_evaluations,
+                msg,
+                (first_commitments, second_commitments, third_commitments, fourth_commitments),
+                public_inputs,
+                verifier_state,
+            ),
+        ) in itertools::izip!(
+            groups,
+            &proof.evaluations,
+            &proof.msgs,
+            round_commitments,
+            all_public_inputs,
+            verifier_states
+        )
+        .enumerate()
+        {
+            // Collect degree bounds for commitments. Indexed polynomials have *no*
+            // degree bounds because we know the committed index polynomial has the
+            // correct degree.
+
+            // Gather commitments in one vector.
+            let circuit_commitments: Vec<_> = circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip_eq(AHPForR1CS::<F, MM>::index_polynomial_info().values())
+                .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+                .chain(first_commitments)
+                .chain(second_commitments)
+                .chain(third_commitments)
+                .chain(fourth_commitments)
+                .collect();
+
+            let (circuit_query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+            let circuit_query_set = circuit_query_set.to_set();
+
+            let mut circuit_evaluations = Evaluations::new();
+
+            for (label, (_point_name, q)) in &circuit_query_set {
+                if AHPForR1CS::<F, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                    circuit_evaluations.insert((label.clone(), *q), F::zero());
+                } else {
+                    let eval = proof_evaluations.get(label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                    circuit_evaluations.insert((label.clone(), *q), eval);
+                }
             }
-        }
 
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_inputs,
-            &evaluations,
-            &proof.msg,
-            &verifier_state,
-        )?;
+            let circuit_lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+                &public_inputs,
+                &circuit_evaluations,
+                msg,
+                &verifier_state,
+            )?;
 
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
-            &circuit_verifying_key.verifier_key,
-            lc_s.values(),
+            lc_s.extend(circuit_lc_s.values().map(|lc| Self::relabel_linear_combination(index, lc)));
+            commitments.extend(circuit_commitments.iter().map(|c| Self::relabel_commitment(index, c)));
+            query_set.extend(Self::relabel_query_set(index, circuit_query_set));
+            evaluations.extend(
+                circuit_evaluations
+                    .into_iter()
+                    .map(|((label, point), eval)| ((Self::circuit_label(index, &label), point), eval)),
+            );
+        }
+
+        let evaluations_are_correct = PC::check_combinations(
+            verifier_key,
+            &lc_s,
             &commitments,
-            &query_set.to_set(),
+            &query_set,
             &evaluations,
             &proof.pc_proof,
             &mut sponge,
@@ -627,13 +833,163 @@ where
 
         if !evaluations_are_correct {
             #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::<E, FS>::Check failed");
+            eprintln!("PC::Check failed");
         }
         end_timer!(verifier_time, || format!(
-            " SonicKZG10::<E, FS>::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
+            " PC::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
         ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        Ok(evaluations_are_correct)
+    }
+
+    fn terminate(terminator: &AtomicBool) -> Result<(), MarlinError> {
+        if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
+    }
+
+    /// Returns the label under which `label` is opened for the circuit at `index` of a heterogeneous proof,
+    /// so that the labels of the circuits proven together do not collide. The labels of the first circuit
+    /// are unchanged, so a proof for a single circuit is unaffected.
+    fn circuit_label(index: usize, label: &str) -> String {
+        match index {
+            0 => label.to_string(),
+            _ => format!("circuit_{index}_{label}"),
+        }
+    }
+
+    fn relabel_polynomial(index: usize, polynomial: &LabeledPolynomial<F>) -> Cow<LabeledPolynomial<F>> {
+        match index {
+            0 => Cow::Borrowed(polynomial),
+            _ => Cow::Owned(LabeledPolynomial::new(
+                Self::circuit_label(index, polynomial.label()),
+                polynomial.polynomial.clone(),
+                polynomial.degree_bound(),
+                polynomial.hiding_bound(),
+            )),
+        }
+    }
+
+    fn relabel_commitment(
+        index: usize,
+        commitment: &LabeledCommitment<PC::Commitment>,
+    ) -> LabeledCommitment<PC::Commitment> {
+        let label = Self::circuit_label(index, commitment.label());
+        LabeledCommitment::new(label, *commitment.commitment(), commitment.degree_bound())
+    }
+
+    fn relabel_linear_combination(index: usize, lc: &LinearCombination<F>) -> LinearCombination<F> {
+        let terms = lc.iter().map(|(coeff, term)| match term {
+            LCTerm::One => (*coeff, LCTerm::One),
+            LCTerm::PolyLabel(label) => (*coeff, LCTerm::PolyLabel(Self::circuit_label(index, label))),
+        });
+        LinearCombination::new(Self::circuit_label(index, lc.label()), terms)
+    }
+
+    /// Relabels the query set of the circuit at `index`. The point names are relabeled as well,
+    /// as each circuit queries its polynomials at its own points.
+    fn relabel_query_set(index: usize, query_set: QuerySet<F>) -> impl Iterator<Item = (String, (String, F))> {
+        query_set.into_iter().map(move |(label, (point_name, point))| {
+            (Self::circuit_label(index, &label), (Self::circuit_label(index, &point_name), point))
+        })
+    }
+
+    fn init_sponge<'b>(groups: impl IntoIterator<Item = (&'b [PC::Commitment], &'b [Vec<F>])>) -> FS {
+        let mut sponge = FS::new();
+        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
+        for (circuit_commitments, inputs) in groups {
+            sponge.absorb_bytes(&inputs.len().to_le_bytes());
+            sponge.absorb_native_field_elements(circuit_commitments);
+            for input in inputs {
+                sponge.absorb_nonnative_field_elements(input.iter().copied(), OptimizationType::Weight);
+            }
+        }
+        sponge
+    }
+
+    fn absorb_labeled_with_msg(
+        comms: &[LabeledCommitment<PC::Commitment>],
+        message: &prover::ThirdMessage<F>,
+        sponge: &mut FS,
+    ) {
+        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
+        Self::absorb_with_msg(&commitments, message, sponge)
+    }
+
+    fn absorb_labeled(comms: &[LabeledCommitment<PC::Commitment>], sponge: &mut FS) {
+        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
+        Self::absorb(&commitments, sponge)
+    }
+
+    fn absorb(commitments: &[PC::Commitment], sponge: &mut FS) {
+        sponge.absorb_native_field_elements(commitments);
+    }
+
+    fn absorb_with_msg(commitments: &[PC::Commitment], msg: &prover::ThirdMessage<F>, sponge: &mut FS) {
+        Self::absorb(commitments, sponge);
+        sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c], OptimizationType::Weight);
+    }
+}
+
+impl<F, CF, PC, FS, MM, Input> SNARK for MarlinSNARK<F, CF, PC, FS, MM, Input>
+where
+    F: PrimeField,
+    CF: PrimeField,
+    PC: PolynomialCommitment<F, CF>,
+    FS: FiatShamirRng<F, CF>,
+    MM: MarlinMode,
+    Input: ToConstraintField<F> + ?Sized,
+{
+    type BaseField = CF;
+    type Proof = Proof<F, CF, PC>;
+    type ProvingKey = CircuitProvingKey<F, CF, PC, MM>;
+    type ScalarField = F;
+    type UniversalSetupConfig = usize;
+    type UniversalSetupParameters = UniversalSRS<F, CF, PC>;
+    type VerifierInput = Input;
+    type VerifyingKey = CircuitVerifyingKey<F, CF, PC, MM>;
+
+    fn universal_setup<R: Rng + CryptoRng>(
+        max_degree: &Self::UniversalSetupConfig,
+        rng: &mut R,
+    ) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {}", max_degree,) });
+
+        let srs = PC::setup(*max_degree, rng).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    fn setup<C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
+        circuit: &C,
+        srs: &mut SRS<R, Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        match srs {
+            SRS::CircuitSpecific(rng) => Self::circuit_specific_setup(circuit, rng),
+            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
+        }
+        .map_err(SNARKError::from)
+    }
+
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
+        circuit_proving_key: &CircuitProvingKey<F, CF, PC, MM>,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        // A batch of instances of one circuit is proven as a single group.
+        let mut proof =
+            Self::prove_heterogeneous_with_terminator(&[(circuit_proving_key, circuits)], terminator, zk_rng)?;
+        let batch_size = proof.batch_sizes()[0];
+        let (commitments, evaluations, msg) =
+            (proof.commitments.remove(0), proof.evaluations.remove(0), proof.msgs.remove(0));
+        Ok(Proof::new(batch_size, commitments, evaluations, msg, proof.pc_proof))
+    }
+
+    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
+        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
+        public_inputs: &[B],
+        proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        Self::verify_heterogeneous(&[(&prepared_verifying_key.orig_vk, public_inputs)], &proof.clone().into())
     }
 }
 
@@ -642,6 +998,7 @@ pub mod test {
     use super::*;
     use crate::{
         crypto_hash::PoseidonSponge,
+        polycommit::sonic_pc::SonicKZG10,
         snark::marlin::{fiat_shamir::FiatShamirAlgebraicSpongeRng, MarlinHidingMode, MarlinSNARK},
         SRS,
     };
@@ -690,7 +1047,7 @@ pub mod test {
     }
 
     type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
-    type TestSNARK = MarlinSNARK<Bls12_377, FS, MarlinHidingMode, Vec<Fr>>;
+    type TestSNARK = MarlinSNARK<Fr, Fq, SonicKZG10<Bls12_377>, FS, MarlinHidingMode, Vec<Fr>>;
 
     #[test]
     fn marlin_snark_test() {
@@ -19,6 +19,11 @@ use core::fmt::Debug;
 /// A trait to specify the Marlin mode.
 pub trait MarlinMode: 'static + Copy + Clone + Debug + PartialEq + Eq + Sync + Send {
     const ZK: bool;
+
+    /// If `true`, the constraint and non-zero domains are the tightest mixed-radix domains
+    /// supported by the field, instead of the next power of two.
+    /// This changes the index, so keys produced with and without it are incompatible.
+    const MIXED_RADIX: bool = false;
 }
 
 /// The Marlin hiding mode produces a hiding Marlin proof.
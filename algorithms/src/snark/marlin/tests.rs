@@ -67,27 +67,71 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<Constrai
 
 mod marlin {
     use super::*;
-    use crate::snark::marlin::{
-        fiat_shamir::FiatShamirChaChaRng,
-        AHPForR1CS,
-        CircuitVerifyingKey,
-        MarlinHidingMode,
-        MarlinNonHidingMode,
-        MarlinSNARK,
+    use crate::{
+        polycommit::{ipa_pc::InnerProductArgPC, sonic_pc::SonicKZG10, PolynomialCommitment},
+        snark::marlin::{
+            fiat_shamir::FiatShamirChaChaRng,
+            AHPForR1CS,
+            CircuitProvingKey,
+            CircuitVerifyingKey,
+            LazyCircuitProvingKey,
+            MappedCircuitProvingKey,
+            MarlinHidingMode,
+            MarlinMode,
+            MarlinNonHidingMode,
+            MarlinSNARK,
+            ProofBundle,
+        },
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr, G1Affine};
+    use snarkvm_utilities::{
+        rand::{test_crypto_rng, UniformRand},
+        FromBytes,
+        ToBytes,
     };
-    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-    use snarkvm_utilities::rand::{test_crypto_rng, UniformRand};
 
     use blake2::Blake2s256;
     use core::ops::MulAssign;
 
-    type MarlinSonicInst = MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinHidingMode, [Fr]>;
+    type MarlinSonicInst =
+        MarlinSNARK<Fr, Fq, SonicKZG10<Bls12_377>, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinHidingMode, [Fr]>;
 
     type MarlinSonicPoswInst =
-        MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinNonHidingMode, [Fr]>;
+        MarlinSNARK<Fr, Fq, SonicKZG10<Bls12_377>, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinNonHidingMode, [Fr]>;
+
+    // The inner-product argument only uses the group structure of G1, and not its pairing.
+    type MarlinIpaInst = MarlinSNARK<
+        Fr,
+        Fq,
+        InnerProductArgPC<G1Affine>,
+        FiatShamirChaChaRng<Fr, Fq, Blake2s256>,
+        MarlinHidingMode,
+        [Fr],
+    >;
+
+    /// A hiding mode that opts into mixed-radix constraint and non-zero domains.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    struct MarlinMixedRadixMode;
+
+    impl MarlinMode for MarlinMixedRadixMode {
+        const MIXED_RADIX: bool = true;
+        const ZK: bool = true;
+    }
+
+    type MarlinSonicMixedRadixInst =
+        MarlinSNARK<Fr, Fq, SonicKZG10<Bls12_377>, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinMixedRadixMode, [Fr]>;
+
+    type MarlinIpaMixedRadixInst = MarlinSNARK<
+        Fr,
+        Fq,
+        InnerProductArgPC<G1Affine>,
+        FiatShamirChaChaRng<Fr, Fq, Blake2s256>,
+        MarlinMixedRadixMode,
+        [Fr],
+    >;
 
     macro_rules! impl_marlin_test {
-        ($test_struct: ident, $marlin_inst: tt, $marlin_mode: tt) => {
+        ($test_struct: ident, $marlin_inst: tt, $pc: ty, $marlin_mode: tt) => {
             struct $test_struct {}
             impl $test_struct {
                 pub(crate) fn test_circuit(num_constraints: usize, num_variables: usize) {
@@ -158,6 +202,93 @@ mod marlin {
                     }
                 }
 
+                pub(crate) fn test_heterogeneous() {
+                    let rng = &mut snarkvm_utilities::rand::test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let mut sample = |num_constraints, num_variables, batch_size| {
+                        (0..batch_size)
+                            .map(|_| {
+                                let a = Fr::rand(rng);
+                                let b = Fr::rand(rng);
+                                let mut c = a;
+                                c.mul_assign(&b);
+                                let mut d = c;
+                                d.mul_assign(&b);
+
+                                (Circuit { a: Some(a), b: Some(b), num_constraints, num_variables }, [c, d])
+                            })
+                            .unzip::<_, _, Vec<_>, Vec<_>>()
+                    };
+                    let (small_circuits, small_inputs) = sample(10, 5, 2);
+                    let (large_circuits, large_inputs) = sample(70, 40, 3);
+
+                    let mut keys =
+                        $marlin_inst::batch_circuit_setup(&universal_srs, &[&small_circuits[0], &large_circuits[0]])
+                            .unwrap();
+                    let (large_pk, large_vk) = keys.pop().unwrap();
+                    let (small_pk, small_vk) = keys.pop().unwrap();
+                    assert!(std::sync::Arc::ptr_eq(&small_pk.committer_key, &large_pk.committer_key));
+                    println!("Called circuit setup");
+
+                    let proof = $marlin_inst::prove_heterogeneous(
+                        &[(&small_pk, &small_circuits[..]), (&large_pk, &large_circuits[..])],
+                        rng,
+                    )
+                    .unwrap();
+                    assert_eq!(proof.batch_sizes(), [2, 3]);
+                    println!("Called prover");
+
+                    assert!(
+                        $marlin_inst::verify_heterogeneous(
+                            &[(&small_vk, &small_inputs[..]), (&large_vk, &large_inputs[..])],
+                            &proof
+                        )
+                        .unwrap()
+                    );
+                    println!("Called verifier");
+
+                    // The proof must survive serialization.
+                    let proof_bytes = proof.to_bytes_le().unwrap();
+                    let recovered_proof = ProofBundle::read_le(&proof_bytes[..]).unwrap();
+                    assert_eq!(proof, recovered_proof);
+
+                    println!("\nShould not verify (i.e. verifier messages should print below):");
+                    assert!(
+                        !$marlin_inst::verify_heterogeneous(
+                            &[(&small_vk, &large_inputs[..2]), (&large_vk, &large_inputs[..])],
+                            &proof
+                        )
+                        .unwrap()
+                    );
+                    assert!(
+                        !$marlin_inst::verify_heterogeneous(
+                            &[(&large_vk, &large_inputs[..]), (&small_vk, &small_inputs[..])],
+                            &proof
+                        )
+                        .unwrap()
+                    );
+                    assert!(!$marlin_inst::verify_heterogeneous(&[(&small_vk, &small_inputs[..])], &proof).unwrap());
+
+                    // A single group proves exactly as a batch.
+                    let proof = $marlin_inst::prove_batch(&small_pk, &small_circuits, rng).unwrap();
+                    assert!(
+                        $marlin_inst::verify_heterogeneous(&[(&small_vk, &small_inputs[..])], &proof.into()).unwrap()
+                    );
+
+                    // Circuits with different committer keys cannot be proven together.
+                    let (other_pk, _) = $marlin_inst::circuit_setup(&universal_srs, &small_circuits[0]).unwrap();
+                    assert!(
+                        $marlin_inst::prove_heterogeneous(
+                            &[(&other_pk, &small_circuits[..]), (&large_pk, &large_circuits[..])],
+                            rng
+                        )
+                        .is_err()
+                    );
+                }
+
                 pub(crate) fn test_serde_json(num_constraints: usize, num_variables: usize) {
                     use std::str::FromStr;
 
@@ -209,12 +340,175 @@ mod marlin {
                     assert_eq!(index_vk, CircuitVerifyingKey::read_le(&expected_bytes[..]).unwrap());
                     assert_eq!(index_vk, bincode::deserialize(&candidate_bytes[..]).unwrap());
                 }
+
+                pub(crate) fn test_compact_proving_key(num_constraints: usize, num_variables: usize) {
+                    use snarkvm_utilities::ToBytes;
+
+                    let rng = &mut test_crypto_rng();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+
+                    let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    println!("Called circuit setup");
+
+                    // The compact format omits the committer key, so it is smaller.
+                    let mut compact_bytes = vec![];
+                    index_pk.write_compact(&mut compact_bytes).unwrap();
+                    let expected_bytes = index_pk.to_bytes_le().unwrap();
+                    assert!(compact_bytes.len() < expected_bytes.len());
+
+                    // Deserialize, sharing the committer key of the indexer.
+                    let candidate_pk = CircuitProvingKey::<Fr, Fq, $pc, $marlin_mode>::read_compact(
+                        &compact_bytes[..],
+                        index_pk.committer_key.clone(),
+                    )
+                    .unwrap();
+                    assert_eq!(expected_bytes, candidate_pk.to_bytes_le().unwrap());
+                    assert!(std::sync::Arc::ptr_eq(&index_pk.committer_key, &candidate_pk.committer_key));
+
+                    // The committer key trimmed from the universal SRS is the same as the one of the indexer.
+                    let (mut committer_key, _) =
+                        index_pk.circuit.trim_universal_srs::<Fq, $pc>(&universal_srs).unwrap();
+                    assert_eq!(index_pk.committer_key.to_bytes_le().unwrap(), committer_key.to_bytes_le().unwrap());
+
+                    // It is only accepted once its tables are precomputed.
+                    let read_compact = |committer_key: &<$pc as PolynomialCommitment<Fr, Fq>>::CommitterKey| {
+                        CircuitProvingKey::<Fr, Fq, $pc, $marlin_mode>::read_compact(
+                            &compact_bytes[..],
+                            std::sync::Arc::new(committer_key.clone()),
+                        )
+                    };
+                    assert_eq!(
+                        read_compact(&committer_key).is_ok(),
+                        <$pc as PolynomialCommitment<Fr, Fq>>::is_committer_key_precomputed(&committer_key)
+                    );
+                    <$pc as PolynomialCommitment<Fr, Fq>>::precompute_committer_key(&mut committer_key);
+                    assert!(read_compact(&committer_key).is_ok());
+
+                    // A committer key for other degrees is rejected.
+                    let (mut other_committer_key, _) =
+                        <$pc as PolynomialCommitment<Fr, Fq>>::trim(&universal_srs, 1, [], 0, None).unwrap();
+                    <$pc as PolynomialCommitment<Fr, Fq>>::precompute_committer_key(&mut other_committer_key);
+                    assert!(read_compact(&other_committer_key).is_err());
+
+                    // Load the proving key on demand, and prove with it.
+                    let lazy_pk =
+                        LazyCircuitProvingKey::<Fr, Fq, $pc, $marlin_mode, _>::from_bytes(&compact_bytes[..]).unwrap();
+                    assert_eq!(&index_vk, lazy_pk.circuit_verifying_key());
+                    let candidate_pk = lazy_pk.load(index_pk.committer_key.clone()).unwrap();
+                    assert_eq!(expected_bytes, candidate_pk.to_bytes_le().unwrap());
+                    assert!(lazy_pk.load(std::sync::Arc::new(other_committer_key)).is_err());
+
+                    let proof = $marlin_inst::prove(&candidate_pk, &circ, rng).unwrap();
+                    let mut c = a;
+                    c.mul_assign(&b);
+                    let mut d = c;
+                    d.mul_assign(&b);
+                    assert!($marlin_inst::verify(&index_vk, [c, d], &proof).unwrap());
+
+                    // Memory-map the proving key from a file.
+                    let path = std::env::temp_dir().join(format!("marlin_compact_pk_{}", u64::rand(rng)));
+                    std::fs::write(&path, &compact_bytes).unwrap();
+                    let mapped_pk = MappedCircuitProvingKey::<Fr, Fq, $pc, $marlin_mode>::open(&path).unwrap();
+                    assert_eq!(&index_vk, mapped_pk.circuit_verifying_key());
+                    let candidate_pk = mapped_pk.load(index_pk.committer_key.clone()).unwrap();
+                    assert_eq!(expected_bytes, candidate_pk.to_bytes_le().unwrap());
+                    drop(mapped_pk);
+                    std::fs::remove_file(&path).unwrap();
+
+                    // A truncated proving key is rejected.
+                    assert!(LazyCircuitProvingKey::<Fr, Fq, $pc, $marlin_mode, _>::from_bytes(
+                        &compact_bytes[..compact_bytes.len() - 1]
+                    )
+                    .is_err());
+                }
             }
         };
     }
 
-    impl_marlin_test!(SonicPCTest, MarlinSonicInst, MarlinHidingMode);
-    impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, MarlinNonHidingMode);
+    impl_marlin_test!(SonicPCTest, MarlinSonicInst, SonicKZG10<Bls12_377>, MarlinHidingMode);
+    impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, SonicKZG10<Bls12_377>, MarlinNonHidingMode);
+    impl_marlin_test!(IpaPCTest, MarlinIpaInst, InnerProductArgPC<G1Affine>, MarlinHidingMode);
+    impl_marlin_test!(SonicPCMixedRadixTest, MarlinSonicMixedRadixInst, SonicKZG10<Bls12_377>, MarlinMixedRadixMode);
+    impl_marlin_test!(IpaPCMixedRadixTest, MarlinIpaMixedRadixInst, InnerProductArgPC<G1Affine>, MarlinMixedRadixMode);
+
+    #[test]
+    fn prove_and_verify_with_inner_product_argument() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        IpaPCTest::test_circuit(num_constraints, num_variables);
+        IpaPCTest::test_serde_json(num_constraints, num_variables);
+        IpaPCTest::test_bincode(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn compact_proving_key() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_compact_proving_key(num_constraints, num_variables);
+        SonicPCPoswTest::test_compact_proving_key(num_constraints, num_variables);
+        IpaPCTest::test_compact_proving_key(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn committer_key_is_precomputed() {
+        let rng = &mut test_crypto_rng();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSonicInst::universal_setup(&max_degree, rng).unwrap();
+        let circ = Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints: 25, num_variables: 25 };
+
+        // The indexer precomputes the committer key, and so does reading the proving key.
+        let (index_pk, _) = MarlinSonicInst::circuit_setup(&universal_srs, &circ).unwrap();
+        assert!(index_pk.committer_key.precomputed_powers_of_beta_g.is_some());
+        let candidate_pk = CircuitProvingKey::<Fr, Fq, SonicKZG10<Bls12_377>, MarlinHidingMode>::read_le(
+            &index_pk.to_bytes_le().unwrap()[..],
+        )
+        .unwrap();
+        assert!(candidate_pk.committer_key.precomputed_powers_of_beta_g.is_some());
+
+        let keys = MarlinSonicInst::batch_circuit_setup(&universal_srs, &[&circ, &circ]).unwrap();
+        assert!(keys[0].0.committer_key.precomputed_powers_of_beta_g.is_some());
+    }
+
+    #[test]
+    fn prove_and_verify_with_mixed_radix_domains() {
+        // The padded constraint system fits in a domain of size 3 * 2^4, instead of 2^6.
+        let num_constraints = 40;
+        let num_variables = 40;
+
+        let rng = &mut test_crypto_rng();
+        let circ = Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+        let index = AHPForR1CS::<Fr, MarlinMixedRadixMode>::index(&circ).unwrap();
+        let mixed_radix_domain = AHPForR1CS::<Fr, MarlinMixedRadixMode>::constraint_domain(&index.index_info).unwrap();
+        let radix_2_domain = AHPForR1CS::<Fr, MarlinHidingMode>::constraint_domain(&index.index_info).unwrap();
+        assert!(!mixed_radix_domain.is_radix_2());
+        assert!(mixed_radix_domain.size() < radix_2_domain.size());
+
+        SonicPCMixedRadixTest::test_circuit(num_constraints, num_variables);
+        SonicPCMixedRadixTest::test_serde_json(num_constraints, num_variables);
+        SonicPCMixedRadixTest::test_bincode(num_constraints, num_variables);
+        SonicPCMixedRadixTest::test_compact_proving_key(num_constraints, num_variables);
+        IpaPCMixedRadixTest::test_circuit(num_constraints, num_variables);
+        IpaPCMixedRadixTest::test_serde_json(num_constraints, num_variables);
+        IpaPCMixedRadixTest::test_bincode(num_constraints, num_variables);
+        IpaPCMixedRadixTest::test_compact_proving_key(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn prove_and_verify_heterogeneous() {
+        SonicPCTest::test_heterogeneous();
+        SonicPCPoswTest::test_heterogeneous();
+        IpaPCTest::test_heterogeneous();
+        SonicPCMixedRadixTest::test_heterogeneous();
+        IpaPCMixedRadixTest::test_heterogeneous();
+    }
 
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
@@ -296,6 +590,7 @@ mod marlin_recursion {
     use super::*;
     use crate::{
         crypto_hash::PoseidonSponge,
+        polycommit::sonic_pc::SonicKZG10,
         snark::marlin::{
             ahp::AHPForR1CS,
             fiat_shamir::FiatShamirAlgebraicSpongeRng,
@@ -314,8 +609,14 @@ mod marlin_recursion {
     use core::ops::MulAssign;
     use std::str::FromStr;
 
-    type MarlinInst =
-        MarlinSNARK<Bls12_377, FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>, MarlinHidingMode, [Fr]>;
+    type MarlinInst = MarlinSNARK<
+        Fr,
+        Fq,
+        SonicKZG10<Bls12_377>,
+        FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>,
+        MarlinHidingMode,
+        [Fr],
+    >;
 
     fn test_circuit(num_constraints: usize, num_variables: usize) {
         let rng = &mut test_crypto_rng();
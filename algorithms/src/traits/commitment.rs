@@ -17,7 +17,7 @@
 use crate::CommitmentError;
 use snarkvm_utilities::{FromBytes, ToBits, ToBytes, UniformRand};
 
-use std::{fmt::Debug, hash::Hash};
+use core::{fmt::Debug, hash::Hash};
 
 pub trait CommitmentScheme: Sized + Clone {
     type Output: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + Sync + Send;
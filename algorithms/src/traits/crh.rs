@@ -17,7 +17,7 @@
 use crate::errors::CRHError;
 use snarkvm_utilities::{FromBytes, ToBits, ToBytes};
 
-use std::{
+use core::{
     fmt::{Debug, Display},
     hash::Hash,
 };
@@ -15,10 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::EncryptionError;
-use snarkvm_utilities::{rand::UniformRand, FromBytes, ToBits, ToBytes};
+use snarkvm_utilities::{rand::UniformRand, vec::Vec, FromBytes, ToBits, ToBytes};
 
+use core::{fmt::Debug, hash::Hash};
 use rand::{CryptoRng, Rng};
-use std::{fmt::Debug, hash::Hash};
 
 pub trait EncryptionScheme: Sized + Debug + Clone + PartialEq + Eq {
     type CiphertextRandomizer: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + ToBits;
@@ -48,6 +48,18 @@ pub trait EncryptionScheme: Sized + Debug + Clone + PartialEq + Eq {
         ciphertext_randomizer: Self::CiphertextRandomizer,
     ) -> Option<Self::SymmetricKey>;
 
+    /// Returns the symmetric key of each of the given ciphertext randomizers, as in `generate_symmetric_key`.
+    fn generate_symmetric_keys(
+        &self,
+        private_key: &Self::PrivateKey,
+        ciphertext_randomizers: &[Self::CiphertextRandomizer],
+    ) -> Vec<Option<Self::SymmetricKey>> {
+        ciphertext_randomizers
+            .iter()
+            .map(|randomizer| self.generate_symmetric_key(private_key, randomizer.clone()))
+            .collect()
+    }
+
     fn generate_symmetric_key_commitment(&self, symmetric_key: &Self::SymmetricKey) -> Self::SymmetricKeyCommitment;
 
     fn encode_message(message: &[u8]) -> Result<Vec<Self::MessageType>, EncryptionError>;
@@ -17,7 +17,7 @@
 use crate::{errors::MerkleError, CRH};
 use snarkvm_utilities::{to_bytes_le, ToBytes};
 
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 pub trait MerkleParameters: Clone + Debug + PartialEq + Eq + Send + Sync {
     type LeafCRH: CRH;
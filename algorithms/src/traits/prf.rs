@@ -16,7 +16,7 @@
 
 use snarkvm_utilities::{FromBytes, ToBytes};
 
-use std::{fmt::Debug, hash::Hash};
+use core::{fmt::Debug, hash::Hash};
 
 pub trait PRF {
     type Input: Default;
@@ -19,8 +19,8 @@ use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
+use core::{fmt::Debug, hash::Hash};
 use rand::{CryptoRng, Rng};
-use std::{fmt::Debug, hash::Hash};
 
 pub trait SignatureScheme: Sized + Debug + Clone + Eq + Send + Sync {
     type Parameters: Clone + Debug + Eq;
@@ -55,5 +55,7 @@ pub trait SignatureSchemeOperations {
     fn pk_sig(signature: &Self::Signature) -> Result<Self::AffineCurve>;
     fn pr_sig(signature: &Self::Signature) -> Result<Self::AffineCurve>;
     fn g_scalar_multiply(&self, scalar: &Self::ScalarField) -> <Self::AffineCurve as AffineCurve>::Projective;
+    /// Returns `G^scalar`, in time independent of the scalar. This must be used for secret scalars.
+    fn g_scalar_multiply_ct(&self, scalar: &Self::ScalarField) -> <Self::AffineCurve as AffineCurve>::Projective;
     fn hash_to_scalar_field(&self, input: &[Self::BaseField]) -> Self::ScalarField;
 }
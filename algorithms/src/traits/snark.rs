@@ -17,10 +17,10 @@
 use crate::errors::SNARKError;
 use snarkvm_utilities::{FromBytes, ToBytes, ToMinimalBits};
 
+use core::{borrow::Borrow, fmt::Debug, sync::atomic::AtomicBool};
 use rand::{CryptoRng, Rng};
 use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_r1cs::ConstraintSynthesizer;
-use std::{borrow::Borrow, fmt::Debug, sync::atomic::AtomicBool};
 
 /// Defines a trait that describes preparing from an unprepared version to a prepare version.
 pub trait Prepare {
@@ -81,7 +81,7 @@ pub trait SNARK {
         input_and_witness: &C,
         rng: &mut R,
     ) -> Result<Self::Proof, SNARKError> {
-        Self::prove_batch(proving_key, std::slice::from_ref(input_and_witness), rng)
+        Self::prove_batch(proving_key, core::slice::from_ref(input_and_witness), rng)
     }
 
     fn prove_batch_with_terminator<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
@@ -97,7 +97,7 @@ pub trait SNARK {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<Self::Proof, SNARKError> {
-        Self::prove_batch_with_terminator(proving_key, std::slice::from_ref(input_and_witness), terminator, rng)
+        Self::prove_batch_with_terminator(proving_key, core::slice::from_ref(input_and_witness), terminator, rng)
     }
 
     fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
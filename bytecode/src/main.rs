@@ -123,6 +123,7 @@ function main:
         {
             use snarkvm_algorithms::{
                 crypto_hash::PoseidonSponge,
+                polycommit::sonic_pc::SonicKZG10,
                 snark::marlin::{
                     ahp::AHPForR1CS,
                     fiat_shamir::FiatShamirAlgebraicSpongeRng,
@@ -134,7 +135,7 @@ function main:
             use snarkvm_utilities::rand::test_crypto_rng;
 
             type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
-            type MarlinInst = MarlinSNARK<Bls12_377, FS, MarlinHidingMode, [Fr]>;
+            type MarlinInst = MarlinSNARK<Fr, Fq, SonicKZG10<Bls12_377>, FS, MarlinHidingMode, [Fr]>;
 
             let rng = &mut test_crypto_rng();
 
@@ -217,6 +217,7 @@ mod tests {
 
         use snarkvm_algorithms::{
             crypto_hash::PoseidonSponge,
+            polycommit::sonic_pc::SonicKZG10,
             snark::marlin::{
                 ahp::AHPForR1CS,
                 fiat_shamir::FiatShamirAlgebraicSpongeRng,
@@ -228,7 +229,9 @@ mod tests {
         use snarkvm_utilities::rand::test_crypto_rng;
 
         type MarlinInst = MarlinSNARK<
-            Bls12_377,
+            Fr,
+            Fq,
+            SonicKZG10<Bls12_377>,
             FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>,
             MarlinHidingMode,
             [Fr],
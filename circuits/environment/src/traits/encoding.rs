@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BooleanTrait, Eject, Environment, FieldTrait, FromBits, Inject, Mode};
+use snarkvm_fields::PrimeField;
+
+///
+/// The bit-exact encoding of a circuit, mirroring `snarkvm_fields::Encoding` for its primitive.
+///
+/// The little-endian bits are the source of truth. The big-endian bits, the packed field
+/// elements and the canonical bytes are all derived from them in the same way as the native
+/// encoding, so the two agree on every representation.
+///
+pub trait Encoding {
+    type Environment: Environment;
+    type Boolean: BooleanTrait;
+
+    /// Returns the little-endian bits of the circuit.
+    fn encode_bits_le(&self) -> Vec<Self::Boolean>;
+
+    /// Returns the big-endian bits of the circuit.
+    fn encode_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_le = self.encode_bits_le();
+        bits_le.reverse();
+        bits_le
+    }
+
+    /// Returns a circuit from the given little-endian bits, halting if they are not a valid encoding.
+    fn decode_bits_le(bits_le: &[Self::Boolean]) -> Self
+    where
+        Self: Sized;
+
+    /// Returns a circuit from the given big-endian bits, halting if they are not a valid encoding.
+    fn decode_bits_be(bits_be: &[Self::Boolean]) -> Self
+    where
+        Self: Sized,
+    {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::decode_bits_le(&bits_le)
+    }
+
+    /// Returns the little-endian bits of the circuit, packed into base fields of `size_in_data_bits` bits each.
+    fn encode_fields<F>(&self) -> Vec<F>
+    where
+        F: FieldTrait
+            + Eject<Primitive = <Self::Environment as Environment>::BaseField>
+            + FromBits<Boolean = Self::Boolean>,
+    {
+        let size_in_data_bits = <<Self::Environment as Environment>::BaseField as PrimeField>::size_in_data_bits();
+        self.encode_bits_le().chunks(size_in_data_bits).map(F::from_bits_le).collect()
+    }
+
+    /// Returns the canonical bytes of the circuit, which are its little-endian bits *with* trailing zeros (to byte-alignment).
+    fn encode_bytes_le(&self) -> Vec<[Self::Boolean; 8]> {
+        let mut bits_le = self.encode_bits_le();
+        let num_bytes = (bits_le.len() + 7) / 8;
+        bits_le.resize(num_bytes * 8, Self::Boolean::new(Mode::Constant, false));
+
+        bits_le
+            .chunks(8)
+            .map(|byte| match byte.to_vec().try_into() {
+                Ok(byte) => byte,
+                Err(..) => Self::Environment::halt("Failed to chunk the encoding into bytes"),
+            })
+            .collect()
+    }
+
+    /// Returns a circuit from the given canonical bytes, halting if they are not a valid encoding.
+    fn decode_bytes_le(bytes_le: &[[Self::Boolean; 8]]) -> Self
+    where
+        Self: Sized,
+    {
+        Self::decode_bits_le(&bytes_le.iter().flatten().cloned().collect::<Vec<_>>())
+    }
+}
@@ -23,6 +23,9 @@ pub use boolean::*;
 pub mod eject;
 pub use eject::*;
 
+pub mod encoding;
+pub use encoding::*;
+
 pub mod field;
 pub use field::*;
 
@@ -16,5 +16,6 @@
 
 use super::*;
 
+pub mod encoding;
 pub mod to_bits;
 pub mod to_group;
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Encoding for Boolean<E> {
+    type Boolean = Boolean<E>;
+    type Environment = E;
+
+    /// Outputs `self` as a single bit.
+    fn encode_bits_le(&self) -> Vec<Self::Boolean> {
+        self.to_bits_le()
+    }
+
+    /// Returns a boolean circuit from exactly one bit.
+    fn decode_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        Self::from_bits_le(bits_le)
+    }
+
+    /// Returns a boolean circuit from a single byte, whose upper seven bits must be zero.
+    fn decode_bytes_le(bytes_le: &[[Self::Boolean; 8]]) -> Self {
+        match bytes_le {
+            [[bit, upper_bits @ ..]] => {
+                // Check if all upper bits are zero.
+                let should_be_zero = upper_bits.iter().fold(Boolean::constant(false), |acc, bit| acc | bit);
+                // Ensure `should_be_zero` is zero.
+                E::assert_eq(E::zero(), should_be_zero);
+
+                bit.clone()
+            }
+            _ => E::halt(format!("Attempted to instantiate a boolean with {} bytes", bytes_le.len())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_fields::Encoding as NativeEncoding;
+    use snarkvm_utilities::bytes_from_bits_le;
+
+    fn check_encoding(mode: Mode) {
+        for expected in [true, false] {
+            let candidate = Boolean::<Circuit>::new(mode, expected);
+
+            Circuit::scope(&format!("{} {}", mode, expected), || {
+                // Ensure the encodings match the native encodings.
+                assert_eq!(expected.encode_bits_le(), candidate.encode_bits_le().eject_value());
+                assert_eq!(expected.encode_bits_be(), candidate.encode_bits_be().eject_value());
+                let candidate_bytes = candidate.encode_bytes_le();
+                assert_eq!(expected.encode_bytes_le(), bytes_from_bits_le(&candidate_bytes.eject_value().concat()));
+
+                // Ensure the encodings decode to the original value.
+                assert_eq!(expected, Boolean::decode_bits_le(&candidate.encode_bits_le()).eject_value());
+                assert_eq!(expected, Boolean::decode_bits_be(&candidate.encode_bits_be()).eject_value());
+                assert_eq!(expected, Boolean::decode_bytes_le(&candidate_bytes).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_encoding_constant() {
+        check_encoding(Mode::Constant);
+    }
+
+    #[test]
+    fn test_encoding_public() {
+        check_encoding(Mode::Public);
+    }
+
+    #[test]
+    fn test_encoding_private() {
+        check_encoding(Mode::Private);
+    }
+
+    #[test]
+    fn test_decode_bytes_le_with_nonzero_upper_bits() {
+        let mut bytes_le = Boolean::<Circuit>::new(Mode::Private, true).encode_bytes_le();
+        bytes_le[0][7] = Boolean::new(Mode::Private, true);
+
+        let candidate = Boolean::decode_bytes_le(&bytes_le);
+        assert!(candidate.eject_value());
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
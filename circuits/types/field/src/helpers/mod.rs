@@ -17,6 +17,7 @@
 use super::*;
 
 pub mod double;
+pub mod encoding;
 pub mod from_bits;
 pub mod from_boolean;
 pub mod one;
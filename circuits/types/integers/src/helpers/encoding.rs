@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Encoding for Integer<E, I> {
+    type Boolean = Boolean<E>;
+    type Environment = E;
+
+    /// Outputs the little-endian bit representation of `self` *with* trailing zeros.
+    fn encode_bits_le(&self) -> Vec<Self::Boolean> {
+        self.to_bits_le()
+    }
+
+    /// Initializes a new integer from a list of little-endian bits *with* trailing zeros.
+    fn decode_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        Self::from_bits_le(bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_fields::Encoding as NativeEncoding;
+    use snarkvm_utilities::{bytes_from_bits_le, test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_encoding<I: IntegerType + NativeEncoding>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: I = UniformRand::rand(&mut test_rng());
+            let candidate = Integer::<Circuit, I>::new(mode, expected);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                // Ensure the encodings match the native encodings.
+                assert_eq!(expected.encode_bits_le(), candidate.encode_bits_le().eject_value());
+                assert_eq!(expected.encode_bits_be(), candidate.encode_bits_be().eject_value());
+                assert_eq!(
+                    expected.encode_fields::<<Circuit as Environment>::BaseField>(),
+                    candidate.encode_fields::<Field<Circuit>>().eject_value()
+                );
+                let candidate_bytes = candidate.encode_bytes_le();
+                assert_eq!(expected.encode_bytes_le(), bytes_from_bits_le(&candidate_bytes.eject_value().concat()));
+
+                // Ensure the encodings decode to the original value.
+                assert_eq!(expected, Integer::decode_bits_le(&candidate.encode_bits_le()).eject_value());
+                assert_eq!(expected, Integer::decode_bits_be(&candidate.encode_bits_be()).eject_value());
+                assert_eq!(expected, Integer::decode_bytes_le(&candidate_bytes).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn run_test(mode: Mode) {
+        check_encoding::<i8>(mode);
+        check_encoding::<i16>(mode);
+        check_encoding::<i32>(mode);
+        check_encoding::<i64>(mode);
+        check_encoding::<i128>(mode);
+        check_encoding::<u8>(mode);
+        check_encoding::<u16>(mode);
+        check_encoding::<u32>(mode);
+        check_encoding::<u64>(mode);
+        check_encoding::<u128>(mode);
+    }
+
+    #[test]
+    fn test_encoding_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_encoding_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_encoding_private() {
+        run_test(Mode::Private);
+    }
+}
@@ -16,6 +16,7 @@
 
 use super::*;
 
+pub mod encoding;
 pub mod from_bits;
 pub mod msb;
 pub mod one;
@@ -310,3 +310,110 @@ impl<E: Environment> ToBytes for Literal<E> {
         self.eject_value().write_le(&mut writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuits_environment::Circuit;
+    use snarkvm_fields::Encoding as NativeEncoding;
+    use snarkvm_utilities::{bytes_from_bits_le, test_rng, UniformRand};
+
+    use rand::Rng;
+
+    const ITERATIONS: u64 = 16;
+
+    /// Checks that the given circuit and its primitive agree on every encoding.
+    fn check_encoding<C, P>(candidate: &C, expected: &P)
+    where
+        C: Encoding<Environment = Circuit, Boolean = Boolean<Circuit>> + Eject<Primitive = P>,
+        P: NativeEncoding + Debug + PartialEq,
+    {
+        // Ensure the encodings match the native encodings.
+        assert_eq!(expected.encode_bits_le(), candidate.encode_bits_le().eject_value());
+        assert_eq!(expected.encode_bits_be(), candidate.encode_bits_be().eject_value());
+        assert_eq!(
+            expected.encode_fields::<<Circuit as Environment>::BaseField>(),
+            candidate.encode_fields::<Field<Circuit>>().eject_value()
+        );
+        let candidate_bytes = candidate.encode_bytes_le();
+        assert_eq!(expected.encode_bytes_le(), bytes_from_bits_le(&candidate_bytes.eject_value().concat()));
+
+        // Ensure the native encodings decode to the original value.
+        assert_eq!(Some(expected), P::decode_bits_le(&expected.encode_bits_le()).as_ref());
+        assert_eq!(Some(expected), P::decode_bits_be(&expected.encode_bits_be()).as_ref());
+        assert_eq!(Some(expected), P::decode_bytes_le(&expected.encode_bytes_le()).as_ref());
+
+        // Ensure the circuit encodings decode to the original value.
+        assert_eq!(*expected, C::decode_bits_le(&candidate.encode_bits_le()).eject_value());
+        assert_eq!(*expected, C::decode_bits_be(&candidate.encode_bits_be()).eject_value());
+        assert_eq!(*expected, C::decode_bytes_le(&candidate_bytes).eject_value());
+        assert!(Circuit::is_satisfied_in_scope());
+    }
+
+    /// Samples a random primitive for every literal variant.
+    fn sample_primitives() -> Vec<Primitive<Circuit>> {
+        let rng = &mut test_rng();
+        vec![
+            Primitive::Address(UniformRand::rand(rng)),
+            Primitive::Boolean(UniformRand::rand(rng)),
+            Primitive::Field(UniformRand::rand(rng)),
+            Primitive::Group(UniformRand::rand(rng)),
+            Primitive::I8(UniformRand::rand(rng)),
+            Primitive::I16(UniformRand::rand(rng)),
+            Primitive::I32(UniformRand::rand(rng)),
+            Primitive::I64(UniformRand::rand(rng)),
+            Primitive::I128(UniformRand::rand(rng)),
+            Primitive::U8(UniformRand::rand(rng)),
+            Primitive::U16(UniformRand::rand(rng)),
+            Primitive::U32(UniformRand::rand(rng)),
+            Primitive::U64(UniformRand::rand(rng)),
+            Primitive::U128(UniformRand::rand(rng)),
+            Primitive::Scalar(UniformRand::rand(rng)),
+            // Take 1/4th of the bytes to ensure we fit for all code points.
+            Primitive::String((0..Circuit::NUM_STRING_BYTES / 4).map(|_| rng.gen::<char>()).collect()),
+        ]
+    }
+
+    fn check_literal_encoding(mode: Mode) {
+        for i in 0..ITERATIONS {
+            for primitive in sample_primitives() {
+                let literal = Literal::<Circuit>::new(mode, primitive);
+
+                Circuit::scope(&format!("{} {} {}", mode, literal.type_name(), i), || match &literal {
+                    Literal::Address(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::Boolean(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::Field(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::Group(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::I8(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::I16(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::I32(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::I64(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::I128(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::U8(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::U16(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::U32(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::U64(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::U128(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::Scalar(literal) => check_encoding(literal, &literal.eject_value()),
+                    Literal::String(literal) => check_encoding(literal, &literal.eject_value()),
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_literal_encoding_constant() {
+        check_literal_encoding(Mode::Constant);
+    }
+
+    #[test]
+    fn test_literal_encoding_public() {
+        check_literal_encoding(Mode::Public);
+    }
+
+    #[test]
+    fn test_literal_encoding_private() {
+        check_literal_encoding(Mode::Private);
+    }
+}
@@ -16,6 +16,7 @@
 
 use super::*;
 
+pub mod encoding;
 pub mod from_bits;
 pub mod to_bits;
 pub mod to_fields;
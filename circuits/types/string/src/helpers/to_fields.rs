@@ -25,7 +25,7 @@ impl<E: Environment> ToFields for StringType<E> {
         // Convert the string bytes into bits, then chunk them into lists of size
         // `E::BaseField::size_in_data_bits()` and recover the base field element for each chunk.
         // (For advanced users: Chunk into CAPACITY bits and create a linear combination per chunk.)
-        self.to_bits_le().chunks(E::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+        self.encode_fields()
     }
 }
 
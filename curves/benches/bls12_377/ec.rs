@@ -48,6 +48,22 @@ pub(crate) mod g1 {
         });
     }
 
+    /// Sums `SAMPLES` independent scalar multiplications. This is a baseline for the curve arithmetic,
+    /// not a multi-scalar multiplication; the Pippenger MSM is benchmarked in `snarkvm-algorithms`
+    /// (`cargo bench -p snarkvm-algorithms --bench variable_base`, with or without
+    /// `--features snarkvm-fields/fp384-limb52`).
+    pub fn bench_g1_mul_sum(c: &mut Criterion) {
+        const SAMPLES: usize = 256;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<(G1Affine, Fr)> = (0..SAMPLES).map(|_| (G1::rand(&mut rng).into(), Fr::rand(&mut rng))).collect();
+
+        c.bench_function(&format!("bls12_377: g1_mul_sum ({})", SAMPLES), |c| {
+            c.iter(|| v.iter().map(|(base, scalar)| *base * *scalar).sum::<G1>())
+        });
+    }
+
     pub fn bench_g1_add_assign(c: &mut Criterion) {
         const SAMPLES: usize = 1000;
 
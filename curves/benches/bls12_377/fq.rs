@@ -190,6 +190,78 @@ pub(crate) fn bench_fq_mul_assign(c: &mut Criterion) {
     });
 }
 
+pub(crate) fn bench_fq_mul_assign_limb64(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<(Fq, Fq)> = (0..SAMPLES).map(|_| (Fq::rand(&mut rng), Fq::rand(&mut rng))).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fq_mul_assign_limb64", |c| {
+        c.iter(|| {
+            let mut tmp = v[count].0;
+            tmp.mul_assign_limb64(&v[count].1);
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
+
+pub(crate) fn bench_fq_mul_assign_limb52(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<(Fq, Fq)> = (0..SAMPLES).map(|_| (Fq::rand(&mut rng), Fq::rand(&mut rng))).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fq_mul_assign_limb52", |c| {
+        c.iter(|| {
+            let mut tmp = v[count].0;
+            tmp.mul_assign_limb52(&v[count].1);
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
+
+pub(crate) fn bench_fq_square_limb64(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::rand(&mut rng)).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fq_square_limb64", |c| {
+        c.iter(|| {
+            let mut tmp = v[count];
+            tmp.square_in_place_limb64();
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
+
+pub(crate) fn bench_fq_square_limb52(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::rand(&mut rng)).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fq_square_limb52", |c| {
+        c.iter(|| {
+            let mut tmp = v[count];
+            tmp.square_in_place_limb52();
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
+
 pub(crate) fn bench_fq_double(c: &mut Criterion) {
     const SAMPLES: usize = 1000;
 
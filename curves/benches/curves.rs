@@ -22,6 +22,7 @@ criterion_group!(
     bls12_377_ec,
     bls12_377::ec::g1::bench_g1_rand,
     bls12_377::ec::g1::bench_g1_mul_assign,
+    bls12_377::ec::g1::bench_g1_mul_sum,
     bls12_377::ec::g1::bench_g1_add_assign,
     bls12_377::ec::g1::bench_g1_add_assign_mixed,
     bls12_377::ec::g1::bench_g1_double,
@@ -42,8 +43,12 @@ criterion_group!(
     bls12_377::fq::bench_fq_add_assign,
     bls12_377::fq::bench_fq_sub_assign,
     bls12_377::fq::bench_fq_mul_assign,
+    bls12_377::fq::bench_fq_mul_assign_limb64,
+    bls12_377::fq::bench_fq_mul_assign_limb52,
     bls12_377::fq::bench_fq_double,
     bls12_377::fq::bench_fq_square,
+    bls12_377::fq::bench_fq_square_limb64,
+    bls12_377::fq::bench_fq_square_limb52,
     bls12_377::fq::bench_fq_inverse,
     bls12_377::fq::bench_fq_negate,
     bls12_377::fq::bench_fq_sqrt,
@@ -69,6 +69,23 @@ impl FftParameters for FrParameters {
         268534165941069093u64,
         1121515446318641358u64,
     ]);
+
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(1);
+    /// LARGE_SUBGROUP_ROOT_OF_UNITY = GENERATOR^((MODULUS - 1) / (2^47 * 3)) =
+    /// 4745010758872139845238200295841730218141082559516036141034422680643841032105
+    /// Encoded in Montgomery form, the value is
+    /// (4745010758872139845238200295841730218141082559516036141034422680643841032105 * R % q) =
+    /// 6305670270485671394103200713230422010059347173612490824610048904823110729716
+    #[rustfmt::skip]
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
+        16312050644684472308u64,
+        16226885886700552844u64,
+        8981803609415491252u64,
+        1004551230217910552u64,
+    ]));
 }
 
 impl FieldParameters for FrParameters {
@@ -19,7 +19,8 @@ use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
     bls12_377::{Fq, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    templates::short_weierstrass_jacobian::Projective,
+    traits::{GLVParameters, ModelParameters, SWUParameters, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -60,6 +61,218 @@ impl ShortWeierstrassParameters for Bls12_377G1Parameters {
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    /// Uses the GLV endomorphism, which is only correct for `base` in the prime-order subgroup.
+    /// Use `mul_bits` to multiply points that may lie outside of it.
+    #[inline]
+    fn mul_projective(base: &Projective<Self>, scalar: &Self::ScalarField) -> Projective<Self> {
+        Self::glv_mul(base, scalar)
+    }
+}
+
+impl GLVParameters for Bls12_377G1Parameters {
+    /// ENDO_COEFF = 80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945
+    const ENDO_COEFF: Fq = field!(
+        Fq,
+        BigInteger384([
+            0xdacd106da5847973,
+            0xd8fe2454bac2a79a,
+            0x1ada4fd6fd832edc,
+            0xfb9868449d150908,
+            0xd63eb8aeea32285e,
+            0x167d6a36f873fd0,
+        ])
+    );
+    /// LAMBDA = x^2 - 1 = 91893752504881257701523279626832445440
+    const LAMBDA: Fr = field!(
+        Fr,
+        BigInteger256([12574070832645531618, 10005695704657941814, 1564543351912391449, 657300228442948690])
+    );
+    /// The lattice basis is `((x^2 - 1, -1), (1, x^2))`, so `(-b1, b2) = (1, x^2)`.
+    const SCALAR_DECOMP_COEFFS: [Fr; 2] = [
+        field!(Fr, BigInteger256([9015221291577245683, 8239323489949974514, 1646089257421115374, 958099254763297437])),
+        field!(
+            Fr,
+            BigInteger256([2417046298041509844, 11783911742408086824, 14689097366802547462, 270119112518072728])
+        ),
+    ];
+    /// The rounding constants `⌊2^256 * x^2 / r⌉` and `⌊2^256 / r⌉`.
+    const SCALAR_DECOMP_ROUNDING: [&'static [u64]; 2] = [&[9183663392111466540, 12968021215939883360, 3], &[14]];
+}
+
+impl SWUParameters for Bls12_377G1Parameters {
+    /// ISOGENOUS_COEFF_A = -15
+    const ISOGENOUS_COEFF_A: Fq = field!(
+        Fq,
+        BigInteger384([
+            0x6f19c000000008ed,
+            0xb06f79a0700004a3,
+            0x426363ff84d7a8cd,
+            0x49135dff8b7f9477,
+            0x5cd256899b9aa488,
+            0x1e23a229f7ff8b
+        ])
+    );
+    /// ISOGENOUS_COEFF_B = 22
+    const ISOGENOUS_COEFF_B: Fq = field!(
+        Fq,
+        BigInteger384([
+            0x9a76bffffffff2e9,
+            0x5a3e286faffff932,
+            0xdc25c143d08286d2,
+            0xe1cd141e77fcf991,
+            0x3167b6320cca6b5c,
+            0x63347edb6f8ed7
+        ])
+    );
+    /// ISOGENY_X_DEN = [-8, 4]
+    const ISOGENY_X_DEN: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                0x78aa4000000004c3,
+                0xbb1d1bb090000279,
+                0x60ec8942da31d86d,
+                0x73f3d01d8efc2280,
+                0xeb0c634543ffb46d,
+                0x9f7bc32f5f8ded
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x862f3ffffffffd9f,
+                0x2df720c9cffffec3,
+                0x5f036c766febb7c9,
+                0xd31784eab8fc7887,
+                0x6d97513d9450ca66,
+                0x875f417432c17e
+            ])
+        ),
+    ];
+    // The 2-isogeny `E': y^2 = x^3 - 15x + 22 -> E: y^2 = x^3 + 1` is
+    // `(x, y) -> ((x^2 - 2x - 3) / (4x - 8), y * (x^2 - 4x + 7) / (8x^2 - 32x + 32))`.
+
+    /// ISOGENY_X_NUM = [-3, -2, 1]
+    const ISOGENY_X_NUM: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                0x7c9ec000000001c9,
+                0x23497eb9b00000ed,
+                0x407a47331a918829,
+                0xa83712ccb57fea7e,
+                0xdf5d4481ebebba81,
+                0x607206ecb331b
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x7f6cc00000000131,
+                0x748a1e3d3000009e,
+                0xdff7fadca50ec81b,
+                0x2385aa8423fc4d83,
+                0x2c51da416c283f6a,
+                0x936d8251c927b6
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a
+            ])
+        ),
+    ];
+    /// ISOGENY_Y_DEN = [32, -32, 8]
+    const ISOGENY_Y_DEN: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                0x27687fffffffecf6,
+                0x41a24bc61ffff61b,
+                0xba349f540b4b2e49,
+                0x6476736fc5f99d1c,
+                0xe0447e6bc943c0c0,
+                0xde857f720bea1d
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x5da040000000130b,
+                0xd569117e100009e5,
+                0x64bec2dbaebe19b6,
+                0xb5ac66833afb7672,
+                0xe5f68754a35d887a,
+                0xcfb4c6a5b926cc
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xc5e7ffffffffb3e,
+                0x5bee41939ffffd87,
+                0xbe06d8ecdfd76f92,
+                0xa62f09d571f8f10e,
+                0xdb2ea27b28a194cd,
+                0x10ebe82e86582fc
+            ])
+        ),
+    ];
+    /// ISOGENY_Y_NUM = [7, -4, 1]
+    const ISOGENY_Y_NUM: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                0x9907ffffffffbd6,
+                0xaada2101ffffdd6,
+                0x1e892543555a2fa0,
+                0x2ae0721e037c8e09,
+                0x8e3a0cbba8650fe5,
+                0x81582105678e62
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xfed9800000000262,
+                0xe9143c7a6000013c,
+                0xbfeff5b94a1d9036,
+                0x470b550847f89b07,
+                0x58a3b482d8507ed4,
+                0x126db04a3924f6c
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a
+            ])
+        ),
+    ];
+    /// SWU_Z = -11
+    const SWU_Z: Fq = field!(
+        Fq,
+        BigInteger384([
+            0xf54900000000068c,
+            0xde669a6a40000366,
+            0xa166d075f4c36096,
+            0x1c2ae2ea447c0cfe,
+            0xca69a7c72feb6eef,
+            0xa582e39e2ac109
+        ])
+    );
 }
 
 ///
@@ -18,8 +18,17 @@ use snarkvm_fields::{field, Zero};
 use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
-    bls12_377::{g1::Bls12_377G1Parameters, Fq, Fq2, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    bls12_377::{
+        g1::Bls12_377G1Parameters,
+        Fq,
+        Fq2,
+        Fr,
+        G2_ISOGENY_X_DEN,
+        G2_ISOGENY_X_NUM,
+        G2_ISOGENY_Y_DEN,
+        G2_ISOGENY_Y_NUM,
+    },
+    traits::{ModelParameters, SWUParameters, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -81,6 +90,70 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
     }
 }
 
+impl SWUParameters for Bls12_377G2Parameters {
+    /// ISOGENOUS_COEFF_A = [158279976478981591358275464952031581739367066110732424779799482203522570432719931092658085312424550601849834083268,
+    /// 11757279919200457848995396340685298960877591011464903214341604307222857889946742718787311633013726950651455338731]
+    const ISOGENOUS_COEFF_A: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xf5fbfe31b29b6db2,
+                0x3affce036b9542dd,
+                0x39779b74d43e9e3a,
+                0x393e0e7ce81a534a,
+                0xe1c3cb5078f7fc57,
+                0x828a914661d752
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x65bc287b39778416,
+                0x4677df8e39baa519,
+                0xfcfd5be4b149d0aa,
+                0xbc36c6c0bbb504f4,
+                0x6d7ee5e86f030bcd,
+                0x18c698914841802
+            ])
+        )
+    );
+    /// ISOGENOUS_COEFF_B = [226276862585999712185683790875930776803030372378326530910576349831919269155768922121928777913825784436778827600165,
+    /// 194360686664440094726607231632689545130308957703164351383480890456330899450764919599901683027193365023998157055571]
+    const ISOGENOUS_COEFF_B: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa6f19ee6c928add7,
+                0x9f5e71121d2262aa,
+                0x4f457b61a2fd7d82,
+                0xd15d2b2ef60e52e5,
+                0xd9d7f070e60f456e,
+                0xf80253e99b87fd
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xd63d07d87383eb6,
+                0xd830cc77a1995abb,
+                0x90e17102e30f6585,
+                0xdd58c97c05d9e6d7,
+                0x53c724fdc585afab,
+                0x57434ebb20d97
+            ])
+        )
+    );
+    const ISOGENY_X_DEN: &'static [Fq2] = &G2_ISOGENY_X_DEN;
+    // The curve `E'` is the codomain of a 23-isogeny from `E`, with the dual isogeny `E' -> E` given below.
+    const ISOGENY_X_NUM: &'static [Fq2] = &G2_ISOGENY_X_NUM;
+    const ISOGENY_Y_DEN: &'static [Fq2] = &G2_ISOGENY_Y_DEN;
+    const ISOGENY_Y_NUM: &'static [Fq2] = &G2_ISOGENY_Y_NUM;
+    /// SWU_Z = [0, 1]
+    const SWU_Z: Fq2 = field!(Fq2, Bls12_377G1Parameters::COEFF_A, Bls12_377G1Parameters::COEFF_B);
+}
+
 pub const G2_GENERATOR_X: Fq2 = field!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
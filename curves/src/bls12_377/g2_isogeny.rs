@@ -0,0 +1,2875 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The coefficients of the rational maps of the 23-isogeny `E' -> E` used by the simplified SWU map
+//! for G2, lowest degree first. The isogeny is `(x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))`,
+//! where `x_den` is the square and `y_den` is the cube of its kernel polynomial.
+
+use snarkvm_fields::field;
+use snarkvm_utilities::biginteger::BigInteger384;
+
+use crate::bls12_377::{Fq, Fq2};
+
+/// The coefficients of the numerator of the `x`-coordinate map.
+pub const G2_ISOGENY_X_NUM: [Fq2; 24] = [
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x783264dd56457402,
+                0xde03ef514a4d1bbd,
+                0xfba1b01e3ce7958b,
+                0xef22675210a2c75e,
+                0x7cb42b18ef4020ce,
+                0x2c7101afb2b99c
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x150fad361c858bdf,
+                0xe0a046217f085076,
+                0x89c99e38b212f489,
+                0x777a21292d692a9b,
+                0xd2291b40aec33699,
+                0x3e2bcbdf3fae92
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x50c956eb74651184,
+                0x33f3acbec8d30170,
+                0xe9559b48e800d6d9,
+                0xcbe57816636f5f1,
+                0x16c4d063cc5c3b72,
+                0x30c8e1c968a93f
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x4f94c62016f966e2,
+                0x6fa968f0ae16221a,
+                0xb58741459e88535,
+                0x19bb23fa38153036,
+                0xf6909e373e41a536,
+                0x1316807708cbf98
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xf0eaf1c3c7344a1a,
+                0x7b9fa220ca95bae4,
+                0x4296b60b29b4deeb,
+                0xc0e75e68e9503c2d,
+                0x70fa40d75afdac3e,
+                0x994ae997eda3e4
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2f642833379cba92,
+                0x8dcd2390f8c046e1,
+                0x8cc23b20ada10f56,
+                0x874adcb2228d3b8,
+                0xf726c5c6217d6936,
+                0x158e515e690814
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x6fcf00a6aa5154b3,
+                0xec198296af2fdcc1,
+                0xfff3b1f69a5bf2d1,
+                0x64a8272a9bf6d140,
+                0xa76668175b98757e,
+                0x11f7a3dd9d5aa10
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xe63618e8c991ec3,
+                0x4201fc1e14bff2d,
+                0x469cf23e0565a704,
+                0x65af0d8780894871,
+                0x1aa0803ed47a055c,
+                0x70647cd67ff899
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xcb1f0098903995c0,
+                0x42f9893992a61b9a,
+                0xa2ec98f64ec13ac6,
+                0xb6994e81da3ff556,
+                0x4c2641c6b742a468,
+                0x8342e8988767ec
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x1759cee781db9038,
+                0x236fae621b958f06,
+                0x1cffbb910418b940,
+                0x915af33336655c9f,
+                0xf112b71904165fb1,
+                0x179bf6cac06f21a
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x944bd5252516afcd,
+                0x6d02b65ad0505ffb,
+                0xaee0964dad928011,
+                0x7ac7738dde8b53e7,
+                0xf1dffb1d8173e6dd,
+                0x10e303ba68db8cb
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x6cc3e79e36fd7fa7,
+                0x6ee6b496434b5a94,
+                0x4f90efd0d02190bb,
+                0xf2d11755bfeb2e7d,
+                0x4e9d89fa7299e263,
+                0xac4e63ffdff654
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x5a0d08d2a7a70ddc,
+                0x7b0e0c503fdc505c,
+                0xfb90114f7f1be07a,
+                0x66a86cd214360312,
+                0x6b8c217f9fc594c8,
+                0x115e4c7df07f248
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x1dbd3aed15346de2,
+                0x32a9a63b0f4c951a,
+                0xf5fc1d257bce9e7a,
+                0x957f219bf83431a5,
+                0x76f70bdbf35a3bc,
+                0x52e847f0f9c168
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x766656374a0ef9c5,
+                0x6299f60d1ec8a865,
+                0xa989b9482758a53b,
+                0x6b33c751b3a15762,
+                0xeaf0b773f3e78201,
+                0x1a0ea6aa1c0ec46
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x9322a9056bebfb3c,
+                0x943a7edb2a443b69,
+                0x12ebdd611fd32a09,
+                0x83fefadbe0ea45d,
+                0x6d129cb340641bff,
+                0x16696064680c86a
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x7b77e249c438dbc3,
+                0x8d0aaed6ea019322,
+                0x7863ef92e6082844,
+                0x8f12452ece6dec67,
+                0x1914996500b76d70,
+                0x119ce86f28b0389
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x244a847839bd849e,
+                0x7e2aed87bee3781d,
+                0x9d56ea38178aa11a,
+                0xc6ceeccf47f470f1,
+                0x45242d76b00d02bc,
+                0x11c3db9ea21f4f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x1e426e4d30ce717d,
+                0xb300e1a576e1abdd,
+                0x440a8830870e1f5c,
+                0x185ee2e5b8297d70,
+                0xfcc931b9a5f2e9c2,
+                0x182f1ab28afca7
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x45022204602eedd3,
+                0x23b72d61002fbc8d,
+                0xb8c4f04fad2bad12,
+                0xd9bdaa28ae922c2e,
+                0x80b844e5c8730a4c,
+                0x164d34643eab5e4
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xd9d3ae614e56793b,
+                0x165e6a728b4b77bc,
+                0xbc9c90302c5dfef1,
+                0x52d16b8194fcb6c,
+                0x57f5e54036544cb5,
+                0xf434296b4ff9e5
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x7b2a7c1103a70eb3,
+                0x721cfd1e0f528508,
+                0xc956a85c680b2315,
+                0x2868de8ee92b2ea8,
+                0xc1777f3fd1440a44,
+                0x90c68de864dbf2
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa4a7912455837121,
+                0x9b3f720bea9de49,
+                0x9481e0320ccd8f36,
+                0xea0b92d8b504108b,
+                0x8ee58d6f69f2fc7c,
+                0x4b617b5ecdb17c
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xf9063dc72cb0958c,
+                0xbc921ded0976338d,
+                0xd6e055206d99527a,
+                0x7f50ae2626c69e5c,
+                0x5ad608696b4ec802,
+                0x15fdbe0a0655ff5
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x47ef81230e484536,
+                0x529e6c0368a51753,
+                0x22258a9687a4864d,
+                0xa21c8ce37e470339,
+                0xc01022febc4ba710,
+                0x192d1b46deb849e
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x15323e9383e7d0f1,
+                0xaf1b89b3e066265c,
+                0x4e05d38e2cc2980c,
+                0xce8ab66734bdca0f,
+                0xd318f889b2794ac6,
+                0x329f503638cfdb
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa88d525b28a21b92,
+                0x3feb52156065e7b2,
+                0xd4df312051311304,
+                0x3078f35e8185008a,
+                0x5635f68c2c61decf,
+                0x15a391a37b6813b
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xb1c7eea70a583186,
+                0x434c08f75a016cfd,
+                0x1a7eea10b7b951f8,
+                0x40a3c0ce3cdfcf42,
+                0x24888a25a0f09a13,
+                0xbb7e66cc873156
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xeaddf87957d81c41,
+                0xbe5a7dad7fe823cc,
+                0xe2d5f8df4ece114,
+                0xf4d67f16ddd651bd,
+                0x311b9e570a156221,
+                0xeca9dfec67671e
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x27d5f668447c299,
+                0xd393fda587594645,
+                0x83efeb3c68f92dfa,
+                0x20db8ab4c14fac04,
+                0x194662bbcb54a44e,
+                0x1798738e1fe35ac
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x2ab8ecb5eb147d6a,
+                0x3d40cf9e2433db67,
+                0xa6b2c0938b24d43,
+                0xbfd10a4b0f44a7f6,
+                0xb768fb87ed42f639,
+                0xa7fa789ac5ce11
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xbf021e8559c3c584,
+                0x4cc10e1f2acff760,
+                0xd6786f2dd4e01d24,
+                0x92671201c8bd7224,
+                0xcb5276f1793d71b9,
+                0x5f95a8eb09dde1
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x1d5264f4e5d74b8f,
+                0x72e1acecf0ea40a5,
+                0x68652ce58478c8d7,
+                0x4f76902d4da9967a,
+                0x8d62e57adf0ac085,
+                0x71c068b4a453e3
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x5aa607b80191c564,
+                0x9bd09607c7fca963,
+                0xa7883bc2627d4b76,
+                0xeadd98e6b8bd5001,
+                0x64385af0354387e4,
+                0x1a82c497413c196
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xdf4e1df0f5735246,
+                0x373a7348de4916aa,
+                0xb5e18e773dbf95d0,
+                0xc386e1158ee52b13,
+                0xb78a5fec8514f63,
+                0x78c3e390c1aad8
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xe791ba17ec127297,
+                0x3ae80fd2ae61ebd1,
+                0x54e2fa98216a2bbc,
+                0xf1247a4848efec82,
+                0x5d02300023e3702,
+                0xb6d6d2b06915bd
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x45734747eae4260a,
+                0x23b64318495f4d09,
+                0x8e91a88e2ca609dd,
+                0xef6d17a63065916e,
+                0xeb46dce9e3baa913,
+                0x640ad6cbf29d8
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x52b54a1ddaa64cce,
+                0x9bec448af6032cf,
+                0x3245ffebb29145b,
+                0xaa9bacb005bd0641,
+                0x3dcfc5194ff5c15a,
+                0x18b47ab897749eb
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xfbdb452d8e06364f,
+                0xf4059b1662868078,
+                0xe24c4dc7d2fac30b,
+                0xb4c1cf0f2c8df3c9,
+                0xd870184255f08655,
+                0x8bb0105d4956a8
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x7cead776e32cd735,
+                0x5f93c8b81a38c950,
+                0xa7240424a3d858f1,
+                0x30995a6fb6707d43,
+                0xffc71cd1a0e40ada,
+                0x1abd519a3f4da44
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xc2d295f5e409fb27,
+                0xa14d58d868236267,
+                0x6b0f2da6e55e0512,
+                0x62dbb91f7edc5c99,
+                0xdff9a7fddacab481,
+                0x118b8706632779c
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xbd9fccfdc792b3e0,
+                0x2dc7ed8d0004a8f8,
+                0xd4574f58b5ca3988,
+                0x1bcb9c0f17180411,
+                0x3340185786567674,
+                0x5c63c66d99f2e4
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x1cd86c6d061389fd,
+                0x33ff4ff1d265720e,
+                0xdd833ab7bac42d22,
+                0xf1afc79e2613b60e,
+                0x24cc6e3ad843a918,
+                0x18e71e2b09d8fa4
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xe6a3f7493dc3249f,
+                0x53b57746f062a31c,
+                0x8fb4bd48aeaf1a16,
+                0xab52801ec5f388e2,
+                0x805808c30c3575c0,
+                0x180e546288f20f6
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x5abb2bd83326a610,
+                0x9229b78a70eddfef,
+                0xe5d62f9d2d2e4d2c,
+                0x13aed5c297944c36,
+                0x71d42a99b7fc2059,
+                0x149c40d663237ce
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xf5e610cf2751a4e7,
+                0x6f915897d3ea6a5c,
+                0xaf6d07a3063346bb,
+                0xae9f9da22a5c4565,
+                0xe20fea41964ece76,
+                0x112ab0fdebaa038
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x3242df55a7eca48a,
+                0x105e7a39c4e65a03,
+                0x868c02141c568769,
+                0x4378c64523ddc744,
+                0xd92a719398a84931,
+                0x16e3ed4bb9b0cea
+            ])
+        ),
+        field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]))
+    ),
+];
+
+/// The coefficients of the denominator of the `x`-coordinate map.
+pub const G2_ISOGENY_X_DEN: [Fq2; 23] = [
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x8058039abc0a6ef0,
+                0x5739edde8f9def9d,
+                0xce542f91d227353d,
+                0x4960f09b0a83a6be,
+                0xdffaeebf15b999c3,
+                0xdc3e34137b84d5
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xd999500b0c305259,
+                0xaec195bf3e1b66a8,
+                0x87f4008ad90c2eec,
+                0x11d7beb637036b80,
+                0x944b38be7b9bfef3,
+                0x1ed003cc65e526
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xed5da4e6938db55e,
+                0x8e2e3b280b56d4,
+                0xafe2aeb4c23e571b,
+                0xc8bd1dc2872341ec,
+                0x212b166293f3500b,
+                0x191fb24351431c6
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x11e1fa3b1e0d7720,
+                0xe12405d000cfad56,
+                0xd9212cc1e57105b7,
+                0xefaf0cbb4bbb6894,
+                0x1960304964caddec,
+                0x50ec7e3335448
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xab2cea2a7fc404c8,
+                0x49aa1d4a7d7dbd85,
+                0xa6a60f9b74284c3c,
+                0x7ac3824cbd06776d,
+                0xd7cc453d4603fe67,
+                0xa04b39d76f8b96
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xaa6b32bd6da92e98,
+                0x3e3f52e06763d34d,
+                0x3676641cf79645ee,
+                0x6f7c6409d4ccea2a,
+                0x4d24ba7855bcbeea,
+                0x16bc8c5159dbdcc
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x70b157ccc3fb8bab,
+                0xf8c60c4f08f83db2,
+                0x98e01c093bcae662,
+                0x151a550fc3912ac7,
+                0xdb8b82d8a5c3c7cf,
+                0x1695166faf3d8b6
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2f0465dbe0513ad6,
+                0x353b0060b6c0b143,
+                0x6b8221c6ade1a239,
+                0x144b405227b6eeb,
+                0x2f24e172961f1bbc,
+                0x187d394e3340baa
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x857593b524fb9749,
+                0x5865b14a89a62b7a,
+                0x5c803f9065224f0d,
+                0x24ff8bea7ad6e69d,
+                0xe1abcbc870001bb5,
+                0x347d5f60e4cfa0
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xba176fac568b0d14,
+                0xf090be0d37442a20,
+                0x2fa6dbe15aef8d8f,
+                0x227a56de5d1bbc25,
+                0x59ddf6743c6a3248,
+                0x13bbd3f42dff13f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x1c6a33f2d771f475,
+                0xf2e678aab73a3127,
+                0x4f9ff9373c398e7b,
+                0xac56960cee77d87e,
+                0x327dccf16972ea77,
+                0x68d0483e0d28e1
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x6c9c8120db62bebd,
+                0x56de9745c511b386,
+                0x8762185386c46724,
+                0x8c66612ffc506460,
+                0x6b7c444ab6855390,
+                0x15578284714becf
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x49d6807f1a6d397e,
+                0x2974155619d9ca89,
+                0x1d733e6d98ff8b36,
+                0x547115ecef8a16a2,
+                0x462859e8f5be316b,
+                0x12309cb49473128
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xa8d40eaca97dbe36,
+                0x9745d19df0ff9c7f,
+                0xcc22c499c42593c5,
+                0xde4433a0558d5ba5,
+                0xccdeaa7f1e0c3d3a,
+                0x48cc8580ba3138
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xd1a4b6cf1548fd7f,
+                0x7330ed97983e8fce,
+                0x15eba8744f86d5a8,
+                0xa8c77962b95bbfbf,
+                0x8f8484870bd21ac7,
+                0xb3c3e781e32a08
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xa9e69212faf08c42,
+                0x2ba3843a327676c3,
+                0x66e179598f4daf17,
+                0xa356fa3977d5fceb,
+                0xcb9692aca560b15d,
+                0x5229c70c0f44b
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x64038bdc517a1bef,
+                0xd558bbcdcf4c1a29,
+                0x61df49ec1b3c2af,
+                0x75d548b7d7cbc93a,
+                0x88abfae33046ab65,
+                0x154bbb3a78e1f1e
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xb84c3ba843af90b2,
+                0xdb0d15693a3f7ce8,
+                0x70a83d8c35e44787,
+                0x38fab0c1f2f92625,
+                0x383a3fe82ef74d5a,
+                0x3c19eb7313583
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x2b824b98be5d80b2,
+                0xf3cfa88e13d4d593,
+                0x7038a04758a6a121,
+                0xe4fc6202c8b54cb5,
+                0xf6fff108c25fdc78,
+                0x7bce0b49589606
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xf5cc815b22b01865,
+                0x35d9f85c226c28e2,
+                0x36d134ff9422e8d,
+                0xfef289d849d117c7,
+                0x43354832656dfc34,
+                0x6331888fe35a65
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x647250bdccf21a7d,
+                0x1ad7532a7175838f,
+                0x68c1e561881d7efb,
+                0xe18ab947a4400730,
+                0xf1367734251b4125,
+                0x6118aea190c3c6
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x9c6cc8a71c051c93,
+                0x9141b637af2dc102,
+                0x53235bb5d2139938,
+                0x20e719f40f1986a6,
+                0x200be41cdc198496,
+                0x186ac1393df24bc
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x160421186dd781ac,
+                0x33450c116f30b3a6,
+                0x35543202f2859fcb,
+                0xd0f88a1e32b4a80a,
+                0x82031258038825bb,
+                0x679080df79b751
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x55861559f1f7a17d,
+                0xf86989bde04d4057,
+                0x79c23e88a6f36c3b,
+                0x37d6cbd33379691a,
+                0xa7a975cf5bd4f9ef,
+                0x1063ffc44916d47
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xbb5a5997432c7c66,
+                0xb35fee87dd587423,
+                0x2b4fb3f83e8b84ea,
+                0xc1f957c587c87b3d,
+                0x4dc71f0edf91b4cc,
+                0xa8de786e324ddf
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xf033dba9244d4704,
+                0x756e98090e7565aa,
+                0x8e8727e439c72b89,
+                0xe66e9d445dd1a046,
+                0xb7ca368065c3d7f,
+                0x54a53c5795ddf7
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x8f4d6eb8dbaa58da,
+                0xa24e9d1afce9cb81,
+                0x683005484badf850,
+                0x12019e9b82336299,
+                0xb669a32cb31d113e,
+                0xacf336057f03d1
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xf8ddcf264b32b52d,
+                0x10e6fbe6711911ba,
+                0x629a751641a75a0a,
+                0xef1045c441b19f1e,
+                0x2b2569d02605cb91,
+                0x7b130f0fee881d
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x565c6886329cc6f2,
+                0x11773d580695af40,
+                0x3de8a28630ea5814,
+                0x8ad8125daa1d02da,
+                0xf6de50c400432c77,
+                0xc09b5e003af599
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xf997968c418c826b,
+                0x942d78d0905ea272,
+                0xd5de3fa1a4acd826,
+                0x9933365f93d48c5d,
+                0x5a79adc3ea6ed7f2,
+                0x15569617895d445
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xb5c03f103cb183b1,
+                0x6d4d1fdd6ddf9bf6,
+                0x57b856dc4932eaa4,
+                0x959e16d8977c9165,
+                0xecda1e1a2a795113,
+                0x118082c12e78dfa
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xabd8c9dd4b60fb69,
+                0xc290252bc0200db9,
+                0x6ef2a5b45d81967c,
+                0x7af6a3601f122f6f,
+                0x1d8121722fa0b0ee,
+                0x16bd54c7eff48f3
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x67646008d1cffe5a,
+                0xbab0e29143b45274,
+                0x724f61513bf35458,
+                0x373d379fa4dc2bd4,
+                0x6099f598fae4d097,
+                0x67da4bd8ec99ef
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x93af9b3e51a4c41b,
+                0x90817ab6b0ad48c4,
+                0xa555516a257de360,
+                0xc3c6f91d70a24c07,
+                0xb968b07776c9cad6,
+                0x107eb5691f5227f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x541d9784397bec8c,
+                0x32e4d52582f38386,
+                0x30efac02d85ff2eb,
+                0x295e1a475ef0b400,
+                0x8ab425554f8b4f6f,
+                0x1813d341a053705
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x16de94a1bfa1ff9,
+                0xbb30ee192da47c70,
+                0x6c339a69c7c0040c,
+                0x93d9d236892403fb,
+                0x8d0aaee622d957fc,
+                0x8819385b0bd7e
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xbf6a01df0284276c,
+                0x3953ce601ee4f4fd,
+                0x5c0de3e7111df097,
+                0xb8d94c02b37ed5c0,
+                0xfd252a1379abfbb7,
+                0xb2af4dfd64ffbe
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xdb39a961cbecd7ef,
+                0xce7f142bbd0603af,
+                0x7fcff39d2f5712f4,
+                0x3665c84811986ec0,
+                0x4865556b014b7d85,
+                0x6f7aa43401ae05
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x8af6e8f9928c6ae7,
+                0x1564cb8376e9d5ac,
+                0x547757c5727f4555,
+                0xc5cd151b19c45934,
+                0x20be1e326bed23cb,
+                0x11b022c5227cfe5
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xe27e3f9dc674e6a2,
+                0xbce6b800a12bdd8,
+                0x10e9eb269b9dee25,
+                0x220c41ad2aef61fa,
+                0xf13680d7f895517f,
+                0x5a73b86d4659d6
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x2fb00da604410bf3,
+                0x543eaa4a7ae93a65,
+                0xe59bd4e42cc7d70f,
+                0x6487da284533bbf0,
+                0x8c673885d7292c3e,
+                0x6fc6de0b6b7fb7
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x720763ffb26c37f,
+                0xff51650ce9e0ecbe,
+                0xa4c3ae5788d8e16f,
+                0xb86689f227529df,
+                0xed801cc87c3e94e9,
+                0x122f5202202adbc
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x5edd9c1b2dd257b,
+                0x9339b8336b8bbcc8,
+                0xf88e0e490bfe90f5,
+                0x5324e9aab5b98c04,
+                0x9c0bee3954d5fd91,
+                0xcbeecc93018e2d
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xffe3fc103fb5c006,
+                0x35624af9b765c968,
+                0xc1e8870be9bd6133,
+                0x6ff3d335460ca7b7,
+                0x2f307f3898873c71,
+                0x138c585f539d07c
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a
+            ])
+        ),
+        field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]))
+    ),
+];
+
+/// The coefficients of the numerator of the `y`-coordinate map.
+pub const G2_ISOGENY_Y_NUM: [Fq2; 34] = [
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa67d99c4868342ec,
+                0x5f915c9b5c863011,
+                0x36b4bb8f9ddba794,
+                0x8279db7f4e766a9d,
+                0x42e829c694d9f081,
+                0x151cab5124b35f2
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xdc1a8547edb13b30,
+                0xff9a03fcfef884d0,
+                0xf4b51f00fa67bc13,
+                0xfe6fe74015985fca,
+                0xe5de36f50f70f991,
+                0x1828e710b9bb480
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x256c1179c9c8184c,
+                0xf2fd4657b7800338,
+                0x1f87103c3c3972ec,
+                0x5e7afe69981acfdc,
+                0x2654213394c84e53,
+                0x80ccf77b280960
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x1c29e74ddfe9d99d,
+                0x1c22c6da879c4fa3,
+                0xe61aa0f82bfc920f,
+                0x3b156fa8e1d5675d,
+                0xba583c5e448d7c27,
+                0x857822995784b0
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xed154d2ded10f4ad,
+                0x926a0c3f3779dd23,
+                0x1e5e76da57c8c001,
+                0xb3f283f2b3e7183e,
+                0x3cd6c8ee68f78b87,
+                0xc0c0a9270eed96
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xe79b30778fa1fad4,
+                0x64ef75ee61b682df,
+                0xd9c0c66f46170784,
+                0xb0d416906afb9dbe,
+                0xa6f2d526d5e7ebdd,
+                0x1a776a714c183be
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x9a1553dff0f0a2bd,
+                0x64bfa9800fe5453b,
+                0xdabe4066866706b9,
+                0xa5e3f762476a2e86,
+                0x421316c15ef585a4,
+                0x3ea6f51a32df97
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xdc4bf2fe00fc3c91,
+                0xf422d6de6e6f10a5,
+                0x755d921dd0746c46,
+                0x93faacbcbe4998d2,
+                0xca393ed7999d4786,
+                0xdc7385e8758a1c
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x53523bbc5d7551e7,
+                0x61b7fa78c75b7eff,
+                0x938e7f006c45ed30,
+                0x73967f14fca6e835,
+                0xcc5edce4d7e52813,
+                0xf40d616d894aa
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x984e7558a211cf8,
+                0x63d18da4bcb9568,
+                0x46c5ab1e03dc2b54,
+                0xfebc2e144d1c551d,
+                0x1d5516d9fd4af847,
+                0xdea6b3f832e98e
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xd6afe75a6a1635e5,
+                0xe8aff5559a5ca0f1,
+                0x3ec5261f8e602234,
+                0x188ad5b714d2cdd5,
+                0xd49d20777844d9fb,
+                0x96fa7ad47fd73d
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xff70212e8b9f8639,
+                0xa953800047a69be2,
+                0x57a0e0723d47cb6d,
+                0x60154645bc063798,
+                0x4f783a9788720b3a,
+                0x18166999e4a6fce
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xe2d71cbfd60dd4c6,
+                0x3a62e96aa6fa4c38,
+                0xd093d88ad731e9aa,
+                0xaebe9b13e2618db1,
+                0xb088e1cfc41d0e8,
+                0xccc6be84a6bb85
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xad3783cf6242e65,
+                0x1084401ec6b79b7f,
+                0xaeb9a70b824a30c5,
+                0xd459dcc5be8b0577,
+                0xe4f8c149c83210f4,
+                0x1671a635dcf458d
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xb6473e718061fe8f,
+                0x2aa4ed844e9bec63,
+                0xe20a53ee81ff1999,
+                0xe539f1216fb961f3,
+                0x9f1799333a6ba6a1,
+                0x107724915ddddd7
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x42573562631cd9ac,
+                0x788199b3489fef05,
+                0x6db48c912481caa7,
+                0x3af3e8d87f0db122,
+                0x1241a84053b26b79,
+                0x69d94e58d3abed
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xf50825953afc4a80,
+                0x8964bd80d226f998,
+                0x77cc1d38dbcc9a6e,
+                0xc367bdfe154219ed,
+                0x13caf394c7928baf,
+                0x11458d859127b97
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xbecb550af260413,
+                0xbfb71aa0ada53660,
+                0xbb6eed6fcd8745fb,
+                0x8a9b6512b2ac30a2,
+                0xb62c9c6b39e35d8a,
+                0x53ad554eb5b19a
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x1bfebeb0de7e7f2f,
+                0x31f3d5b60826cd82,
+                0xe78beef502dcb198,
+                0xe02f7e34684e4e1c,
+                0xd7c1e5f5858b3ace,
+                0x121eb8865c56b1
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x8dc24bb85e203e93,
+                0xde85d5f25e0bc5bc,
+                0x40cf6f867ac0101d,
+                0x8ff6dd557f414952,
+                0x7c3f8299029be02b,
+                0xab8d429c5b4aa1
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xceb4d311f99614c4,
+                0xde934fc447a80ffd,
+                0x3dc7b4e4553affa0,
+                0x754df7b4e8f7d7e1,
+                0x13e1f8bdf3af6314,
+                0x1104caf4ac6e403
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x291e54d29db54bb3,
+                0x6d36f019142f0b82,
+                0xe9ea132d08c6963c,
+                0xa2d382699ecdfb7c,
+                0x97709fe1e4f7f0f2,
+                0x1a110a416bdc72a
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x2160b094174f75d4,
+                0x8b54f4cbc1a0de53,
+                0x6ee2740d618646a2,
+                0x4fed61600509f9df,
+                0x57b7cf8fe28255ef,
+                0x1720c48b1ff5ddd
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x28f5d26773b04ea5,
+                0xda13c1292c92180e,
+                0xbe088e6167b99584,
+                0x53bbc386390a0e2,
+                0x4b7435bda1a111d6,
+                0x2f53da4d545fe5
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x6eee994979e5d386,
+                0xf126c9334e53bc90,
+                0x47dbb47f647644d3,
+                0xb0f9b18dde2a10ad,
+                0x831e132f224c2ab1,
+                0xf54ca6943ad17
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xc4d24ec5a72dac32,
+                0x2fe19f4815551633,
+                0x263d2212707f1f03,
+                0x74d303a80d9c8b1f,
+                0xbcd6ae5bcc88a396,
+                0x12165eaaee70b6e
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa191548a9a5cb2fb,
+                0xb5a78345906f713e,
+                0xf48cc4094613b7ac,
+                0xbb2dda485ee0328d,
+                0xc2d1db2098b4aafd,
+                0x76f5579a3c9dee
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xde23370cbf8427e5,
+                0x2a01ce4d49c09a8b,
+                0x637b2f92eb5e0d2e,
+                0xcf4799a090ff87c1,
+                0x910a3b8dbabf4554,
+                0x1a8cf8b7d992fe3
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa0966452379fb6bb,
+                0xede84d5ba3259098,
+                0x86184cdd52c964c,
+                0x2af5b374262dc941,
+                0x9a41b43925248bc0,
+                0x170b555b1d901c7
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x844fd38038f89c7d,
+                0x2fff3ff1d45f0921,
+                0xd4d342936fb37e1,
+                0xca0096fc22b17141,
+                0x4b7219420f7ae2a8,
+                0x22cb9d8fe504e9
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xd3cc0171cfb3c454,
+                0xc2d09371117b5d83,
+                0xede1dc9d83b1be50,
+                0x39bc1f4cc6d24e8f,
+                0x179578c88f498902,
+                0x16c88b5023c2d6
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xc66688c2961b36e7,
+                0xe4dbe314ef93ff1a,
+                0x1f730571d6302ded,
+                0xd4d9227b65e9219d,
+                0x47a4af79cde6b76e,
+                0x1a7c5e862eabd8d
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x2bd2a50094272971,
+                0xd0b34ca86cd69d37,
+                0xdbfd6ad5c54a7a65,
+                0xb6e8963a38ab5bae,
+                0xa55adad401e3704a,
+                0x748a85a261d440
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x8b4ee7b6315d33be,
+                0xd7c24036b51d9ebb,
+                0xa9145727d540ab1c,
+                0x17c9948c71c42726,
+                0x9d24660ad373c2ac,
+                0x16ad24e29187d33
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x74ce0d1c1d516ba1,
+                0x3072918195d1b373,
+                0x7b358d0715a961b4,
+                0xe5d3ead388cd7fc6,
+                0xe28fdff81ed7ddc7,
+                0x15f04eee2d0205b
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x3a95159fac7039ae,
+                0x92943a4b3c3f672f,
+                0xc596c6c8644e949b,
+                0x89c4c56ab2191c14,
+                0x7136049c787f369f,
+                0x1569f1272a020b6
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x904aa37c19b99b8f,
+                0x7376cb85b5eeafb,
+                0x196aaaf776b7c785,
+                0x378b0158f4b74e66,
+                0x920e2176c971995e,
+                0x12e7319b37c3bfb
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x17a1101db3ab7ee8,
+                0x47d185d9eb9105d8,
+                0xd63c3cf4f0f8da49,
+                0xd541793d7b5e0557,
+                0x7fa146e0abe4f1f2,
+                0xf4c9bcbebcbf1f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa2e595b36541fe14,
+                0x39e33fbde95e8f2c,
+                0xb60853d8445a56ab,
+                0x8361700afaa8385d,
+                0xeb5bd2def5dcd8e2,
+                0xecf1688e440cc9
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x23f3bb3cd630d1d6,
+                0x5c9632d520e27805,
+                0xf8e391315b29e74b,
+                0xa3a9d2ac12397ffa,
+                0xa957fe05f00bbe2,
+                0xb5e6dd6be56e3a
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x5fc330f7b68a055,
+                0x96737234d7c0720e,
+                0xb55c36291230c305,
+                0x76ffaefd42643b14,
+                0xfbf1c8b41e118814,
+                0xaa7e6f6906ee4d
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xb0b7560a4cc3ff28,
+                0xe5449adc4bcaee95,
+                0xfc5cd680830a31b9,
+                0xd5fd66bca87ef1cc,
+                0xe8f06a49a32f8bb2,
+                0x292eb8ef60d593
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x62e7c2a6a86873a7,
+                0x3292bebb8ca1249e,
+                0x92776cc79534a2a9,
+                0xb6e5a701ae0ac313,
+                0x4f1dbcb7d91ef31c,
+                0x233b0fd35e9227
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xa5f1ebd58012065,
+                0xda6588a7eab1947f,
+                0x1dba6fc810bb1b0f,
+                0x2eec13dbbbb87754,
+                0x4b66ea60436fb1b1,
+                0xa9910f513138f8
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xcd8235f0da0ec4c4,
+                0xd0d4b79761d557a4,
+                0x17891ead7b2828bd,
+                0x6c0c9be4b6cca1f9,
+                0xde4866d7d302bb12,
+                0x772a8654bfddb3
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x5d74538867faad7,
+                0xe8bfd5da2dda6127,
+                0x9d8e4d2deb090483,
+                0x9f7f9e2884eec078,
+                0xcb0c60bc6994fe12,
+                0x1624d928ad578e1
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xaf645d596d2d4cb5,
+                0xe030fad24a5f58e6,
+                0xd07f29f5ce2a4932,
+                0x805715ba7e782e47,
+                0x51cf2cbdb7ca28f2,
+                0x1493fdd36e7f0a4
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x727f2662e8ca85d7,
+                0x93aec3eb07bd6550,
+                0xb17beab658cf13f5,
+                0xfa96b8a818a03cec,
+                0xc3508d282f3c86,
+                0x25f3fcc9c2c93d
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xc5ce584f9c45c701,
+                0xc3752c987288d8a5,
+                0xa841668ec1c7108a,
+                0xd37017de7fed528e,
+                0x51962b57d7415736,
+                0x125463251b22d9e
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xc1c8e452bf1e6539,
+                0x851a882c4e27eecd,
+                0xd00a855ec6b36a23,
+                0x11dc6d479b1eb548,
+                0x9294befb7a9fe953,
+                0x8787176f238dc2
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x62c7c1a0402010d0,
+                0xb6de12db85917da0,
+                0x516d089682fc9307,
+                0xaff88d2633bdd37,
+                0xf675e58b50770110,
+                0x12ad44af1fb7528
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xb6d7bdf168bbee59,
+                0xca44d3a07dd8a2e,
+                0x314f58d83fc8660c,
+                0x203038a9a4df9768,
+                0x33086344767dca43,
+                0x151a52c215681a
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xd05d990deb91ab76,
+                0xb007980be1fb0ac8,
+                0x729f53cc4dc25072,
+                0x9feba2097f43cbea,
+                0xe197065f32b9b307,
+                0xda59c8932b97a9
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x94cc076a0f6b2ccf,
+                0x2d43aa6a4be15c9c,
+                0x62b6b9a60f9d31d7,
+                0xfc75423986778a70,
+                0xeae13470fef7bfc4,
+                0xa7def87506e79f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xeb502ae93abe8a74,
+                0x728706d6c00794b1,
+                0xce3e6c3a9aa13b87,
+                0xda85a6de6352b98b,
+                0x13369b04e468c21e,
+                0x1366b4678ab3d9b
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x59ffd6e7f7df12e7,
+                0x40a96f2c8792bb0a,
+                0xe019f93f86d47f84,
+                0xf49c56a72c02cebd,
+                0x1c7ea4b53003c13d,
+                0xa33f0d344bb96f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xfd736133858a3bfd,
+                0x28e0d0ba03c940c2,
+                0xf68a1be348c1ecc6,
+                0xf8728237366ccf1c,
+                0x2bccbc6c50ec4d2f,
+                0x13a9ee46dc7a432
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xd37ffecaa3bc987e,
+                0x195736c8829e7649,
+                0xa9cb148ced802a5b,
+                0x4eb56d4e04b74da5,
+                0x19e504d7127eec53,
+                0x2fc3722bf86ead
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x44a5fd598a0415c4,
+                0x3b1ec82b820429ea,
+                0x82cc0e2763771c85,
+                0xb933ecb02d686082,
+                0x3ce62b8b5301332e,
+                0x19136e50f7ea446
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xc492d4768fd83955,
+                0x1fee7fe6ed2a0d92,
+                0x50f32b43324cca6b,
+                0x70088da3c605ba2,
+                0x8745ce3baca5d7f5,
+                0xa0e1567610bace
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x97a91b2cbe61b254,
+                0xcf93b168d5f4788b,
+                0x6f6d0487b21f2c47,
+                0x6648366a6a02e50a,
+                0xbc89fcba34d7343d,
+                0x16f869ed21383a1
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x6af7834d93a1830a,
+                0x5aee67b622ed81ea,
+                0xe73aea59301b9980,
+                0x2fe4eacaa06dcbb3,
+                0x4a0b7465ec65df72,
+                0x1225442ee738306
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x8f2a7c6703a29cd5,
+                0xbd4f13a4a4c2c607,
+                0xcba359474f31673c,
+                0x13b6f05a0479ec34,
+                0x95b4b533238c2d42,
+                0xc0c6d3cf2678f5
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xf252d95c56edf057,
+                0x9cc713ef4978c9ec,
+                0xdd3f3852e2bc07d9,
+                0xa1f65d88272b3e5b,
+                0x8a80492bf4c5ace,
+                0xba1bd8d5fe5671
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x6727086cb56c427c,
+                0x5856901be8c199bc,
+                0x408762dacdbf83a4,
+                0xd1b3d8b26e8f1a4e,
+                0x20ef69f3fc53e588,
+                0xf5f91a5048b36c
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xd65e3fb4776f1052,
+                0xeef4f29447363e96,
+                0x3cac25b3493c689c,
+                0x4c954745d0d244b8,
+                0x16a5d8802b076c16,
+                0x5361efe00e555f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xfd46d4d733d2a2fb,
+                0x67e432dee24ccc42,
+                0x7930f6a2ecf9d7a0,
+                0x657b5be873fdeb1,
+                0xa8dd05b036329d6e,
+                0x480a552ca602c4
+            ])
+        ),
+        field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]))
+    ),
+];
+
+/// The coefficients of the denominator of the `y`-coordinate map.
+pub const G2_ISOGENY_Y_DEN: [Fq2; 34] = [
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x90174e14d377a017,
+                0x24c984024e3314e,
+                0x88c1e9969f47a9ae,
+                0x941212188e0a3bd9,
+                0x3f0e8b783a2112a0,
+                0x4308eb621f9c3a
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xa74a8c739ebea2ab,
+                0xc24b22d510e3ec17,
+                0x31ee852533ae81cc,
+                0x25d5bf47f17fea9e,
+                0xcb47c61ad6cb23aa,
+                0x2f440d807475e7
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xe634e6d0b69bec4,
+                0xbc737b12c06b3491,
+                0x6a9b1df510e5463f,
+                0x1cdfcdb5ceee33d1,
+                0xa2c3a6414956528e,
+                0xa9324479ce246f
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x63937d33477d6d41,
+                0x2aa7fa06859827de,
+                0xbd69c698fcfc005b,
+                0x9c6e27ec2e7c7954,
+                0xcfb1a78672b4467,
+                0x657dc09abf5214
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x23c66d77d140fa71,
+                0xb108f8a016b8365a,
+                0x6a7c9d56f40b1e59,
+                0xec9032a88eb5a8ba,
+                0x75acf7fa0a9059a5,
+                0xf0a46575991963
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2fcf81c942accdc,
+                0xf906a17e9b320b6e,
+                0x7534a51e320b4b27,
+                0x7d7d2f3f63f72699,
+                0x6e3316d71d88243f,
+                0xa1e039b1f61eb
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x36a638a681142ae7,
+                0x546798c048bc85f7,
+                0xd7782d999ec2c31d,
+                0x6574710da9298766,
+                0x6c6855364fde799b,
+                0x12e8df87b4857ee
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2d4ed607980038f0,
+                0x873b0bd70a02afe8,
+                0x8548c7f73a802861,
+                0xa5e21a9404c3b686,
+                0xf886c194cbcc7b75,
+                0x54d5ee33f8381f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x49c0c980d0a108e7,
+                0x7f9ad61787257bbb,
+                0x1cb06d14c26524cd,
+                0x27bb14a46c84814c,
+                0x72a091cfacea9ac8,
+                0x9d109b59223aee
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xbdb6ca2af7c19bae,
+                0xfcce4bf8df7d830a,
+                0x96cd27c6ea6b1bef,
+                0xe626a2e511c7a891,
+                0x60a956b764d68467,
+                0x1735e53982808c1
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xfda59c01b31f2ff3,
+                0xcbd815faaa493d42,
+                0xbe2803c477901d5,
+                0xa02b570aeba9bec1,
+                0xe376be1fb53fbad7,
+                0x171fc84b6e7d057
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x76cd1bfea43a6bb7,
+                0x2ece80dfc037bde7,
+                0x3f8066ab7f433832,
+                0xe8ef4220c93a199b,
+                0x37a46337e8e7bbef,
+                0x1571cf5a88f83a5
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x28a20a1b83fbb056,
+                0x873b75fcfee1a3c9,
+                0x5a272ad837f38e4c,
+                0x9ada23dbca3a08d1,
+                0x59d5cc281ece5f95,
+                0xd8646d12199615
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x148bad5cf2a0e37d,
+                0x7dc5186eb2657d53,
+                0x696dec732d935df0,
+                0x9276b39b3b6b9468,
+                0x736f3377dc6d3fa1,
+                0x2c750b8d5c8683
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xd3ad4849b2902dc8,
+                0xae13bd0b66465dcb,
+                0x5521c398569e34fa,
+                0x9fad6978a036b320,
+                0x9112981bc8b72303,
+                0x38d52d392cf2f9
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xdab507c06d9610f2,
+                0x39868d04609dc86,
+                0x8b097b6d6eb733db,
+                0xdcac183e437fcb3d,
+                0xdd164cac7daac27d,
+                0x15db78f738964a6
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa56d5119d6f6bd0,
+                0xc24c2823679d3c3e,
+                0xd474c0abd2ed6775,
+                0xb5581434d8786043,
+                0xe5cd1e797b30d710,
+                0x158882e38992c57
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x15dd17e499467ad8,
+                0xb86da2d654373bc1,
+                0x1473399afcec0965,
+                0x88b73a00e1cb6c4c,
+                0x59cf0a213c09c7ea,
+                0x6836bd0f8ec9aa
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xd0408186bcef19e7,
+                0xea61e0a211c783ef,
+                0x416b986b03126f72,
+                0x1f9ee1a9f301c441,
+                0xd86e82ba34c31808,
+                0x1628be016fd1d08
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x426c455d00f70348,
+                0x76556567cc252836,
+                0x419e4793e84821ce,
+                0x183a71b04373b461,
+                0x54cb3de43b347b26,
+                0x19059fae0a53a53
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xb98ed7c68e373667,
+                0x63d034cad099d1c7,
+                0xbf6d9f0ca8e50d0d,
+                0x433c13993929c307,
+                0xd9b53d95eb34dbcf,
+                0x12c693b39bc8053
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x55f71eaa938bcf04,
+                0xeb1e461fa254fe9f,
+                0x950faa06f7820d01,
+                0x62e2d07908928294,
+                0xdb5fd365c37e0534,
+                0x16c87e54bebc514
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xd4bc1af152afc00e,
+                0xef5cd46a371e5d6a,
+                0x88c710955309d589,
+                0x9457d30e2c17a8e1,
+                0xe12b4c095e35f6a0,
+                0x65aca0b7962f1c
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x581d8b184b0884c,
+                0xc7fff8d5b57b339,
+                0x96d781190710208b,
+                0xf1ed24458f995ceb,
+                0xd798f9e9491c3825,
+                0xcd6b383f61c3ac
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x67c7b869df140683,
+                0xeeafbc05e85970df,
+                0x77b24bbd74fb1792,
+                0xd2da8decc717aeca,
+                0xa8de5fc3053c817c,
+                0xc5c3035929f943
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x619ec077d6e7736a,
+                0x4288f3047b7289d1,
+                0xe456146e1cb087a6,
+                0xdb1f557e488b2a35,
+                0xd9fcca8c2f65e4de,
+                0xd57bfcc422ef4f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xfb522b56a255b79,
+                0x295e9e75efedbac,
+                0x2691086772e8317,
+                0xac7a04e1cf32a333,
+                0x5ef84b084a4c03d,
+                0x1728f0600efefa5
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xcae7de6b40a756c1,
+                0xa0dbf4c8764ca423,
+                0x26367d4ca510e950,
+                0x8c5e49aa560bd0e8,
+                0x7b2b3571d20af6fd,
+                0x16b5262c2c503bc
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x94ed1b59a515789c,
+                0x82f1391540430593,
+                0xf2968e7f0a8fba48,
+                0x49aa7c9dbf60648f,
+                0x58d04fabfed25c59,
+                0x147e12284a2bfd2
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xe83a88afa281f793,
+                0x83fb19d9ddafc1e0,
+                0x99389e61bc28c0c5,
+                0xb2dccdd6fb869383,
+                0xb9ba6c09baada352,
+                0xfed5b686c2812
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xf092dc76c095e81d,
+                0x5d69993c3280f4ae,
+                0xce2462fdb0514c16,
+                0x6752d2f7372390e9,
+                0xb5d2e430917cfeda,
+                0x18770bbf9c4f252
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xb35f0228a2408e0d,
+                0xfe866066c41f4895,
+                0x59905d33ea2161dd,
+                0xa4da2d0b62cc8314,
+                0x96df96d1ef8cec48,
+                0x1909a99439d651e
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x156fdcff63925821,
+                0xb8a769ca42816c83,
+                0xc7ed74ea7be0b6f,
+                0x62ae4bdc76effc4c,
+                0xe523b3b70611322d,
+                0x1ad2611e4f3c032
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xb6e3883fbf901758,
+                0xabc2c54fe2c118e,
+                0xb59557214bf3acb7,
+                0xfb118251a52fb84e,
+                0x3b4e8d94cd53f6cc,
+                0x20ac4ecb611496
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x2731e191bdee9ceb,
+                0x7056d271767801a2,
+                0xf965857e87f6053e,
+                0x6da449f10917d1e3,
+                0x8a6230578bf3460a,
+                0x1671f8aa31638e9
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x657f39524dcd39d,
+                0xb9bd2c8184b42ad7,
+                0x98063bc5c695c71a,
+                0xe5f424e063b21374,
+                0x266e4ab30c1bdcd4,
+                0x8a0a3c50d5a67c
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x48b7d5aecf053047,
+                0xa596e826d89cf34c,
+                0x716ef945ad4fd788,
+                0xd6e7888b5834187e,
+                0x547df348ce09888b,
+                0x75626a4bf33704
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x3db2e455e4c398c0,
+                0x6d1e8cc3677e911a,
+                0x4ad87c050a4348b8,
+                0x251179b0a5cfaffa,
+                0x31e110ecb27a1ed0,
+                0x5754ed4050c469
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xddf48d0497f1db1c,
+                0xe885ea713f36d9b3,
+                0x4967147de57d8290,
+                0x469f5f571a434754,
+                0x83277fc8a610c008,
+                0x513777a738daa0
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x17b973f69a9b860b,
+                0x27fa9b2bd84dd9bb,
+                0x44cb7095a40f3ccf,
+                0x950f6d14f51f72b1,
+                0x43c71176125c336b,
+                0x7a59c233201c4
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x4d3c41c54348c0d5,
+                0x85ee1f546c6bd9a6,
+                0xd5ebaa7b963fe0e5,
+                0xd862d20c0434c90,
+                0xd952113152e91081,
+                0x164b5402b8416ac
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x1ed522d86290548d,
+                0xb39922b93b82adab,
+                0x13c7ad9947df831c,
+                0x24326bc1893165aa,
+                0xbaf6bc8f9ba31afc,
+                0xe37832db038234
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xe1242285b88e3e75,
+                0x96430ba3fc040613,
+                0x9fadf730fd79a7d7,
+                0xb5232b8a95f845f9,
+                0xab5b315931e14ba4,
+                0x9e527fa88b8882
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x8fd8b2e156f7964f,
+                0xd4f2ec74dad67abc,
+                0xb6978ade1c0fc56e,
+                0x9c8d2abdde4aa13f,
+                0x9523bc8e3d567538,
+                0x1a67c7e95b0138e
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x3b3470ef2e36e70c,
+                0xa0a9ce34d5a11e39,
+                0xbe368522498e3b1e,
+                0xa0c9f3c7efd7f0f9,
+                0xf51f6b679a395416,
+                0x1478a95e7f1862f
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2bb67eed0ff4fa54,
+                0x25fc09e6679d0f6f,
+                0x4183de1bd10da290,
+                0x8fa42b92fc203cbb,
+                0x9caab241ef1ed54b,
+                0x1112d35b480395f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xe74b7912b16761c5,
+                0x4827c9d4e9443ae5,
+                0x8e6ba198a5ae8333,
+                0x9fa31b5feb885269,
+                0x313bc610151169a,
+                0x51f92c3582c84b
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x98cc41ddb1861f35,
+                0x10dcc30fed736128,
+                0x486d08bbfd2bdc42,
+                0x15dea166d017f5c8,
+                0x1a808bcd834b188b,
+                0x25b32e78d3822a
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x263e7e93bbd7cf9,
+                0xc00a75e2d3a8d5e7,
+                0xa33f1ecfd8aa1df8,
+                0x79fdd89b4d371b3e,
+                0x5da0aa489a2f52f1,
+                0xf311cbb0df4a02
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x30a58689544c332c,
+                0x43f1540139beeaa5,
+                0xda7d9ea3b4e4d8d5,
+                0x9fe90045bc0ba719,
+                0x981011703ddd311d,
+                0x8da632e22556d6
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x4050dfb99468b125,
+                0x72bc4e30fb3b8116,
+                0x8e4d1c5313ff6e60,
+                0x96298e71164821fc,
+                0xe1b865ace8dd951a,
+                0x5cb7eeecb34e4f
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xbf412d20308f880e,
+                0x7f5248e82ad432c8,
+                0x5ad10584fdd1e779,
+                0x961be7f926723488,
+                0xad274a8fec4df32f,
+                0x178f3210ce29625
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x368fedf4d0fe5798,
+                0x937487703d85e921,
+                0x45d7bbf9ef440380,
+                0x8a237e5947cc095b,
+                0xd02bdf44ca8d29a8,
+                0x130bc67d1db7143
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x9a8ab9ab0af9b9f4,
+                0x9dfb128dc775f948,
+                0xdffffce20021dab9,
+                0x46d501af8d5b4af7,
+                0xf16daed16f930848,
+                0x9603acb517d55d
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x39b822cb89a5339a,
+                0x4e02aa7ef85054b9,
+                0xc659adac84bde44c,
+                0x73d37d52395af384,
+                0x6d7cbdbfa0e60a24,
+                0x13eb0d879956faa
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xb5553142f16c4c9d,
+                0x8216a3b7ab1a039a,
+                0xaac23858d8ff472b,
+                0xd76b0b975b9d9f57,
+                0x5cac7858abede9de,
+                0xd194897237bdf0
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x20027e125259129b,
+                0x7443fcb5280603ac,
+                0x6257c7d0b42844ff,
+                0xf4fc20563c80a2a,
+                0x1413211dea7f372,
+                0x12bb5c8b2db627f
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x557e49368e55fe9b,
+                0xc0b1268af7fb4a9d,
+                0x3cf595155839e9b2,
+                0xa7f96861ec5f062c,
+                0x71af42a11c39a673,
+                0x186f6b237793a2f
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x85700b5c46928652,
+                0xb162a7ccad5bd533,
+                0x3757e11f200971c8,
+                0x60dc093fc6b2d0c6,
+                0x86643a4634a00486,
+                0x189149c4ae942f4
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x39b95a0bd1ac3204,
+                0xfe889ab8ac12bfa6,
+                0xe13f2137ed50335c,
+                0x730156e8a6fd8d60,
+                0x6a6404d9240806cc,
+                0x1a15d6156d6c240
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xb243f43e8150436a,
+                0x2d77e022d7709e52,
+                0xc34aa31f0b90cfed,
+                0x86889468f480806,
+                0x9a98033cdb8e9c5d,
+                0x1174e56a7595ee2
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xc866b2d76e898393,
+                0x9bc1f38821f85338,
+                0x45f1836ae05e2a5b,
+                0xa532e3b5455d9038,
+                0x626aaac826ae8602,
+                0x10e39b792e92ebc
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x63b6c3a14faacff8,
+                0xace8af0dfdc939fa,
+                0xf0e30f513e371a50,
+                0x7880f589f0b1d197,
+                0x45dc05f88ce9c72e,
+                0xc4eea597579a0c
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x4a44dbc02bb1898a,
+                0xf4b823a76db712c7,
+                0x3908c4fedfa92438,
+                0x1b4b161b0fc0471d,
+                0xdd9d7f0412fa05e6,
+                0x15cd73db900ca55
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xc66066a28c4bb838,
+                0x5150e5ab09519b2b,
+                0xe55b6455b4f93570,
+                0x6fa5f186901bc83f,
+                0x6f46275c8f057bc,
+                0x5ac90fd09fcccf
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0xfacd3a185f90a008,
+                0xb90813326318ae1c,
+                0x83e968622492c9cc,
+                0xdcae2dce81de804,
+                0x808db9147829916f,
+                0x26ee02d811a7cf
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a
+            ])
+        ),
+        field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]))
+    ),
+];
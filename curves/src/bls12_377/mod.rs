@@ -44,6 +44,10 @@ pub mod g2;
 #[doc(inline)]
 pub use g2::*;
 
+pub mod g2_isogeny;
+#[doc(inline)]
+pub use g2_isogeny::*;
+
 pub mod parameters;
 #[doc(inline)]
 pub use parameters::*;
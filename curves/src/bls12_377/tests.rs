@@ -28,17 +28,33 @@ use crate::{
         Fq6Parameters,
         FqParameters,
         Fr,
+        FrParameters,
         G1Affine,
         G1Projective,
         G2Affine,
         G2Projective,
     },
-    templates::{short_weierstrass_jacobian::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
+    templates::{
+        bls12::{ZcashEncoding, ZcashFieldEncoding},
+        short_weierstrass_jacobian::{tests::sw_tests, Affine, Projective},
+        twisted_edwards_extended::tests::edwards_test,
+    },
     traits::{
-        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_field::{
+            cyclotomic_field_test,
+            extension_field_encoding_test,
+            field_encoding_test,
+            field_serialization_test,
+            field_test,
+            frobenius_test,
+            primefield_test,
+            sqrt_field_test,
+        },
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
+        GLVParameters,
+        PairingCurve,
         PairingEngine,
         ProjectiveCurve,
         ShortWeierstrassParameters,
@@ -60,6 +76,7 @@ use snarkvm_fields::{
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger384},
     rand::{test_rng, UniformRand},
+    BitIteratorBE,
 };
 
 use rand::{thread_rng, Rng, SeedableRng};
@@ -78,11 +95,38 @@ fn test_bls12_377_fr() {
         let b: Fr = rand::random();
         field_test(a, b);
         primefield_test::<Fr>();
+        field_encoding_test::<Fr>();
         sqrt_field_test(b);
         field_serialization_test::<Fr>();
     }
 }
 
+#[test]
+fn test_fr_root_of_unity() {
+    assert_eq!(FrParameters::TWO_ADICITY, 47);
+    assert_eq!(FrParameters::SMALL_SUBGROUP_BASE, Some(3));
+    assert_eq!(FrParameters::SMALL_SUBGROUP_BASE_ADICITY, Some(1));
+
+    // The large subgroup root of unity has order exactly 2^47 * 3.
+    let large_root = Fr::large_subgroup_root_of_unity().unwrap();
+    assert_eq!(large_root.pow([3u64 << FrParameters::TWO_ADICITY]), Fr::one());
+    assert_ne!(large_root.pow([1u64 << FrParameters::TWO_ADICITY]), Fr::one());
+    assert_ne!(large_root.pow([3u64 << (FrParameters::TWO_ADICITY - 1)]), Fr::one());
+    assert_eq!(large_root.pow([3u64]), Fr::two_adic_root_of_unity());
+
+    // Power-of-two roots of unity are unaffected by the small subgroup.
+    assert_eq!(Fr::get_root_of_unity(1 << FrParameters::TWO_ADICITY).unwrap(), Fr::two_adic_root_of_unity());
+    for size in [1usize, 2, 3, 6, 12, 3 << 10] {
+        let root = Fr::get_root_of_unity(size).unwrap();
+        assert_eq!(root.pow([size as u64]), Fr::one());
+        if size > 1 {
+            assert_ne!(root.pow([(size / 2) as u64]), Fr::one());
+        }
+    }
+    assert!(Fr::get_root_of_unity(9).is_none());
+    assert!(Fr::get_root_of_unity(5).is_none());
+}
+
 #[test]
 fn test_bls12_377_fq() {
     for _ in 0..ITERATIONS {
@@ -90,6 +134,7 @@ fn test_bls12_377_fq() {
         let b: Fq = rand::random();
         field_test(a, b);
         primefield_test::<Fq>();
+        field_encoding_test::<Fq>();
         sqrt_field_test(a);
         field_serialization_test::<Fq>();
     }
@@ -105,6 +150,7 @@ fn test_bls12_377_fq2() {
     }
     frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
     field_serialization_test::<Fq2>();
+    extension_field_encoding_test::<Fq2>();
 }
 
 #[test]
@@ -116,6 +162,7 @@ fn test_bls12_377_fq6() {
     }
     frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
     field_serialization_test::<Fq6>();
+    extension_field_encoding_test::<Fq6>();
 }
 
 #[test]
@@ -127,6 +174,7 @@ fn test_bls12_377_fq12() {
     }
     frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
     field_serialization_test::<Fq12>();
+    extension_field_encoding_test::<Fq12>();
 }
 
 #[test]
@@ -279,6 +327,28 @@ fn test_fq_squaring() {
     }
 }
 
+#[test]
+fn test_fq_limb52_matches_limb64() {
+    let mut rng = test_rng();
+
+    let edge_cases = [Fq::zero(), Fq::one(), -Fq::one(), Fq::from_repr(FqParameters::T).unwrap()];
+    let random = (0..100000).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>();
+
+    for a in edge_cases.into_iter().chain(random) {
+        for b in edge_cases.into_iter().chain([Fq::rand(&mut rng)]) {
+            let (mut tmp1, mut tmp2) = (a, a);
+            tmp1.mul_assign_limb64(&b);
+            tmp2.mul_assign_limb52(&b);
+            assert_eq!(tmp1, tmp2);
+        }
+
+        let (mut tmp1, mut tmp2) = (a, a);
+        tmp1.square_in_place_limb64();
+        tmp2.square_in_place_limb52();
+        assert_eq!(tmp1, tmp2);
+    }
+}
+
 #[test]
 fn test_fq_inverse() {
     assert!(Fq::zero().inverse().is_none());
@@ -570,6 +640,50 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_glv_endomorphism() {
+    let generator = G1Affine::prime_subgroup_generator();
+    let lambda_bits = BitIteratorBE::new(Bls12_377G1Parameters::LAMBDA.to_repr());
+    assert_eq!(Bls12_377G1Parameters::endomorphism_affine(&generator).to_projective(), generator.mul_bits(lambda_bits));
+    assert_eq!(Bls12_377G1Parameters::LAMBDA.pow([3]), Fr::one());
+    assert_eq!(Bls12_377G1Parameters::ENDO_COEFF.pow([3]), Fq::one());
+
+    let p: G1Projective = rand::random();
+    let expected = Bls12_377G1Parameters::endomorphism_affine(&p.to_affine());
+    assert_eq!(Bls12_377G1Parameters::endomorphism(&p).to_affine(), expected);
+}
+
+#[test]
+fn test_g1_glv_scalar_decomposition() {
+    let signed = |(is_negative, k): (bool, Fr)| if is_negative { -k } else { k };
+
+    let mut rng = test_rng();
+    let edge_cases = [Fr::zero(), Fr::one(), -Fr::one(), Bls12_377G1Parameters::LAMBDA];
+    for scalar in (0..1000).map(|_| Fr::rand(&mut rng)).chain(edge_cases) {
+        let (k1, k2) = Bls12_377G1Parameters::scalar_decomposition(&scalar);
+        assert!(k1.1.to_repr().num_bits() <= 128);
+        assert!(k2.1.to_repr().num_bits() <= 128);
+        assert_eq!(signed(k1) + signed(k2) * Bls12_377G1Parameters::LAMBDA, scalar);
+    }
+}
+
+#[test]
+fn test_g1_glv_mul() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let p: G1Projective = rand::random();
+        let scalar = Fr::rand(&mut rng);
+        let expected = p.to_affine().mul_bits(BitIteratorBE::new(scalar.to_repr()));
+        assert_eq!(Bls12_377G1Parameters::glv_mul(&p, &scalar), expected);
+        assert_eq!(p * scalar, expected);
+        assert_eq!(p.to_affine() * scalar, expected);
+    }
+
+    let p: G1Projective = rand::random();
+    assert!((p * Fr::zero()).is_zero());
+    assert_eq!(p * -Fr::one(), -p);
+}
+
 #[test]
 fn test_g2_projective_curve() {
     curve_tests::<G2Projective>();
@@ -614,3 +728,162 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
+
+#[test]
+fn test_fq12_cyclotomic() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let gt = Bls12_377::pairing(G1Projective::rand(&mut rng), G2Projective::rand(&mut rng));
+        cyclotomic_field_test(gt);
+
+        // The compressed squarings decompress to the repeated squares.
+        let mut compressed = vec![gt.cyclotomic_square_compressed()];
+        for _ in 1..8 {
+            compressed.push(compressed.last().unwrap().cyclotomic_square_compressed());
+        }
+        let mut expected = gt;
+        for square in Fq12::decompress_karabina(&compressed) {
+            expected = expected.cyclotomic_square();
+            assert_eq!(square, expected);
+        }
+    }
+}
+
+#[test]
+fn test_multi_pairing() {
+    let mut rng = test_rng();
+    let g1 = (0..10).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let g2 = (0..10).map(|_| G2Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let g1_prepared = g1.iter().map(|p| p.prepare()).collect::<Vec<_>>();
+    let g2_prepared = g2.iter().map(|q| q.prepare()).collect::<Vec<_>>();
+
+    let expected = g1.iter().zip(&g2).fold(Fq12::one(), |acc, (p, q)| acc * Bls12_377::pairing(*p, *q));
+    assert_eq!(Bls12_377::multi_pairing(g1_prepared.iter().zip(&g2_prepared)), expected);
+    assert_eq!(Bls12_377::product_of_pairings(g1_prepared.iter().zip(&g2_prepared)), expected);
+    assert_eq!(
+        Bls12_377::multi_pairing(g1_prepared.iter().zip(&g2_prepared).take(1)),
+        Bls12_377::pairing(g1[0], g2[0])
+    );
+    assert_eq!(Bls12_377::multi_pairing([]), Fq12::one());
+    assert!(!Bls12_377::product_of_pairings_is_one(g1_prepared.iter().zip(&g2_prepared)));
+
+    // e(s * P, Q) * e(-P, s * Q) = 1.
+    let s = Fr::rand(&mut rng);
+    let (p, q) = (g1[0], g2[0]);
+    let pairs = [((p * s).to_affine().prepare(), q.prepare()), ((-p).prepare(), (q * s).to_affine().prepare())];
+    assert!(Bls12_377::product_of_pairings_is_one(pairs.iter().map(|(p, q)| (p, q))));
+    assert!(Bls12_377::product_of_pairings_is_one([]));
+}
+
+fn zcash_encoding_test<P: ShortWeierstrassParameters>()
+where
+    P::BaseField: ZcashFieldEncoding,
+{
+    let mut rng = test_rng();
+    let compressed_size = <Affine<P> as ZcashEncoding>::COMPRESSED_SIZE;
+    let uncompressed_size = <Affine<P> as ZcashEncoding>::UNCOMPRESSED_SIZE;
+
+    for _ in 0..ITERATIONS {
+        let point = Projective::<P>::rand(&mut rng).to_affine();
+
+        let compressed = point.to_compressed();
+        assert_eq!(compressed.len(), compressed_size);
+        assert_eq!(compressed[0] & 0xc0, 0x80);
+        assert_eq!(Affine::<P>::from_compressed(&compressed).unwrap(), point);
+        assert_eq!(Affine::<P>::from_compressed_unchecked(&compressed).unwrap(), point);
+
+        let uncompressed = point.to_uncompressed();
+        assert_eq!(uncompressed.len(), uncompressed_size);
+        assert_eq!(uncompressed[0] & 0xe0, 0);
+        assert_eq!(Affine::<P>::from_uncompressed(&uncompressed).unwrap(), point);
+        assert_eq!(Affine::<P>::from_uncompressed_unchecked(&uncompressed).unwrap(), point);
+        assert_eq!(compressed[1..], uncompressed[1..compressed_size]);
+
+        // Flipping the sort flag decodes to the negation.
+        let mut flipped = compressed.clone();
+        flipped[0] ^= 1 << 5;
+        assert_eq!(Affine::<P>::from_compressed(&flipped).unwrap(), -point);
+
+        // The compression flag must match the decoder.
+        assert!(Affine::<P>::from_uncompressed(&[compressed.clone(), compressed.clone()].concat()).is_err());
+        let mut wrong_flag = uncompressed.clone();
+        wrong_flag[0] |= 1 << 7;
+        assert!(Affine::<P>::from_uncompressed(&wrong_flag).is_err());
+        let mut wrong_flag = compressed.clone();
+        wrong_flag[0] &= !(1 << 7);
+        assert!(Affine::<P>::from_compressed(&wrong_flag).is_err());
+
+        // The sort flag is not allowed in uncompressed encodings.
+        let mut sorted = uncompressed.clone();
+        sorted[0] |= 1 << 5;
+        assert!(Affine::<P>::from_uncompressed(&sorted).is_err());
+
+        // The encodings have a fixed length.
+        assert!(Affine::<P>::from_compressed(&compressed[1..]).is_err());
+        assert!(Affine::<P>::from_uncompressed(&[uncompressed.clone(), vec![0]].concat()).is_err());
+
+        // A point with a modified y-coordinate is not on the curve.
+        let mut off_curve = uncompressed.clone();
+        off_curve[uncompressed_size - 1] ^= 1;
+        assert!(Affine::<P>::from_uncompressed_unchecked(&off_curve).is_err());
+    }
+
+    // The point at infinity.
+    let zero = Affine::<P>::zero();
+    let mut expected = vec![0u8; compressed_size];
+    expected[0] = 0xc0;
+    assert_eq!(zero.to_compressed(), expected);
+    assert_eq!(Affine::<P>::from_compressed(&expected).unwrap(), zero);
+    let mut expected = vec![0u8; uncompressed_size];
+    expected[0] = 0x40;
+    assert_eq!(zero.to_uncompressed(), expected);
+    assert_eq!(Affine::<P>::from_uncompressed(&expected).unwrap(), zero);
+    expected[uncompressed_size - 1] = 1;
+    assert!(Affine::<P>::from_uncompressed(&expected).is_err());
+    let mut sorted = zero.to_compressed();
+    sorted[0] |= 1 << 5;
+    assert!(Affine::<P>::from_compressed(&sorted).is_err());
+
+    // A non-canonical x-coordinate is rejected.
+    let non_canonical = vec![0x1f | 0x80; compressed_size];
+    assert!(Affine::<P>::from_compressed_unchecked(&non_canonical).is_err());
+
+    // A point on the curve outside the prime-order subgroup is only accepted by the unchecked decoders.
+    let point = loop {
+        if let Some(point) = Affine::<P>::from_x_coordinate(P::BaseField::rand(&mut rng), false) {
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                break point;
+            }
+        }
+    };
+    assert!(Affine::<P>::from_compressed(&point.to_compressed()).is_err());
+    assert_eq!(Affine::<P>::from_compressed_unchecked(&point.to_compressed()).unwrap(), point);
+    assert!(Affine::<P>::from_uncompressed(&point.to_uncompressed()).is_err());
+    assert_eq!(Affine::<P>::from_uncompressed_unchecked(&point.to_uncompressed()).unwrap(), point);
+
+    // Decoding random bytes never panics, and every accepted encoding is canonical.
+    for _ in 0..1000 {
+        let mut bytes = (0..compressed_size).map(|_| rng.gen()).collect::<Vec<u8>>();
+        bytes[0] = (bytes[0] & 0xe1) | 0x80;
+        if let Ok(point) = Affine::<P>::from_compressed_unchecked(&bytes) {
+            assert!(point.is_on_curve());
+            assert_eq!(point.to_compressed(), bytes);
+        }
+
+        let bytes = (0..uncompressed_size).map(|_| rng.gen()).collect::<Vec<u8>>();
+        if let Ok(point) = Affine::<P>::from_uncompressed_unchecked(&bytes) {
+            assert_eq!(point.to_uncompressed(), bytes);
+        }
+    }
+}
+
+#[test]
+fn test_g1_zcash_encoding() {
+    zcash_encoding_test::<Bls12_377G1Parameters>();
+}
+
+#[test]
+fn test_g2_zcash_encoding() {
+    zcash_encoding_test::<Bls12_377G2Parameters>();
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bw6_761::{g1::BW6_761G1Parameters, g2::BW6_761G2Parameters, Fq, Fq3Parameters, Fq6, Fq6Parameters},
+    templates::{
+        bls12::TwistType,
+        bw6::{
+            BW6Parameters,
+            G1Affine as BW6G1Affine,
+            G1Prepared,
+            G1Projective as BW6G1Projective,
+            G2Affine as BW6G2Affine,
+            G2Prepared,
+            G2Projective as BW6G2Projective,
+            BW6,
+        },
+    },
+    traits::{PairingCurve, PairingEngine},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BW6_761Parameters;
+
+impl BW6Parameters for BW6_761Parameters {
+    type Fp = Fq;
+    type Fp3Params = Fq3Parameters;
+    type Fp6Params = Fq6Parameters;
+    type G1Parameters = BW6_761G1Parameters;
+    type G2Parameters = BW6_761G2Parameters;
+
+    /// ATE_LOOP_COUNT_1 = X + 1
+    const ATE_LOOP_COUNT_1: &'static [u64] = &[0x8508c00000000002];
+    const ATE_LOOP_COUNT_1_IS_NEGATIVE: bool = false;
+    /// ATE_LOOP_COUNT_2 = X^3 - X^2 - X = 880904806456922042166256752416502360955572640081583800319
+    #[rustfmt::skip]
+    const ATE_LOOP_COUNT_2: &'static [i8] = &[
+        -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, 0, -1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, -1, 0, 0, 0, 0, -1, 0, 0, 1, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, 1, 0, 0, 1, 0, -1, 0, 1, 0, 1, 0, 0, 0, 1, 0, -1, 0, -1, 0, 0, 0, 0, 0, 1, 0, 0, 1
+    ];
+    const ATE_LOOP_COUNT_2_IS_NEGATIVE: bool = false;
+    /// R0(x) = -103x^7 + 70x^6 + 269x^5 - 197x^4 - 314x^3 - 73x^2 - 263x - 220
+    const FINAL_EXPONENT_R0: &'static [i64] = &[-220, -263, -73, -314, -197, 269, 70, -103];
+    /// R1(x) = 103x^9 - 276x^8 + 77x^7 + 492x^6 - 445x^5 - 65x^4 + 452x^3 - 181x^2 + 34x + 229
+    const FINAL_EXPONENT_R1: &'static [i64] = &[229, 34, -181, 452, -65, -445, 492, 77, -276, 103];
+    const TWIST_TYPE: TwistType = TwistType::M;
+    /// X = 9586122913090633729, the seed of BLS12-377.
+    const X: &'static [u64] = &[0x8508c00000000001];
+    /// `x` is positive.
+    const X_IS_NEGATIVE: bool = false;
+}
+
+pub type BW6_761 = BW6<BW6_761Parameters>;
+
+pub type G1Affine = BW6G1Affine<BW6_761Parameters>;
+pub type G1Projective = BW6G1Projective<BW6_761Parameters>;
+
+impl PairingCurve for G1Affine {
+    type Engine = BW6_761;
+    type PairWith = G2Affine;
+    type PairingResult = Fq6;
+    type Prepared = G1Prepared<BW6_761Parameters>;
+
+    fn prepare(&self) -> Self::Prepared {
+        Self::Prepared::from_affine(*self)
+    }
+
+    fn pairing_with(&self, other: &Self::PairWith) -> Self::PairingResult {
+        BW6_761::pairing(*self, *other)
+    }
+}
+
+pub type G2Affine = BW6G2Affine<BW6_761Parameters>;
+pub type G2Projective = BW6G2Projective<BW6_761Parameters>;
+
+impl PairingCurve for G2Affine {
+    type Engine = BW6_761;
+    type PairWith = G1Affine;
+    type PairingResult = Fq6;
+    type Prepared = G2Prepared<BW6_761Parameters>;
+
+    fn prepare(&self) -> Self::Prepared {
+        Self::Prepared::from_affine(*self)
+    }
+
+    fn pairing_with(&self, other: &Self::PairWith) -> Self::PairingResult {
+        BW6_761::pairing(*other, *self)
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bw6_761::{
+        g1::BW6_761G1Parameters,
+        g2::BW6_761G2Parameters,
+        Fq,
+        Fq3,
+        Fq6,
+        FqParameters,
+        Fr,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+        BW6_761,
+    },
+    templates::{bw6::G2Prepared, short_weierstrass_jacobian::tests::sw_tests},
+    traits::{
+        tests_field::{
+            cyclotomic_field_test,
+            extension_field_encoding_test,
+            field_encoding_test,
+            field_serialization_test,
+            field_test,
+            frobenius_test,
+            primefield_test,
+            sqrt_field_test,
+        },
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        PairingCurve,
+        PairingEngine,
+        ProjectiveCurve,
+    },
+};
+use snarkvm_fields::{Field, FieldParameters, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    rand::{test_rng, UniformRand},
+    FromBytes,
+    ToBytes,
+};
+
+use std::ops::MulAssign;
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_bw6_761_fr() {
+    // The scalar field of BW6-761 is the base field of BLS12-377.
+    assert_eq!(Fr::characteristic(), crate::bls12_377::Fq::characteristic());
+}
+
+#[test]
+fn test_bw6_761_fq() {
+    for _ in 0..ITERATIONS {
+        let a: Fq = rand::random();
+        let b: Fq = rand::random();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        field_encoding_test::<Fq>();
+        sqrt_field_test(a);
+        field_serialization_test::<Fq>();
+    }
+}
+
+#[test]
+fn test_bw6_761_fq3() {
+    for _ in 0..ITERATIONS {
+        let a: Fq3 = rand::random();
+        let b: Fq3 = rand::random();
+        field_test(a, b);
+    }
+    frobenius_test::<Fq3, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq3>();
+    extension_field_encoding_test::<Fq3>();
+}
+
+#[test]
+fn test_bw6_761_fq6() {
+    for _ in 0..ITERATIONS {
+        let a: Fq6 = rand::random();
+        let b: Fq6 = rand::random();
+        field_test(a, b);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq6>();
+    extension_field_encoding_test::<Fq6>();
+}
+
+#[test]
+fn test_fq_mul_assign() {
+    let mut rng = test_rng();
+
+    for _ in 0..1000 {
+        // Ensure that (a * b) * c = a * (b * c) and a * (b + c) = a * b + a * c.
+        let a = Fq::rand(&mut rng);
+        let b = Fq::rand(&mut rng);
+        let c = Fq::rand(&mut rng);
+
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * (b + c), a * b + a * c);
+        assert_eq!(a.square(), a * a);
+    }
+}
+
+#[test]
+fn test_fq_inverse() {
+    assert!(Fq::zero().inverse().is_none());
+
+    let mut rng = test_rng();
+
+    for _ in 0..1000 {
+        let a = Fq::rand(&mut rng);
+        assert_eq!(a * a.inverse().unwrap(), Fq::one());
+    }
+}
+
+#[test]
+fn test_fq_repr_roundtrip() {
+    let mut rng = test_rng();
+
+    for _ in 0..1000 {
+        let a = Fq::rand(&mut rng);
+        assert_eq!(Fq::from_repr(a.to_repr()), Some(a));
+    }
+    assert_eq!(Fq::from_repr(FqParameters::MODULUS), None);
+    assert_eq!(Fq::from(7u64).to_repr(), 7u64.into());
+}
+
+#[test]
+fn test_fq6_mul_by_014() {
+    let mut rng = test_rng();
+
+    for _ in 0..1000 {
+        let c0 = Fq::rand(&mut rng);
+        let c1 = Fq::rand(&mut rng);
+        let c4 = Fq::rand(&mut rng);
+        let mut a = Fq6::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_014(&c0, &c1, &c4);
+        b.mul_assign(&Fq6::new(Fq3::new(c0, c1, Fq::zero()), Fq3::new(Fq::zero(), c4, Fq::zero())));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq6_mul_by_034() {
+    let mut rng = test_rng();
+
+    for _ in 0..1000 {
+        let c0 = Fq::rand(&mut rng);
+        let c3 = Fq::rand(&mut rng);
+        let c4 = Fq::rand(&mut rng);
+        let mut a = Fq6::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_034(&c0, &c3, &c4);
+        b.mul_assign(&Fq6::new(Fq3::new(c0, Fq::zero(), Fq::zero()), Fq3::new(c3, c4, Fq::zero())));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    curve_tests::<G1Projective>();
+    sw_tests::<BW6_761G1Parameters>();
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let a: G1Projective = rand::random();
+    let b: G1Projective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+    sw_tests::<BW6_761G2Parameters>();
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let a: G2Projective = rand::random();
+    let b: G2Projective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let a: G1Projective = rand::random();
+    let b: G2Projective = rand::random();
+    let s: Fr = rand::random();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = BW6_761::pairing(sa, b);
+    let ans2 = BW6_761::pairing(a, sb);
+    let ans3 = BW6_761::pairing(a, b).pow(s.to_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq6::one());
+    assert_ne!(ans2, Fq6::one());
+    assert_ne!(ans3, Fq6::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq6::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq6::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq6::one());
+}
+
+#[test]
+fn test_fq6_cyclotomic() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        cyclotomic_field_test(BW6_761::pairing(G1Projective::rand(&mut rng), G2Projective::rand(&mut rng)));
+    }
+}
+
+#[test]
+fn test_multi_pairing() {
+    let mut rng = test_rng();
+    let g1 = (0..4).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let g2 = (0..4).map(|_| G2Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let g1_prepared = g1.iter().map(|p| p.prepare()).collect::<Vec<_>>();
+    let g2_prepared = g2.iter().map(|q| q.prepare()).collect::<Vec<_>>();
+
+    let expected = g1.iter().zip(&g2).fold(Fq6::one(), |acc, (p, q)| acc * BW6_761::pairing(*p, *q));
+    assert_eq!(BW6_761::multi_pairing(g1_prepared.iter().zip(&g2_prepared)), expected);
+    assert_eq!(BW6_761::multi_pairing([]), Fq6::one());
+
+    // e(s * P, Q) * e(-P, s * Q) = 1.
+    let s = Fr::rand(&mut rng);
+    let (p, q) = (g1[0], g2[0]);
+    let pairs = [((p * s).to_affine().prepare(), q.prepare()), ((-p).prepare(), (q * s).to_affine().prepare())];
+    assert!(BW6_761::product_of_pairings_is_one(pairs.iter().map(|(p, q)| (p, q))));
+
+    // The pairing with the point at infinity is one.
+    assert_eq!(BW6_761::pairing(G1Affine::zero(), g2[0]), Fq6::one());
+    assert_eq!(BW6_761::pairing(g1[0], G2Affine::zero()), Fq6::one());
+}
+
+#[test]
+fn test_g2_prepared_serialization() {
+    let q: G2Projective = rand::random();
+    let prepared = q.to_affine().prepare();
+
+    let bytes = prepared.to_bytes_le().unwrap();
+    assert_eq!(G2Prepared::read_le(&bytes[..]).unwrap(), prepared);
+}
@@ -18,12 +18,12 @@ use crate::{
     edwards_bls12::{Fq, Fr},
     errors::GroupError,
     templates::twisted_edwards_extended::{Affine, Projective},
-    traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+    traits::{AffineCurve, Elligator2Parameters, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::field;
-use snarkvm_utilities::biginteger::BigInteger256;
+use snarkvm_utilities::{biginteger::BigInteger256, vec::Vec};
 
-use std::str::FromStr;
+use core::str::FromStr;
 
 pub type EdwardsAffine = Affine<EdwardsParameters>;
 pub type EdwardsProjective = Projective<EdwardsParameters>;
@@ -44,6 +44,7 @@ impl TwistedEdwardsParameters for EdwardsParameters {
     /// COEFF_A = -1
     const COEFF_A: Fq =
         field!(Fq, BigInteger256([0x8cf500000000000e, 0xe75281ef6000000e, 0x49dc37a90b0ba012, 0x55f8b2c6e710ab9,]));
+    const COEFF_A_IS_MINUS_ONE: bool = true;
     /// COEFF_D = 3021
     const COEFF_D: Fq =
         field!(Fq, BigInteger256([0xd047ffffffff5e30, 0xf0a91026ffff57d2, 0x9013f560d102582, 0x9fd242ca7be5700,]));
@@ -89,6 +90,12 @@ impl MontgomeryParameters for EdwardsParameters {
     );
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// ELLIGATOR2_Z = 11
+    const ELLIGATOR2_Z: Fq =
+        field!(Fq, BigInteger256([0x19beffffffffff6a, 0x761e46b21fffff64, 0x565ad035f75edf35, 0xf929a91a9d71f63]));
+}
+
 impl FromStr for EdwardsAffine {
     type Err = GroupError;
 
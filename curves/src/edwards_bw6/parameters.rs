@@ -21,9 +21,9 @@ use crate::{
     traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::field;
-use snarkvm_utilities::biginteger::BigInteger384 as BigInteger;
+use snarkvm_utilities::{biginteger::BigInteger384 as BigInteger, vec::Vec};
 
-use std::str::FromStr;
+use core::str::FromStr;
 
 pub type EdwardsAffine = Affine<EdwardsParameters>;
 pub type EdwardsProjective = Projective<EdwardsParameters>;
@@ -54,6 +54,7 @@ impl TwistedEdwardsParameters for EdwardsParameters {
             81297770384137296,
         ])
     );
+    const COEFF_A_IS_MINUS_ONE: bool = true;
     /// COEFF_D = 79743
     const COEFF_D: Fq = field!(
         Fq,
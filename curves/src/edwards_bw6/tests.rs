@@ -18,7 +18,7 @@ use crate::{
     edwards_bw6::*,
     templates::twisted_edwards_extended::tests::{edwards_test, montgomery_conversion_test},
     traits::{
-        tests_field::{field_serialization_test, field_test, primefield_test},
+        tests_field::{field_encoding_test, field_serialization_test, field_test, primefield_test},
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
@@ -32,6 +32,7 @@ fn test_edwards_bw6_fr() {
     let b: Fr = rand::random();
     field_test(a, b);
     primefield_test::<Fr>();
+    field_encoding_test::<Fr>();
     field_serialization_test::<Fr>();
 }
 
@@ -41,6 +42,7 @@ fn test_edwards_bw6_fq() {
     let b: Fq = rand::random();
     field_test(a, b);
     primefield_test::<Fq>();
+    field_encoding_test::<Fq>();
     field_serialization_test::<Fq>();
 }
 
@@ -64,6 +66,7 @@ fn test_affine_group() {
     for _i in 0..10 {
         let a: EdwardsAffine = rand::random();
         affine_test::<EdwardsAffine>(a);
+        affine_encoding_test::<EdwardsAffine>(a);
     }
 }
 
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::module_inception)]
 // #![cfg_attr(nightly, feature(doc_cfg, external_doc))]
 // #![cfg_attr(nightly, warn(missing_docs))]
@@ -22,15 +23,16 @@
 #[macro_use]
 extern crate derivative;
 
-#[macro_use]
-extern crate thiserror;
-
 pub mod bls12_377;
 
+pub mod bw6_761;
+
 pub mod edwards_bls12;
 
 pub mod edwards_bw6;
 
+pub mod pasta;
+
 pub mod errors;
 pub use errors::*;
 
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// Pallas base field, which is also the Vesta scalar field.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// p = 28948022309329048855892746252171976963363056481941560715954676764349967630337
+/// R = 28948022309329048855892746252171976963180815219815881891593553714863226748925 # Montgomery R
+/// s = 32
+/// o = p - 1
+/// F = GF(p)
+/// g = F(5)
+/// assert g.multiplicative_order() == o
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen (g % p): ", g % p)
+/// print("Gen (g * R % p): ", g * R % p)
+/// print("Gen into_chunks(g * R % p): ", into_chunks(g * R % p, 64, 4))
+/// print("2-adic gen (g2 % p): ", g2 % p)
+/// print("2-adic gen (g2 * R % p): ", g2 * R % p)
+/// print("2-adic gen into_chunks(g2 * R % p): ", into_chunks(g2 * R % p, 64, 4))
+/// ```
+pub type Fp = Fp256<FpParameters>;
+
+pub struct FpParameters;
+
+impl Fp256Parameters for FpParameters {}
+
+impl FftParameters for FpParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = 19814229590243028906643993866117402072516588566294623396325693409366934201135
+    /// Encoded in Montgomery form, the value is
+    /// (19814229590243028906643993866117402072516588566294623396325693409366934201135 * R % p) =
+    /// 28398812082400746296050252412403405061164701788217750804166588481020022414320
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        11713220832667294704u64,
+        10413392179731184095u64,
+        18133385229535560846u64,
+        4524191781424318170u64,
+    ]);
+}
+
+impl FieldParameters for FpParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 5
+    /// Encoded in Montgomery form, so the value is
+    /// (5 * R) % p = 28948022309329048855892746252171976962451850171313166594149061516916263223277
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        11647819816328232941u64,
+        8413468796752855795u64,
+        18446744073709551613u64,
+        4611686018427387903u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 11037532056220336127u64;
+    /// MODULUS = 28948022309329048855892746252171976963363056481941560715954676764349967630337
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        11037532056220336129u64,
+        2469829653914515739u64,
+        0u64,
+        4611686018427387904u64,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (p - 1)/2 =
+    /// 14474011154664524427946373126085988481681528240970780357977338382174983815168
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xcc96987680000000,
+        0x11234c7e04a67c8d,
+        0x0,
+        0x2000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        3780891978758094845u64,
+        11037255111966004397u64,
+        18446744073709551615u64,
+        4611686018427387903u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        10122100416058490895u64,
+        15551789045973377255u64,
+        8617542898466512152u64,
+        679271340751763220u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where p - 1 = 2^s * t
+
+    /// t = (p - 1) / 2^s =
+    /// 6739986666787659948666753771754907668419893943225396963757154709741
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x94cf91b992d30ed,
+        0x224698fc,
+        0x0,
+        0x40000000,
+    ]);
+    /// (t - 1) / 2 =
+    /// 3369993333393829974333376885877453834209946971612698481878577354870
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x4a67c8dcc969876,
+        0x11234c7e,
+        0x0,
+        0x20000000,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FpParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+    const PARAMS_OPT_FOR_WEIGHTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(3, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(4, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(5, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(6, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(7, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(8, 257, 8, 13, 0),
+    ];
+}
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// Vesta base field, which is also the Pallas scalar field.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// q = 28948022309329048855892746252171976963363056481941647379679742748393362948097
+/// R = 28948022309329048855892746252171976963180815219815621900418355762733040795645 # Montgomery R
+/// s = 32
+/// o = q - 1
+/// F = GF(q)
+/// g = F(5)
+/// assert g.multiplicative_order() == o
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen (g % q): ", g % q)
+/// print("Gen (g * R % q): ", g * R % q)
+/// print("Gen into_chunks(g * R % q): ", into_chunks(g * R % q, 64, 4))
+/// print("2-adic gen (g2 % q): ", g2 % q)
+/// print("2-adic gen (g2 * R % q): ", g2 * R % q)
+/// print("2-adic gen into_chunks(g2 * R % q): ", into_chunks(g2 * R % q, 64, 4))
+/// ```
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = 20761624379169977859705911634190121761503565370703356079647768903521299517535
+    /// Encoded in Montgomery form, the value is
+    /// (20761624379169977859705911634190121761503565370703356079647768903521299517535 * R % q) =
+    /// 5190958972746962149895688928775469715904512015574928711273653649140790936286
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        2414060527980987102u64,
+        14720393103524889748u64,
+        12406956448539459298u64,
+        826967475050360918u64,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 5
+    /// Encoded in Montgomery form, so the value is
+    /// (5 * R) % q = 28948022309329048855892746252171976962451850171311519983372807820091752185837
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        10861710938529071085u64,
+        8413468796663592846u64,
+        18446744073709551613u64,
+        4611686018427387903u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 10108024940646105087u64;
+    /// MODULUS = 28948022309329048855892746252171976963363056481941647379679742748393362948097
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        10108024940646105089u64,
+        2469829653919213789u64,
+        0u64,
+        4611686018427387904u64,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (q - 1)/2 =
+    /// 14474011154664524427946373126085988481681528240970823689839871374196681474048
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xc623759080000000,
+        0x11234c7e04ca546e,
+        0x0,
+        0x2000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        6569413325480787965u64,
+        11037255111951910247u64,
+        18446744073709551615u64,
+        4611686018427387903u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        18200867980676431887u64,
+        7474641938123724515u64,
+        9200329640471491984u64,
+        679271340771891881u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where q - 1 = 2^s * t
+
+    /// t = (q - 1) / 2^s =
+    /// 6739986666787659948666753771754907668419893943225417141728043264801
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x994a8dd8c46eb21,
+        0x224698fc,
+        0x0,
+        0x40000000,
+    ]);
+    /// (t - 1) / 2 =
+    /// 3369993333393829974333376885877453834209946971612708570864021632400
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x4ca546ec6237590,
+        0x11234c7e,
+        0x0,
+        0x20000000,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+    const PARAMS_OPT_FOR_WEIGHTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(3, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(4, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(5, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(6, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(7, 257, 8, 13, 0),
+        PoseidonDefaultParametersEntry::new(8, 257, 8, 13, 0),
+    ];
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    pasta::{Fp, Fq},
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+
+pub type PallasAffine = Affine<PallasParameters>;
+pub type PallasProjective = Projective<PallasParameters>;
+
+/// Pallas: `y^2 = x^3 + 5` over `Fp`, whose prime order is the modulus of `Fq`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct PallasParameters;
+
+impl ModelParameters for PallasParameters {
+    type BaseField = Fp;
+    type ScalarField = Fq;
+}
+
+impl ShortWeierstrassParameters for PallasParameters {
+    /// AFFINE_GENERATOR_COEFFS = (PALLAS_GENERATOR_X, PALLAS_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (PALLAS_GENERATOR_X, PALLAS_GENERATOR_Y);
+    /// COEFF_A = 0
+    const COEFF_A: Fp = field!(Fp, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// COEFF_B = 5
+    const COEFF_B: Fp =
+        field!(Fp, BigInteger256([0xa1a55e68ffffffed, 0x74c2a54b4f4982f3, 0xfffffffffffffffd, 0x3fffffffffffffff]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod q = 1
+    const COFACTOR_INV: Fq =
+        field!(Fq, BigInteger256([0x5b2b3e9cfffffffd, 0x992c350be3420567, 0xffffffffffffffff, 0x3fffffffffffffff]));
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// PALLAS_GENERATOR_X = -1
+pub const PALLAS_GENERATOR_X: Fp = field!(Fp, BigInteger256([0x64b4c3b400000004, 0x891a63f02533e46e, 0x0, 0x0]));
+
+/// PALLAS_GENERATOR_Y = 2
+pub const PALLAS_GENERATOR_Y: Fp =
+    field!(Fp, BigInteger256([0xcfc3a984fffffff9, 0x1011d11bbee5303e, 0xffffffffffffffff, 0x3fffffffffffffff]));
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    pasta::*,
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        tests_field::{field_encoding_test, field_serialization_test, field_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        ProjectiveCurve,
+    },
+};
+use snarkvm_fields::{
+    FftField,
+    FftParameters,
+    Field,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+    PrimeField,
+    Zero,
+};
+use snarkvm_utilities::{
+    serialize::{CanonicalSerialize, ConstantSerializedSize},
+    BitIteratorBE,
+};
+
+#[test]
+fn test_pasta_fp() {
+    let a: Fp = rand::random();
+    let b: Fp = rand::random();
+    field_test(a, b);
+    primefield_test::<Fp>();
+    field_encoding_test::<Fp>();
+    sqrt_field_test(a);
+    field_serialization_test::<Fp>();
+}
+
+#[test]
+fn test_pasta_fq() {
+    let a: Fq = rand::random();
+    let b: Fq = rand::random();
+    field_test(a, b);
+    primefield_test::<Fq>();
+    field_encoding_test::<Fq>();
+    sqrt_field_test(a);
+    field_serialization_test::<Fq>();
+}
+
+#[test]
+fn test_root_of_unity() {
+    fn check<F: PrimeField>() {
+        let two_adicity = <F as FftField>::FftParameters::TWO_ADICITY;
+        assert_eq!(F::two_adic_root_of_unity(), F::multiplicative_generator().pow(F::trace()));
+        assert_eq!(F::two_adic_root_of_unity().pow([1 << two_adicity]), F::one());
+        assert_ne!(F::two_adic_root_of_unity().pow([1 << (two_adicity - 1)]), F::one());
+    }
+    check::<Fp>();
+    check::<Fq>();
+}
+
+#[test]
+fn test_poseidon_defaults() {
+    fn check<F: PrimeField>()
+    where
+        F::Parameters: PoseidonDefaultParameters,
+    {
+        for entry in F::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().chain(&F::Parameters::PARAMS_OPT_FOR_WEIGHTS) {
+            let generated = PoseidonDefaultParametersEntry::generate::<F>(entry.rate, entry.alpha as u64, 128).unwrap();
+            assert_eq!(*entry, generated);
+        }
+    }
+    check::<Fp>();
+    check::<Fq>();
+}
+
+#[test]
+fn test_pallas_projective_curve() {
+    curve_tests::<PallasProjective>();
+    sw_tests::<PallasParameters>();
+}
+
+#[test]
+fn test_pallas_projective_group() {
+    let a: PallasProjective = rand::random();
+    let b: PallasProjective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_pallas_affine_group() {
+    let a: PallasAffine = rand::random();
+    affine_test(a);
+}
+
+#[test]
+fn test_pallas_generator() {
+    let generator = PallasAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_vesta_projective_curve() {
+    curve_tests::<VestaProjective>();
+    sw_tests::<VestaParameters>();
+}
+
+#[test]
+fn test_vesta_projective_group() {
+    let a: VestaProjective = rand::random();
+    let b: VestaProjective = rand::random();
+    projective_test(a, b);
+}
+
+#[test]
+fn test_vesta_affine_group() {
+    let a: VestaAffine = rand::random();
+    affine_test(a);
+}
+
+#[test]
+fn test_vesta_generator() {
+    let generator = VestaAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_cycle() {
+    // The order of each curve is the base field modulus of the other.
+    let pallas = PallasAffine::prime_subgroup_generator();
+    assert!(pallas.mul_bits(BitIteratorBE::new(Fq::characteristic())).is_zero());
+    assert!(!pallas.mul_bits(BitIteratorBE::new(Fp::characteristic())).is_zero());
+
+    let vesta = VestaAffine::prime_subgroup_generator();
+    assert!(vesta.mul_bits(BitIteratorBE::new(Fp::characteristic())).is_zero());
+    assert!(!vesta.mul_bits(BitIteratorBE::new(Fq::characteristic())).is_zero());
+
+    // A scalar of one curve embeds into the base field of the other without reduction.
+    let scalar: Fq = rand::random();
+    assert_eq!(Fp::from_repr(scalar.to_repr()).unwrap().to_repr(), scalar.to_repr());
+    assert_eq!((pallas * scalar).to_affine(), pallas.mul_bits(BitIteratorBE::new(scalar.to_repr())).to_affine());
+}
+
+#[test]
+fn test_serialized_size() {
+    // The two bits of `SWFlags` do not fit in the one spare bit of a 255-bit field, so they take an additional byte.
+    assert_eq!(Fp::SPARE_BITS, 1);
+    assert_eq!(PallasAffine::SERIALIZED_SIZE, 33);
+    assert_eq!(PallasAffine::UNCOMPRESSED_SIZE, 65);
+    assert_eq!(VestaAffine::SERIALIZED_SIZE, 33);
+    assert_eq!(VestaAffine::UNCOMPRESSED_SIZE, 65);
+
+    let a: PallasAffine = rand::random();
+    assert_eq!(a.serialized_size(), 33);
+    assert_eq!(a.uncompressed_size(), 65);
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    pasta::{Fp, Fq},
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+
+pub type VestaAffine = Affine<VestaParameters>;
+pub type VestaProjective = Projective<VestaParameters>;
+
+/// Vesta: `y^2 = x^3 + 5` over `Fq`, whose prime order is the modulus of `Fp`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct VestaParameters;
+
+impl ModelParameters for VestaParameters {
+    type BaseField = Fq;
+    type ScalarField = Fp;
+}
+
+impl ShortWeierstrassParameters for VestaParameters {
+    /// AFFINE_GENERATOR_COEFFS = (VESTA_GENERATOR_X, VESTA_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (VESTA_GENERATOR_X, VESTA_GENERATOR_Y);
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// COEFF_B = 5
+    const COEFF_B: Fq =
+        field!(Fq, BigInteger256([0x96bc8c8cffffffed, 0x74c2a54b49f7778e, 0xfffffffffffffffd, 0x3fffffffffffffff]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod p = 1
+    const COFACTOR_INV: Fp =
+        field!(Fp, BigInteger256([0x34786d38fffffffd, 0x992c350be41914ad, 0xffffffffffffffff, 0x3fffffffffffffff]));
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// VESTA_GENERATOR_X = -1
+pub const VESTA_GENERATOR_X: Fq = field!(Fq, BigInteger256([0x311bac8400000004, 0x891a63f02652a376, 0x0, 0x0]));
+
+/// VESTA_GENERATOR_Y = 2
+pub const VESTA_GENERATOR_Y: Fq =
+    field!(Fq, BigInteger256([0x2a0f9218fffffff9, 0x1011d11bbcef61f1, 0xffffffffffffffff, 0x3fffffffffffffff]));
@@ -33,9 +33,9 @@ use snarkvm_fields::{
     PrimeField,
     SquareRootField,
 };
-use snarkvm_utilities::bititerator::BitIteratorBE;
+use snarkvm_utilities::{bititerator::BitIteratorBE, vec};
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 pub enum TwistType {
     M,
@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::GroupError,
+    templates::short_weierstrass_jacobian::Affine,
+    traits::{AffineCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{Fp2, Fp2Parameters, Fp384, Fp384Parameters, PrimeField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger384, format, string::ToString, vec, vec::Vec};
+
+/// The flag bit set in the first byte of a compressed encoding.
+const COMPRESSION_FLAG: u8 = 1 << 7;
+/// The flag bit set in the first byte of an encoding of the point at infinity.
+const INFINITY_FLAG: u8 = 1 << 6;
+/// The flag bit set in the first byte of a compressed encoding whose y-coordinate is lexicographically largest.
+const SORT_FLAG: u8 = 1 << 5;
+/// The mask of all flag bits in the first byte of an encoding.
+const FLAGS_MASK: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG;
+
+/// A base field with a fixed-size big-endian encoding, as used by the Zcash BLS12 serialization convention.
+///
+/// The encoding of a field element must leave the three most significant bits of its first byte unset,
+/// so that they are available for the flags of a point encoding.
+pub trait ZcashFieldEncoding: Sized {
+    /// The number of bytes in the encoding of a field element.
+    const SERIALIZED_SIZE: usize;
+
+    /// Writes the big-endian encoding of `self` into `output`, which holds exactly `Self::SERIALIZED_SIZE` bytes.
+    fn write_be(&self, output: &mut [u8]);
+
+    /// Reads a field element from `input`, which holds exactly `Self::SERIALIZED_SIZE` bytes.
+    /// Returns `None` if the encoding is not canonical.
+    fn read_be(input: &[u8]) -> Option<Self>;
+}
+
+impl<P: Fp384Parameters> ZcashFieldEncoding for Fp384<P> {
+    const SERIALIZED_SIZE: usize = 48;
+
+    fn write_be(&self, output: &mut [u8]) {
+        let repr = self.to_repr();
+        for (chunk, limb) in output.chunks_exact_mut(8).zip(repr.0.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+    }
+
+    fn read_be(input: &[u8]) -> Option<Self> {
+        let mut repr = BigInteger384::default();
+        for (limb, chunk) in repr.0.iter_mut().rev().zip(input.chunks_exact(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            *limb = u64::from_be_bytes(bytes);
+        }
+        Self::from_repr(repr)
+    }
+}
+
+/// Elements of `Fp2` are encoded as `c1 || c0`.
+impl<P: Fp2Parameters> ZcashFieldEncoding for Fp2<P>
+where
+    P::Fp: ZcashFieldEncoding,
+{
+    const SERIALIZED_SIZE: usize = 2 * P::Fp::SERIALIZED_SIZE;
+
+    fn write_be(&self, output: &mut [u8]) {
+        let (c1, c0) = output.split_at_mut(P::Fp::SERIALIZED_SIZE);
+        self.c1.write_be(c1);
+        self.c0.write_be(c0);
+    }
+
+    fn read_be(input: &[u8]) -> Option<Self> {
+        let (c1, c0) = input.split_at(P::Fp::SERIALIZED_SIZE);
+        Some(Self::new(P::Fp::read_be(c0)?, P::Fp::read_be(c1)?))
+    }
+}
+
+/// The point encodings of the Zcash BLS12 serialization convention.
+///
+/// A compressed encoding is the big-endian x-coordinate, and an uncompressed encoding is
+/// the big-endian x-coordinate followed by the big-endian y-coordinate. The three most
+/// significant bits of the first byte hold the compression flag, the infinity flag, and,
+/// for compressed encodings, the sort flag, which is set if the y-coordinate is the
+/// lexicographically largest of `y` and `-y`. The point at infinity is encoded with all
+/// other bits unset.
+///
+/// The `_unchecked` decoders check that the point is on the curve, but skip the
+/// (expensive) check that the point is in the prime-order subgroup. They must only be
+/// used on trusted inputs.
+pub trait ZcashEncoding: Sized {
+    /// The number of bytes in a compressed encoding.
+    const COMPRESSED_SIZE: usize;
+
+    /// The number of bytes in an uncompressed encoding.
+    const UNCOMPRESSED_SIZE: usize;
+
+    /// Returns the compressed encoding of `self`.
+    fn to_compressed(&self) -> Vec<u8>;
+
+    /// Returns the uncompressed encoding of `self`.
+    fn to_uncompressed(&self) -> Vec<u8>;
+
+    /// Returns the point of the given compressed encoding, without checking that it is in the prime-order subgroup.
+    fn from_compressed_unchecked(bytes: &[u8]) -> Result<Self, GroupError>;
+
+    /// Returns the point of the given uncompressed encoding, without checking that it is in the prime-order subgroup.
+    fn from_uncompressed_unchecked(bytes: &[u8]) -> Result<Self, GroupError>;
+
+    /// Returns `true` if `self` is in the prime-order subgroup.
+    fn is_in_prime_order_subgroup(&self) -> bool;
+
+    /// Returns the point of the given compressed encoding.
+    fn from_compressed(bytes: &[u8]) -> Result<Self, GroupError> {
+        let point = Self::from_compressed_unchecked(bytes)?;
+        match point.is_in_prime_order_subgroup() {
+            true => Ok(point),
+            false => Err(GroupError::InvalidGroupElement),
+        }
+    }
+
+    /// Returns the point of the given uncompressed encoding.
+    fn from_uncompressed(bytes: &[u8]) -> Result<Self, GroupError> {
+        let point = Self::from_uncompressed_unchecked(bytes)?;
+        match point.is_in_prime_order_subgroup() {
+            true => Ok(point),
+            false => Err(GroupError::InvalidGroupElement),
+        }
+    }
+}
+
+/// Returns the flags of the given encoding, and a copy of its first `size` bytes with the flags cleared.
+fn split_flags(bytes: &[u8], size: usize) -> Result<(u8, Vec<u8>), GroupError> {
+    if bytes.len() != size {
+        return Err(GroupError::Message(format!("Expected an encoding of {} bytes, found {}", size, bytes.len())));
+    }
+    let mut bytes = bytes.to_vec();
+    let flags = bytes[0] & FLAGS_MASK;
+    bytes[0] &= !FLAGS_MASK;
+    Ok((flags, bytes))
+}
+
+/// Returns `true` if `y` is the lexicographically largest of `y` and `-y`.
+fn is_lexicographically_largest<F: Ord + Copy + core::ops::Neg<Output = F>>(y: &F) -> bool {
+    *y > -*y
+}
+
+impl<P: ShortWeierstrassParameters> ZcashEncoding for Affine<P>
+where
+    P::BaseField: ZcashFieldEncoding,
+{
+    const COMPRESSED_SIZE: usize = P::BaseField::SERIALIZED_SIZE;
+    const UNCOMPRESSED_SIZE: usize = 2 * P::BaseField::SERIALIZED_SIZE;
+
+    fn to_compressed(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::COMPRESSED_SIZE];
+        match self.infinity {
+            true => bytes[0] |= INFINITY_FLAG,
+            false => {
+                self.x.write_be(&mut bytes);
+                if is_lexicographically_largest(&self.y) {
+                    bytes[0] |= SORT_FLAG;
+                }
+            }
+        }
+        bytes[0] |= COMPRESSION_FLAG;
+        bytes
+    }
+
+    fn to_uncompressed(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; Self::UNCOMPRESSED_SIZE];
+        match self.infinity {
+            true => bytes[0] |= INFINITY_FLAG,
+            false => {
+                let (x, y) = bytes.split_at_mut(P::BaseField::SERIALIZED_SIZE);
+                self.x.write_be(x);
+                self.y.write_be(y);
+            }
+        }
+        bytes
+    }
+
+    fn from_compressed_unchecked(bytes: &[u8]) -> Result<Self, GroupError> {
+        let (flags, bytes) = split_flags(bytes, Self::COMPRESSED_SIZE)?;
+        if flags & COMPRESSION_FLAG == 0 {
+            return Err(GroupError::Message("Expected a compressed encoding".to_string()));
+        }
+
+        if flags & INFINITY_FLAG != 0 {
+            // The point at infinity has no sort flag and no other bits set.
+            return match flags & SORT_FLAG == 0 && bytes.iter().all(|byte| *byte == 0) {
+                true => Ok(Self::zero()),
+                false => Err(GroupError::InvalidGroupElement),
+            };
+        }
+
+        let x = P::BaseField::read_be(&bytes).ok_or(GroupError::InvalidGroupElement)?;
+        Self::from_x_coordinate(x, flags & SORT_FLAG != 0).ok_or(GroupError::InvalidGroupElement)
+    }
+
+    fn from_uncompressed_unchecked(bytes: &[u8]) -> Result<Self, GroupError> {
+        let (flags, bytes) = split_flags(bytes, Self::UNCOMPRESSED_SIZE)?;
+        if flags & COMPRESSION_FLAG != 0 {
+            return Err(GroupError::Message("Expected an uncompressed encoding".to_string()));
+        }
+        if flags & SORT_FLAG != 0 {
+            return Err(GroupError::InvalidGroupElement);
+        }
+
+        if flags & INFINITY_FLAG != 0 {
+            // The point at infinity has no other bits set.
+            return match bytes.iter().all(|byte| *byte == 0) {
+                true => Ok(Self::zero()),
+                false => Err(GroupError::InvalidGroupElement),
+            };
+        }
+
+        let (x, y) = bytes.split_at(P::BaseField::SERIALIZED_SIZE);
+        let x = P::BaseField::read_be(x).ok_or(GroupError::InvalidGroupElement)?;
+        let y = P::BaseField::read_be(y).ok_or(GroupError::InvalidGroupElement)?;
+        let point = Self::new(x, y, false);
+        match point.is_on_curve() {
+            true => Ok(point),
+            false => Err(GroupError::InvalidGroupElement),
+        }
+    }
+
+    fn is_in_prime_order_subgroup(&self) -> bool {
+        self.is_in_correct_subgroup_assuming_on_curve()
+    }
+}
@@ -17,6 +17,9 @@
 pub mod bls12;
 pub use bls12::*;
 
+pub mod encoding;
+pub use encoding::*;
+
 pub mod g1;
 pub use g1::*;
 
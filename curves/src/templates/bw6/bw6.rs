@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bw6::{
+            g1::{G1Affine, G1Prepared, G1Projective},
+            g2::{G2Affine, G2Prepared, G2Projective},
+        },
+    },
+    traits::{ModelParameters, PairingCurve, PairingEngine, ShortWeierstrassParameters},
+};
+use serde::{Deserialize, Serialize};
+use snarkvm_fields::{
+    fp6_2over3::{Fp6, Fp6Parameters},
+    Field,
+    Fp3Parameters,
+    One,
+    PrimeField,
+    SquareRootField,
+};
+use snarkvm_utilities::{bititerator::BitIteratorBE, vec};
+
+use core::marker::PhantomData;
+
+pub trait BW6Parameters: 'static {
+    /// The seed `u` of the inner BLS12 curve.
+    const X: &'static [u64];
+    const X_IS_NEGATIVE: bool;
+    /// The loop count `u + 1` of the first Miller loop.
+    const ATE_LOOP_COUNT_1: &'static [u64];
+    const ATE_LOOP_COUNT_1_IS_NEGATIVE: bool;
+    /// The non-adjacent form of the loop count `u^3 - u^2 - u` of the second Miller loop, lowest digit first.
+    const ATE_LOOP_COUNT_2: &'static [i8];
+    const ATE_LOOP_COUNT_2_IS_NEGATIVE: bool;
+    /// The coefficients of `R0(x)`, lowest degree first, where the hard part of the final exponentiation
+    /// is computed as `f^(R0(u) + q * R1(u))`, as in https://eprint.iacr.org/2020/351.pdf, Alg. 6.
+    const FINAL_EXPONENT_R0: &'static [i64];
+    /// The coefficients of `R1(x)`, lowest degree first.
+    const FINAL_EXPONENT_R1: &'static [i64];
+    const TWIST_TYPE: TwistType;
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInteger>;
+    type Fp3Params: Fp3Parameters<Fp = Self::Fp>;
+    type Fp6Params: Fp6Parameters<Fp3Params = Self::Fp3Params>;
+    type G1Parameters: ShortWeierstrassParameters<BaseField = Self::Fp>;
+    type G2Parameters: ShortWeierstrassParameters<
+        BaseField = Self::Fp,
+        ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+    >;
+}
+
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct BW6<P: BW6Parameters>(PhantomData<fn() -> P>);
+
+type CoeffTriplet<T> = (T, T, T);
+
+impl<P: BW6Parameters> BW6<P> {
+    // Evaluate the line function at point p.
+    fn ell(f: &mut Fp6<P::Fp6Params>, coeffs: &CoeffTriplet<P::Fp>, p: &G1Affine<P>) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_014(&c0, &c1, &c2);
+            }
+            TwistType::D => {
+                c0 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_034(&c0, &c1, &c2);
+            }
+        }
+    }
+
+    fn exp_by_x(mut f: Fp6<P::Fp6Params>) -> Fp6<P::Fp6Params> {
+        f = f.cyclotomic_exp(P::X);
+        if P::X_IS_NEGATIVE {
+            f.conjugate();
+        }
+        f
+    }
+
+    // Raise an element of the cyclotomic subgroup to a small signed power.
+    fn exp_by_small(f: &Fp6<P::Fp6Params>, exponent: i64) -> Fp6<P::Fp6Params> {
+        let mut result = f.cyclotomic_exp([exponent.unsigned_abs()]);
+        if exponent < 0 {
+            result.conjugate();
+        }
+        result
+    }
+
+    fn final_exponentiation_hard_part(f: &Fp6<P::Fp6Params>) -> Fp6<P::Fp6Params> {
+        // Computes f^(R0(u) + q * R1(u)) with Horner's rule in u,
+        // where each coefficient is f^(R0_i) * (f^q)^(R1_i).
+        let mut f_q = *f;
+        f_q.frobenius_map(1);
+
+        let coefficient = |i: usize| {
+            let r0 = P::FINAL_EXPONENT_R0.get(i).copied().unwrap_or(0);
+            let r1 = P::FINAL_EXPONENT_R1.get(i).copied().unwrap_or(0);
+            Self::exp_by_small(f, r0) * Self::exp_by_small(&f_q, r1)
+        };
+
+        let degree = P::FINAL_EXPONENT_R0.len().max(P::FINAL_EXPONENT_R1.len());
+        let mut result = coefficient(degree - 1);
+        for i in (0..degree - 1).rev() {
+            result = Self::exp_by_x(result) * coefficient(i);
+        }
+        result
+    }
+}
+
+impl<P: BW6Parameters> PairingEngine for BW6<P>
+where
+    G1Affine<P>: PairingCurve<
+        BaseField = <P::G1Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G1Projective<P>,
+        PairWith = G2Affine<P>,
+        Prepared = G1Prepared<P>,
+        PairingResult = Fp6<P::Fp6Params>,
+    >,
+    G2Affine<P>: PairingCurve<
+        BaseField = <P::G2Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G2Projective<P>,
+        PairWith = G1Affine<P>,
+        Prepared = G2Prepared<P>,
+        PairingResult = Fp6<P::Fp6Params>,
+    >,
+{
+    type Fq = P::Fp;
+    type Fqe = P::Fp;
+    type Fqk = Fp6<P::Fp6Params>;
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Affine = G1Affine<P>;
+    type G1Projective = G1Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Projective = G2Projective<P>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: Iterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        // Computes f_{u + 1, Q}(P) * f_{u^3 - u^2 - u, Q}(P)^q, as in https://eprint.iacr.org/2020/351.pdf.
+        let mut pairs_1 = vec![];
+        let mut pairs_2 = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs_1.push((p, q.ell_coeffs_1.iter()));
+                pairs_2.push((p, q.ell_coeffs_2.iter()));
+            }
+        }
+
+        let mut f_1 = Self::Fqk::one();
+
+        for i in BitIteratorBE::new(P::ATE_LOOP_COUNT_1).skip(1) {
+            f_1.square_in_place();
+
+            for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                    Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::ATE_LOOP_COUNT_1_IS_NEGATIVE {
+            f_1.conjugate();
+        }
+
+        let mut f_2 = Self::Fqk::one();
+
+        for i in (1..P::ATE_LOOP_COUNT_2.len()).rev() {
+            if i != P::ATE_LOOP_COUNT_2.len() - 1 {
+                f_2.square_in_place();
+            }
+
+            for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+            }
+
+            if P::ATE_LOOP_COUNT_2[i - 1] != 0 {
+                for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                    Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::ATE_LOOP_COUNT_2_IS_NEGATIVE {
+            f_2.conjugate();
+        }
+
+        f_2.frobenius_map(1);
+
+        f_1 * f_2
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        // The easy part of the final exponentiation is f^((q^3 - 1) * (q + 1)).
+        let f_inv = f.inverse()?;
+
+        // f1 = f^(q^3 - 1)
+        let mut f1 = *f;
+        f1.conjugate();
+        f1 *= &f_inv;
+
+        // f2 = f^((q^3 - 1) * (q + 1))
+        let mut f2 = f1;
+        f2.frobenius_map(1);
+        f2 *= &f1;
+
+        Some(Self::final_exponentiation_hard_part(&f2))
+    }
+}
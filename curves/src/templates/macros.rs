@@ -69,8 +69,13 @@ macro_rules! impl_sw_curve_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for Projective<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
-            const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::number_of_bytes_with_flags(
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE,
+                <P::BaseField as ConstantSerializedSize>::SPARE_BITS,
+                snarkvm_utilities::serialize::SWFlags::BIT_SIZE,
+            );
+            const UNCOMPRESSED_SIZE: usize =
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE + Self::SERIALIZED_SIZE;
         }
 
         impl<P: $params> CanonicalSerialize for Affine<P> {
@@ -118,8 +123,13 @@ macro_rules! impl_sw_curve_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for Affine<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
-            const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::number_of_bytes_with_flags(
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE,
+                <P::BaseField as ConstantSerializedSize>::SPARE_BITS,
+                snarkvm_utilities::serialize::SWFlags::BIT_SIZE,
+            );
+            const UNCOMPRESSED_SIZE: usize =
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE + Self::SERIALIZED_SIZE;
         }
 
         impl<P: $params> CanonicalDeserialize for Affine<P> {
@@ -134,6 +144,7 @@ macro_rules! impl_sw_curve_serializer {
                 } else {
                     let p = Affine::<P>::from_x_coordinate(x, flags.is_positive().unwrap())
                         .ok_or(snarkvm_utilities::serialize::SerializationError::InvalidData)?;
+                    #[cfg(feature = "std")]
                     if !snarkvm_utilities::PROCESSING_SNARK_PARAMS
                         .with(|p| p.load(std::sync::atomic::Ordering::Relaxed))
                     {
@@ -144,6 +155,11 @@ macro_rules! impl_sw_curve_serializer {
                         snarkvm_utilities::SNARK_PARAMS_AFFINE_COUNT
                             .with(|p| p.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
                     }
+                    // Without `std`, the SNARK parameters are not loaded, so the subgroup check is always performed.
+                    #[cfg(not(feature = "std"))]
+                    if !p.is_in_correct_subgroup_assuming_on_curve() {
+                        return Err(snarkvm_utilities::serialize::SerializationError::InvalidData);
+                    }
                     Ok(p)
                 }
             }
@@ -199,7 +215,11 @@ macro_rules! impl_edwards_curve_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for Projective<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::number_of_bytes_with_flags(
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE,
+                <P::BaseField as ConstantSerializedSize>::SPARE_BITS,
+                snarkvm_utilities::serialize::EdwardsFlags::BIT_SIZE,
+            );
             const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
         }
 
@@ -261,7 +281,11 @@ macro_rules! impl_edwards_curve_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for Affine<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::number_of_bytes_with_flags(
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE,
+                <P::BaseField as ConstantSerializedSize>::SPARE_BITS,
+                snarkvm_utilities::serialize::EdwardsFlags::BIT_SIZE,
+            );
             const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
         }
 
@@ -17,29 +17,42 @@
 use crate::{
     impl_sw_curve_serializer,
     templates::short_weierstrass_jacobian::Projective,
-    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    traits::{AffineCurve, ConstantTimeMul, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+};
+use snarkvm_fields::{
+    Choice,
+    ConditionallySelectable,
+    ConstantTime,
+    ConstantTimeEq,
+    Encoding,
+    Field,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
 };
-use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
+    error,
+    io::{Read, Result as IoResult, Write},
     rand::UniformRand,
     serialize::*,
+    BigInteger,
     FromBytes,
     ToBits,
     ToBytes,
     ToMinimalBits,
 };
 
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Mul, Neg},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
 use serde::{Deserialize, Serialize};
-use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
-    io::{Error, ErrorKind, Read, Result as IoResult, Write},
-    ops::{Mul, Neg},
-};
 
 #[derive(Derivative, Serialize, Deserialize)]
 #[derivative(
@@ -139,6 +152,27 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         })
     }
 
+    /// Attempts to construct affine points given pairs of an x-coordinate and
+    /// the `greatest` flag of [`AffineCurve::from_x_coordinate`]. The points are
+    /// not guaranteed to be in the prime order subgroup.
+    fn batch_from_x_coordinate(x_coordinates: &[(Self::BaseField, bool)]) -> Vec<Option<Self>> {
+        // Compute x^3 + ax + b
+        let x3b = x_coordinates.iter().map(|(x, _)| P::add_b(&((x.square() * x) + P::mul_by_a(x)))).collect::<Vec<_>>();
+
+        P::BaseField::batch_sqrt(&x3b)
+            .into_iter()
+            .zip(x_coordinates)
+            .map(|(y, (x, greatest))| {
+                y.map(|y| {
+                    let negy = -y;
+
+                    let y = if (y < negy) ^ greatest { y } else { negy };
+                    Self::new(*x, y, false)
+                })
+            })
+            .collect()
+    }
+
     /// Attempts to construct an affine point given a y-coordinate. The
     /// point is not guaranteed to be in the prime order subgroup.
     ///
@@ -251,6 +285,67 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+/// Returns the sum of two points in homogeneous projective coordinates `(X : Y : Z)`, using the
+/// complete addition formulas of Renes, Costello, and Batina (Algorithm 1 of <https://eprint.iacr.org/2015/1060>).
+///
+/// The formulas have no exceptional cases, unless the difference of the points has order two.
+fn complete_add<P: Parameters>(p1: &[P::BaseField; 3], p2: &[P::BaseField; 3]) -> [P::BaseField; 3] {
+    let [x1, y1, z1] = p1;
+    let [x2, y2, z2] = p2;
+    let b3 = P::COEFF_B.double() + P::COEFF_B;
+
+    let t0 = *x1 * x2;
+    let t1 = *y1 * y2;
+    let t2 = *z1 * z2;
+    let t3 = (*x1 + y1) * (*x2 + y2) - (t0 + t1);
+    let t4 = (*x1 + z1) * (*x2 + z2) - (t0 + t2);
+    let t5 = (*y1 + z1) * (*y2 + z2) - (t1 + t2);
+
+    let z3 = b3 * t2 + P::mul_by_a(&t4);
+    let x3 = t1 - z3;
+    let z3 = t1 + z3;
+    let y3 = x3 * z3;
+
+    let a_t2 = P::mul_by_a(&t2);
+    let t1 = t0.double() + t0 + a_t2;
+    let t4 = b3 * t4 + P::mul_by_a(&(t0 - a_t2));
+
+    [x3 * t3 - t5 * t4, y3 + t1 * t4, z3 * t5 + t3 * t1]
+}
+
+impl<P: Parameters> ConstantTimeMul for Affine<P>
+where
+    P::BaseField: ConstantTime,
+{
+    fn mul_ct(&self, scalar: &P::ScalarField) -> Projective<P> {
+        let zero = [P::BaseField::zero(), P::BaseField::one(), P::BaseField::zero()];
+        let mut r0 = zero;
+        let mut r1 = [self.x, self.y, P::BaseField::one()];
+        for (r1, zero) in r1.iter_mut().zip(&zero) {
+            r1.conditional_assign(zero, Choice::from(self.infinity as u8));
+        }
+
+        // The points in the ladder differ by `self`, which has odd order, so the addition law is complete.
+        let scalar = scalar.to_repr();
+        for i in (0..P::ScalarField::size_in_bits()).rev() {
+            let bit = Choice::from(scalar.get_bit(i) as u8);
+            for (c0, c1) in r0.iter_mut().zip(r1.iter_mut()) {
+                P::BaseField::conditional_swap(c0, c1, bit);
+            }
+            r1 = complete_add::<P>(&r0, &r1);
+            r0 = complete_add::<P>(&r0, &r0);
+            for (c0, c1) in r0.iter_mut().zip(r1.iter_mut()) {
+                P::BaseField::conditional_swap(c0, c1, bit);
+            }
+        }
+
+        // Convert `(X : Y : Z)` to the Jacobian coordinates `(X * Z, Y * Z^2, Z)`.
+        let [x, y, z] = r0;
+        let result = Projective::new(x * z, y * z.square(), z);
+        Projective::conditional_select(&result, &Projective::zero(), z.ct_eq(&P::BaseField::zero()))
+    }
+}
+
 impl<P: Parameters> ToMinimalBits for Affine<P> {
     fn to_minimal_bits(&self) -> Vec<bool> {
         let mut res_bits = self.x.to_bits_le();
@@ -260,6 +355,41 @@ impl<P: Parameters> ToMinimalBits for Affine<P> {
     }
 }
 
+impl<P: Parameters> Encoding for Affine<P>
+where
+    P::BaseField: Encoding,
+{
+    /// Returns the little-endian bits of the x-coordinate, followed by whether the y-coordinate
+    /// is the lexicographically largest one, and whether the point is at infinity.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        let mut bits_le = self.x.encode_bits_le();
+        bits_le.push(self.y > -self.y);
+        bits_le.push(self.infinity);
+        bits_le
+    }
+
+    /// Returns the point in the prime-order subgroup with the given encoding, or `None` if there is no such point.
+    /// Missing bits are treated as zero, and any excess bits must be zero.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        // The x-coordinate has a fixed-size encoding, which is followed by the two flags.
+        let size_in_bits = P::BaseField::zero().encode_bits_le().len();
+        // Ensure all excess bits are zero.
+        if bits_le.iter().skip(size_in_bits + 2).any(|bit| *bit) {
+            return None;
+        }
+
+        let (x_bits_le, flags) = bits_le.split_at(core::cmp::min(bits_le.len(), size_in_bits));
+        let x = P::BaseField::decode_bits_le(x_bits_le)?;
+        let greatest = flags.first().copied().unwrap_or(false);
+        match flags.get(1).copied().unwrap_or(false) {
+            true => (x.is_zero() && !greatest).then(Self::zero),
+            false => {
+                Self::from_x_coordinate(x, greatest).filter(|point| point.is_in_correct_subgroup_assuming_on_curve())
+            }
+        }
+    }
+}
+
 impl<P: Parameters> Neg for Affine<P> {
     type Output = Self;
 
@@ -272,8 +402,10 @@ impl<P: Parameters> Neg for Affine<P> {
 impl<P: Parameters> Mul<P::ScalarField> for Affine<P> {
     type Output = Projective<P>;
 
+    /// Performs scalar multiplication of this element, which must be in the prime-order subgroup
+    /// (see `Parameters::mul_projective`).
     fn mul(self, other: P::ScalarField) -> Self::Output {
-        self.mul_bits(BitIteratorBE::new(other.to_repr()))
+        P::mul_projective(&self.into(), &other)
     }
 }
 
@@ -294,7 +426,7 @@ impl<P: Parameters> FromBytes for Affine<P> {
         let infinity = bool::read_le(&mut reader)?;
 
         if infinity != x.is_zero() && y.is_one() {
-            return Err(Error::new(ErrorKind::InvalidData, "Infinity flag is not valid"));
+            return Err(error("Infinity flag is not valid"));
         }
         Ok(Self::new(x, y, infinity))
     }
@@ -18,18 +18,23 @@ use crate::{
     templates::short_weierstrass_jacobian::Affine,
     traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBytes};
+use snarkvm_fields::{impl_add_sub_from_field_ref, Choice, ConditionallySelectable, Field, One, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
 
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
-    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-};
 
 #[derive(Derivative)]
 #[derivative(
@@ -51,6 +56,20 @@ impl<P: Parameters> Projective<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Display for Projective<P> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}", self.to_affine())
@@ -310,7 +329,6 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
     }
 
     #[inline]
-    #[must_use]
     fn double(&self) -> Self {
         let mut tmp = *self;
         tmp.double_in_place();
@@ -507,19 +525,12 @@ impl<'a, P: Parameters> SubAssign<&'a Self> for Projective<P> {
 impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
     type Output = Self;
 
-    /// Performs scalar multiplication of this element.
+    /// Performs scalar multiplication of this element, which must be in the prime-order subgroup
+    /// (see `Parameters::mul_projective`).
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn mul(self, other: P::ScalarField) -> Self {
-        let mut res = Self::zero();
-        for i in BitIteratorBE::new_without_leading_zeros(other.to_repr()) {
-            res.double_in_place();
-            if i {
-                res += self;
-            }
-        }
-
-        res
+        P::mul_projective(&self, &other)
     }
 }
 
@@ -15,8 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{Affine, Projective};
-use crate::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters};
-use snarkvm_fields::Zero;
+use crate::{AffineCurve, ConstantTimeMul, ProjectiveCurve, ShortWeierstrassParameters};
+use snarkvm_fields::{ConstantTime, Encoding, One, Zero};
 use snarkvm_utilities::{
     io::Cursor,
     rand::{test_rng, UniformRand},
@@ -25,9 +25,72 @@ use snarkvm_utilities::{
 
 pub const ITERATIONS: usize = 10;
 
-pub fn sw_tests<P: ShortWeierstrassParameters>() {
+pub fn sw_tests<P: ShortWeierstrassParameters>()
+where
+    P::BaseField: ConstantTime + Encoding,
+{
     sw_curve_serialization_test::<P>();
     sw_from_random_bytes::<P>();
+    sw_constant_time_mul_test::<P>();
+    sw_batch_from_x_coordinate_test::<P>();
+    sw_encoding_test::<P>();
+}
+
+pub fn sw_encoding_test<P: ShortWeierstrassParameters>()
+where
+    P::BaseField: Encoding,
+{
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(&mut rng).to_affine();
+
+        let bits_le = a.encode_bits_le();
+        assert_eq!(bits_le[..bits_le.len() - 2], a.x.encode_bits_le());
+        assert_eq!(Affine::<P>::decode_bits_le(&bits_le), Some(a));
+        assert_eq!(Affine::<P>::decode_bits_be(&a.encode_bits_be()), Some(a));
+        assert_eq!(Affine::<P>::decode_bytes_le(&a.encode_bytes_le()), Some(a));
+
+        // The negation has the same x-coordinate, so it must be told apart by its y-coordinate.
+        assert_ne!(a.encode_bits_le(), (-a).encode_bits_le());
+        assert_eq!(Affine::<P>::decode_bits_le(&(-a).encode_bits_le()), Some(-a));
+    }
+
+    let zero = Affine::<P>::zero();
+    assert_eq!(Affine::<P>::decode_bits_le(&zero.encode_bits_le()), Some(zero));
+}
+
+pub fn sw_batch_from_x_coordinate_test<P: ShortWeierstrassParameters>() {
+    let mut rng = test_rng();
+
+    let mut x_coordinates = Vec::with_capacity(2 * ITERATIONS);
+    for _ in 0..ITERATIONS {
+        let x = Projective::<P>::rand(&mut rng).to_affine().x;
+        x_coordinates.push((x, true));
+        x_coordinates.push((P::BaseField::rand(&mut rng), false));
+    }
+
+    let expected =
+        x_coordinates.iter().map(|(x, greatest)| Affine::<P>::from_x_coordinate(*x, *greatest)).collect::<Vec<_>>();
+    assert_eq!(Affine::<P>::batch_from_x_coordinate(&x_coordinates), expected);
+}
+
+pub fn sw_constant_time_mul_test<P: ShortWeierstrassParameters>()
+where
+    P::BaseField: ConstantTime,
+{
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(&mut rng).to_affine();
+        let s = P::ScalarField::rand(&mut rng);
+
+        assert_eq!(a.mul_ct(&s), a * s);
+        assert_eq!(a.mul_ct(&-s), -(a * s));
+        assert_eq!(a.mul_ct(&P::ScalarField::one()), a.to_projective());
+        assert!(a.mul_ct(&P::ScalarField::zero()).is_zero());
+        assert!(Affine::<P>::zero().mul_ct(&s).is_zero());
+    }
 }
 
 pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>() {
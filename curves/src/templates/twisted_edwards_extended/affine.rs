@@ -17,29 +17,30 @@
 use crate::{
     impl_edwards_curve_serializer,
     templates::twisted_edwards_extended::Projective,
-    traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
+    traits::{AffineCurve, ConstantTimeMul, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
-use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{Choice, ConditionallySelectable, Encoding, Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
+    io::{Read, Result as IoResult, Write},
     rand::UniformRand,
     serialize::*,
+    BigInteger,
     FromBytes,
     ToBits,
     ToBytes,
     ToMinimalBits,
 };
 
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Mul, Neg},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
 use serde::{Deserialize, Serialize};
-use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
-    ops::{Mul, Neg},
-};
 
 #[derive(Derivative, Serialize, Deserialize)]
 #[derivative(
@@ -133,6 +134,33 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         })
     }
 
+    /// Attempts to construct affine points given pairs of an x-coordinate and
+    /// the `greatest` flag of [`AffineCurve::from_x_coordinate`]. The points are
+    /// not guaranteed to be in the prime order subgroup.
+    fn batch_from_x_coordinate(x_coordinates: &[(Self::BaseField, bool)]) -> Vec<Option<Self>> {
+        // y = sqrt( (a * x^2 - 1)  / (d * x^2 - 1) )
+        let one = Self::BaseField::one();
+        let x2s = x_coordinates.iter().map(|(x, _)| x.square()).collect::<Vec<_>>();
+        let mut denominators = x2s.iter().map(|x2| P::COEFF_D * x2 - one).collect::<Vec<_>>();
+        // Zero denominators are left unchanged by the batch inversion, and are rejected below.
+        Self::BaseField::batch_inverse_in_place(&mut denominators);
+        let y2s = x2s.iter().zip(&denominators).map(|(x2, denom)| *denom * (P::mul_by_a(x2) - one)).collect::<Vec<_>>();
+
+        Self::BaseField::batch_sqrt(&y2s)
+            .into_iter()
+            .zip(denominators)
+            .zip(x_coordinates)
+            .map(|((y, denom), (x, greatest))| match denom.is_zero() {
+                true => None,
+                false => y.map(|y| {
+                    let negy = -y;
+                    let y = if (y < negy) ^ greatest { y } else { negy };
+                    Self::new(*x, y)
+                }),
+            })
+            .collect()
+    }
+
     /// Attempts to construct an affine point given a y-coordinate. The
     /// point is not guaranteed to be in the prime order subgroup.
     ///
@@ -236,12 +264,52 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+impl<P: Parameters> ConstantTimeMul for Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn mul_ct(&self, scalar: &P::ScalarField) -> Projective<P> {
+        // The unified addition law is complete, so the ladder has no exceptional cases.
+        let scalar = scalar.to_repr();
+        let mut r0 = Projective::<P>::zero();
+        let mut r1 = self.to_projective();
+        for i in (0..P::ScalarField::size_in_bits()).rev() {
+            let bit = Choice::from(scalar.get_bit(i) as u8);
+            Projective::conditional_swap(&mut r0, &mut r1, bit);
+            r1 += &r0;
+            r0.double_in_place();
+            Projective::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+}
+
 impl<P: Parameters> ToMinimalBits for Affine<P> {
     fn to_minimal_bits(&self) -> Vec<bool> {
         self.x.to_bits_le()
     }
 }
 
+impl<P: Parameters> Encoding for Affine<P>
+where
+    P::BaseField: Encoding,
+{
+    /// Returns the little-endian bits of the x-coordinate.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        self.x.encode_bits_le()
+    }
+
+    /// Returns the point in the prime-order subgroup with the given x-coordinate as little-endian bits,
+    /// preferring the largest y-coordinate, or `None` if there is no such point.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        let x = P::BaseField::decode_bits_le(bits_le)?;
+        [true, false]
+            .into_iter()
+            .filter_map(|greatest| Self::from_x_coordinate(x, greatest))
+            .find(|point| point.is_in_correct_subgroup_assuming_on_curve())
+    }
+}
+
 impl<P: Parameters> Neg for Affine<P> {
     type Output = Self;
 
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::twisted_edwards_extended::{Affine, ExtendedNiels, Projective},
+    traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
+};
+use snarkvm_fields::{ConditionallySelectable, ConstantTimeEq, PrimeField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger, vec::Vec};
+
+/// A fixed-base comb table, for the multiplication of a fixed point by many scalars.
+///
+/// See "More Flexible Exponentiation with Precomputation" by Chae Hoon Lim and Pil Joong Lee.
+/// The scalar bits are split into `COMBS * TEETH` blocks of `spacing` bits, and the table
+/// holds, for each comb, the sums of all subsets of its teeth. A multiplication then costs
+/// `spacing - 1` doublings and `COMBS * spacing` additions of extended Niels points.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: Parameters"),
+    PartialEq(bound = "P: Parameters"),
+    Eq(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters")
+)]
+pub struct CombTable<P: Parameters> {
+    spacing: usize,
+    table: Vec<ExtendedNiels<P>>,
+}
+
+impl<P: Parameters> CombTable<P> {
+    /// The number of combs.
+    const COMBS: usize = 4;
+    /// The number of teeth of each comb.
+    const TEETH: usize = 8;
+
+    /// Returns the comb table for the given base.
+    pub fn new(base: &Affine<P>) -> Self {
+        let num_blocks = Self::COMBS * Self::TEETH;
+        let spacing = (P::ScalarField::size_in_bits() + num_blocks - 1) / num_blocks;
+
+        // Compute the first multiple of the base in each block.
+        let mut g = base.to_projective();
+        let mut block_bases = Vec::with_capacity(num_blocks);
+        for _ in 0..num_blocks {
+            block_bases.push(g);
+            for _ in 0..spacing {
+                g.double_in_place();
+            }
+        }
+
+        // For each comb, compute the sums of all subsets of its teeth, where tooth `j` of comb `c` is block `j * COMBS + c`.
+        let mut table = Vec::with_capacity(Self::COMBS << Self::TEETH);
+        for comb in 0..Self::COMBS {
+            let start = table.len();
+            table.push(Projective::zero());
+            for tooth in 0..Self::TEETH {
+                let tooth_base = block_bases[tooth * Self::COMBS + comb];
+                for subset in 0..(1 << tooth) {
+                    let point = table[start + subset] + tooth_base;
+                    table.push(point);
+                }
+            }
+        }
+        Projective::batch_normalization(&mut table);
+
+        Self { spacing, table: table.into_iter().map(ExtendedNiels::from).collect() }
+    }
+
+    /// Returns `scalar * base`.
+    ///
+    /// The running time depends on the scalar, so this should only be used for public scalars.
+    pub fn mul(&self, scalar: &P::ScalarField) -> Projective<P> {
+        let scalar = scalar.to_repr();
+        self.mul_with(|comb, row| self.table[(comb << Self::TEETH) + self.subset(&scalar, comb, row)])
+    }
+
+    /// Returns `scalar * base`, reading every entry of the table for each addition,
+    /// so that the running time is independent of the scalar.
+    pub fn mul_ct(&self, scalar: &P::ScalarField) -> Projective<P>
+    where
+        P::BaseField: ConditionallySelectable,
+    {
+        let scalar = scalar.to_repr();
+        self.mul_with(|comb, row| {
+            let mut entry = ExtendedNiels::zero();
+            let subset = self.subset(&scalar, comb, row) as u64;
+            let table = &self.table[(comb << Self::TEETH)..((comb + 1) << Self::TEETH)];
+            for (i, candidate) in table.iter().enumerate() {
+                entry.conditional_assign(candidate, (i as u64).ct_eq(&subset));
+            }
+            entry
+        })
+    }
+
+    /// Returns the sum of the table entries given by `lookup(comb, row)`, over all rows and combs.
+    fn mul_with<F: FnMut(usize, usize) -> ExtendedNiels<P>>(&self, mut lookup: F) -> Projective<P> {
+        let mut result = Projective::zero();
+        for row in (0..self.spacing).rev() {
+            result.double_in_place();
+            for comb in 0..Self::COMBS {
+                result.add_assign_niels(&lookup(comb, row));
+            }
+        }
+        result
+    }
+
+    /// Returns the subset of teeth of the given comb, whose bits are set in the given row of the scalar.
+    fn subset(&self, scalar: &<P::ScalarField as PrimeField>::BigInteger, comb: usize, row: usize) -> usize {
+        (0..Self::TEETH).fold(0, |subset, tooth| {
+            subset | ((scalar.get_bit((tooth * Self::COMBS + comb) * self.spacing + row) as usize) << tooth)
+        })
+    }
+}
@@ -17,6 +17,12 @@
 pub mod affine;
 pub use affine::*;
 
+pub mod comb;
+pub use comb::*;
+
+pub mod niels;
+pub use niels::*;
+
 pub mod projective;
 pub use projective::*;
 
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::twisted_edwards_extended::{Affine, Projective},
+    traits::TwistedEdwardsParameters as Parameters,
+};
+use snarkvm_fields::{Choice, ConditionallySelectable, Field, One, Zero};
+
+use core::ops::Neg;
+
+/// A point in the "extended Niels" coordinates `(Y + X, Y - X, 2dT, Z)` of the extended
+/// twisted Edwards coordinates `(X, Y, T, Z)`, as introduced in
+/// "Twisted Edwards Curves Revisited" by Hisil, Wong, Carter and Dawson.
+///
+/// Points in this representation are cheaper to add to a [`Projective`] point, and are
+/// intended for precomputed tables of points that are added repeatedly.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    PartialEq(bound = "P: Parameters"),
+    Eq(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters"),
+    Hash(bound = "P: Parameters")
+)]
+pub struct ExtendedNiels<P: Parameters> {
+    pub y_plus_x: P::BaseField,
+    pub y_minus_x: P::BaseField,
+    pub t2d: P::BaseField,
+    pub z: P::BaseField,
+}
+
+impl<P: Parameters> ExtendedNiels<P> {
+    pub fn new(y_plus_x: P::BaseField, y_minus_x: P::BaseField, t2d: P::BaseField, z: P::BaseField) -> Self {
+        Self { y_plus_x, y_minus_x, t2d, z }
+    }
+
+    /// Returns the identity in extended Niels coordinates.
+    pub fn zero() -> Self {
+        Self::new(P::BaseField::one(), P::BaseField::one(), P::BaseField::zero(), P::BaseField::one())
+    }
+}
+
+impl<P: Parameters> From<Projective<P>> for ExtendedNiels<P> {
+    fn from(p: Projective<P>) -> Self {
+        Self::new(p.y + p.x, p.y - p.x, (P::COEFF_D * p.t).double(), p.z)
+    }
+}
+
+impl<P: Parameters> From<Affine<P>> for ExtendedNiels<P> {
+    fn from(p: Affine<P>) -> Self {
+        Self::new(p.y + p.x, p.y - p.x, (P::COEFF_D * p.x * p.y).double(), P::BaseField::one())
+    }
+}
+
+impl<P: Parameters> Neg for ExtendedNiels<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(self.y_minus_x, self.y_plus_x, -self.t2d, self.z)
+    }
+}
+
+impl<P: Parameters> ConditionallySelectable for ExtendedNiels<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.y_plus_x, &b.y_plus_x, choice),
+            P::BaseField::conditional_select(&a.y_minus_x, &b.y_minus_x, choice),
+            P::BaseField::conditional_select(&a.t2d, &b.t2d, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: Parameters> Projective<P> {
+    /// Adds the given point in extended Niels coordinates to this point.
+    #[allow(clippy::many_single_char_names)]
+    pub fn add_assign_niels(&mut self, other: &ExtendedNiels<P>) {
+        // C = t1 * 2d * t2
+        let c = self.t * other.t2d;
+        // D = z1 * 2 * z2
+        let d = (self.z * other.z).double();
+
+        let (e, h) = if P::COEFF_A_IS_MINUS_ONE {
+            // See "Twisted Edwards Curves Revisited"
+            // Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter, and Ed Dawson
+            // 3.1 Unified Addition in E^e, for a = -1
+
+            // A = (y1 - x1) * (y2 - x2)
+            let a = (self.y - self.x) * other.y_minus_x;
+            // B = (y1 + x1) * (y2 + x2)
+            let b = (self.y + self.x) * other.y_plus_x;
+            // E = B - A, H = B + A
+            (b - a, b + a)
+        } else {
+            // The unified addition of 3.1, applied to the second point scaled by 2.
+
+            // A = x1 * 2 * x2
+            let a = self.x * (other.y_plus_x - other.y_minus_x);
+            // B = y1 * 2 * y2
+            let b = self.y * (other.y_plus_x + other.y_minus_x);
+            // E = (x1 + y1) * 2 * (x2 + y2) - A - B, H = B - aA
+            ((self.x + self.y) * other.y_plus_x.double() - a - b, b - P::mul_by_a(&a))
+        };
+
+        // F = D - C
+        let f = d - c;
+        // G = D + C
+        let g = d + c;
+        // x3 = E * F
+        self.x = e * f;
+        // y3 = G * H
+        self.y = g * h;
+        // t3 = E * H
+        self.t = e * h;
+        // z3 = F * G
+        self.z = f * g;
+    }
+}
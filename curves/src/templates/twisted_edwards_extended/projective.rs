@@ -18,18 +18,24 @@ use crate::{
     templates::twisted_edwards_extended::Affine,
     traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBytes};
+use snarkvm_fields::{impl_add_sub_from_field_ref, Choice, ConditionallySelectable, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
 
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
-    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-};
 
 #[derive(Derivative)]
 #[derivative(
@@ -52,6 +58,21 @@ impl<P: Parameters> Projective<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Display for Projective<P> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}", self.to_affine())
@@ -205,6 +226,38 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
 
     #[allow(clippy::many_single_char_names)]
     fn add_assign_mixed(&mut self, other: &Self::Affine) {
+        if P::COEFF_A_IS_MINUS_ONE {
+            // See "Twisted Edwards Curves Revisited"
+            // Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter, and Ed Dawson
+            // 3.1 Unified Addition in E^e, for a = -1 and Z2 = 1
+
+            // A = (Y1-X1)*(Y2-X2)
+            let a = (self.y - self.x) * (other.y - other.x);
+            // B = (Y1+X1)*(Y2+X2)
+            let b = (self.y + self.x) * (other.y + other.x);
+            // C = T1*2d*T2
+            let c = (P::COEFF_D * self.t * other.x * other.y).double();
+            // D = 2*Z1
+            let d = self.z.double();
+            // E = B-A
+            let e = b - a;
+            // F = D-C
+            let f = d - c;
+            // G = D+C
+            let g = d + c;
+            // H = B+A
+            let h = b + a;
+            // X3 = E*F
+            self.x = e * f;
+            // Y3 = G*H
+            self.y = g * h;
+            // T3 = E*H
+            self.t = e * h;
+            // Z3 = F*G
+            self.z = f * g;
+            return;
+        }
+
         // A = X1*X2
         let a = self.x * other.x;
         // B = Y1*Y2
@@ -232,17 +285,43 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
     }
 
     #[inline]
-    #[must_use]
     fn double(&self) -> Self {
         let mut tmp = *self;
-        tmp += self;
+        tmp.double_in_place();
         tmp
     }
 
     #[inline]
+    #[allow(clippy::many_single_char_names)]
     fn double_in_place(&mut self) {
-        let tmp = *self;
-        *self = tmp.double();
+        // See "Twisted Edwards Curves Revisited"
+        // Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter, and Ed Dawson
+        // 3.3 Doubling in E^e
+
+        // A = x1^2
+        let a = self.x.square();
+        // B = y1^2
+        let b = self.y.square();
+        // C = 2 * z1^2
+        let c = self.z.square().double();
+        // D = a * A
+        let d = P::mul_by_a(&a);
+        // E = (x1 + y1)^2 - A - B
+        let e = (self.x + self.y).square() - a - b;
+        // G = D + B
+        let g = d + b;
+        // F = G - C
+        let f = g - c;
+        // H = D - B
+        let h = d - b;
+        // x3 = E * F
+        self.x = e * f;
+        // y3 = G * H
+        self.y = g * h;
+        // t3 = E * H
+        self.t = e * h;
+        // z3 = F * G
+        self.z = f * g;
     }
 
     fn to_affine(&self) -> Affine<P> {
@@ -276,6 +355,10 @@ impl<'a, P: Parameters> AddAssign<&'a Self> for Projective<P> {
     #[allow(clippy::many_single_char_names)]
     #[allow(clippy::suspicious_op_assign_impl)]
     fn add_assign(&mut self, other: &'a Self) {
+        if P::COEFF_A_IS_MINUS_ONE {
+            return self.add_assign_niels(&(*other).into());
+        }
+
         // See "Twisted Edwards Curves Revisited"
         // Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter, and Ed Dawson
         // 3.1 Unified Addition in E^e
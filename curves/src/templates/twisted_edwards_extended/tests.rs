@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{Affine, Projective};
+use super::{Affine, CombTable, ExtendedNiels, Projective};
 
 use snarkvm_utilities::{
     io::Cursor,
@@ -24,7 +24,7 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
-use crate::traits::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
+use crate::traits::{AffineCurve, ConstantTimeMul, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
 
 pub const ITERATIONS: usize = 10;
@@ -49,6 +49,100 @@ where
     edwards_curve_serialization_test::<P>();
     edwards_from_random_bytes::<P>();
     edwards_from_x_and_y_coordinates::<P>();
+    edwards_constant_time_mul_test::<P>();
+    edwards_batch_from_x_coordinate_test::<P>();
+    edwards_niels_test::<P>();
+    edwards_comb_table_test::<P>();
+}
+
+pub fn edwards_niels_test<P: TwistedEdwardsParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    assert_eq!(P::COEFF_A_IS_MINUS_ONE, P::COEFF_A == -P::BaseField::one());
+
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(&mut rng);
+        let b = Projective::<P>::rand(&mut rng);
+
+        let mut c = a;
+        c.add_assign_niels(&b.into());
+        assert_eq!(c, a + b);
+
+        let mut c = a;
+        c.add_assign_niels(&b.to_affine().into());
+        assert_eq!(c, a + b);
+
+        let mut c = a;
+        c.add_assign_niels(&(-ExtendedNiels::from(b)));
+        assert_eq!(c, a - b);
+
+        let mut c = a;
+        c.add_assign_niels(&a.into());
+        assert_eq!(c, a.double());
+
+        let mut c = a;
+        c.add_assign_niels(&ExtendedNiels::zero());
+        assert_eq!(c, a);
+    }
+}
+
+pub fn edwards_comb_table_test<P: TwistedEdwardsParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    let mut rng = test_rng();
+
+    let base = Projective::<P>::rand(&mut rng).to_affine();
+    let table = CombTable::new(&base);
+
+    for _ in 0..ITERATIONS {
+        let s = P::ScalarField::rand(&mut rng);
+        assert_eq!(table.mul(&s), base * s);
+        assert_eq!(table.mul_ct(&s), base * s);
+    }
+    for s in [P::ScalarField::zero(), P::ScalarField::one(), -P::ScalarField::one()] {
+        assert_eq!(table.mul(&s), base * s);
+        assert_eq!(table.mul_ct(&s), base * s);
+    }
+}
+
+pub fn edwards_batch_from_x_coordinate_test<P: TwistedEdwardsParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    let mut rng = test_rng();
+
+    let mut x_coordinates = Vec::with_capacity(2 * ITERATIONS);
+    for _ in 0..ITERATIONS {
+        let x = Projective::<P>::rand(&mut rng).to_affine().x;
+        x_coordinates.push((x, true));
+        x_coordinates.push((P::BaseField::rand(&mut rng), false));
+    }
+
+    let expected =
+        x_coordinates.iter().map(|(x, greatest)| Affine::<P>::from_x_coordinate(*x, *greatest)).collect::<Vec<_>>();
+    assert_eq!(Affine::<P>::batch_from_x_coordinate(&x_coordinates), expected);
+}
+
+pub fn edwards_constant_time_mul_test<P: TwistedEdwardsParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(&mut rng).to_affine();
+        let s = P::ScalarField::rand(&mut rng);
+
+        assert_eq!(a.mul_ct(&s), a * s);
+        assert_eq!(a.mul_ct(&-s), -(a * s));
+        assert_eq!(a.mul_ct(&P::ScalarField::one()), a.to_projective());
+        assert!(a.mul_ct(&P::ScalarField::zero()).is_zero());
+        assert!(Affine::<P>::zero().mul_ct(&s).is_zero());
+    }
 }
 
 pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>() {
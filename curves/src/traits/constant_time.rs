@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::AffineCurve;
+
+/// A curve with a scalar multiplication whose running time is independent of the scalar.
+///
+/// This is intended for scalar multiplications by secrets, such as private keys and
+/// nonces. Scalar multiplications by public scalars should use [`AffineCurve::mul`],
+/// which is faster.
+pub trait ConstantTimeMul: AffineCurve {
+    /// Returns `self * scalar`, with a Montgomery ladder over all the bits of the scalar field.
+    ///
+    /// `self` must be in the prime-order subgroup.
+    fn mul_ct(&self, scalar: &Self::ScalarField) -> Self::Projective;
+}
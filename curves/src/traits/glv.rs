@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{ProjectiveCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{FieldParameters, PrimeField, Zero};
+use snarkvm_utilities::{vec, vec::Vec, BitIteratorBE};
+
+/// The parameters of a short Weierstrass curve with an efficiently computable endomorphism
+/// `φ(x, y) = (βx, y)`, where `β` is a primitive cube root of unity in the base field.
+///
+/// On the prime-order subgroup, `φ` acts as multiplication by a cube root of unity `λ` in the scalar field.
+/// The GLV method splits a scalar `k` into `k1 + k2 * λ`, where `k1` and `k2` have half the bit length of `k`,
+/// and computes `k * P = k1 * P + k2 * φ(P)` with half the number of doublings.
+pub trait GLVParameters: ShortWeierstrassParameters + Sized {
+    /// The cube root of unity `β` in the base field.
+    const ENDO_COEFF: Self::BaseField;
+
+    /// The cube root of unity `λ` in the scalar field, such that `φ(P) = λP` on the prime-order subgroup.
+    const LAMBDA: Self::ScalarField;
+
+    /// The coefficients `(-b1, b2)` of a short basis `((a1, b1), (a2, b2))` of the lattice of pairs `(k1, k2)`
+    /// with `k1 + k2 * λ = 0 mod r`, where the basis is chosen so that both coefficients are non-negative.
+    const SCALAR_DECOMP_COEFFS: [Self::ScalarField; 2];
+
+    /// The rounding constants `⌊2^256 * b2 / r⌉` and `⌊2^256 * -b1 / r⌉`, as little-endian limbs.
+    const SCALAR_DECOMP_ROUNDING: [&'static [u64]; 2];
+
+    /// Returns `φ(p)`.
+    #[inline]
+    fn endomorphism(p: &Projective<Self>) -> Projective<Self> {
+        // In Jacobian coordinates, `φ(X / Z^2, Y / Z^3) = (βX / Z^2, Y / Z^3)`.
+        Projective::new(p.x * Self::ENDO_COEFF, p.y, p.z)
+    }
+
+    /// Returns `φ(p)`.
+    #[inline]
+    fn endomorphism_affine(p: &Affine<Self>) -> Affine<Self> {
+        Affine::new(p.x * Self::ENDO_COEFF, p.y, p.infinity)
+    }
+
+    /// Decomposes `scalar` into `((is_k1_negative, |k1|), (is_k2_negative, |k2|))`,
+    /// such that `scalar = k1 + k2 * λ` and `|k1|` and `|k2|` are about half the size of the scalar field.
+    fn scalar_decomposition(scalar: &Self::ScalarField) -> ((bool, Self::ScalarField), (bool, Self::ScalarField)) {
+        let scalar_repr = scalar.to_repr();
+        let [b2_rounding, minus_b1_rounding] = Self::SCALAR_DECOMP_ROUNDING;
+        // `c1 = ⌊scalar * b2 / r⌉` and `c2 = ⌊scalar * -b1 / r⌉`, up to a rounding error of one.
+        let c1 = mul_and_shift_by_256::<Self::ScalarField>(scalar_repr.as_ref(), b2_rounding);
+        let c2 = mul_and_shift_by_256::<Self::ScalarField>(scalar_repr.as_ref(), minus_b1_rounding);
+
+        // `(k1, k2) = (scalar, 0) - c1 * (a1, b1) - c2 * (a2, b2)`.
+        let [minus_b1, b2] = Self::SCALAR_DECOMP_COEFFS;
+        let k2 = c1 * minus_b1 - c2 * b2;
+        let k1 = *scalar - k2 * Self::LAMBDA;
+
+        (to_signed_magnitude(k1), to_signed_magnitude(k2))
+    }
+
+    /// Returns `scalar * p` using the GLV method, for a point `p` in the prime-order subgroup.
+    fn glv_mul(p: &Projective<Self>, scalar: &Self::ScalarField) -> Projective<Self> {
+        let ((k1_is_negative, k1), (k2_is_negative, k2)) = Self::scalar_decomposition(scalar);
+
+        let mut p1 = *p;
+        let mut p2 = Self::endomorphism(p);
+        if k1_is_negative {
+            p1 = -p1;
+        }
+        if k2_is_negative {
+            p2 = -p2;
+        }
+        let p1_plus_p2 = p1 + p2;
+
+        // Pad both halves to the same number of bits, so that they can be processed jointly.
+        let k1_bits = BitIteratorBE::new(k1.to_repr()).collect::<Vec<_>>();
+        let k2_bits = BitIteratorBE::new(k2.to_repr()).collect::<Vec<_>>();
+        let skip = k1_bits.iter().zip(&k2_bits).take_while(|(b1, b2)| !**b1 && !**b2).count();
+
+        let mut res = Projective::<Self>::zero();
+        for (b1, b2) in k1_bits.into_iter().zip(k2_bits).skip(skip) {
+            res.double_in_place();
+            match (b1, b2) {
+                (true, true) => res += p1_plus_p2,
+                (true, false) => res += p1,
+                (false, true) => res += p2,
+                (false, false) => {}
+            }
+        }
+        res
+    }
+}
+
+/// Returns `⌊a * b / 2^256⌋` as an element of `F`, where `a` and `b` are given as little-endian limbs.
+fn mul_and_shift_by_256<F: PrimeField>(a: &[u64], b: &[u64]) -> F {
+    let mut product = vec![0u64; a.len() + b.len()];
+    for (i, a_i) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b_j) in b.iter().enumerate() {
+            let t = (*a_i as u128) * (*b_j as u128) + (product[i + j] as u128) + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+
+    let two_to_64 = F::from(1u128 << 64);
+    product.iter().skip(4).rev().fold(F::zero(), |acc, limb| acc * two_to_64 + F::from(*limb))
+}
+
+/// Returns `(is_negative, |x|)` for the representative of `x` in `(-r/2, r/2)`.
+fn to_signed_magnitude<F: PrimeField>(x: F) -> (bool, F) {
+    match x.to_repr() > F::Parameters::MODULUS_MINUS_ONE_DIV_TWO {
+        true => (true, -x),
+        false => (false, x),
+    }
+}
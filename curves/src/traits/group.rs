@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::PairingEngine;
+use crate::{templates::short_weierstrass_jacobian::Projective, PairingEngine};
 use snarkvm_fields::{Field, PrimeField, SquareRootField, Zero};
-use snarkvm_utilities::{rand::UniformRand, serialize::*, FromBytes, ToBytes, ToMinimalBits};
+use snarkvm_utilities::{cfg_iter, rand::UniformRand, serialize::*, BitIteratorBE, FromBytes, ToBytes, ToMinimalBits};
 
 use core::{
     fmt::{Debug, Display},
@@ -26,6 +26,9 @@ use core::{
 };
 use serde::{de::DeserializeOwned, Serialize};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// Projective representation of an elliptic curve point guaranteed to be in the prime order subgroup.
 pub trait ProjectiveCurve:
     ToBytes
@@ -155,6 +158,15 @@ pub trait AffineCurve:
     /// largest y-coordinate be selected.
     fn from_x_coordinate(x: Self::BaseField, greatest: bool) -> Option<Self>;
 
+    /// Attempts to construct affine points given pairs of an x-coordinate and
+    /// the `greatest` flag of [`AffineCurve::from_x_coordinate`]. The points are
+    /// not guaranteed to be in the prime order subgroup.
+    ///
+    /// The square roots are computed in parallel if the `parallel` feature is enabled.
+    fn batch_from_x_coordinate(x_coordinates: &[(Self::BaseField, bool)]) -> Vec<Option<Self>> {
+        cfg_iter!(x_coordinates).map(|(x, greatest)| Self::from_x_coordinate(*x, *greatest)).collect()
+    }
+
     /// Attempts to construct an affine point given a y-coordinate. The
     /// point is not guaranteed to be in the prime order subgroup.
     ///
@@ -256,6 +268,25 @@ pub trait ShortWeierstrassParameters: ModelParameters {
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
 
+    /// Returns `scalar * base`, for a point `base` in the prime-order subgroup.
+    ///
+    /// By default, this uses double-and-add. Curves with an efficient endomorphism
+    /// override this with `GLVParameters::glv_mul`.
+    #[inline]
+    fn mul_projective(base: &Projective<Self>, scalar: &Self::ScalarField) -> Projective<Self>
+    where
+        Self: Sized,
+    {
+        let mut res = Projective::zero();
+        for i in BitIteratorBE::new_without_leading_zeros(scalar.to_repr()) {
+            res.double_in_place();
+            if i {
+                res += base;
+            }
+        }
+        res
+    }
+
     #[inline(always)]
     fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
         let mut copy = *elem;
@@ -277,6 +308,8 @@ pub trait TwistedEdwardsParameters: Copy + Clone + Debug + Default + PartialEq +
     const COFACTOR: &'static [u64];
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
+    /// Set to `true` if `COEFF_A = -1`, to use the dedicated addition formulas for these curves.
+    const COEFF_A_IS_MINUS_ONE: bool = false;
 
     type MontgomeryParameters: MontgomeryParameters<BaseField = Self::BaseField>;
 
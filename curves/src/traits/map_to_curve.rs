@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{short_weierstrass_jacobian, twisted_edwards_extended},
+    traits::{MontgomeryParameters, ShortWeierstrassParameters, TwistedEdwardsParameters},
+};
+use snarkvm_fields::{
+    Choice,
+    ConditionallySelectable,
+    ConstantTime,
+    ConstantTimeEq,
+    ExtensionField,
+    Field,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::BigInteger;
+
+/// Returns the sign of `x`, as defined by `sgn0` in RFC 9380, section 4.1.
+///
+/// The sign of an element of an extension field is the parity of its first
+/// non-zero coordinate over the base prime field. Every coordinate is visited,
+/// so that the control flow does not depend on which of them is non-zero.
+pub fn sgn0<F: ExtensionField>(x: &F) -> bool {
+    let zero = F::BasePrimeField::zero();
+    let (sign, _) = x.to_base_prime_field_elements().iter().fold((false, true), |(sign, is_zero), element| {
+        (sign | (is_zero & element.to_repr().is_odd()), is_zero & bool::from(element.ct_eq(&zero)))
+    });
+    sign
+}
+
+/// Returns whether `x` is a square, including zero, as `is_square` in RFC 9380, section 4.
+#[inline]
+fn is_square<F: SquareRootField>(x: &F) -> Choice {
+    Choice::from(!x.legendre().is_qnr() as u8)
+}
+
+/// Returns `1 / x`, or zero if `x` is zero, as `inv0` in RFC 9380, section 4.
+#[inline]
+fn inv0<F: Field>(x: &F) -> F {
+    x.inverse().unwrap_or_else(F::zero)
+}
+
+/// Evaluates the polynomial with the given coefficients, lowest degree first, at `x`.
+#[inline]
+fn evaluate<F: Field>(coefficients: &[F], x: &F) -> F {
+    coefficients.iter().rev().fold(F::zero(), |acc, c| acc * x + c)
+}
+
+/// The parameters of the simplified SWU map of RFC 9380, section 6.6.3, for a short Weierstrass curve.
+///
+/// The simplified SWU map requires `A * B != 0`, so for curves with `A = 0`, such as BLS12-377,
+/// it maps to an isogenous curve `E': y^2 = x^3 + A' * x + B'` and applies the isogeny `E' -> E`.
+pub trait SWUParameters: ShortWeierstrassParameters + Sized
+where
+    Self::BaseField: ExtensionField + ConstantTime,
+{
+    /// The coefficient `A'` of the isogenous curve.
+    const ISOGENOUS_COEFF_A: Self::BaseField;
+
+    /// The coefficient `B'` of the isogenous curve.
+    const ISOGENOUS_COEFF_B: Self::BaseField;
+
+    /// The non-square `Z` of the map, chosen as in RFC 9380, appendix H.2.
+    const SWU_Z: Self::BaseField;
+
+    /// The coefficients of the numerator of the `x`-coordinate of the isogeny, lowest degree first.
+    const ISOGENY_X_NUM: &'static [Self::BaseField];
+
+    /// The coefficients of the denominator of the `x`-coordinate of the isogeny, lowest degree first.
+    const ISOGENY_X_DEN: &'static [Self::BaseField];
+
+    /// The coefficients of the numerator of the `y`-coordinate of the isogeny, lowest degree first.
+    const ISOGENY_Y_NUM: &'static [Self::BaseField];
+
+    /// The coefficients of the denominator of the `y`-coordinate of the isogeny, lowest degree first.
+    const ISOGENY_Y_DEN: &'static [Self::BaseField];
+
+    /// Returns the image of `u` on the isogenous curve, as in RFC 9380, section 6.6.2.
+    ///
+    /// Both candidates for `x` are always computed, and the one for which `g(x)` is a square is
+    /// chosen with `ConditionallySelectable`. Note that the field inversions and square root
+    /// are those of `Self::BaseField`, which are not constant-time themselves.
+    fn map_to_isogenous_curve(u: &Self::BaseField) -> (Self::BaseField, Self::BaseField) {
+        let (a, b, z) = (Self::ISOGENOUS_COEFF_A, Self::ISOGENOUS_COEFF_B, Self::SWU_Z);
+        let g = |x: &Self::BaseField| (x.square() + a) * x + b;
+
+        let z_u2 = z * u.square();
+        let tv1 = inv0(&(z_u2.square() + z_u2));
+        let x1 = Self::BaseField::conditional_select(
+            &(-b * inv0(&a) * (Self::BaseField::one() + tv1)),
+            &(b * inv0(&(z * a))),
+            tv1.ct_eq(&Self::BaseField::zero()),
+        );
+        let x2 = z_u2 * x1;
+
+        // If `g(x1)` is not a square, then `g(x2) = Z^3 * u^6 * g(x1)` is a square.
+        let (gx1, gx2) = (g(&x1), g(&x2));
+        let gx1_is_square = is_square(&gx1);
+        let x = Self::BaseField::conditional_select(&x2, &x1, gx1_is_square);
+        let y = Self::BaseField::conditional_select(&gx2, &gx1, gx1_is_square).sqrt().expect("g(x) must be a square");
+        let y = Self::BaseField::conditional_select(&y, &-y, Choice::from((sgn0(u) != sgn0(&y)) as u8));
+        (x, y)
+    }
+
+    /// Returns the image of a point `(x, y)` on the isogenous curve under the isogeny `E' -> E`.
+    fn isogeny_map(x: &Self::BaseField, y: &Self::BaseField) -> short_weierstrass_jacobian::Affine<Self> {
+        let x_den = evaluate(Self::ISOGENY_X_DEN, x);
+        let y_den = evaluate(Self::ISOGENY_Y_DEN, x);
+        // The points in the kernel of the isogeny are mapped to the point at infinity.
+        match (x_den.inverse(), y_den.inverse()) {
+            (Some(x_den_inv), Some(y_den_inv)) => short_weierstrass_jacobian::Affine::new(
+                evaluate(Self::ISOGENY_X_NUM, x) * x_den_inv,
+                *y * evaluate(Self::ISOGENY_Y_NUM, x) * y_den_inv,
+                false,
+            ),
+            _ => short_weierstrass_jacobian::Affine::zero(),
+        }
+    }
+
+    /// Returns the image of `u` on the curve, which may not be in the prime-order subgroup.
+    fn map_to_curve(u: &Self::BaseField) -> short_weierstrass_jacobian::Affine<Self> {
+        let (x, y) = Self::map_to_isogenous_curve(u);
+        Self::isogeny_map(&x, &y)
+    }
+}
+
+/// The parameters of the Elligator 2 map of RFC 9380, section 6.7.1, for a twisted Edwards curve.
+///
+/// The map sends `u` to the Montgomery curve `K * t^2 = s^3 + J * s^2 + s` given by `Self::MontgomeryParameters`,
+/// and applies the rational map of RFC 9380, appendix D.1, to the twisted Edwards curve.
+pub trait Elligator2Parameters: TwistedEdwardsParameters + Sized
+where
+    Self::BaseField: ExtensionField + ConstantTime,
+{
+    /// The non-square `Z` of the map, chosen as in RFC 9380, appendix H.3.
+    const ELLIGATOR2_Z: Self::BaseField;
+
+    /// Returns the image of `u` on the Montgomery curve, as in RFC 9380, section 6.7.1.
+    ///
+    /// As for `SWUParameters::map_to_isogenous_curve`, both candidates for `s` are always computed,
+    /// and one of them is chosen with `ConditionallySelectable`.
+    fn map_to_montgomery_curve(u: &Self::BaseField) -> (Self::BaseField, Self::BaseField) {
+        let j = <Self::MontgomeryParameters as MontgomeryParameters>::COEFF_A;
+        let k = <Self::MontgomeryParameters as MontgomeryParameters>::COEFF_B;
+        let k_inv = k.inverse().expect("K must be non-zero");
+        let j_over_k = j * k_inv;
+        let k_inv2 = k_inv.square();
+        let g = |x: &Self::BaseField| ((*x + j_over_k) * x + k_inv2) * x;
+
+        let x1 = -j_over_k * inv0(&(Self::BaseField::one() + Self::ELLIGATOR2_Z * u.square()));
+        let x1 = Self::BaseField::conditional_select(&x1, &-j_over_k, x1.ct_eq(&Self::BaseField::zero()));
+        let x2 = -x1 - j_over_k;
+
+        // If `g(x1)` is not a square, then `g(x2)` is a square.
+        let (gx1, gx2) = (g(&x1), g(&x2));
+        let gx1_is_square = is_square(&gx1);
+        let x = Self::BaseField::conditional_select(&x2, &x1, gx1_is_square);
+        let y = Self::BaseField::conditional_select(&gx2, &gx1, gx1_is_square).sqrt().expect("g(x) must be a square");
+        // `sgn0(y)` is one if and only if `x1` was chosen.
+        let y = Self::BaseField::conditional_select(&y, &-y, Choice::from((sgn0(&y) != bool::from(gx1_is_square)) as u8));
+        (x * k, y * k)
+    }
+
+    /// Returns the image of `u` on the curve, which may not be in the prime-order subgroup.
+    fn map_to_curve(u: &Self::BaseField) -> twisted_edwards_extended::Affine<Self> {
+        let (s, t) = Self::map_to_montgomery_curve(u);
+        // The exceptional cases `t = 0` and `s = -1` are mapped to the identity.
+        match (t.inverse(), (s + Self::BaseField::one()).inverse()) {
+            (Some(t_inv), Some(s_plus_one_inv)) => {
+                twisted_edwards_extended::Affine::new(s * t_inv, (s - Self::BaseField::one()) * s_plus_one_inv)
+            }
+            _ => twisted_edwards_extended::Affine::zero(),
+        }
+    }
+}
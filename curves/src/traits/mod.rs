@@ -14,9 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod constant_time;
+pub use constant_time::*;
+
+pub mod glv;
+pub use glv::*;
+
 pub mod group;
 pub use group::*;
 
+pub mod map_to_curve;
+pub use map_to_curve::*;
+
 pub mod pairing_engine;
 pub use pairing_engine::*;
 
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::traits::{AffineCurve, PairingCurve, ProjectiveCurve};
-use snarkvm_fields::{Field, PrimeField, SquareRootField, ToConstraintField};
+use snarkvm_fields::{CyclotomicField, One, PrimeField, SquareRootField, ToConstraintField};
 
 use core::{fmt::Debug, iter};
 
@@ -50,7 +50,7 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Sync
     type Fqe: SquareRootField;
 
     /// The extension field that hosts the target group of the pairing.
-    type Fqk: Field;
+    type Fqk: CyclotomicField;
 
     /// Perform a miller loop with some number of (G1, G2) pairs.
     #[must_use]
@@ -64,6 +64,60 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Sync
     #[must_use]
     fn final_exponentiation(_: &Self::Fqk) -> Option<Self::Fqk>;
 
+    /// Performs the miller loops of some number of (G1, G2) pairs, and returns the product of their results.
+    ///
+    /// With the `parallel` feature, the pairs are split into chunks whose miller loops run in parallel.
+    #[must_use]
+    fn multi_miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        #[cfg(not(feature = "parallel"))]
+        {
+            Self::miller_loop(i.into_iter())
+        }
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            let pairs = i.into_iter().collect::<Vec<_>>();
+            let num_chunks = snarkvm_utilities::parallel::max_available_threads().clamp(1, pairs.len().max(1));
+            let chunk_size = ((pairs.len() + num_chunks - 1) / num_chunks).max(1);
+            pairs
+                .par_chunks(chunk_size)
+                .map(|chunk| Self::miller_loop(chunk.iter().copied()))
+                .reduce(Self::Fqk::one, |a, b| a * b)
+        }
+    }
+
+    /// Computes the product of the pairings of some number of (G1, G2) pairs,
+    /// sharing a single final exponentiation.
+    ///
+    /// The pairs are taken prepared, so that fixed G2 elements are prepared once and cached by their owner,
+    /// as the KZG10 and SonicKZG10 verifier keys do for `h`, `beta_h` and the degree-bound shifts.
+    #[must_use]
+    fn multi_pairing<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        Self::final_exponentiation(&Self::multi_miller_loop(i)).unwrap()
+    }
+
+    /// Returns `true` if the product of the pairings of some number of (G1, G2) pairs is one.
+    #[must_use]
+    fn product_of_pairings_is_one<'a, I>(i: I) -> bool
+    where
+        I: IntoIterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        Self::final_exponentiation(&Self::multi_miller_loop(i)).map_or(false, |result| result.is_one())
+    }
+
     /// Computes a product of pairings.
     #[must_use]
     fn product_of_pairings<'a, I>(i: I) -> Self::Fqk
@@ -72,7 +126,7 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Sync
             Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
         >,
     {
-        Self::final_exponentiation(&Self::miller_loop(i)).unwrap()
+        Self::multi_pairing(i)
     }
 
     /// Performs multiple pairing operations
@@ -14,11 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{traits::FftParameters, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
+use snarkvm_fields::{
+    traits::FftParameters,
+    Choice,
+    CyclotomicField,
+    Encoding,
+    ExtensionField,
+    FftField,
+    Field,
+    LegendreSymbol,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{
     io::Cursor,
     rand::test_rng,
-    serialize::{CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags},
+    serialize::{number_of_bytes_with_flags, CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags},
+    ToBits,
 };
 
 use rand::Rng;
@@ -102,6 +115,11 @@ fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
 
         assert_eq!(a, F::one());
     }
+
+    let mut v = (0..ITERATIONS).map(|_| F::rand(rng)).chain([F::zero()]).collect::<Vec<_>>();
+    let expected = v.iter().map(|a| a.inverse().unwrap_or_else(F::zero)).collect::<Vec<_>>();
+    F::batch_inverse_in_place(&mut v);
+    assert_eq!(v, expected);
 }
 
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
@@ -238,10 +256,34 @@ fn random_sqrt_tests<F: SquareRootField>() {
 
         c += &F::one();
     }
+
+    let elements = (0..ITERATIONS).map(|_| F::rand(&mut rng)).chain([F::zero()]).collect::<Vec<_>>();
+    let expected = elements.iter().map(|a| a.sqrt()).collect::<Vec<_>>();
+    assert_eq!(F::batch_sqrt(&elements), expected);
+}
+
+fn random_constant_time_tests<F: PrimeField, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+        assert!(bool::from((-F::zero()).ct_eq(&F::zero())));
+
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(1)), b);
+
+        let (mut c, mut d) = (a, b);
+        F::conditional_swap(&mut c, &mut d, Choice::from(0));
+        assert_eq!((c, d), (a, b));
+        F::conditional_swap(&mut c, &mut d, Choice::from(1));
+        assert_eq!((c, d), (b, a));
+    }
 }
 
 #[allow(clippy::eq_op)]
-pub fn field_test<F: Field>(a: F, b: F) {
+pub fn field_test<F: ExtensionField>(a: F, b: F) {
     let zero = F::zero();
     assert!(zero == zero);
     assert!(zero.is_zero()); // true
@@ -282,6 +324,13 @@ pub fn field_test<F: Field>(a: F, b: F) {
     assert_eq!((a + b).double(), (a + b) + (b + a));
     assert_eq!(F::half(), F::one().double().inverse().unwrap());
 
+    // Conversions to and from coefficients over the base prime field.
+    let elems = a.to_base_prime_field_elements();
+    assert_eq!(elems.len() as u64, F::extension_degree());
+    assert_eq!(F::from_base_prime_field_elems(&elems), Some(a));
+    assert_eq!(F::from_base_prime_field_elems(&elems[1..]), None);
+    assert_eq!(F::from_base_prime_field(elems[0]).to_base_prime_field_elements()[0], elems[0]);
+
     // a * 0 = 0
     assert_eq!(a * zero, zero);
     // a * 1 = a
@@ -375,9 +424,67 @@ pub fn primefield_test<F: PrimeField>() {
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
     random_string_tests::<F>();
+    random_constant_time_tests::<F, _>(&mut test_rng());
     fft_field_test::<F>();
 }
 
+pub fn field_encoding_test<F: PrimeField + Encoding>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+
+        let bits_le = a.encode_bits_le();
+        assert_eq!(bits_le, a.to_bits_le());
+        assert_eq!(bits_le.len(), F::size_in_bits());
+        assert_eq!(F::decode_bits_le(&bits_le), Some(a));
+        assert_eq!(F::decode_bits_be(&a.encode_bits_be()), Some(a));
+
+        let bytes_le = a.encode_bytes_le();
+        assert_eq!(bytes_le.len(), (F::size_in_bits() + 7) / 8);
+        assert_eq!(bytes_le, a.to_bytes_le().unwrap()[..bytes_le.len()]);
+        assert_eq!(F::decode_bytes_le(&bytes_le), Some(a));
+
+        let fields = a.encode_fields::<F>();
+        assert_eq!(fields.len(), (F::size_in_bits() + F::size_in_data_bits() - 1) / F::size_in_data_bits());
+        let packed_bits = fields.iter().flat_map(|field| field.to_bits_le().into_iter().take(F::size_in_data_bits()));
+        assert!(packed_bits.zip(&bits_le).all(|(packed, bit)| packed == *bit));
+
+        // Excess bits are only accepted if they are zero.
+        let mut padded_bits_le = bits_le.clone();
+        padded_bits_le.push(false);
+        assert_eq!(F::decode_bits_le(&padded_bits_le), Some(a));
+        padded_bits_le.push(true);
+        assert_eq!(F::decode_bits_le(&padded_bits_le), None);
+    }
+
+    // The modulus itself is not a valid encoding.
+    let mut modulus_bits_le = F::modulus().to_bits_le();
+    modulus_bits_le.truncate(F::size_in_bits());
+    assert_eq!(F::decode_bits_le(&modulus_bits_le), None);
+}
+
+pub fn extension_field_encoding_test<F: Field + Encoding>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+
+        let bits_le = a.encode_bits_le();
+        assert_eq!(bits_le, a.to_bits_le());
+        assert_eq!(F::decode_bits_le(&bits_le), Some(a));
+        assert_eq!(F::decode_bits_be(&a.encode_bits_be()), Some(a));
+        assert_eq!(F::decode_bytes_le(&a.encode_bytes_le()), Some(a));
+
+        // Excess bits are only accepted if they are zero.
+        let mut padded_bits_le = bits_le.clone();
+        padded_bits_le.push(false);
+        assert_eq!(F::decode_bits_le(&padded_bits_le), Some(a));
+        padded_bits_le.push(true);
+        assert_eq!(F::decode_bits_le(&padded_bits_le), None);
+    }
+}
+
 pub fn sqrt_field_test<F: SquareRootField>(elem: F) {
     let square = elem.square();
     let sqrt = square.sqrt().unwrap();
@@ -409,6 +516,33 @@ pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: us
     }
 }
 
+/// Tests the cyclotomic operations on `elem`, which must be in the cyclotomic subgroup and not the identity.
+pub fn cyclotomic_field_test<F: CyclotomicField>(elem: F) {
+    let mut rng = test_rng();
+
+    assert_eq!(elem.cyclotomic_inverse(), elem.inverse().unwrap());
+    assert_eq!(F::one().cyclotomic_inverse(), F::one());
+
+    for exp in [[0u64; 4], [1, 0, 0, 0], [2, 0, 0, 0], [u64::MAX; 4], [rng.gen(), rng.gen(), rng.gen(), rng.gen()]] {
+        assert_eq!(elem.cyclotomic_exp(exp), elem.pow(exp));
+    }
+    assert_eq!(elem.cyclotomic_exp([]), F::one());
+
+    assert_eq!(F::one().to_torus(), F::Subfield::zero());
+    assert_eq!(F::from_torus(&F::Subfield::zero()), F::one());
+    // -1 is in the cyclotomic subgroup but not the target group, and shares the encoding of the identity.
+    assert_eq!((-F::one()).to_torus(), F::Subfield::zero());
+    assert_eq!(F::from_torus(&elem.to_torus()), elem);
+    assert_eq!(F::from_torus(&elem.square().to_torus()), elem.square());
+
+    for a in [elem, F::one()] {
+        let mut serialized = Vec::new();
+        a.serialize_torus(&mut serialized).unwrap();
+        assert_eq!(serialized.len() * 2, a.serialized_size());
+        assert_eq!(F::deserialize_torus(&mut &serialized[..]).unwrap(), a);
+    }
+}
+
 pub fn field_serialization_test<F: Field>() {
     let buf_size = F::SERIALIZED_SIZE;
 
@@ -441,7 +575,7 @@ pub fn field_serialization_test<F: Field>() {
         }
 
         {
-            let mut serialized = vec![0u8; buf_size];
+            let mut serialized = vec![0u8; number_of_bytes_with_flags(buf_size, F::SPARE_BITS, SWFlags::BIT_SIZE)];
             let mut cursor = Cursor::new(&mut serialized[..]);
             a.serialize_with_flags(&mut cursor, SWFlags::from_y_sign(true)).unwrap();
             let mut cursor = Cursor::new(&serialized[..]);
@@ -15,8 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::rand::{test_rng, UniformRand};
+use snarkvm_fields::{Encoding, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    rand::{test_rng, UniformRand},
+    ToBits,
+};
 
 #[allow(clippy::eq_op)]
 pub fn affine_test<G: AffineCurve>(a: G) {
@@ -34,6 +37,21 @@ pub fn affine_test<G: AffineCurve>(a: G) {
     assert_eq!(a.mul_by_cofactor_inv().mul_by_cofactor(), a);
 }
 
+pub fn affine_encoding_test<G: AffineCurve + Encoding>(a: G)
+where
+    G::BaseField: PrimeField + Encoding,
+{
+    let bits_le = a.encode_bits_le();
+    assert_eq!(bits_le, a.to_x_coordinate().to_bits_le());
+    assert_eq!(G::decode_bits_le(&bits_le), Some(a));
+    assert_eq!(G::decode_bits_be(&a.encode_bits_be()), Some(a));
+    assert_eq!(G::decode_bytes_le(&a.encode_bytes_le()), Some(a));
+    assert_eq!(a.encode_fields::<G::BaseField>(), a.to_x_coordinate().encode_fields::<G::BaseField>());
+
+    // The negation has a different x-coordinate, so it must decode to a different point.
+    assert_eq!(G::decode_bits_le(&(-a).encode_bits_le()), Some(-a));
+}
+
 #[allow(clippy::eq_op)]
 pub fn projective_test<G: ProjectiveCurve>(a: G, mut b: G) {
     let mut rng = test_rng();
@@ -198,7 +198,8 @@ fn random_transformation_test<G: ProjectiveCurve>() {
 
     // Batch normalization
     for _ in 0..10 {
-        let mut v = (0..ITERATIONS).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+        // Doubling ensures the points are not normalized, even on curves with a cofactor of one.
+        let mut v = (0..ITERATIONS).map(|_| G::rand(&mut rng).double()).collect::<Vec<_>>();
 
         for i in &v {
             assert!(!i.is_normalized());
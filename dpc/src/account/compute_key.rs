@@ -52,10 +52,10 @@ impl<N: Network> ComputeKey<N> {
     /// Derives the account compute key from an account private key.
     pub fn from_private_key(private_key: &PrivateKey<N>) -> Self {
         // Compute G^sk_sig.
-        let pk_sig = N::account_signature_scheme().g_scalar_multiply(&private_key.sk_sig);
+        let pk_sig = N::account_signature_scheme().g_scalar_multiply_ct(&private_key.sk_sig);
 
         // Compute G^r_sig.
-        let pr_sig = N::account_signature_scheme().g_scalar_multiply(&private_key.r_sig);
+        let pr_sig = N::account_signature_scheme().g_scalar_multiply_ct(&private_key.r_sig);
 
         let mut to_normalize = [pk_sig, pr_sig];
         <N::ProgramAffineCurve as AffineCurve>::Projective::batch_normalization(&mut to_normalize);
@@ -101,7 +101,7 @@ impl<N: Network> ComputeKey<N> {
     /// Returns the encryption key.
     pub fn to_encryption_key(&self) -> N::ProgramAffineCurve {
         // Compute G^sk_prf.
-        let pk_prf = N::account_signature_scheme().g_scalar_multiply(&self.sk_prf);
+        let pk_prf = N::account_signature_scheme().g_scalar_multiply_ct(&self.sk_prf);
 
         (self.pk_sig.to_projective() + self.pr_sig.to_projective() + pk_prf).into()
     }
@@ -278,7 +278,7 @@ mod testnet2 {
     use crate::{testnet2::Testnet2, Account, Address, Network, PrivateKey, ViewKey};
     use snarkvm_algorithms::prelude::*;
     use snarkvm_curves::AffineCurve;
-    use snarkvm_utilities::{FromBytes, ToBits, ToBytes};
+    use snarkvm_utilities::{FromBytes, ToBits, ToBytes, UniformRand};
 
     use rand::{thread_rng, Rng, SeedableRng};
     use rand_chacha::ChaChaRng;
@@ -414,6 +414,17 @@ mod testnet2 {
         }
     }
 
+    #[test]
+    fn test_constant_time_g_scalar_multiply() {
+        let rng = &mut thread_rng();
+        let scheme = Testnet2::account_signature_scheme();
+
+        for _ in 0..100 {
+            let scalar = <Testnet2 as Network>::ProgramScalarField::rand(rng);
+            assert_eq!(scheme.g_scalar_multiply_ct(&scalar), scheme.g_scalar_multiply(&scalar));
+        }
+    }
+
     #[test]
     fn test_invalid_account_signatures() {
         let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
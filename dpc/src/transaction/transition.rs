@@ -18,7 +18,10 @@ use crate::{
     circuits::{InputPublicVariables, OutputPublicVariables},
     prelude::*,
 };
-use snarkvm_algorithms::merkle_tree::{MerklePath, MerkleTree};
+use snarkvm_algorithms::{
+    merkle_tree::{MerklePath, MerkleTree},
+    EncryptionScheme,
+};
 use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
@@ -285,9 +288,24 @@ impl<N: Network> Transition<N> {
         &'a self,
         decryption_key: &'a DecryptionKey<N>,
     ) -> impl Iterator<Item = Record<N>> + 'a {
+        let decryption_keys = match decryption_key {
+            // Compute the candidate record view keys in a single batch.
+            DecryptionKey::AccountViewKey(account_view_key) => {
+                let randomizers =
+                    self.ciphertexts.iter().map(|ciphertext| *ciphertext.randomizer()).collect::<Vec<_>>();
+                N::account_encryption_scheme()
+                    .generate_symmetric_keys(account_view_key, &randomizers)
+                    .into_iter()
+                    .map(|record_view_key| record_view_key.map(|key| DecryptionKey::RecordViewKey(key.into())))
+                    .collect::<Vec<_>>()
+            }
+            DecryptionKey::RecordViewKey(_) => vec![Some(decryption_key.clone()); self.ciphertexts.len()],
+        };
+
         self.ciphertexts
             .iter()
-            .filter_map(move |ciphertext| Record::<N>::decrypt(decryption_key, ciphertext).ok())
+            .zip_eq(decryption_keys)
+            .filter_map(|(ciphertext, decryption_key)| Record::<N>::decrypt(&decryption_key?, ciphertext).ok())
             .filter(|record| !record.is_dummy())
     }
 
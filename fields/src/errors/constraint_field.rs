@@ -14,20 +14,45 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-#[derive(Debug, Error)]
-pub enum ConstraintFieldError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
+use snarkvm_utilities::{error::Error, format, io, string::String};
 
-    #[error("{}: {}", _0, _1)]
-    Crate(&'static str, String),
+use core::fmt;
 
-    #[error("{}", _0)]
+#[derive(Debug)]
+pub enum ConstraintFieldError {
+    AnyhowError(anyhow::Error),
+    Crate(&'static str, String),
     Message(&'static str),
 }
 
-impl From<std::io::Error> for ConstraintFieldError {
-    fn from(error: std::io::Error) -> Self {
+impl fmt::Display for ConstraintFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{}", error),
+            Self::Crate(crate_name, message) => write!(f, "{}: {}", crate_name, message),
+            Self::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for ConstraintFieldError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AnyhowError(error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for ConstraintFieldError {
+    fn from(error: anyhow::Error) -> Self {
+        ConstraintFieldError::AnyhowError(error)
+    }
+}
+
+impl From<io::Error> for ConstraintFieldError {
+    fn from(error: io::Error) -> Self {
         ConstraintFieldError::Crate("std::io", format!("{:?}", error))
     }
 }
@@ -14,35 +14,45 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-#[derive(Debug, Error)]
+use snarkvm_utilities::{error::Error, format, io, string::String};
+
+use core::fmt;
+
+#[derive(Debug)]
 pub enum FieldError {
-    #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
-
-    #[error("Invalid field element")]
     InvalidFieldElement,
-
-    #[error("Attempting to parse an invalid string into a field element")]
     InvalidString,
-
-    #[error("{}", _0)]
     Message(String),
-
-    #[error("Attempting to parse an empty string into a field element")]
     ParsingEmptyString,
-
-    #[error("Attempting to parse a non-digit character into a field element")]
     ParsingNonDigitCharacter,
 }
 
-impl From<std::io::Error> for FieldError {
-    fn from(error: std::io::Error) -> Self {
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Crate(crate_name, message) => write!(f, "{}: {}", crate_name, message),
+            Self::InvalidFieldElement => write!(f, "Invalid field element"),
+            Self::InvalidString => write!(f, "Attempting to parse an invalid string into a field element"),
+            Self::Message(message) => write!(f, "{}", message),
+            Self::ParsingEmptyString => write!(f, "Attempting to parse an empty string into a field element"),
+            Self::ParsingNonDigitCharacter => {
+                write!(f, "Attempting to parse a non-digit character into a field element")
+            }
+        }
+    }
+}
+
+impl Error for FieldError {}
+
+impl From<io::Error> for FieldError {
+    fn from(error: io::Error) -> Self {
         FieldError::Crate("std::io", format!("{:?}", error))
     }
 }
 
-impl From<FieldError> for std::io::Error {
+impl From<FieldError> for io::Error {
     fn from(error: FieldError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, format!("{}", error))
+        snarkvm_utilities::error(format!("{}", error))
     }
 }
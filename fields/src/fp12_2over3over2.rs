@@ -14,19 +14,39 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{fp6_3over2::*, Field, Fp2, Fp2Parameters, One, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBits, ToBytes};
+use crate::{
+    decode_components,
+    fp6_3over2::*,
+    CyclotomicField,
+    Encoding,
+    ExtensionField,
+    Field,
+    Fp2,
+    Fp2Parameters,
+    One,
+    PrimeField,
+    Zero,
+};
+use snarkvm_utilities::{
+    bititerator::BitIteratorLE,
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    serialize::*,
+    vec,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
 
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
 use serde::{Deserialize, Serialize};
-use std::{
-    cmp::Ordering,
-    io::{Read, Result as IoResult, Write},
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-};
 
 pub trait Fp12Parameters: 'static + Send + Sync + Copy {
     type Fp6Params: Fp6Parameters;
@@ -175,32 +195,146 @@ impl<P: Fp12Parameters> Fp12<P> {
         result
     }
 
-    pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
-        let mut res = Self::one();
+    /// Squares an element of the cyclotomic subgroup in Karabina's compressed form.
+    ///
+    /// The compressed form keeps only the coefficients `c0.c1`, `c0.c2`, `c1.c0` and `c1.c2`,
+    /// which this squaring computes without the others, and sets `c0.c0` and `c1.c1` to zero.
+    /// See "Squaring in Cyclotomic Subgroups" by Koray Karabina, and
+    /// [`Self::decompress_karabina`] to recover the full element.
+    pub fn cyclotomic_square_compressed(&self) -> Self {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
 
-        let mut found_one = false;
+        let z4 = self.c0.c1;
+        let z3 = self.c0.c2;
+        let z2 = self.c1.c0;
+        let z5 = self.c1.c2;
 
-        for i in BitIteratorBE::new(exp) {
-            if !found_one {
-                if i {
-                    found_one = true;
-                } else {
-                    continue;
-                }
-            }
+        // t2 + t3*y = (z2 + z3*y)^2 = b^2
+        let z2_sq = z2.square();
+        let z3_sq = z3.square();
+        let t2 = z2_sq + fp2_nr(&z3_sq);
+        let t3 = (z2 + z3).square() - z2_sq - z3_sq;
 
-            res = res.cyclotomic_square();
+        // t4 + t5*y = (z4 + z5*y)^2 = c^2
+        let z4_sq = z4.square();
+        let z5_sq = z5.square();
+        let t4 = z4_sq + fp2_nr(&z5_sq);
+        let t5 = (z4 + z5).square() - z4_sq - z5_sq;
+
+        // z2 = 3 * (xi * t5) + 2 * z2
+        let tmp = fp2_nr(&t5);
+        let z2 = (tmp + z2).double() + tmp;
+        // z3 = 3 * t4 - 2 * z3
+        let z3 = (t4 - z3).double() + t4;
+        // z4 = 3 * t2 - 2 * z4
+        let z4 = (t2 - z4).double() + t2;
+        // z5 = 3 * t3 + 2 * z5
+        let z5 = (t3 + z5).double() + t3;
+
+        Self::new(Fp6::new(Fp2::zero(), z4, z3), Fp6::new(z2, Fp2::zero(), z5))
+    }
+
+    /// Returns the elements of the cyclotomic subgroup in the given Karabina's compressed forms,
+    /// with a single inversion.
+    pub fn decompress_karabina(compressed: &[Self]) -> Vec<Self> {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        // c1.c1 = (xi * z5^2 + 3 * z4^2 - 2 * z3) / (4 * z2) if z2 != 0, and 2 * z4 * z5 / z3 otherwise.
+        let (numerators, mut denominators): (Vec<_>, Vec<_>) = compressed
+            .iter()
+            .map(|element| {
+                let (z4, z3, z2, z5) = (element.c0.c1, element.c0.c2, element.c1.c0, element.c1.c2);
+                match z2.is_zero() {
+                    false => {
+                        let z4_sq = z4.square();
+                        (fp2_nr(&z5.square()) + z4_sq.double() + z4_sq - z3.double(), z2.double().double())
+                    }
+                    // If z2 = z3 = 0, the element is the identity, and the zero denominator is left unchanged.
+                    true => ((z4 * z5).double(), z3),
+                }
+            })
+            .unzip();
+        crate::serial_batch_inversion_and_mul(&mut denominators, &Fp2::one());
+
+        compressed
+            .iter()
+            .zip(numerators.into_iter().zip(denominators))
+            .map(|(element, (numerator, denominator))| {
+                let (z4, z3, z2, z5) = (element.c0.c1, element.c0.c2, element.c1.c0, element.c1.c2);
+                let z1 = numerator * denominator;
+                // c0.c0 = xi * (2 * z1^2 + z2 * z5 - 3 * z3 * z4) + 1
+                let z3z4 = z3 * z4;
+                let z0 = fp2_nr(&(z1.square().double() + z2 * z5 - z3z4.double() - z3z4)) + Fp2::one();
+                Self::new(Fp6::new(z0, z4, z3), Fp6::new(z2, z1, z5))
+            })
+            .collect()
+    }
 
-            if i {
-                res *= self;
+    /// Returns `self^exp`, for an element of the cyclotomic subgroup.
+    ///
+    /// The squarings are computed in Karabina's compressed form, and the powers
+    /// for the set bits of `exp` are decompressed with a single inversion.
+    pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut bits = BitIteratorLE::new_without_trailing_zeros(exp);
+
+        let mut res = match bits.next() {
+            Some(true) => *self,
+            Some(false) => Self::one(),
+            None => return Self::one(),
+        };
+
+        let mut square = *self;
+        let mut powers = Vec::new();
+        for bit in bits {
+            square = square.cyclotomic_square_compressed();
+            if bit {
+                powers.push(square);
             }
         }
+
+        for power in Self::decompress_karabina(&powers) {
+            res *= power;
+        }
         res
     }
 }
 
-impl<P: Fp12Parameters> std::fmt::Display for Fp12<P> {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl<P: Fp12Parameters> CyclotomicField for Fp12<P> {
+    type Subfield = Fp6<P::Fp6Params>;
+
+    fn cyclotomic_inverse(&self) -> Self {
+        let mut result = *self;
+        result.conjugate();
+        result
+    }
+
+    fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        Self::cyclotomic_exp(self, exp)
+    }
+
+    fn to_torus(&self) -> Self::Subfield {
+        // The identity and -1 are the only elements of the cyclotomic subgroup with c1 = 0.
+        // Both are represented by zero, which is unambiguous in the target group as it excludes -1.
+        match self.c1.inverse() {
+            Some(c1_inverse) => (Fp6::one() + self.c0) * c1_inverse,
+            None => Fp6::zero(),
+        }
+    }
+
+    fn from_torus(torus: &Self::Subfield) -> Self {
+        if torus.is_zero() {
+            return Self::one();
+        }
+        // (m + w) / (m - w) = (m^2 + v + 2 * m * w) / (m^2 - v), where m^2 - v != 0 as v is not a square.
+        let m_sq = torus.square();
+        let v = Self::mul_fp6_by_nonresidue(&Fp6::one());
+        let denominator = (m_sq - v).inverse().unwrap();
+        Self::new((m_sq + v) * denominator, torus.double() * denominator)
+    }
+}
+
+impl<P: Fp12Parameters> core::fmt::Display for Fp12<P> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         write!(f, "Fp12({} + {} * w)", self.c0, self.c1)
     }
 }
@@ -337,6 +471,26 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
     }
 }
 
+impl<P: Fp12Parameters> ExtensionField for Fp12<P> {
+    fn extension_degree() -> u64 {
+        12
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != Self::extension_degree() as usize {
+            return None;
+        }
+        let (c0, c1) = elems.split_at(6);
+        Some(Self::new(Fp6::from_base_prime_field_elems(c0)?, Fp6::from_base_prime_field_elems(c1)?))
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        let mut elems = self.c0.to_base_prime_field_elements();
+        elems.extend(self.c1.to_base_prime_field_elements());
+        elems
+    }
+}
+
 impl<P: Fp12Parameters> Neg for Fp12<P> {
     type Output = Self;
 
@@ -491,6 +645,24 @@ impl<P: Fp12Parameters> ToBits for Fp12<P> {
     }
 }
 
+impl<P: Fp12Parameters> Encoding for Fp12<P>
+where
+    Fp6<P::Fp6Params>: Encoding,
+{
+    /// Returns the little-endian bits of `c0`, followed by those of `c1`.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        [self.c0.encode_bits_le(), self.c1.encode_bits_le()].concat()
+    }
+
+    /// Returns an element of Fp12 from the little-endian bits of `c0`, followed by those of `c1`.
+    /// Missing bits are treated as zero, and any excess bits must be zero.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        let size_in_bits = 6 * <Fp2Params<P> as Fp2Parameters>::Fp::size_in_bits();
+        let [c0, c1] = decode_components(bits_le, size_in_bits)?;
+        Some(Self::new(c0, c1))
+    }
+}
+
 impl<P: Fp12Parameters> ToBytes for Fp12<P> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -531,6 +703,7 @@ impl<P: Fp12Parameters> CanonicalSerialize for Fp12<P> {
 
 impl<P: Fp12Parameters> ConstantSerializedSize for Fp12<P> {
     const SERIALIZED_SIZE: usize = 2 * <Fp6<P::Fp6Params> as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <Fp6<P::Fp6Params> as ConstantSerializedSize>::SPARE_BITS;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
 }
 
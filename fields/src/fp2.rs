@@ -14,19 +14,39 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
-use snarkvm_utilities::{rand::UniformRand, serialize::*, FromBytes, ToBits, ToBytes};
+use crate::{
+    decode_components,
+    Choice,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    Encoding,
+    ExtensionField,
+    Field,
+    LegendreSymbol,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    serialize::*,
+    vec,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
 
+use core::{
+    cmp::{Ord, Ordering, PartialOrd},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
 use serde::{Deserialize, Serialize};
-use std::{
-    cmp::{Ord, Ordering, PartialOrd},
-    io::{Read, Result as IoResult, Write},
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-};
 
 pub trait Fp2Parameters: 'static + Send + Sync + Serialize + for<'a> Deserialize<'a> {
     type Fp: PrimeField;
@@ -194,6 +214,23 @@ impl<P: Fp2Parameters> Field for Fp2<P> {
     }
 }
 
+impl<P: Fp2Parameters> ExtensionField for Fp2<P> {
+    fn extension_degree() -> u64 {
+        2
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        match elems {
+            [c0, c1] => Some(Self::new(*c0, *c1)),
+            _ => None,
+        }
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        vec![self.c0, self.c1]
+    }
+}
+
 impl<P: Fp2Parameters> SquareRootField for Fp2<P>
 where
     P::Fp: SquareRootField,
@@ -235,6 +272,20 @@ where
 }
 
 /// `Fp2` elements are ordered lexicographically.
+impl<P: Fp2Parameters> ConstantTimeEq for Fp2<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl<P: Fp2Parameters> ConditionallySelectable for Fp2<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(P::Fp::conditional_select(&a.c0, &b.c0, choice), P::Fp::conditional_select(&a.c1, &b.c1, choice))
+    }
+}
+
 impl<P: Fp2Parameters> Ord for Fp2<P> {
     #[inline(always)]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -299,6 +350,23 @@ impl<P: Fp2Parameters> ToBits for Fp2<P> {
     }
 }
 
+impl<P: Fp2Parameters> Encoding for Fp2<P>
+where
+    P::Fp: Encoding,
+{
+    /// Returns the little-endian bits of `c0`, followed by those of `c1`.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        [self.c0.encode_bits_le(), self.c1.encode_bits_le()].concat()
+    }
+
+    /// Returns an element of Fp2 from the little-endian bits of `c0`, followed by those of `c1`.
+    /// Missing bits are treated as zero, and any excess bits must be zero.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        let [c0, c1] = decode_components(bits_le, P::Fp::size_in_bits())?;
+        Some(Self::new(c0, c1))
+    }
+}
+
 impl<P: Fp2Parameters> ToBytes for Fp2<P> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -320,7 +388,6 @@ impl<P: Fp2Parameters> Neg for Fp2<P> {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn neg(self) -> Self {
         let mut res = self;
         res.c0 = res.c0.neg();
@@ -423,8 +490,8 @@ impl<'a, P: Fp2Parameters> DivAssign<&'a Self> for Fp2<P> {
     }
 }
 
-impl<P: Fp2Parameters> std::fmt::Display for Fp2<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<P: Fp2Parameters> core::fmt::Display for Fp2<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Fp2({} + {} * u)", self.c0, self.c1)
     }
 }
@@ -452,6 +519,7 @@ impl<P: Fp2Parameters> CanonicalSerialize for Fp2<P> {
 
 impl<P: Fp2Parameters> ConstantSerializedSize for Fp2<P> {
     const SERIALIZED_SIZE: usize = 2 * <P::Fp as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <P::Fp as ConstantSerializedSize>::SPARE_BITS;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
 }
 
@@ -0,0 +1,513 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{decode_components, Encoding, ExtensionField, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    serialize::*,
+    vec,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use core::{
+    cmp::{Ord, Ordering, PartialOrd},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+
+pub trait Fp3Parameters: 'static + Send + Sync + Serialize + for<'a> Deserialize<'a> {
+    type Fp: PrimeField;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP3_C1: [Self::Fp; 3];
+    const FROBENIUS_COEFF_FP3_C2: [Self::Fp; 3];
+
+    const NONRESIDUE: Self::Fp;
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue(fe: &Self::Fp) -> Self::Fp {
+        Self::NONRESIDUE * fe
+    }
+}
+
+/// An element of Fp3, represented by c0 + c1 * u + c2 * u^(2).
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(
+    Default(bound = "P: Fp3Parameters"),
+    Hash(bound = "P: Fp3Parameters"),
+    Clone(bound = "P: Fp3Parameters"),
+    Copy(bound = "P: Fp3Parameters"),
+    Debug(bound = "P: Fp3Parameters"),
+    PartialEq(bound = "P: Fp3Parameters"),
+    Eq(bound = "P: Fp3Parameters")
+)]
+pub struct Fp3<P: Fp3Parameters> {
+    pub c0: P::Fp,
+    pub c1: P::Fp,
+    pub c2: P::Fp,
+}
+
+impl<P: Fp3Parameters> Fp3<P> {
+    pub fn new(c0: P::Fp, c1: P::Fp, c2: P::Fp) -> Self {
+        Fp3 { c0, c1, c2 }
+    }
+
+    pub fn mul_by_fp(&mut self, element: &P::Fp) {
+        self.c0.mul_assign(element);
+        self.c1.mul_assign(element);
+        self.c2.mul_assign(element);
+    }
+
+    pub fn mul_by_1(&mut self, c1: &P::Fp) {
+        let c0 = P::mul_fp_by_nonresidue(&(self.c2 * c1));
+        let new_c1 = self.c0 * c1;
+        let c2 = self.c1 * c1;
+
+        self.c0 = c0;
+        self.c1 = new_c1;
+        self.c2 = c2;
+    }
+
+    pub fn mul_by_01(&mut self, c0: &P::Fp, c1: &P::Fp) {
+        let a_a = self.c0 * c0;
+        let b_b = self.c1 * c1;
+
+        let t1 = P::mul_fp_by_nonresidue(&(self.c2 * c1)) + a_a;
+        let t2 = (*c0 + c1) * (self.c0 + self.c1) - a_a - b_b;
+        let t3 = self.c2 * c0 + b_b;
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = t3;
+    }
+}
+
+impl<P: Fp3Parameters> Zero for Fp3<P> {
+    fn zero() -> Self {
+        Self::new(P::Fp::zero(), P::Fp::zero(), P::Fp::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<P: Fp3Parameters> One for Fp3<P> {
+    fn one() -> Self {
+        Self::new(P::Fp::one(), P::Fp::zero(), P::Fp::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<P: Fp3Parameters> Field for Fp3<P> {
+    type BasePrimeField = P::Fp;
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        Self::new(other, P::Fp::zero(), P::Fp::zero())
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::Fp::characteristic()
+    }
+
+    fn double(&self) -> Self {
+        let mut result = *self;
+        result.double_in_place();
+        result
+    }
+
+    fn double_in_place(&mut self) {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+        self.c2.double_in_place();
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        let split_at = bytes.len() / 3;
+        if let Some(c0) = P::Fp::from_random_bytes(&bytes[..split_at]) {
+            if let Some(c1) = P::Fp::from_random_bytes(&bytes[split_at..2 * split_at]) {
+                if let Some((c2, flags)) = P::Fp::from_random_bytes_with_flags::<F>(&bytes[2 * split_at..]) {
+                    return Some((Fp3::new(c0, c1, c2), flags));
+                }
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    fn square(&self) -> Self {
+        let mut result = *self;
+        result.square_in_place();
+        result
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
+        // Pairing-Friendly Fields.pdf; Section 4 (CH-SQR2)
+        let s0 = self.c0.square();
+        let s1 = (self.c0 * self.c1).double();
+        let s2 = (self.c0 - self.c1 + self.c2).square();
+        let s3 = (self.c1 * self.c2).double();
+        let s4 = self.c2.square();
+
+        self.c0 = s0 + P::mul_fp_by_nonresidue(&s3);
+        self.c1 = s1 + P::mul_fp_by_nonresidue(&s4);
+        self.c2 = s1 + s2 + s3 - s0 - s4;
+
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guide to Pairing-based Cryptography, Algorithm 5.23.
+            // t0 = c0^2 - beta * c1 * c2
+            let t0 = self.c0.square() - P::mul_fp_by_nonresidue(&(self.c1 * self.c2));
+            // t1 = beta * c2^2 - c0 * c1
+            let t1 = P::mul_fp_by_nonresidue(&self.c2.square()) - self.c0 * self.c1;
+            // t2 = c1^2 - c0 * c2
+            let t2 = self.c1.square() - self.c0 * self.c2;
+            // norm = c0 * t0 + beta * (c2 * t1 + c1 * t2)
+            let norm = self.c0 * t0 + P::mul_fp_by_nonresidue(&(self.c2 * t1 + self.c1 * t2));
+
+            norm.inverse().map(|t| Self::new(t0 * t, t1 * t, t2 * t))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c1.mul_assign(&P::FROBENIUS_COEFF_FP3_C1[power % 3]);
+        self.c2.mul_assign(&P::FROBENIUS_COEFF_FP3_C2[power % 3]);
+    }
+}
+
+impl<P: Fp3Parameters> ExtensionField for Fp3<P> {
+    fn extension_degree() -> u64 {
+        3
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        match elems {
+            [c0, c1, c2] => Some(Self::new(*c0, *c1, *c2)),
+            _ => None,
+        }
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        vec![self.c0, self.c1, self.c2]
+    }
+}
+
+/// `Fp3` elements are ordered lexicographically.
+impl<P: Fp3Parameters> Ord for Fp3<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let c2_cmp = self.c2.cmp(&other.c2);
+        let c1_cmp = self.c1.cmp(&other.c1);
+        let c0_cmp = self.c0.cmp(&other.c0);
+        if c2_cmp == Ordering::Equal {
+            if c1_cmp == Ordering::Equal {
+                c0_cmp
+            } else {
+                c1_cmp
+            }
+        } else {
+            c2_cmp
+        }
+    }
+}
+
+impl<P: Fp3Parameters> PartialOrd for Fp3<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp3Parameters> From<u128> for Fp3<P> {
+    fn from(other: u128) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<u64> for Fp3<P> {
+    fn from(other: u64) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<u32> for Fp3<P> {
+    fn from(other: u32) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<u16> for Fp3<P> {
+    fn from(other: u16) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<u8> for Fp3<P> {
+    fn from(other: u8) -> Self {
+        Self::new(other.into(), P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> ToBits for Fp3<P> {
+    fn to_bits_le(&self) -> Vec<bool> {
+        let mut res = vec![];
+        res.extend_from_slice(&self.c0.to_bits_le());
+        res.extend_from_slice(&self.c1.to_bits_le());
+        res.extend_from_slice(&self.c2.to_bits_le());
+        res
+    }
+
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut res = vec![];
+        res.extend_from_slice(&self.c0.to_bits_be());
+        res.extend_from_slice(&self.c1.to_bits_be());
+        res.extend_from_slice(&self.c2.to_bits_be());
+        res
+    }
+}
+
+impl<P: Fp3Parameters> Encoding for Fp3<P>
+where
+    P::Fp: Encoding,
+{
+    /// Returns the little-endian bits of `c0`, followed by those of `c1` and `c2`.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        [self.c0.encode_bits_le(), self.c1.encode_bits_le(), self.c2.encode_bits_le()].concat()
+    }
+
+    /// Returns an element of Fp3 from the little-endian bits of `c0`, followed by those of `c1` and `c2`.
+    /// Missing bits are treated as zero, and any excess bits must be zero.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        let [c0, c1, c2] = decode_components(bits_le, P::Fp::size_in_bits())?;
+        Some(Self::new(c0, c1, c2))
+    }
+}
+
+impl<P: Fp3Parameters> ToBytes for Fp3<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.c0.write_le(&mut writer)?;
+        self.c1.write_le(&mut writer)?;
+        self.c2.write_le(writer)
+    }
+}
+
+impl<P: Fp3Parameters> FromBytes for Fp3<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let c0 = P::Fp::read_le(&mut reader)?;
+        let c1 = P::Fp::read_le(&mut reader)?;
+        let c2 = P::Fp::read_le(reader)?;
+        Ok(Fp3::new(c0, c1, c2))
+    }
+}
+
+impl<P: Fp3Parameters> Neg for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        let mut res = self;
+        res.c0 = res.c0.neg();
+        res.c1 = res.c1.neg();
+        res.c2 = res.c2.neg();
+        res
+    }
+}
+
+impl<P: Fp3Parameters> Distribution<Fp3<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp3<P> {
+        Fp3::new(UniformRand::rand(rng), UniformRand::rand(rng), UniformRand::rand(rng))
+    }
+}
+
+impl_add_sub_from_field_ref!(Fp3, Fp3Parameters);
+impl_mul_div_from_field_ref!(Fp3, Fp3Parameters);
+
+impl<'a, P: Fp3Parameters> Add<&'a Fp3<P>> for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp3Parameters> Sub<&'a Fp3<P>> for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(&other);
+        result
+    }
+}
+
+impl<'a, P: Fp3Parameters> Mul<&'a Fp3<P>> for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other);
+        result
+    }
+}
+
+impl<'a, P: Fp3Parameters> Div<&'a Fp3<P>> for Fp3<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp3Parameters> AddAssign<&'a Self> for Fp3<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(other.c0);
+        self.c1.add_assign(other.c1);
+        self.c2.add_assign(other.c2);
+    }
+}
+
+impl<'a, P: Fp3Parameters> SubAssign<&'a Self> for Fp3<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+        self.c2.sub_assign(&other.c2);
+    }
+}
+
+impl<'a, P: Fp3Parameters> MulAssign<&'a Self> for Fp3<P> {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, other: &Self) {
+        // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
+        // Pairing-Friendly Fields.pdf; Section 4 (Karatsuba)
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let v2 = self.c2 * other.c2;
+
+        let c0 = P::mul_fp_by_nonresidue(&((self.c1 + self.c2) * (other.c1 + other.c2) - v1 - v2)) + v0;
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1 + P::mul_fp_by_nonresidue(&v2);
+        let c2 = (self.c0 + self.c2) * (other.c0 + other.c2) - v0 - v2 + v1;
+
+        self.c0 = c0;
+        self.c1 = c1;
+        self.c2 = c2;
+    }
+}
+
+impl<'a, P: Fp3Parameters> DivAssign<&'a Self> for Fp3<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl<P: Fp3Parameters> core::fmt::Display for Fp3<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Fp3({} + {} * u + {} * u^2)", self.c0, self.c1, self.c2)
+    }
+}
+
+impl<P: Fp3Parameters> CanonicalSerializeWithFlags for Fp3<P> {
+    #[inline]
+    fn serialize_with_flags<W: Write, F: Flags>(&self, writer: &mut W, flags: F) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.c0, writer)?;
+        CanonicalSerialize::serialize(&self.c1, writer)?;
+        self.c2.serialize_with_flags(writer, flags)?;
+        Ok(())
+    }
+}
+
+impl<P: Fp3Parameters> CanonicalSerialize for Fp3<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+}
+
+impl<P: Fp3Parameters> ConstantSerializedSize for Fp3<P> {
+    const SERIALIZED_SIZE: usize = 3 * <P::Fp as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <P::Fp as ConstantSerializedSize>::SPARE_BITS;
+    const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+}
+
+impl<P: Fp3Parameters> CanonicalDeserializeWithFlags for Fp3<P> {
+    #[inline]
+    fn deserialize_with_flags<R: Read, F: Flags>(reader: &mut R) -> Result<(Self, F), SerializationError> {
+        let c0: P::Fp = CanonicalDeserialize::deserialize(reader)?;
+        let c1: P::Fp = CanonicalDeserialize::deserialize(reader)?;
+        let (c2, flags): (P::Fp, _) = CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+        Ok((Fp3::new(c0, c1, c2), flags))
+    }
+}
+
+impl<P: Fp3Parameters> CanonicalDeserialize for Fp3<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let c0: P::Fp = CanonicalDeserialize::deserialize(reader)?;
+        let c1: P::Fp = CanonicalDeserialize::deserialize(reader)?;
+        let c2: P::Fp = CanonicalDeserialize::deserialize(reader)?;
+        Ok(Fp3::new(c0, c1, c2))
+    }
+}
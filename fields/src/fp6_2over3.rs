@@ -0,0 +1,555 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    decode_components,
+    CyclotomicField,
+    Encoding,
+    ExtensionField,
+    Field,
+    Fp3,
+    Fp3Parameters,
+    One,
+    PrimeField,
+    Zero,
+};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    serialize::*,
+    vec,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+
+pub trait Fp6Parameters: 'static + Send + Sync + Copy {
+    type Fp3Params: Fp3Parameters;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP6_C1: [Fp<Self>; 6];
+
+    const NONRESIDUE: Fp3<Self::Fp3Params>;
+
+    #[inline(always)]
+    fn mul_fp3_by_nonresidue(fe: &Fp3<Self::Fp3Params>) -> Fp3<Self::Fp3Params> {
+        Self::NONRESIDUE * fe
+    }
+}
+
+type Fp<P> = <<P as Fp6Parameters>::Fp3Params as Fp3Parameters>::Fp;
+
+/// An element of Fp6, represented by c0 + c1 * v.
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(
+    Default(bound = "P: Fp6Parameters"),
+    Hash(bound = "P: Fp6Parameters"),
+    Clone(bound = "P: Fp6Parameters"),
+    Copy(bound = "P: Fp6Parameters"),
+    Debug(bound = "P: Fp6Parameters"),
+    PartialEq(bound = "P: Fp6Parameters"),
+    Eq(bound = "P: Fp6Parameters")
+)]
+#[must_use]
+pub struct Fp6<P: Fp6Parameters> {
+    pub c0: Fp3<P::Fp3Params>,
+    pub c1: Fp3<P::Fp3Params>,
+}
+
+impl<P: Fp6Parameters> Fp6<P> {
+    pub fn new(c0: Fp3<P::Fp3Params>, c1: Fp3<P::Fp3Params>) -> Self {
+        Self { c0, c1 }
+    }
+
+    pub fn mul_by_fp(&mut self, element: &Fp<P>) {
+        self.c0.mul_by_fp(element);
+        self.c1.mul_by_fp(element);
+    }
+
+    pub fn conjugate(&mut self) {
+        self.c1 = self.c1.neg();
+    }
+
+    /// Multiplies `self` by the sparse element `c0 + c3 * v + c4 * u * v`.
+    pub fn mul_by_034(&mut self, c0: &Fp<P>, c3: &Fp<P>, c4: &Fp<P>) {
+        let mut a = self.c0;
+        a.mul_by_fp(c0);
+        let mut b = self.c1;
+        b.mul_by_01(c3, c4);
+
+        let mut e = self.c0 + self.c1;
+        e.mul_by_01(&(*c0 + c3), c4);
+        self.c1 = e - (a + b);
+        self.c0 = a + P::mul_fp3_by_nonresidue(&b);
+    }
+
+    /// Multiplies `self` by the sparse element `c0 + c1 * u + c4 * u * v`.
+    pub fn mul_by_014(&mut self, c0: &Fp<P>, c1: &Fp<P>, c4: &Fp<P>) {
+        let mut a = self.c0;
+        a.mul_by_01(c0, c1);
+        let mut b = self.c1;
+        b.mul_by_1(c4);
+
+        let mut e = self.c0 + self.c1;
+        e.mul_by_01(c0, &(*c1 + c4));
+        self.c1 = e - (a + b);
+        self.c0 = a + P::mul_fp3_by_nonresidue(&b);
+    }
+
+    pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut res = Self::one();
+
+        let mut found_one = false;
+
+        for i in BitIteratorBE::new(exp) {
+            if !found_one {
+                if i {
+                    found_one = true;
+                } else {
+                    continue;
+                }
+            }
+
+            res.square_in_place();
+
+            if i {
+                res *= self;
+            }
+        }
+        res
+    }
+}
+
+impl<P: Fp6Parameters> CyclotomicField for Fp6<P> {
+    type Subfield = Fp3<P::Fp3Params>;
+
+    fn cyclotomic_inverse(&self) -> Self {
+        let mut result = *self;
+        result.conjugate();
+        result
+    }
+
+    fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        Self::cyclotomic_exp(self, exp)
+    }
+
+    fn to_torus(&self) -> Self::Subfield {
+        // The identity and -1 are the only elements of the cyclotomic subgroup with c1 = 0.
+        // Both are represented by zero, which is unambiguous in the target group as it excludes -1.
+        match self.c1.inverse() {
+            Some(c1_inverse) => (Fp3::one() + self.c0) * c1_inverse,
+            None => Fp3::zero(),
+        }
+    }
+
+    fn from_torus(torus: &Self::Subfield) -> Self {
+        if torus.is_zero() {
+            return Self::one();
+        }
+        // (m + w) / (m - w) = (m^2 + v + 2 * m * w) / (m^2 - v), where m^2 - v != 0 as v is not a square.
+        let m_sq = torus.square();
+        let v = P::mul_fp3_by_nonresidue(&Fp3::one());
+        let denominator = (m_sq - v).inverse().unwrap();
+        Self::new((m_sq + v) * denominator, torus.double() * denominator)
+    }
+}
+
+impl<P: Fp6Parameters> core::fmt::Display for Fp6<P> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "Fp6_2over3({} + {} * v)", self.c0, self.c1)
+    }
+}
+
+impl<P: Fp6Parameters> Distribution<Fp6<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp6<P> {
+        Fp6::new(UniformRand::rand(rng), UniformRand::rand(rng))
+    }
+}
+
+impl<P: Fp6Parameters> Zero for Fp6<P> {
+    fn zero() -> Self {
+        Self::new(Fp3::zero(), Fp3::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+}
+
+impl<P: Fp6Parameters> One for Fp6<P> {
+    fn one() -> Self {
+        Self::new(Fp3::one(), Fp3::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero()
+    }
+}
+
+impl<P: Fp6Parameters> Field for Fp6<P> {
+    type BasePrimeField = Fp<P>;
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        Self::new(Fp3::from_base_prime_field(other), Fp3::zero())
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        Fp3::<P::Fp3Params>::characteristic()
+    }
+
+    fn double(&self) -> Self {
+        let mut copy = *self;
+        copy.double_in_place();
+        copy
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        let split_at = bytes.len() / 2;
+        if let Some(c0) = Fp3::<P::Fp3Params>::from_random_bytes(&bytes[..split_at]) {
+            if let Some((c1, flags)) = Fp3::<P::Fp3Params>::from_random_bytes_with_flags::<F>(&bytes[split_at..]) {
+                return Some((Fp6::new(c0, c1), flags));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    fn double_in_place(&mut self) {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+        self.c1.mul_by_fp(&P::FROBENIUS_COEFF_FP6_C1[power % 6]);
+    }
+
+    fn square(&self) -> Self {
+        let mut copy = *self;
+        copy.square_in_place();
+        copy
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
+        // Pairing-Friendly Fields.pdf; Section 3 (Complex)
+        let ab = self.c0 * self.c1;
+        let c0 =
+            (self.c0 + self.c1) * (self.c0 + P::mul_fp3_by_nonresidue(&self.c1)) - ab - P::mul_fp3_by_nonresidue(&ab);
+        self.c1 = ab.double();
+        self.c0 = c0;
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guide to Pairing-based Cryptography, Algorithm 5.16.
+            let t = self.c0.square() - P::mul_fp3_by_nonresidue(&self.c1.square());
+
+            t.inverse().map(|t| Self::new(self.c0 * t, -(self.c1 * t)))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        match self.inverse() {
+            Some(inv) => {
+                *self = inv;
+                Some(self)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<P: Fp6Parameters> ExtensionField for Fp6<P> {
+    fn extension_degree() -> u64 {
+        6
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != Self::extension_degree() as usize {
+            return None;
+        }
+        let (c0, c1) = elems.split_at(3);
+        Some(Self::new(Fp3::from_base_prime_field_elems(c0)?, Fp3::from_base_prime_field_elems(c1)?))
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        let mut elems = self.c0.to_base_prime_field_elements();
+        elems.extend(self.c1.to_base_prime_field_elements());
+        elems
+    }
+}
+
+impl<P: Fp6Parameters> Neg for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        let mut copy = Self::zero();
+        copy.c0 = self.c0.neg();
+        copy.c1 = self.c1.neg();
+        copy
+    }
+}
+
+impl_add_sub_from_field_ref!(Fp6, Fp6Parameters);
+impl_mul_div_from_field_ref!(Fp6, Fp6Parameters);
+
+impl<'a, P: Fp6Parameters> Add<&'a Self> for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp6Parameters> Sub<&'a Self> for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(&other);
+        result
+    }
+}
+
+impl<'a, P: Fp6Parameters> Mul<&'a Self> for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other);
+        result
+    }
+}
+
+impl<'a, P: Fp6Parameters> Div<&'a Self> for Fp6<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp6Parameters> AddAssign<&'a Self> for Fp6<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(other.c0);
+        self.c1.add_assign(other.c1);
+    }
+}
+
+impl<'a, P: Fp6Parameters> SubAssign<&'a Self> for Fp6<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+    }
+}
+
+impl<'a, P: Fp6Parameters> MulAssign<&'a Self> for Fp6<P> {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, other: &Self) {
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        self.c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1;
+        self.c0 = v0 + P::mul_fp3_by_nonresidue(&v1);
+    }
+}
+
+impl<'a, P: Fp6Parameters> DivAssign<&'a Self> for Fp6<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+/// `Fp6` elements are ordered lexicographically.
+impl<P: Fp6Parameters> Ord for Fp6<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let c1_cmp = self.c1.cmp(&other.c1);
+        if c1_cmp == Ordering::Equal {
+            self.c0.cmp(&other.c0)
+        } else {
+            c1_cmp
+        }
+    }
+}
+
+impl<P: Fp6Parameters> PartialOrd for Fp6<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp6Parameters> From<u128> for Fp6<P> {
+    fn from(other: u128) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<u64> for Fp6<P> {
+    fn from(other: u64) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<u32> for Fp6<P> {
+    fn from(other: u32) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<u16> for Fp6<P> {
+    fn from(other: u16) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<u8> for Fp6<P> {
+    fn from(other: u8) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> ToBits for Fp6<P> {
+    fn to_bits_le(&self) -> Vec<bool> {
+        let mut res = vec![];
+        res.extend_from_slice(&self.c0.to_bits_le());
+        res.extend_from_slice(&self.c1.to_bits_le());
+        res
+    }
+
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut res = vec![];
+        res.extend_from_slice(&self.c0.to_bits_be());
+        res.extend_from_slice(&self.c1.to_bits_be());
+        res
+    }
+}
+
+impl<P: Fp6Parameters> Encoding for Fp6<P>
+where
+    Fp3<P::Fp3Params>: Encoding,
+{
+    /// Returns the little-endian bits of `c0`, followed by those of `c1`.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        [self.c0.encode_bits_le(), self.c1.encode_bits_le()].concat()
+    }
+
+    /// Returns an element of Fp6 from the little-endian bits of `c0`, followed by those of `c1`.
+    /// Missing bits are treated as zero, and any excess bits must be zero.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        let size_in_bits = 3 * <P::Fp3Params as Fp3Parameters>::Fp::size_in_bits();
+        let [c0, c1] = decode_components(bits_le, size_in_bits)?;
+        Some(Self::new(c0, c1))
+    }
+}
+
+impl<P: Fp6Parameters> ToBytes for Fp6<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.c0.write_le(&mut writer)?;
+        self.c1.write_le(&mut writer)
+    }
+}
+
+impl<P: Fp6Parameters> FromBytes for Fp6<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let c0 = Fp3::read_le(&mut reader)?;
+        let c1 = Fp3::read_le(&mut reader)?;
+        Ok(Fp6::new(c0, c1))
+    }
+}
+
+impl<P: Fp6Parameters> CanonicalSerializeWithFlags for Fp6<P> {
+    #[inline]
+    fn serialize_with_flags<W: Write, F: Flags>(&self, writer: &mut W, flags: F) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.c0, writer)?;
+        self.c1.serialize_with_flags(writer, flags)?;
+        Ok(())
+    }
+}
+
+impl<P: Fp6Parameters> CanonicalSerialize for Fp6<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+}
+
+impl<P: Fp6Parameters> ConstantSerializedSize for Fp6<P> {
+    const SERIALIZED_SIZE: usize = 2 * <Fp3<P::Fp3Params> as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <Fp3<P::Fp3Params> as ConstantSerializedSize>::SPARE_BITS;
+    const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+}
+
+impl<P: Fp6Parameters> CanonicalDeserializeWithFlags for Fp6<P> {
+    #[inline]
+    fn deserialize_with_flags<R: Read, F: Flags>(reader: &mut R) -> Result<(Self, F), SerializationError> {
+        let c0 = CanonicalDeserialize::deserialize(reader)?;
+        let (c1, flags) = Fp3::deserialize_with_flags(reader)?;
+        Ok((Fp6::new(c0, c1), flags))
+    }
+}
+
+impl<P: Fp6Parameters> CanonicalDeserialize for Fp6<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let c0 = CanonicalDeserialize::deserialize(reader)?;
+        let c1 = CanonicalDeserialize::deserialize(reader)?;
+        Ok(Fp6::new(c0, c1))
+    }
+}
@@ -14,19 +14,26 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Field, Fp2, Fp2Parameters, One, Zero};
-use snarkvm_utilities::{rand::UniformRand, serialize::*, FromBytes, ToBits, ToBytes};
+use crate::{decode_components, Encoding, ExtensionField, Field, Fp2, Fp2Parameters, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    serialize::*,
+    vec,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
 
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
 use serde::{Deserialize, Serialize};
-use std::{
-    cmp::Ordering,
-    io::{Read, Result as IoResult, Write},
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-};
 
 pub trait Fp6Parameters: 'static + Send + Sync + Copy {
     type Fp2Params: Fp2Parameters;
@@ -295,8 +302,26 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
     }
 }
 
-impl<P: Fp6Parameters> std::fmt::Display for Fp6<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<P: Fp6Parameters> ExtensionField for Fp6<P> {
+    fn extension_degree() -> u64 {
+        6
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != Self::extension_degree() as usize {
+            return None;
+        }
+        let mut coeffs = elems.chunks(2).map(Fp2::from_base_prime_field_elems);
+        Some(Self::new(coeffs.next()??, coeffs.next()??, coeffs.next()??))
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        [self.c0, self.c1, self.c2].iter().flat_map(|c| c.to_base_prime_field_elements()).collect()
+    }
+}
+
+impl<P: Fp6Parameters> core::fmt::Display for Fp6<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Fq6_3over2({} + {} * v, {} * v^2)", self.c0, self.c1, self.c2)
     }
 }
@@ -312,7 +337,6 @@ impl<P: Fp6Parameters> Neg for Fp6<P> {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn neg(self) -> Self {
         let mut copy = Self::zero();
         copy.c0 = self.c0.neg();
@@ -487,6 +511,24 @@ impl<P: Fp6Parameters> ToBits for Fp6<P> {
     }
 }
 
+impl<P: Fp6Parameters> Encoding for Fp6<P>
+where
+    Fp2<P::Fp2Params>: Encoding,
+{
+    /// Returns the little-endian bits of `c0`, followed by those of `c1` and `c2`.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        [self.c0.encode_bits_le(), self.c1.encode_bits_le(), self.c2.encode_bits_le()].concat()
+    }
+
+    /// Returns an element of Fp6 from the little-endian bits of `c0`, followed by those of `c1` and `c2`.
+    /// Missing bits are treated as zero, and any excess bits must be zero.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        let size_in_bits = 2 * <P::Fp2Params as Fp2Parameters>::Fp::size_in_bits();
+        let [c0, c1, c2] = decode_components(bits_le, size_in_bits)?;
+        Some(Self::new(c0, c1, c2))
+    }
+}
+
 impl<P: Fp6Parameters> ToBytes for Fp6<P> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -530,6 +572,7 @@ impl<P: Fp6Parameters> CanonicalSerialize for Fp6<P> {
 
 impl<P: Fp6Parameters> ConstantSerializedSize for Fp6<P> {
     const SERIALIZED_SIZE: usize = 3 * <Fp2<P::Fp2Params> as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <Fp2<P::Fp2Params> as ConstantSerializedSize>::SPARE_BITS;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
 }
 
@@ -15,8 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    impl_add_sub_from_field_ref,
-    impl_mul_div_from_field_ref,
+    Choice,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    ExtensionField,
     FftField,
     Field,
     FieldError,
@@ -31,16 +33,18 @@ use crate::{
 };
 use snarkvm_utilities::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger256 as BigInteger},
+    io::{Read, Result as IoResult, Write},
     serialize::CanonicalDeserialize,
+    vec,
+    vec::Vec,
     FromBytes,
     ToBits,
     ToBytes,
 };
 
-use std::{
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
     fmt::{Debug, Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
@@ -77,9 +81,11 @@ impl<P: Fp256Parameters> Fp256<P> {
 
     #[inline]
     fn reduce(&mut self) {
-        if !self.is_valid() {
-            self.0.sub_noborrow(&P::MODULUS);
-        }
+        // Subtract the modulus, and keep the difference only if it did not borrow,
+        // without branching on the value.
+        let mut reduced = self.0;
+        let borrow = reduced.sub_noborrow(&P::MODULUS);
+        self.0.conditional_assign(&reduced, !Choice::from(borrow as u8));
     }
 
     #[inline(always)]
@@ -315,6 +321,23 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
     }
 }
 
+impl<P: Fp256Parameters> ExtensionField for Fp256<P> {
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        match elems {
+            [elem] => Some(*elem),
+            _ => None,
+        }
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        vec![*self]
+    }
+}
+
 impl<P: Fp256Parameters> PrimeField for Fp256<P> {
     type BigInteger = BigInteger;
     type Parameters = P;
@@ -442,6 +465,8 @@ impl_primefield_from_int!(Fp256, u16, Fp256Parameters);
 impl_primefield_from_int!(Fp256, u8, Fp256Parameters);
 
 impl_primefield_standard_sample!(Fp256, Fp256Parameters);
+impl_primefield_constant_time!(Fp256, Fp256Parameters);
+impl_primefield_encoding!(Fp256, Fp256Parameters);
 
 impl_add_sub_from_field_ref!(Fp256, Fp256Parameters);
 impl_mul_div_from_field_ref!(Fp256, Fp256Parameters);
@@ -559,15 +584,12 @@ impl<P: Fp256Parameters> Neg for Fp256<P> {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn neg(self) -> Self {
-        if !self.is_zero() {
-            let mut tmp = P::MODULUS;
-            tmp.sub_noborrow(&self.0);
-            Fp256::<P>(tmp, PhantomData)
-        } else {
-            self
-        }
+        let mut tmp = P::MODULUS;
+        tmp.sub_noborrow(&self.0);
+        // The negation of zero is zero, rather than the modulus.
+        tmp.conditional_assign(&self.0, self.0.ct_eq(&BigInteger::default()));
+        Fp256::<P>(tmp, PhantomData)
     }
 }
 
@@ -629,12 +651,11 @@ impl<'a, P: Fp256Parameters> AddAssign<&'a Self> for Fp256<P> {
 impl<'a, P: Fp256Parameters> SubAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn sub_assign(&mut self, other: &Self) {
-        // If `other` is larger than `self`, add the modulus to self first.
-        if other.0 > self.0 {
-            self.0.add_nocarry(&P::MODULUS);
-        }
-
-        self.0.sub_noborrow(&other.0);
+        // If `other` is larger than `self`, the subtraction borrows, and the modulus is added back.
+        let borrow = self.0.sub_noborrow(&other.0);
+        let mut corrected = self.0;
+        corrected.add_nocarry(&P::MODULUS);
+        self.0.conditional_assign(&corrected, Choice::from(borrow as u8));
     }
 }
 
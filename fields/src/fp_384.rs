@@ -15,8 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    impl_add_sub_from_field_ref,
-    impl_mul_div_from_field_ref,
+    Choice,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    ExtensionField,
     FftField,
     Field,
     FieldError,
@@ -31,16 +33,18 @@ use crate::{
 };
 use snarkvm_utilities::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger384 as BigInteger},
+    io::{Read, Result as IoResult, Write},
     serialize::CanonicalDeserialize,
+    vec,
+    vec::Vec,
     FromBytes,
     ToBits,
     ToBytes,
 };
 
-use std::{
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
     fmt::{Debug, Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
@@ -75,11 +79,218 @@ impl<P: Fp384Parameters> Fp384<P> {
         self.0 < P::MODULUS
     }
 
+    /// Multiplies this element by `other` in place, with 64-bit limbs.
+    #[doc(hidden)]
+    #[inline]
+    pub fn mul_assign_limb64(&mut self, other: &Self) {
+        let mut r = [0u64; 6];
+        let mut carry1 = 0u64;
+        let mut carry2 = 0u64;
+
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[0], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[0], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[0], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[0], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[0], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[0], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 1.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[1], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[1], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[1], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[1], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[1], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[1], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 2.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[2], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[2], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[2], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[2], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[2], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[2], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 3.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[3], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[3], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[3], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[3], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[3], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[3], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 4.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[4], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[4], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[4], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[4], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[4], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[4], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        // Iteration 5.
+        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[5], &mut carry1);
+        let k = r[0].wrapping_mul(P::INV);
+        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[5], &mut carry1);
+        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
+
+        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[5], &mut carry1);
+        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
+
+        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[5], &mut carry1);
+        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
+
+        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[5], &mut carry1);
+        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
+
+        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[5], &mut carry1);
+        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
+        r[5] = carry1 + carry2;
+
+        (self.0).0 = r;
+        self.reduce();
+    }
+
+    /// Squares this element in place, with 64-bit limbs.
+    #[doc(hidden)]
+    #[inline]
+    pub fn square_in_place_limb64(&mut self) {
+        let mut carry = 0;
+        let r1 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[1], &mut carry);
+        let r2 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[2], &mut carry);
+        let r3 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[3], &mut carry);
+        let r4 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[4], &mut carry);
+        let r5 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[5], &mut carry);
+        let r6 = carry;
+        let mut carry = 0;
+        let r3 = fa::mac_with_carry(r3, (self.0).0[1], (self.0).0[2], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[1], (self.0).0[3], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[1], (self.0).0[4], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[1], (self.0).0[5], &mut carry);
+        let r7 = carry;
+        let mut carry = 0;
+        let r5 = fa::mac_with_carry(r5, (self.0).0[2], (self.0).0[3], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[2], (self.0).0[4], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[2], (self.0).0[5], &mut carry);
+        let r8 = carry;
+        let mut carry = 0;
+        let r7 = fa::mac_with_carry(r7, (self.0).0[3], (self.0).0[4], &mut carry);
+        let r8 = fa::mac_with_carry(r8, (self.0).0[3], (self.0).0[5], &mut carry);
+        let r9 = carry;
+        let mut carry = 0;
+        let r9 = fa::mac_with_carry(r9, (self.0).0[4], (self.0).0[5], &mut carry);
+        let r10 = carry;
+
+        let mut r11 = r10 >> 63;
+        let r10 = (r10 << 1) | (r9 >> 63);
+        let mut r9 = (r9 << 1) | (r8 >> 63);
+        let r8 = (r8 << 1) | (r7 >> 63);
+        let mut r7 = (r7 << 1) | (r6 >> 63);
+        let r6 = (r6 << 1) | (r5 >> 63);
+        let mut r5 = (r5 << 1) | (r4 >> 63);
+        let r4 = (r4 << 1) | (r3 >> 63);
+        let mut r3 = (r3 << 1) | (r2 >> 63);
+        let r2 = (r2 << 1) | (r1 >> 63);
+        let mut r1 = r1 << 1;
+
+        let mut carry = 0;
+        let r0 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[0], &mut carry);
+        carry = fa::adc(&mut r1, 0, carry);
+        let r2 = fa::mac_with_carry(r2, (self.0).0[1], (self.0).0[1], &mut carry);
+        carry = fa::adc(&mut r3, 0, carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[2], (self.0).0[2], &mut carry);
+        carry = fa::adc(&mut r5, 0, carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[3], (self.0).0[3], &mut carry);
+        carry = fa::adc(&mut r7, 0, carry);
+        let r8 = fa::mac_with_carry(r8, (self.0).0[4], (self.0).0[4], &mut carry);
+        carry = fa::adc(&mut r9, 0, carry);
+        let r10 = fa::mac_with_carry(r10, (self.0).0[5], (self.0).0[5], &mut carry);
+        fa::adc(&mut r11, 0, carry);
+        self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11);
+    }
+
+    /// Multiplies this element by `other` in place, with unsaturated 52-bit limbs.
+    #[doc(hidden)]
+    #[inline]
+    pub fn mul_assign_limb52(&mut self, other: &Self) {
+        (self.0).0 = crate::fp_384_limb52::mul(&(self.0).0, &(other.0).0, &P::MODULUS.0, P::INV);
+        self.reduce();
+    }
+
+    /// Squares this element in place, with unsaturated 52-bit limbs.
+    #[doc(hidden)]
+    #[inline]
+    pub fn square_in_place_limb52(&mut self) {
+        (self.0).0 = crate::fp_384_limb52::square(&(self.0).0, &P::MODULUS.0, P::INV);
+        self.reduce();
+    }
+
     #[inline]
     fn reduce(&mut self) {
-        if !self.is_valid() {
-            self.0.sub_noborrow(&P::MODULUS);
-        }
+        // Subtract the modulus, and keep the difference only if it did not borrow,
+        // without branching on the value.
+        let mut reduced = self.0;
+        let borrow = reduced.sub_noborrow(&P::MODULUS);
+        self.0.conditional_assign(&reduced, !Choice::from(borrow as u8));
     }
 
     #[inline(always)]
@@ -244,58 +455,10 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
 
     #[inline]
     fn square_in_place(&mut self) -> &mut Self {
-        let mut carry = 0;
-        let r1 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[1], &mut carry);
-        let r2 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[2], &mut carry);
-        let r3 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[3], &mut carry);
-        let r4 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[4], &mut carry);
-        let r5 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[5], &mut carry);
-        let r6 = carry;
-        let mut carry = 0;
-        let r3 = fa::mac_with_carry(r3, (self.0).0[1], (self.0).0[2], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[1], (self.0).0[3], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[1], (self.0).0[4], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[1], (self.0).0[5], &mut carry);
-        let r7 = carry;
-        let mut carry = 0;
-        let r5 = fa::mac_with_carry(r5, (self.0).0[2], (self.0).0[3], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[2], (self.0).0[4], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[2], (self.0).0[5], &mut carry);
-        let r8 = carry;
-        let mut carry = 0;
-        let r7 = fa::mac_with_carry(r7, (self.0).0[3], (self.0).0[4], &mut carry);
-        let r8 = fa::mac_with_carry(r8, (self.0).0[3], (self.0).0[5], &mut carry);
-        let r9 = carry;
-        let mut carry = 0;
-        let r9 = fa::mac_with_carry(r9, (self.0).0[4], (self.0).0[5], &mut carry);
-        let r10 = carry;
-
-        let mut r11 = r10 >> 63;
-        let r10 = (r10 << 1) | (r9 >> 63);
-        let mut r9 = (r9 << 1) | (r8 >> 63);
-        let r8 = (r8 << 1) | (r7 >> 63);
-        let mut r7 = (r7 << 1) | (r6 >> 63);
-        let r6 = (r6 << 1) | (r5 >> 63);
-        let mut r5 = (r5 << 1) | (r4 >> 63);
-        let r4 = (r4 << 1) | (r3 >> 63);
-        let mut r3 = (r3 << 1) | (r2 >> 63);
-        let r2 = (r2 << 1) | (r1 >> 63);
-        let mut r1 = r1 << 1;
-
-        let mut carry = 0;
-        let r0 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[0], &mut carry);
-        carry = fa::adc(&mut r1, 0, carry);
-        let r2 = fa::mac_with_carry(r2, (self.0).0[1], (self.0).0[1], &mut carry);
-        carry = fa::adc(&mut r3, 0, carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[2], (self.0).0[2], &mut carry);
-        carry = fa::adc(&mut r5, 0, carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[3], (self.0).0[3], &mut carry);
-        carry = fa::adc(&mut r7, 0, carry);
-        let r8 = fa::mac_with_carry(r8, (self.0).0[4], (self.0).0[4], &mut carry);
-        carry = fa::adc(&mut r9, 0, carry);
-        let r10 = fa::mac_with_carry(r10, (self.0).0[5], (self.0).0[5], &mut carry);
-        fa::adc(&mut r11, 0, carry);
-        self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11);
+        #[cfg(not(feature = "fp384-limb52"))]
+        self.square_in_place_limb64();
+        #[cfg(feature = "fp384-limb52")]
+        self.square_in_place_limb52();
         self
     }
 
@@ -367,6 +530,23 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
     }
 }
 
+impl<P: Fp384Parameters> ExtensionField for Fp384<P> {
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        match elems {
+            [elem] => Some(*elem),
+            _ => None,
+        }
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        vec![*self]
+    }
+}
+
 impl<P: Fp384Parameters> PrimeField for Fp384<P> {
     type BigInteger = BigInteger;
     type Parameters = P;
@@ -531,6 +711,8 @@ impl_primefield_from_int!(Fp384, u16, Fp384Parameters);
 impl_primefield_from_int!(Fp384, u8, Fp384Parameters);
 
 impl_primefield_standard_sample!(Fp384, Fp384Parameters);
+impl_primefield_constant_time!(Fp384, Fp384Parameters);
+impl_primefield_encoding!(Fp384, Fp384Parameters);
 
 impl_add_sub_from_field_ref!(Fp384, Fp384Parameters);
 impl_mul_div_from_field_ref!(Fp384, Fp384Parameters);
@@ -633,15 +815,12 @@ impl<P: Fp384Parameters> Neg for Fp384<P> {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn neg(self) -> Self {
-        if !self.is_zero() {
-            let mut tmp = P::MODULUS;
-            tmp.sub_noborrow(&self.0);
-            Fp384::<P>(tmp, PhantomData)
-        } else {
-            self
-        }
+        let mut tmp = P::MODULUS;
+        tmp.sub_noborrow(&self.0);
+        // The negation of zero is zero, rather than the modulus.
+        tmp.conditional_assign(&self.0, self.0.ct_eq(&BigInteger::default()));
+        Fp384::<P>(tmp, PhantomData)
     }
 }
 
@@ -702,143 +881,21 @@ impl<'a, P: Fp384Parameters> AddAssign<&'a Self> for Fp384<P> {
 impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn sub_assign(&mut self, other: &Self) {
-        // If `other` is larger than `self`, add the modulus to self first.
-        if other.0 > self.0 {
-            self.0.add_nocarry(&P::MODULUS);
-        }
-
-        self.0.sub_noborrow(&other.0);
+        // If `other` is larger than `self`, the subtraction borrows, and the modulus is added back.
+        let borrow = self.0.sub_noborrow(&other.0);
+        let mut corrected = self.0;
+        corrected.add_nocarry(&P::MODULUS);
+        self.0.conditional_assign(&corrected, Choice::from(borrow as u8));
     }
 }
 
 impl<'a, P: Fp384Parameters> MulAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
-        let mut r = [0u64; 6];
-        let mut carry1 = 0u64;
-        let mut carry2 = 0u64;
-
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[0], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[0], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[0], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[0], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[0], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[0], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 1.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[1], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[1], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[1], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[1], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[1], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[1], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 2.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[2], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[2], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[2], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[2], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[2], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[2], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 3.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[3], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[3], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[3], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[3], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[3], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[3], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 4.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[4], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[4], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[4], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[4], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[4], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[4], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        // Iteration 5.
-        r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[5], &mut carry1);
-        let k = r[0].wrapping_mul(P::INV);
-        fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
-        r[1] = fa::mac_with_carry(r[1], (self.0).0[1], (other.0).0[5], &mut carry1);
-        r[0] = fa::mac_with_carry(r[1], k, P::MODULUS.0[1], &mut carry2);
-
-        r[2] = fa::mac_with_carry(r[2], (self.0).0[2], (other.0).0[5], &mut carry1);
-        r[1] = fa::mac_with_carry(r[2], k, P::MODULUS.0[2], &mut carry2);
-
-        r[3] = fa::mac_with_carry(r[3], (self.0).0[3], (other.0).0[5], &mut carry1);
-        r[2] = fa::mac_with_carry(r[3], k, P::MODULUS.0[3], &mut carry2);
-
-        r[4] = fa::mac_with_carry(r[4], (self.0).0[4], (other.0).0[5], &mut carry1);
-        r[3] = fa::mac_with_carry(r[4], k, P::MODULUS.0[4], &mut carry2);
-
-        r[5] = fa::mac_with_carry(r[5], (self.0).0[5], (other.0).0[5], &mut carry1);
-        r[4] = fa::mac_with_carry(r[5], k, P::MODULUS.0[5], &mut carry2);
-        r[5] = carry1 + carry2;
-
-        (self.0).0 = r;
-        self.reduce();
+        #[cfg(not(feature = "fp384-limb52"))]
+        self.mul_assign_limb64(other);
+        #[cfg(feature = "fp384-limb52")]
+        self.mul_assign_limb52(other);
     }
 }
 
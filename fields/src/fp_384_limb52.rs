@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Montgomery multiplication for 384-bit fields with unsaturated 52-bit limbs.
+//!
+//! The operands are split into 8 limbs of 52 bits, so that the partial products of each
+//! column can be accumulated in a `u128` without propagating carries, and carries are only
+//! resolved once per column during the reduction. The 52-bit limbs match the operands of the
+//! AVX-512 IFMA instructions (`vpmadd52luq` and `vpmadd52huq`).
+//!
+//! The elements stay in the Montgomery form of [`Fp384`](crate::Fp384), with `R = 2^384`.
+//! As `384 = 7 * 52 + 20`, the reduction runs 7 rounds with a 52-bit radix, and one round
+//! with a 20-bit radix.
+
+const NUM_LIMBS: usize = 8;
+const LIMB_BITS: usize = 52;
+const LIMB_MASK: u64 = (1 << LIMB_BITS) - 1;
+const LAST_ROUND_BITS: usize = 384 - (NUM_LIMBS - 1) * LIMB_BITS;
+const LAST_ROUND_MASK: u64 = (1 << LAST_ROUND_BITS) - 1;
+
+/// Returns the 52-bit limbs of the given 64-bit limbs.
+#[inline(always)]
+fn to_limbs(a: &[u64; 6]) -> [u64; NUM_LIMBS] {
+    let mut limbs = [0u64; NUM_LIMBS];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let (word, shift) = ((i * LIMB_BITS) / 64, (i * LIMB_BITS) % 64);
+        let mut value = a[word] >> shift;
+        if shift > 64 - LIMB_BITS && word + 1 < 6 {
+            value |= a[word + 1] << (64 - shift);
+        }
+        *limb = value & LIMB_MASK;
+    }
+    limbs
+}
+
+/// Returns `t / 2^384 mod p`, in the range `[0, 2p)`, for the given columns of a product `t < p * 2^384`.
+#[inline(always)]
+fn mont_reduce(mut t: [u128; 2 * NUM_LIMBS], modulus: &[u64; NUM_LIMBS], inv: u64) -> [u64; 6] {
+    // Clear the low 364 bits of `t`, one 52-bit limb at a time.
+    for i in 0..NUM_LIMBS - 1 {
+        let k = (t[i] as u64).wrapping_mul(inv) & LIMB_MASK;
+        for (j, modulus) in modulus.iter().enumerate() {
+            t[i + j] += (k as u128) * (*modulus as u128);
+        }
+        t[i + 1] += t[i] >> LIMB_BITS;
+    }
+
+    // Clear the remaining 20 bits of `t`.
+    let i = NUM_LIMBS - 1;
+    let k = (t[i] as u64).wrapping_mul(inv) & LAST_ROUND_MASK;
+    for (j, modulus) in modulus.iter().enumerate() {
+        t[i + j] += (k as u128) * (*modulus as u128);
+    }
+    for i in NUM_LIMBS - 1..2 * NUM_LIMBS - 1 {
+        t[i + 1] += t[i] >> LIMB_BITS;
+        t[i] &= LIMB_MASK as u128;
+    }
+
+    // Pack the bits of `t` from 2^384 onwards into 64-bit limbs.
+    let mut result = [0u64; 6];
+    let (mut buffer, mut buffer_bits) = (t[NUM_LIMBS - 1] >> LAST_ROUND_BITS, LIMB_BITS - LAST_ROUND_BITS);
+    let mut words = result.iter_mut();
+    for column in &t[NUM_LIMBS..] {
+        buffer |= column << buffer_bits;
+        buffer_bits += LIMB_BITS;
+        while buffer_bits >= 64 {
+            if let Some(word) = words.next() {
+                *word = buffer as u64;
+            }
+            buffer >>= 64;
+            buffer_bits -= 64;
+        }
+    }
+    result
+}
+
+/// Returns `a * b / 2^384 mod p`, in the range `[0, 2p)`.
+///
+/// `inv` is `-p^{-1} mod 2^64`, as in [`FieldParameters::INV`](crate::FieldParameters::INV).
+#[inline]
+pub(crate) fn mul(a: &[u64; 6], b: &[u64; 6], modulus: &[u64; 6], inv: u64) -> [u64; 6] {
+    let (a, b) = (to_limbs(a), to_limbs(b));
+
+    let mut t = [0u128; 2 * NUM_LIMBS];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            t[i + j] += (*a as u128) * (*b as u128);
+        }
+    }
+
+    mont_reduce(t, &to_limbs(modulus), inv)
+}
+
+/// Returns `a^2 / 2^384 mod p`, in the range `[0, 2p)`.
+///
+/// `inv` is `-p^{-1} mod 2^64`, as in [`FieldParameters::INV`](crate::FieldParameters::INV).
+#[inline]
+pub(crate) fn square(a: &[u64; 6], modulus: &[u64; 6], inv: u64) -> [u64; 6] {
+    let a = to_limbs(a);
+
+    // Compute the cross products once, double them, and add the squares.
+    let mut t = [0u128; 2 * NUM_LIMBS];
+    for i in 0..NUM_LIMBS {
+        for j in i + 1..NUM_LIMBS {
+            t[i + j] += (a[i] as u128) * (a[j] as u128);
+        }
+    }
+    for (i, a) in a.iter().enumerate() {
+        t[2 * i] <<= 1;
+        t[2 * i + 1] <<= 1;
+        t[2 * i] += (*a as u128) * (*a as u128);
+    }
+
+    mont_reduce(t, &to_limbs(modulus), inv)
+}
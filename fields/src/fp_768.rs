@@ -0,0 +1,605 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Choice,
+    ConditionallySelectable,
+    ConstantTimeEq,
+    ExtensionField,
+    FftField,
+    Field,
+    FieldError,
+    FieldParameters,
+    LegendreSymbol,
+    One,
+    PoseidonDefaultField,
+    PoseidonDefaultParameters,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger768 as BigInteger},
+    io::{Read, Result as IoResult, Write},
+    serialize::CanonicalDeserialize,
+    vec,
+    vec::Vec,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use core::{
+    cmp::{Ord, Ordering, PartialOrd},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+pub trait Fp768Parameters: FieldParameters<BigInteger = BigInteger> {}
+
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = "P: Fp768Parameters"),
+    Hash(bound = "P: Fp768Parameters"),
+    Clone(bound = "P: Fp768Parameters"),
+    Copy(bound = "P: Fp768Parameters"),
+    PartialEq(bound = "P: Fp768Parameters"),
+    Eq(bound = "P: Fp768Parameters")
+)]
+pub struct Fp768<P: Fp768Parameters>(
+    pub BigInteger,
+    #[derivative(Debug = "ignore")]
+    #[doc(hidden)]
+    pub PhantomData<P>,
+);
+
+impl<P: Fp768Parameters> Fp768<P> {
+    #[inline]
+    pub fn new(element: BigInteger) -> Self {
+        Fp768::<P>(element, PhantomData)
+    }
+
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.0 < P::MODULUS
+    }
+
+    #[inline]
+    fn reduce(&mut self) {
+        // Subtract the modulus, and keep the difference only if it did not borrow,
+        // without branching on the value.
+        let mut reduced = self.0;
+        let borrow = reduced.sub_noborrow(&P::MODULUS);
+        self.0.conditional_assign(&reduced, !Choice::from(borrow as u8));
+    }
+
+    #[inline(always)]
+    fn mont_reduce(&mut self, mut r: [u64; 24]) {
+        // The Montgomery reduction here is based on Algorithm 14.32 in
+        // Handbook of Applied Cryptography
+        // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
+
+        let mut carry2 = 0;
+        for i in 0..12 {
+            let k = r[i].wrapping_mul(P::INV);
+            let mut carry = 0;
+            fa::mac_with_carry(r[i], k, P::MODULUS.0[0], &mut carry);
+            for j in 1..12 {
+                r[j + i] = fa::mac_with_carry(r[j + i], k, P::MODULUS.0[j], &mut carry);
+            }
+            carry2 = fa::adc(&mut r[i + 12], carry2, carry);
+        }
+        (self.0).0.copy_from_slice(&r[12..]);
+        self.reduce();
+    }
+}
+
+impl<P: Fp768Parameters> Zero for Fp768<P> {
+    #[inline]
+    fn zero() -> Self {
+        Fp768::<P>(BigInteger::from(0), PhantomData)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: Fp768Parameters> One for Fp768<P> {
+    #[inline]
+    fn one() -> Self {
+        Fp768::<P>(P::R, PhantomData)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == P::R
+    }
+}
+
+impl<P: Fp768Parameters> Field for Fp768<P> {
+    type BasePrimeField = Self;
+
+    // 768/64 = 12 limbs.
+    impl_field_from_random_bytes_with_flags!(12);
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        other
+    }
+
+    fn half() -> Self {
+        // Compute 1/2 `(p+1)/2` as `1/2`.
+        // This is cheaper than `Self::one().double().inverse()`
+        let mut two_inv = P::MODULUS;
+        two_inv.add_nocarry(&1u64.into());
+        two_inv.div2();
+        Self::from_repr(two_inv).unwrap() // Guaranteed to be valid.
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        let mut temp = *self;
+        temp.double_in_place();
+        temp
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) {
+        // This cannot exceed the backing capacity.
+        self.0.mul2();
+        // However, it may need to be reduced.
+        self.reduce();
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        let mut r = [0u64; 24];
+
+        // Compute the off-diagonal products `a_i * a_j` for `i < j`.
+        for i in 0..11 {
+            let mut carry = 0;
+            for j in (i + 1)..12 {
+                r[i + j] = fa::mac_with_carry(r[i + j], (self.0).0[i], (self.0).0[j], &mut carry);
+            }
+            r[i + 12] = carry;
+        }
+
+        // Double the off-diagonal products.
+        r[23] = r[22] >> 63;
+        for i in (1..23).rev() {
+            r[i] = (r[i] << 1) | (r[i - 1] >> 63);
+        }
+
+        // Add the diagonal products `a_i * a_i`.
+        let mut carry = 0;
+        for i in 0..12 {
+            r[2 * i] = fa::mac_with_carry(r[2 * i], (self.0).0[i], (self.0).0[i], &mut carry);
+            carry = fa::adc(&mut r[2 * i + 1], 0, carry);
+        }
+        self.mont_reduce(r);
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guajardo Kumar Paar Pelzl
+            // Efficient Software-Implementation of Finite Fields with Applications to
+            // Cryptography
+            // Algorithm 16 (BEA for Inversion in Fp)
+
+            let one = BigInteger::from(1);
+
+            let mut u = self.0;
+            let mut v = P::MODULUS;
+            let mut b = Fp768::<P>(P::R2, PhantomData); // Avoids unnecessary reduction step.
+            let mut c = Self::zero();
+
+            while u != one && v != one {
+                while u.is_even() {
+                    u.div2();
+
+                    if b.0.is_even() {
+                        b.0.div2();
+                    } else {
+                        b.0.add_nocarry(&P::MODULUS);
+                        b.0.div2();
+                    }
+                }
+
+                while v.is_even() {
+                    v.div2();
+
+                    if c.0.is_even() {
+                        c.0.div2();
+                    } else {
+                        c.0.add_nocarry(&P::MODULUS);
+                        c.0.div2();
+                    }
+                }
+
+                if v < u {
+                    u.sub_noborrow(&v);
+                    b.sub_assign(&c);
+                } else {
+                    v.sub_noborrow(&u);
+                    c.sub_assign(&b);
+                }
+            }
+
+            if u == one {
+                Some(b)
+            } else {
+                Some(c)
+            }
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {
+        // No-op: No effect in a prime field.
+    }
+}
+
+impl<P: Fp768Parameters> ExtensionField for Fp768<P> {
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        match elems {
+            [elem] => Some(*elem),
+            _ => None,
+        }
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        vec![*self]
+    }
+}
+
+impl<P: Fp768Parameters> PrimeField for Fp768<P> {
+    type BigInteger = BigInteger;
+    type Parameters = P;
+
+    #[inline]
+    fn from_repr(r: BigInteger) -> Option<Self> {
+        let mut r = Fp768(r, PhantomData);
+        if r.is_zero() {
+            Some(r)
+        } else if r.is_valid() {
+            r *= &Fp768(P::R2, PhantomData);
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn to_repr(&self) -> BigInteger {
+        let mut r = [0u64; 24];
+        r[..12].copy_from_slice(&(self.0).0);
+        let mut tmp = *self;
+        tmp.mont_reduce(r);
+        tmp.0
+    }
+
+    #[inline]
+    fn to_repr_unchecked(&self) -> BigInteger {
+        self.0
+    }
+}
+
+impl<P: Fp768Parameters> FftField for Fp768<P> {
+    type FftParameters = P;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Some(Self(P::LARGE_SUBGROUP_ROOT_OF_UNITY?, PhantomData))
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self(P::GENERATOR, PhantomData)
+    }
+}
+
+impl<P: Fp768Parameters> SquareRootField for Fp768<P> {
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        use crate::LegendreSymbol::*;
+
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+        if s.is_zero() {
+            Zero
+        } else if s.is_one() {
+            QuadraticResidue
+        } else {
+            QuadraticNonResidue
+        }
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_impl!(Self, P, self)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+impl<P: Fp768Parameters> Ord for Fp768<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_repr().cmp(&other.to_repr())
+    }
+}
+
+impl<P: Fp768Parameters> PartialOrd for Fp768<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp768Parameters + PoseidonDefaultParameters> PoseidonDefaultField for Fp768<P> {}
+
+impl_primefield_from_int!(Fp768, u128, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u64, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u32, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u16, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u8, Fp768Parameters);
+
+impl_primefield_standard_sample!(Fp768, Fp768Parameters);
+impl_primefield_constant_time!(Fp768, Fp768Parameters);
+impl_primefield_encoding!(Fp768, Fp768Parameters);
+
+impl_add_sub_from_field_ref!(Fp768, Fp768Parameters);
+impl_mul_div_from_field_ref!(Fp768, Fp768Parameters);
+
+impl<P: Fp768Parameters> ToBits for Fp768<P> {
+    fn to_bits_le(&self) -> Vec<bool> {
+        let mut bits_vec = self.to_repr().to_bits_le();
+        bits_vec.truncate(P::MODULUS_BITS as usize);
+
+        bits_vec
+    }
+
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits_vec = self.to_bits_le();
+        bits_vec.reverse();
+
+        bits_vec
+    }
+}
+
+impl<P: Fp768Parameters> ToBytes for Fp768<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.to_repr().write_le(writer)
+    }
+}
+
+impl<P: Fp768Parameters> FromBytes for Fp768<P> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        BigInteger::read_le(reader).and_then(|b| match Self::from_repr(b) {
+            Some(f) => Ok(f),
+            None => Err(FieldError::InvalidFieldElement.into()),
+        })
+    }
+}
+
+impl<P: Fp768Parameters> FromStr for Fp768<P> {
+    type Err = FieldError;
+
+    /// Interpret a string of numbers as a (congruent) prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(FieldError::ParsingEmptyString);
+        }
+
+        if s == "0" {
+            return Ok(Self::zero());
+        }
+
+        let mut res = Self::zero();
+
+        let ten = Self::from_repr(<Self as PrimeField>::BigInteger::from(10)).ok_or(FieldError::InvalidFieldElement)?;
+
+        let mut first_digit = true;
+
+        for c in s.chars() {
+            match c.to_digit(10) {
+                Some(c) => {
+                    if first_digit {
+                        if c == 0 {
+                            return Err(FieldError::InvalidString);
+                        }
+
+                        first_digit = false;
+                    }
+
+                    res.mul_assign(&ten);
+                    res.add_assign(
+                        &Self::from_repr(<Self as PrimeField>::BigInteger::from(u64::from(c)))
+                            .ok_or(FieldError::InvalidFieldElement)?,
+                    );
+                }
+                None => {
+                    return Err(FieldError::ParsingNonDigitCharacter);
+                }
+            }
+        }
+
+        if !res.is_valid() {
+            Err(FieldError::InvalidFieldElement)
+        } else {
+            Ok(res)
+        }
+    }
+}
+
+impl<P: Fp768Parameters> Debug for Fp768<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_repr())
+    }
+}
+
+impl<P: Fp768Parameters> Display for Fp768<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_repr())
+    }
+}
+
+impl<P: Fp768Parameters> Neg for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        let mut tmp = P::MODULUS;
+        tmp.sub_noborrow(&self.0);
+        // The negation of zero is zero, rather than the modulus.
+        tmp.conditional_assign(&self.0, self.0.ct_eq(&BigInteger::default()));
+        Fp768::<P>(tmp, PhantomData)
+    }
+}
+
+impl<'a, P: Fp768Parameters> Add<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Sub<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Mul<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Div<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> AddAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // This cannot exceed the backing capacity.
+        self.0.add_nocarry(&other.0);
+        // However, it may need to be reduced
+        self.reduce();
+    }
+}
+
+impl<'a, P: Fp768Parameters> SubAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        // If `other` is larger than `self`, the subtraction borrows, and the modulus is added back.
+        let borrow = self.0.sub_noborrow(&other.0);
+        let mut corrected = self.0;
+        corrected.add_nocarry(&P::MODULUS);
+        self.0.conditional_assign(&corrected, Choice::from(borrow as u8));
+    }
+}
+
+impl<'a, P: Fp768Parameters> MulAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let mut r = [0u64; 24];
+        for i in 0..12 {
+            let mut carry = 0;
+            for j in 0..12 {
+                r[i + j] = fa::mac_with_carry(r[i + j], (self.0).0[i], (other.0).0[j], &mut carry);
+            }
+            r[i + 12] = carry;
+        }
+        self.mont_reduce(r);
+    }
+}
+
+impl<'a, P: Fp768Parameters> DivAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
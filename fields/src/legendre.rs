@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 #[derive(Debug, PartialEq)]
 pub enum LegendreSymbol {
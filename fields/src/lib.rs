@@ -14,15 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::module_inception)]
 #![forbid(unsafe_code)]
 
 #[macro_use]
 extern crate derivative;
 
-#[macro_use]
-extern crate thiserror;
-
 #[macro_use]
 mod macros;
 
@@ -35,9 +33,19 @@ pub use fp_256::*;
 mod fp_384;
 pub use fp_384::*;
 
+mod fp_384_limb52;
+
+mod fp_768;
+pub use fp_768::*;
+
 mod fp2;
 pub use fp2::*;
 
+mod fp3;
+pub use fp3::*;
+
+pub mod fp6_2over3;
+
 pub mod fp6_3over2;
 
 mod fp12_2over3over2;
@@ -47,7 +55,6 @@ mod legendre;
 pub use legendre::*;
 
 mod to_field_vec;
-pub use to_field_vec::*;
 
 pub mod traits;
 pub use traits::*;
@@ -61,15 +68,18 @@ use snarkvm_utilities::{
         CanonicalSerializeWithFlags,
         ConstantSerializedSize,
     },
+    vec::Vec,
     FromBytes,
     ToBytes,
 };
 
 impl_field_into_biginteger!(Fp256, BigInteger256, Fp256Parameters);
 impl_field_into_biginteger!(Fp384, BigInteger384, Fp384Parameters);
+impl_field_into_biginteger!(Fp768, BigInteger768, Fp768Parameters);
 
 impl_primefield_serializer!(Fp256, Fp256Parameters, 32);
 impl_primefield_serializer!(Fp384, Fp384Parameters, 48);
+impl_primefield_serializer!(Fp768, Fp768Parameters, 96);
 
 // Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
 pub fn batch_inversion<F: Field>(v: &mut [F]) {
@@ -17,7 +17,7 @@
 #[macro_export]
 macro_rules! field {
     ($name:ident, $c0:expr) => {
-        $name { 0: $c0, 1: std::marker::PhantomData }
+        $name { 0: $c0, 1: core::marker::PhantomData }
     };
     ($name:ident, $c0:expr, $c1:expr $(,)?) => {
         $name { c0: $c0, c1: $c1 }
@@ -46,7 +46,7 @@ macro_rules! impl_primefield_standard_sample {
                 loop {
                     let mut tmp = $field(rng.sample(rand::distributions::Standard), PhantomData);
                     // Mask away the unused bits at the beginning.
-                    tmp.0.as_mut().last_mut().map(|val| *val &= std::u64::MAX >> P::REPR_SHAVE_BITS);
+                    tmp.0.as_mut().last_mut().map(|val| *val &= u64::MAX >> P::REPR_SHAVE_BITS);
 
                     if tmp.is_valid() {
                         return tmp;
@@ -57,6 +57,48 @@ macro_rules! impl_primefield_standard_sample {
     };
 }
 
+macro_rules! impl_primefield_constant_time {
+    ($field: ident, $params: ident) => {
+        impl<P: $params> $crate::ConstantTimeEq for $field<P> {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> $crate::Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl<P: $params> $crate::ConditionallySelectable for $field<P> {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: $crate::Choice) -> Self {
+                $field($crate::ConditionallySelectable::conditional_select(&a.0, &b.0, choice), PhantomData)
+            }
+        }
+    };
+}
+
+macro_rules! impl_primefield_encoding {
+    ($field: ident, $params: ident) => {
+        impl<P: $params> $crate::Encoding for $field<P> {
+            /// Returns the little-endian bits of `self` *without* trailing zeros.
+            fn encode_bits_le(&self) -> snarkvm_utilities::vec::Vec<bool> {
+                snarkvm_utilities::ToBits::to_bits_le(self)
+            }
+
+            /// Returns a field element from little-endian bits *without* trailing zeros.
+            /// Missing bits are treated as zero, and any excess bits must be zero.
+            fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+                let size_in_bits = P::MODULUS_BITS as usize;
+                // Ensure all excess bits are zero.
+                if bits_le.iter().skip(size_in_bits).any(|bit| *bit) {
+                    return None;
+                }
+
+                let bits_le = &bits_le[..core::cmp::min(bits_le.len(), size_in_bits)];
+                Self::from_repr(snarkvm_utilities::FromBits::from_bits_le(bits_le))
+            }
+        }
+    };
+}
+
 macro_rules! impl_primefield_from_int {
     ($field: ident, u128, $params: ident) => {
         impl<P: $params> From<u128> for $field<P> {
@@ -151,13 +193,17 @@ macro_rules! impl_primefield_serializer {
             ) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
                 const BYTE_SIZE: usize = $byte_size;
 
-                let (output_bit_size, output_byte_size) =
-                    snarkvm_utilities::serialize::number_of_bits_and_bytes($field::<P>::size_in_bits());
-                if F::num_bits() > (output_bit_size - P::MODULUS_BITS as usize) {
+                // The flags are packed into the last byte, which is an additional byte if the spare bits are too few.
+                if F::num_bits() > 8 {
                     return Err(snarkvm_utilities::serialize::SerializationError::NotEnoughSpace);
                 }
+                let output_byte_size = snarkvm_utilities::serialize::number_of_bytes_with_flags(
+                    Self::SERIALIZED_SIZE,
+                    Self::SPARE_BITS,
+                    F::num_bits(),
+                );
 
-                let mut bytes = [0u8; BYTE_SIZE];
+                let mut bytes = [0u8; BYTE_SIZE + 1];
                 self.write_le(&mut bytes[..])?;
 
                 bytes[output_byte_size - 1] |= flags.u8_bitmask();
@@ -171,6 +217,8 @@ macro_rules! impl_primefield_serializer {
             const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::number_of_bits_to_number_of_bytes(
                 <$field<P> as crate::PrimeField>::Parameters::MODULUS_BITS as usize,
             );
+            const SPARE_BITS: usize =
+                Self::SERIALIZED_SIZE * 8 - <$field<P> as crate::PrimeField>::Parameters::MODULUS_BITS as usize;
             const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
         }
 
@@ -197,16 +245,23 @@ macro_rules! impl_primefield_serializer {
             ) -> Result<(Self, F), snarkvm_utilities::serialize::SerializationError> {
                 const BYTE_SIZE: usize = $byte_size;
 
-                let (output_bit_size, output_byte_size) =
-                    snarkvm_utilities::serialize::number_of_bits_and_bytes($field::<P>::size_in_bits());
-                if F::num_bits() > (output_bit_size - P::MODULUS_BITS as usize) {
+                if F::num_bits() > 8 {
                     return Err(snarkvm_utilities::serialize::SerializationError::NotEnoughSpace);
                 }
+                let output_byte_size = snarkvm_utilities::serialize::number_of_bytes_with_flags(
+                    Self::SERIALIZED_SIZE,
+                    Self::SPARE_BITS,
+                    F::num_bits(),
+                );
 
-                let mut masked_bytes = [0; BYTE_SIZE];
+                let mut masked_bytes = [0; BYTE_SIZE + 1];
                 reader.read_exact(&mut masked_bytes[..output_byte_size])?;
 
                 let flags = F::from_u8_remove_flags(&mut masked_bytes[output_byte_size - 1]);
+                // An additional byte for the flags must not hold anything else.
+                if masked_bytes[BYTE_SIZE] != 0 {
+                    return Err(snarkvm_utilities::serialize::SerializationError::InvalidData);
+                }
 
                 Ok((Self::read_le(&masked_bytes[..])?, flags))
             }
@@ -230,7 +285,7 @@ macro_rules! impl_primefield_serializer {
 
         impl<P: $params> serde::Serialize for $field<P> {
             fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                let mut bytes = Vec::with_capacity(Self::SERIALIZED_SIZE);
+                let mut bytes = snarkvm_utilities::vec::Vec::with_capacity(Self::SERIALIZED_SIZE);
                 CanonicalSerialize::serialize(self, &mut bytes).map_err(serde::ser::Error::custom)?;
 
                 match serializer.is_human_readable() {
@@ -244,16 +299,16 @@ macro_rules! impl_primefield_serializer {
             fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
                 match deserializer.is_human_readable() {
                     true => {
-                        let s: String = serde::Deserialize::deserialize(deserializer)?;
+                        let s: snarkvm_utilities::string::String = serde::Deserialize::deserialize(deserializer)?;
                         core::str::FromStr::from_str(&s).map_err(serde::de::Error::custom)
                     }
                     false => {
-                        struct SerVisitor<P>(std::marker::PhantomData<P>);
+                        struct SerVisitor<P>(core::marker::PhantomData<P>);
 
                         impl<'de, P: $params> serde::de::Visitor<'de> for SerVisitor<P> {
                             type Value = $field<P>;
 
-                            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
                                 formatter.write_str("a valid field element")
                             }
 
@@ -262,18 +317,18 @@ macro_rules! impl_primefield_serializer {
                                 S: serde::de::SeqAccess<'de>,
                             {
                                 let len = <Self::Value as ConstantSerializedSize>::SERIALIZED_SIZE;
-                                let bytes: Vec<u8> = (0..len)
+                                let bytes: snarkvm_utilities::vec::Vec<u8> = (0..len)
                                     .map(|_| {
                                         seq.next_element()?
                                             .ok_or_else(|| serde::de::Error::custom("could not read bytes"))
                                     })
-                                    .collect::<Result<Vec<_>, _>>()?;
+                                    .collect::<Result<snarkvm_utilities::vec::Vec<_>, _>>()?;
 
                                 CanonicalDeserialize::deserialize(&mut &bytes[..]).map_err(serde::de::Error::custom)
                             }
                         }
 
-                        let visitor = SerVisitor(std::marker::PhantomData);
+                        let visitor = SerVisitor(core::marker::PhantomData);
                         deserializer.deserialize_tuple(Self::SERIALIZED_SIZE, visitor)
                     }
                 }
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{ConstraintFieldError, Field, Fp2, Fp2Parameters, PrimeField, ToConstraintField};
-use snarkvm_utilities::FromBits;
+use snarkvm_utilities::{vec, vec::Vec, FromBits};
 
 impl<F: Field> ToConstraintField<F> for () {
     #[inline]
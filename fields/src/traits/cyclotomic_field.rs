@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Field;
+use snarkvm_utilities::{
+    io::{Read, Write},
+    serialize::*,
+};
+
+/// A quadratic extension field `F = B[w] / (w^2 - v)` that hosts the target group of a pairing.
+///
+/// The target group is contained in the cyclotomic subgroup of `F`, whose elements have norm 1
+/// over `B`. The cyclotomic subgroup is in turn contained in the algebraic torus `T2(B)`, whose
+/// elements other than 1 are represented by a single element of `B`, which halves their size.
+/// See "Practical Cryptography in High Dimensional Tori" by Marten van Dijk et al.
+pub trait CyclotomicField: Field {
+    /// The subfield `B` of the quadratic extension.
+    type Subfield: Field;
+
+    /// Returns the inverse of an element of the cyclotomic subgroup, which is its conjugate.
+    fn cyclotomic_inverse(&self) -> Self;
+
+    /// Returns `self^exp`, for an element of the cyclotomic subgroup.
+    fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self;
+
+    /// Returns the torus representation `(1 + c0) / c1` of an element `c0 + c1 * w` of the cyclotomic subgroup,
+    /// where the identity is represented by zero.
+    ///
+    /// The elements with `c1 = 0` are the identity and -1, so -1 is also represented by zero.
+    /// This is injective on the target group, which has odd order and so excludes -1,
+    /// but not on the whole cyclotomic subgroup.
+    fn to_torus(&self) -> Self::Subfield;
+
+    /// Returns the element of the cyclotomic subgroup with the given torus representation.
+    ///
+    /// Zero is mapped to the identity, so this inverts [`Self::to_torus`] on the target group but not on -1.
+    /// The result is not checked to be in the prime-order subgroup.
+    fn from_torus(torus: &Self::Subfield) -> Self;
+
+    /// Serializes an element of the cyclotomic subgroup in its torus representation.
+    fn serialize_torus<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.to_torus().serialize(writer)
+    }
+
+    /// Deserializes an element of the cyclotomic subgroup from its torus representation.
+    ///
+    /// The result is not checked to be in the prime-order subgroup.
+    fn deserialize_torus<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self::from_torus(&Self::Subfield::deserialize(reader)?))
+    }
+}
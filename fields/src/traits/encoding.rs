@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrimeField;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le, string::String, vec, vec::Vec};
+
+///
+/// The bit-exact encoding of a native value, as used by its circuit counterpart.
+///
+/// The little-endian bits are the source of truth. The big-endian bits, the packed field
+/// elements and the canonical bytes are all derived from them, so a value and its circuit
+/// agree on every representation as long as they agree on the little-endian bits.
+///
+pub trait Encoding: Sized {
+    /// Returns the little-endian bits of `self`.
+    fn encode_bits_le(&self) -> Vec<bool>;
+
+    /// Returns the big-endian bits of `self`.
+    fn encode_bits_be(&self) -> Vec<bool> {
+        let mut bits_le = self.encode_bits_le();
+        bits_le.reverse();
+        bits_le
+    }
+
+    /// Returns `Self` from the given little-endian bits, or `None` if they are not a valid encoding.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self>;
+
+    /// Returns `Self` from the given big-endian bits, or `None` if they are not a valid encoding.
+    fn decode_bits_be(bits_be: &[bool]) -> Option<Self> {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::decode_bits_le(&bits_le)
+    }
+
+    /// Returns the little-endian bits of `self`, packed into field elements of `F::size_in_data_bits()` bits each.
+    fn encode_fields<F: PrimeField>(&self) -> Vec<F> {
+        // Each chunk is strictly less than the modulus, so it is never reduced.
+        self.encode_bits_le()
+            .chunks(F::size_in_data_bits())
+            .map(|chunk| F::from_bytes_le_mod_order(&bytes_from_bits_le(chunk)))
+            .collect()
+    }
+
+    /// Returns the canonical bytes of `self`, which are its little-endian bits *with* trailing zeros (to byte-alignment).
+    fn encode_bytes_le(&self) -> Vec<u8> {
+        bytes_from_bits_le(&self.encode_bits_le())
+    }
+
+    /// Returns `Self` from the given canonical bytes, or `None` if they are not a valid encoding.
+    fn decode_bytes_le(bytes_le: &[u8]) -> Option<Self> {
+        Self::decode_bits_le(&bits_from_bytes_le(bytes_le).collect::<Vec<_>>())
+    }
+}
+
+/// Returns `N` components from the given little-endian bits, which hold their encodings back to back
+/// in `size_in_bits` bits each. Missing bits are treated as zero, and any excess bits must be zero.
+pub(crate) fn decode_components<T: Encoding, const N: usize>(bits_le: &[bool], size_in_bits: usize) -> Option<[T; N]> {
+    // Ensure all excess bits are zero.
+    if bits_le.iter().skip(N * size_in_bits).any(|bit| *bit) {
+        return None;
+    }
+
+    let mut chunks = bits_le.chunks(size_in_bits);
+    let components = (0..N).map(|_| T::decode_bits_le(chunks.next().unwrap_or(&[]))).collect::<Option<Vec<_>>>()?;
+    components.try_into().ok()
+}
+
+impl Encoding for bool {
+    /// Returns `self` as a single bit.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        vec![*self]
+    }
+
+    /// Returns a boolean from exactly one bit.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        match bits_le {
+            [bit] => Some(*bit),
+            _ => None,
+        }
+    }
+
+    /// Returns `self` as a single byte.
+    fn encode_bytes_le(&self) -> Vec<u8> {
+        vec![*self as u8]
+    }
+
+    /// Returns a boolean from a single byte, which must be `0` or `1`.
+    fn decode_bytes_le(bytes_le: &[u8]) -> Option<Self> {
+        match bytes_le {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_integer_encoding {
+    ($($integer:ty),*) => {
+        $(
+            impl Encoding for $integer {
+                /// Returns the little-endian bits of `self` *with* trailing zeros.
+                fn encode_bits_le(&self) -> Vec<bool> {
+                    bits_from_bytes_le(&self.to_le_bytes()).collect()
+                }
+
+                /// Returns an integer from little-endian bits *with* trailing zeros.
+                /// Missing bits are treated as zero, and any excess bits must be zero.
+                fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+                    let size_in_bits = <$integer>::BITS as usize;
+                    // Ensure all excess bits are zero.
+                    if bits_le.iter().skip(size_in_bits).any(|bit| *bit) {
+                        return None;
+                    }
+
+                    let mut bytes = [0u8; core::mem::size_of::<$integer>()];
+                    for (i, bit) in bits_le.iter().take(size_in_bits).enumerate() {
+                        bytes[i / 8] |= (*bit as u8) << (i % 8);
+                    }
+                    Some(<$integer>::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_encoding!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl Encoding for String {
+    /// Returns the little-endian bits of the UTF-8 bytes of `self`.
+    fn encode_bits_le(&self) -> Vec<bool> {
+        bits_from_bytes_le(self.as_bytes()).collect()
+    }
+
+    /// Returns a string from the little-endian bits of its UTF-8 bytes, which must be byte-aligned.
+    fn decode_bits_le(bits_le: &[bool]) -> Option<Self> {
+        match bits_le.len() % 8 == 0 {
+            true => String::from_utf8(bytes_from_bits_le(bits_le)).ok(),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Field;
+use snarkvm_utilities::vec::Vec;
+
+/// A field, viewed as an extension of its base prime field, whose elements have coordinates
+/// over the base prime field. A prime field is an extension of degree one of itself.
+pub trait ExtensionField: Field {
+    /// Returns the extension degree of this field with respect to its base prime field.
+    fn extension_degree() -> u64;
+
+    /// Constructs an element of `Self` from its coefficients over the base prime field,
+    /// in the order returned by `to_base_prime_field_elements`.
+    /// Returns `None` if the number of elements does not match the extension degree.
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self>;
+
+    /// Returns the coefficients of `self` over the base prime field.
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField>;
+}
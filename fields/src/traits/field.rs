@@ -32,7 +32,7 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
-use std::{
+use core::{
     fmt::{Debug, Display},
     hash::Hash,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -129,6 +129,14 @@ pub trait Field:
     /// Sets `self` to `self`'s inverse if it exists. Otherwise it is a no-op.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
 
+    /// Sets each element of `v` to its inverse, leaving zero elements unchanged.
+    ///
+    /// This uses Montgomery's trick to compute a single inversion per chunk of `v`,
+    /// and processes the chunks in parallel if the `parallel` feature is enabled.
+    fn batch_inverse_in_place(v: &mut [Self]) {
+        crate::batch_inversion(v);
+    }
+
     /// Exponentiates this element by a power of the base prime modulus via
     /// the Frobenius automorphism.
     fn frobenius_map(&mut self, power: usize);
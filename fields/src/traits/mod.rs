@@ -14,6 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod constant_time;
+pub use constant_time::*;
+
+mod cyclotomic_field;
+pub use cyclotomic_field::*;
+
+mod encoding;
+pub use encoding::*;
+
+mod extension_field;
+pub use extension_field::*;
+
 mod fft_field;
 pub use fft_field::*;
 
@@ -35,6 +47,9 @@ pub use poseidon_grain_lfsr::*;
 mod poseidon_default;
 pub use poseidon_default::*;
 
+mod poseidon_generator;
+pub use poseidon_generator::*;
+
 mod prime_field;
 pub use prime_field::*;
 
@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{PoseidonGrainLFSR, PrimeField};
+use crate::{sample_cauchy_matrix, FieldError, PoseidonGrainLFSR, PrimeField};
+use snarkvm_utilities::{format, string::ToString, vec::Vec};
 
 /// Parameters and RNG used
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -32,6 +33,58 @@ pub struct PoseidonParameters<F: PrimeField, const RATE: usize, const CAPACITY:
     pub mds: Vec<Vec<F>>,
 }
 
+impl<F: PrimeField, const RATE: usize> PoseidonParameters<F, RATE, 1> {
+    /// Returns the parameters of the given entry, with the round constants and MDS matrix
+    /// sampled from the Poseidon Grain LFSR.
+    pub fn from_entry(entry: &PoseidonDefaultParametersEntry) -> Result<Self, FieldError> {
+        if entry.rate != RATE {
+            return Err(FieldError::Message(format!(
+                "Expected a Poseidon entry of rate {}, found {}",
+                RATE, entry.rate
+            )));
+        }
+
+        let mut lfsr = PoseidonGrainLFSR::new(
+            false,
+            F::size_in_bits() as u64,
+            (RATE + 1) as u64,
+            entry.full_rounds as u64,
+            entry.partial_rounds as u64,
+        );
+
+        let mut ark = Vec::<Vec<F>>::with_capacity(entry.full_rounds + entry.partial_rounds);
+        for _ in 0..(entry.full_rounds + entry.partial_rounds) {
+            ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1));
+        }
+
+        for _ in 0..entry.skip_matrices {
+            let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1));
+        }
+
+        // A qualifying matrix must satisfy the following requirements
+        // - there is no duplication among the elements in x or y
+        // - there is no i and j such that x[i] + y[j] = p
+        // - the resultant MDS passes all the three tests
+        let mds = sample_cauchy_matrix(&mut lfsr, RATE + 1)
+            .ok_or_else(|| FieldError::Message("The Poseidon entry skips an invalid number of matrices".to_string()))?;
+
+        Ok(Self {
+            full_rounds: entry.full_rounds,
+            partial_rounds: entry.partial_rounds,
+            alpha: entry.alpha as u64,
+            ark,
+            mds,
+        })
+    }
+
+    /// Returns the parameters of a Poseidon instance with the S-box `x^alpha`, which provides `security_level` bits of security.
+    /// See [`PoseidonDefaultParametersEntry::generate`].
+    #[cfg(feature = "std")]
+    pub fn generate(alpha: u64, security_level: u32) -> Result<Self, FieldError> {
+        Self::from_entry(&PoseidonDefaultParametersEntry::generate::<F>(RATE, alpha, security_level)?)
+    }
+}
+
 /// A field with Poseidon parameters associated
 pub trait PoseidonDefaultField {
     /// Obtain the default Poseidon parameters for this rate and for this prime field,
@@ -42,61 +95,15 @@ pub trait PoseidonDefaultField {
     where
         Self: PrimeField,
     {
-        /// Internal function that computes the ark and mds from the Poseidon Grain LFSR.
-        fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
-            full_rounds: u64,
-            partial_rounds: u64,
-            skip_matrices: u64,
-        ) -> (Vec<Vec<F>>, Vec<Vec<F>>) {
-            let mut lfsr =
-                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
-
-            let mut ark = Vec::<Vec<F>>::new();
-            for _ in 0..(full_rounds + partial_rounds) {
-                ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1));
-            }
-
-            let mut mds = vec![vec![F::zero(); RATE + 1]; RATE + 1];
-            for _ in 0..skip_matrices {
-                let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1));
-            }
-
-            // a qualifying matrix must satisfy the following requirements
-            // - there is no duplication among the elements in x or y
-            // - there is no i and j such that x[i] + y[j] = p
-            // - the resultant MDS passes all the three tests
-
-            let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1);
-            let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1);
-
-            for (i, x) in xs.iter().enumerate().take(RATE + 1) {
-                for (j, y) in ys.iter().enumerate().take(RATE + 1) {
-                    mds[i][j] = (*x + y).inverse().unwrap();
-                }
-            }
-
-            (ark, mds)
-        }
-
         let default_entries = match optimized_for_weights {
             true => Self::Parameters::PARAMS_OPT_FOR_WEIGHTS,
             false => Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS,
         };
 
-        default_entries.iter().find(|entry| entry.rate == RATE).map(|entry| {
-            let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
-                entry.full_rounds as u64,
-                entry.partial_rounds as u64,
-                entry.skip_matrices as u64,
-            );
-            PoseidonParameters {
-                full_rounds: entry.full_rounds,
-                partial_rounds: entry.partial_rounds,
-                alpha: entry.alpha as u64,
-                ark,
-                mds,
-            }
-        })
+        default_entries
+            .iter()
+            .find(|entry| entry.rate == RATE)
+            .and_then(|entry| PoseidonParameters::from_entry(entry).ok())
     }
 }
 
@@ -117,6 +124,7 @@ pub trait PoseidonDefaultParameters {
 }
 
 /// An entry in the default Poseidon parameters
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct PoseidonDefaultParametersEntry {
     /// The rate (in terms of number of field elements).
     pub rate: usize,
@@ -0,0 +1,346 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(feature = "std")]
+use crate::{FieldError, PoseidonDefaultParametersEntry};
+use crate::{PoseidonGrainLFSR, PrimeField};
+use snarkvm_utilities::{vec, vec::Vec};
+
+/// The maximum number of MDS matrices to sample from the Grain LFSR before giving up.
+pub const MAX_SKIP_MATRICES: usize = 1000;
+
+#[cfg(feature = "std")]
+impl PoseidonDefaultParametersEntry {
+    /// Returns the parameters of a Poseidon instance over `F` with the given rate and S-box exponent,
+    /// which provides `security_level` bits of security.
+    ///
+    /// The round numbers are the cheapest ones that satisfy the security analysis,
+    /// and `skip_matrices` is the number of MDS matrices from the Grain LFSR that fail [`is_secure_mds`].
+    /// See the [reference implementation](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_params_poseidon.sage).
+    pub fn generate<F: PrimeField>(rate: usize, alpha: u64, security_level: u32) -> Result<Self, FieldError> {
+        if rate == 0 {
+            return Err(FieldError::Message("The Poseidon rate must be positive".to_string()));
+        }
+        if !is_valid_sbox_exponent::<F>(alpha) {
+            return Err(FieldError::Message(format!("x^{} is not a permutation of the field", alpha)));
+        }
+
+        let (full_rounds, partial_rounds) =
+            find_poseidon_round_numbers(F::size_in_bits() as u32, rate + 1, alpha, security_level)
+                .ok_or_else(|| FieldError::Message(format!("No Poseidon round numbers for {} bits", security_level)))?;
+
+        // Skip the round constants, and find the first secure MDS matrix.
+        let mut lfsr = PoseidonGrainLFSR::new(
+            false,
+            F::size_in_bits() as u64,
+            (rate + 1) as u64,
+            full_rounds as u64,
+            partial_rounds as u64,
+        );
+        for _ in 0..(full_rounds + partial_rounds) {
+            let _ = lfsr.get_field_elements_rejection_sampling::<F>(rate + 1);
+        }
+        for skip_matrices in 0..MAX_SKIP_MATRICES {
+            if let Some(mds) = sample_cauchy_matrix::<F>(&mut lfsr, rate + 1) {
+                if is_secure_mds(&mds) {
+                    return Ok(Self::new(rate, alpha as usize, full_rounds, partial_rounds, skip_matrices));
+                }
+            }
+        }
+        Err(FieldError::Message(format!("No secure MDS matrix among the first {} candidates", MAX_SKIP_MATRICES)))
+    }
+}
+
+/// Returns `true` if `x -> x^alpha` is a permutation of `F`, i.e. if `alpha > 1` and `gcd(alpha, p - 1) = 1`.
+pub fn is_valid_sbox_exponent<F: PrimeField>(alpha: u64) -> bool {
+    if alpha < 2 {
+        return false;
+    }
+    // Compute (p - 1) mod alpha.
+    let modulus_mod_alpha =
+        F::modulus().as_ref().iter().rev().fold(0u128, |acc, limb| ((acc << 64) + *limb as u128) % alpha as u128)
+            as u64;
+    let (mut a, mut b) = (alpha, (modulus_mod_alpha + alpha - 1) % alpha);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a == 1
+}
+
+/// Returns the smallest `alpha` such that `x -> x^alpha` is a permutation of `F`, which is the smallest prime not dividing `p - 1`.
+pub fn smallest_sbox_exponent<F: PrimeField>() -> u64 {
+    (3..).find(|alpha| is_valid_sbox_exponent::<F>(*alpha)).unwrap()
+}
+
+///
+/// Returns the numbers of full and partial rounds `(R_F, R_P)` of a Poseidon instance with `state_len` elements
+/// over a field of `field_size_in_bits` bits, which provide `security_level` bits of security.
+///
+/// The round numbers satisfy the bounds against statistical, interpolation and Gröbner basis attacks
+/// of [GKRRS19](https://eprint.iacr.org/2019/458) and [BBLP22](https://eprint.iacr.org/2023/537),
+/// and include the recommended security margin of 2 full rounds and 7.5% partial rounds.
+/// Among these, it returns the ones that minimize the number of S-boxes.
+/// (Parameters optimized for weights differ only in their larger `alpha`, which allows fewer partial rounds.)
+///
+/// The security analysis uses floating-point arithmetic, so this requires the `std` feature.
+///
+#[cfg(feature = "std")]
+pub fn find_poseidon_round_numbers(
+    field_size_in_bits: u32,
+    state_len: usize,
+    alpha: u64,
+    security_level: u32,
+) -> Option<(usize, usize)> {
+    let cost = |full_rounds: usize, partial_rounds: usize| state_len * full_rounds + partial_rounds;
+
+    let mut best: Option<(usize, usize)> = None;
+    for partial_rounds in 1..500 {
+        for full_rounds in (4..100).step_by(2) {
+            if is_secure_round_numbers(
+                field_size_in_bits,
+                state_len,
+                full_rounds,
+                partial_rounds,
+                alpha,
+                security_level,
+            ) {
+                // Add the security margin.
+                let candidate = (full_rounds + 2, (partial_rounds as f64 * 1.075).ceil() as usize);
+                best = match best {
+                    Some(best) if (cost(best.0, best.1), best.0) <= (cost(candidate.0, candidate.1), candidate.0) => {
+                        Some(best)
+                    }
+                    _ => Some(candidate),
+                };
+            }
+        }
+    }
+    best
+}
+
+/// Returns `true` if `full_rounds` and `partial_rounds` satisfy the bounds of the security analysis.
+#[cfg(feature = "std")]
+fn is_secure_round_numbers(
+    field_size_in_bits: u32,
+    state_len: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+    security_level: u32,
+) -> bool {
+    let (n, t, r_p, m) = (field_size_in_bits as f64, state_len as f64, partial_rounds as f64, security_level as f64);
+    let log_alpha = (alpha as f64).log2();
+
+    // The statistical attacks.
+    let r_f_1 = match m <= (n - (alpha as f64 - 1.0) / 2.0).floor() * (t + 1.0) {
+        true => 6.0,
+        false => 10.0,
+    };
+    // The interpolation attack.
+    let r_f_2 = 1.0 + (m.min(n) / log_alpha).ceil() + (t.log2() / log_alpha).ceil() - r_p;
+    // The Gröbner basis attacks.
+    let r_f_3 = m.min(n) / log_alpha - r_p;
+    let r_f_4 = t - 1.0 + (m / (t + 1.0)).min(n / 2.0) / log_alpha - r_p;
+    let r_f_5 = (t - 2.0 + m / (2.0 * log_alpha) - r_p) / (t - 1.0);
+    let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5].iter().map(|r| r.ceil()).fold(f64::MIN, f64::max);
+
+    // The Gröbner basis attack of BBLP22, with a conservative linear algebra exponent of 2.
+    let r_temp = state_len / 3;
+    let over =
+        (full_rounds - 1) * state_len + 2 * partial_rounds + r_temp + r_temp * (full_rounds / 2) + alpha as usize;
+    let under = r_temp * (full_rounds / 2) + partial_rounds + alpha as usize;
+    let log_binomial = (1..=under).map(|i| ((over - under + i) as f64 / i as f64).log2()).sum::<f64>();
+
+    full_rounds as f64 >= r_f_max && (2.0 * log_binomial).ceil() >= m
+}
+
+///
+/// Returns `true` if the MDS matrix admits no infinitely long subspace trails in the partial rounds of Poseidon,
+/// following [GRS20](https://eprint.iacr.org/2020/500).
+///
+/// This holds if the minimal polynomials of `M, M^2, ..., M^(2t)` are irreducible and of maximal degree `t`.
+/// Otherwise, it checks directly that `M` has no proper invariant subspace in which the S-box of the partial rounds
+/// is inactive, and no proper invariant subspace which contains the output of an active S-box.
+///
+pub fn is_secure_mds<F: PrimeField>(mds: &[Vec<F>]) -> bool {
+    let t = mds.len();
+    let mut power = mds.to_vec();
+    for _ in 0..(2 * t) {
+        // An irreducible characteristic polynomial is the minimal polynomial.
+        if !is_irreducible(&characteristic_polynomial(&power)) {
+            // e_0^T M^j and M^j e_0 for j < t must span the whole space.
+            let mut rows = vec![unit_vector::<F>(t)];
+            let mut columns = vec![unit_vector::<F>(t)];
+            for j in 1..t {
+                rows.push((0..t).map(|k| (0..t).map(|l| rows[j - 1][l] * mds[l][k]).sum()).collect());
+                columns
+                    .push(mds.iter().map(|row| row.iter().zip(&columns[j - 1]).map(|(a, b)| *a * b).sum()).collect());
+            }
+            return rank(rows) == t && rank(columns) == t;
+        }
+        power = matrix_multiply(mds, &power);
+    }
+    true
+}
+
+/// Samples a Cauchy matrix `1 / (x_i + y_j)` from the Grain LFSR, or returns `None` if the samples do not satisfy
+/// the requirements, i.e. if there is a duplicate among the `x_i` or the `y_j`, or an `x_i + y_j = 0`.
+pub fn sample_cauchy_matrix<F: PrimeField>(lfsr: &mut PoseidonGrainLFSR, size: usize) -> Option<Vec<Vec<F>>> {
+    let xs = lfsr.get_field_elements_mod_p::<F>(size);
+    let ys = lfsr.get_field_elements_mod_p::<F>(size);
+
+    let has_duplicates = |elements: &[F]| (1..size).any(|i| elements[..i].contains(&elements[i]));
+    if has_duplicates(&xs) || has_duplicates(&ys) {
+        return None;
+    }
+    xs.iter().map(|x| ys.iter().map(|y| (*x + y).inverse()).collect()).collect()
+}
+
+fn unit_vector<F: PrimeField>(size: usize) -> Vec<F> {
+    let mut vector = vec![F::zero(); size];
+    vector[0] = F::one();
+    vector
+}
+
+fn matrix_multiply<F: PrimeField>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    a.iter()
+        .map(|row| (0..b[0].len()).map(|j| row.iter().zip(b).map(|(a, b_row)| *a * b_row[j]).sum()).collect())
+        .collect()
+}
+
+/// Returns the rank of the matrix, by Gaussian elimination.
+fn rank<F: PrimeField>(mut matrix: Vec<Vec<F>>) -> usize {
+    let num_columns = matrix.first().map_or(0, |row| row.len());
+    let mut rank = 0;
+    for column in 0..num_columns {
+        let pivot = match (rank..matrix.len()).find(|&i| !matrix[i][column].is_zero()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        matrix.swap(rank, pivot);
+        let inverse = matrix[rank][column].inverse().unwrap();
+        let (pivot_rows, remaining_rows) = matrix.split_at_mut(rank + 1);
+        let pivot_row = &pivot_rows[rank];
+        for row in remaining_rows {
+            let factor = row[column] * inverse;
+            for (value, pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= *pivot_value * factor;
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+/// Returns the characteristic polynomial of the square matrix, lowest degree first, by the Faddeev-LeVerrier algorithm.
+fn characteristic_polynomial<F: PrimeField>(matrix: &[Vec<F>]) -> Vec<F> {
+    let n = matrix.len();
+    let mut coefficients = vec![F::zero(); n + 1];
+    coefficients[n] = F::one();
+
+    let mut m = vec![vec![F::zero(); n]; n];
+    for k in 1..=n {
+        // M_k := A M_{k-1} + c_{n-k+1} I, and c_{n-k} := -tr(A M_k) / k.
+        m = matrix_multiply(matrix, &m);
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] += coefficients[n - k + 1];
+        }
+        let trace = (0..n).map(|i| (0..n).map(|j| matrix[i][j] * m[j][i]).sum::<F>()).sum::<F>();
+        coefficients[n - k] = -trace * F::from(k as u128).inverse().unwrap();
+    }
+    coefficients
+}
+
+/// Returns `true` if the monic polynomial is irreducible, by Rabin's test.
+fn is_irreducible<F: PrimeField>(polynomial: &[F]) -> bool {
+    let n = polynomial.len() - 1;
+    let x = vec![F::zero(), F::one()];
+
+    // Compute x^(p^k) mod f for k = 1, ..., n.
+    let modulus = F::modulus();
+    let mut frobenius_powers = vec![reduce(x.clone(), polynomial)];
+    for _ in 0..n {
+        let next = polynomial_pow_mod(frobenius_powers.last().unwrap(), modulus.as_ref(), polynomial);
+        frobenius_powers.push(next);
+    }
+
+    // f is irreducible iff x^(p^n) = x mod f, and gcd(x^(p^(n/q)) - x, f) = 1 for every prime q dividing n.
+    if frobenius_powers[n] != frobenius_powers[0] {
+        return false;
+    }
+    (2..=n).filter(|q| n % q == 0 && (2..*q).all(|d| q % d != 0)).all(|q| {
+        let mut difference = frobenius_powers[n / q].clone();
+        difference.resize(difference.len().max(2), F::zero());
+        difference[1] -= F::one();
+        polynomial_gcd(polynomial.to_vec(), trim(difference)).len() == 1
+    })
+}
+
+fn trim<F: PrimeField>(mut polynomial: Vec<F>) -> Vec<F> {
+    while polynomial.last().map_or(false, |c| c.is_zero()) {
+        polynomial.pop();
+    }
+    polynomial
+}
+
+/// Returns the remainder of `polynomial` by `divisor`.
+fn reduce<F: PrimeField>(polynomial: Vec<F>, divisor: &[F]) -> Vec<F> {
+    let mut remainder = trim(polynomial);
+    let degree = divisor.len() - 1;
+    let leading_inverse = divisor[degree].inverse().unwrap();
+    while remainder.len() > degree {
+        let factor = *remainder.last().unwrap() * leading_inverse;
+        let shift = remainder.len() - 1 - degree;
+        for (i, coefficient) in divisor.iter().enumerate() {
+            remainder[shift + i] -= factor * coefficient;
+        }
+        remainder = trim(remainder);
+    }
+    remainder
+}
+
+fn polynomial_mul_mod<F: PrimeField>(a: &[F], b: &[F], modulus: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut product = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            product[i + j] += *a * b;
+        }
+    }
+    reduce(product, modulus)
+}
+
+fn polynomial_pow_mod<F: PrimeField>(base: &[F], exponent: &[u64], modulus: &[F]) -> Vec<F> {
+    let mut result = vec![F::one()];
+    for bit in exponent.iter().rev().flat_map(|limb| (0..64).rev().map(move |i| (limb >> i) & 1 == 1)) {
+        result = polynomial_mul_mod(&result, &result, modulus);
+        if bit {
+            result = polynomial_mul_mod(&result, base, modulus);
+        }
+    }
+    result
+}
+
+fn polynomial_gcd<F: PrimeField>(mut a: Vec<F>, mut b: Vec<F>) -> Vec<F> {
+    while !b.is_empty() {
+        let remainder = reduce(a, &b);
+        a = b;
+        b = remainder;
+    }
+    a
+}
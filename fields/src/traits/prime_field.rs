@@ -14,12 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{FftField, FieldError, FieldParameters, PoseidonDefaultField};
+use crate::{ConstantTime, FftField, FieldError, FieldParameters, PoseidonDefaultField};
 use snarkvm_utilities::{biginteger::BigInteger, cmp::min, str::FromStr};
 
 /// The interface for a prime field.
 pub trait PrimeField:
-    FftField<FftParameters = <Self as PrimeField>::Parameters> + PoseidonDefaultField + FromStr<Err = FieldError>
+    FftField<FftParameters = <Self as PrimeField>::Parameters>
+    + ConstantTime
+    + PoseidonDefaultField
+    + FromStr<Err = FieldError>
 {
     type Parameters: FieldParameters<BigInteger = Self::BigInteger>;
     type BigInteger: BigInteger;
@@ -15,6 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{Field, LegendreSymbol};
+use snarkvm_utilities::{cfg_iter, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 /// The interface for a field that supports an efficient square-root operation.
 pub trait SquareRootField: Field {
@@ -27,4 +31,9 @@ pub trait SquareRootField: Field {
 
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self>;
+
+    /// Returns the square roots of the given elements, computed in parallel if the `parallel` feature is enabled.
+    fn batch_sqrt(elements: &[Self]) -> Vec<Option<Self>> {
+        cfg_iter!(elements).map(|element| element.sqrt()).collect()
+    }
 }
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{ConstraintFieldError, Field};
+use snarkvm_utilities::vec::Vec;
 
 use core::fmt::Debug;
 
@@ -30,11 +30,14 @@ pub enum ParameterError {
     #[error("Remote fetch is disabled, enable compiler flag for feature")]
     RemoteFetchDisabled,
 
+    #[error("The universal SRS of degree {} is not available from {}", _0, _1)]
+    SRSDegreeUnavailable(usize, String),
+
     #[error("Expected size of {}, found size of {}", _0, _1)]
     SizeMismatch(usize, usize),
 }
 
-#[cfg(not(feature = "wasm"))]
+#[cfg(all(feature = "remote", not(feature = "wasm")))]
 impl From<curl::Error> for ParameterError {
     fn from(error: curl::Error) -> Self {
         ParameterError::Crate("curl::error", format!("{:?}", error))
@@ -29,6 +29,45 @@ macro_rules! checksum_error {
     };
 }
 
+/// Returns the expected checksum and size of a parameter file, which its `metadata` records under `checksum_key` and `size_key`.
+#[doc(hidden)]
+pub fn expected_checksum_and_size(metadata: &str, checksum_key: &str, size_key: &str) -> (String, usize) {
+    let metadata: serde_json::Value = serde_json::from_str(metadata).expect("Metadata was not well-formatted");
+    let expected_checksum = metadata[checksum_key].as_str().expect("Failed to parse checksum").to_string();
+    let expected_size = metadata[size_key].to_string().parse().expect("Failed to retrieve the file size");
+    (expected_checksum, expected_size)
+}
+
+/// Ensures the given `buffer` matches the `expected_checksum` and `expected_size`.
+#[doc(hidden)]
+pub fn verify_bytes(
+    buffer: &[u8],
+    expected_checksum: &str,
+    expected_size: usize,
+) -> Result<(), crate::errors::ParameterError> {
+    // Ensure the size matches.
+    if expected_size != buffer.len() {
+        return Err(crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
+    }
+
+    // Ensure the checksum matches.
+    let candidate_checksum = checksum!(buffer);
+    if expected_checksum != candidate_checksum {
+        return checksum_error!(expected_checksum.to_string(), candidate_checksum);
+    }
+
+    Ok(())
+}
+
+/// Returns the given `filename`, versioned by the prefix of the `checksum` of its contents.
+#[doc(hidden)]
+pub fn versioned_filename(filename: &str, checksum: &str) -> String {
+    match checksum.get(0..7) {
+        Some(sum) => format!("{}.{}", filename, sum),
+        _ => filename.to_string(),
+    }
+}
+
 #[macro_export]
 macro_rules! impl_local {
     ($name: ident, $local_dir: expr, $fname: tt, $ftype: tt) => {
@@ -77,30 +116,19 @@ macro_rules! impl_local {
 
         impl $name {
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
-                const METADATA: &'static str =
-                    include_str!(concat!($local_dir, $fname, ".", $ftype, ".", $fdegree, ".metadata"));
-
-                let metadata: serde_json::Value =
-                    serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
-                let expected_checksum: String =
-                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
-                let expected_size: usize =
-                    metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
-
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", $ftype, ".", $fdegree));
+                Self::verify_bytes(buffer)?;
+                return Ok(buffer.to_vec());
+            }
 
-                // Ensure the size matches.
-                if expected_size != buffer.len() {
-                    return Err($crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
-                }
-
-                // Ensure the checksum matches.
-                let candidate_checksum = checksum!(buffer);
-                if expected_checksum != candidate_checksum {
-                    return checksum_error!(expected_checksum, candidate_checksum);
-                }
+            /// Ensures the given `buffer` matches the size and checksum in the metadata.
+            pub fn verify_bytes(buffer: &[u8]) -> Result<(), $crate::errors::ParameterError> {
+                const METADATA: &'static str =
+                    include_str!(concat!($local_dir, $fname, ".", $ftype, ".", $fdegree, ".metadata"));
 
-                return Ok(buffer.to_vec());
+                let (expected_checksum, expected_size) =
+                    $crate::macros::expected_checksum_and_size(METADATA, "checksum", "size");
+                $crate::macros::verify_bytes(buffer, &expected_checksum, expected_size)
             }
         }
 
@@ -150,7 +178,7 @@ macro_rules! impl_remote {
 
                     // Load remote file
                     cfg_if::cfg_if! {
-                        if #[cfg(not(feature = "wasm"))] {
+                        if #[cfg(all(feature = "remote", not(feature = "wasm")))] {
                             #[cfg(not(feature = "no_std_out"))]
                             println!("{} - Downloading parameters...", module_path!());
 
@@ -201,6 +229,7 @@ macro_rules! impl_remote {
 
                             buffer
                         } else {
+                            let _ = ($remote_url, expected_checksum);
                             return Err($crate::errors::ParameterError::RemoteFetchDisabled);
                         }
                     }
@@ -220,7 +249,7 @@ macro_rules! impl_remote {
                 return Ok(buffer)
             }
 
-            #[cfg(not(feature = "wasm"))]
+            #[cfg(all(feature = "remote", not(feature = "wasm")))]
             fn store_bytes(
                 buffer: &[u8],
                 file_path: &std::path::Path,
@@ -243,7 +272,7 @@ macro_rules! impl_remote {
                 Ok(())
             }
 
-            #[cfg(not(feature = "wasm"))]
+            #[cfg(all(feature = "remote", not(feature = "wasm")))]
             fn remote_fetch(buffer: &mut Vec<u8>, url: &str) -> Result<(), $crate::errors::ParameterError> {
                 let mut easy = curl::easy::Easy::new();
                 easy.url(url)?;
@@ -308,15 +337,10 @@ macro_rules! impl_remote {
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".", $ftype, ".", $fdegree, ".metadata"));
 
-                let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
-                let expected_checksum: String = metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
-                let expected_size: usize = metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+                let (expected_checksum, _) = $crate::macros::expected_checksum_and_size(METADATA, "checksum", "size");
 
                 // Construct the versioned filename.
-                let filename = match expected_checksum.get(0..7) {
-                    Some(sum) => format!("{}.{}.{}.{}", $fname, $ftype, $fdegree, sum),
-                    _ => format!("{}.{}.{}", $fname, $ftype, $fdegree),
-                };
+                let filename = Self::versioned_filename();
 
                 // Compose the correct file path for the parameter file.
                 let mut file_path = aleo_std::aleo_dir();
@@ -335,7 +359,7 @@ macro_rules! impl_remote {
 
                     // Load remote file
                     cfg_if::cfg_if! {
-                        if #[cfg(not(feature = "wasm"))] {
+                        if #[cfg(all(feature = "remote", not(feature = "wasm")))] {
                             #[cfg(not(feature = "no_std_out"))]
                             println!("{} - Downloading parameters...", module_path!());
 
@@ -386,26 +410,48 @@ macro_rules! impl_remote {
 
                             buffer
                         } else {
+                            let _ = ($remote_url, expected_checksum);
                             return Err($crate::errors::ParameterError::RemoteFetchDisabled);
                         }
                     }
                 };
 
-                 // Ensure the size matches.
-                if expected_size != buffer.len() {
-                    return Err($crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
-                }
+                // Ensure the size and checksum match.
+                Self::verify_bytes(&buffer)?;
 
-                // Ensure the checksum matches.
-                let candidate_checksum = checksum!(buffer.as_slice());
-                if expected_checksum != candidate_checksum {
-                    return checksum_error!(expected_checksum, candidate_checksum)
+                return Ok(buffer)
+            }
+
+            /// Returns the filename of the parameters, versioned by the prefix of their checksum.
+            /// This is the filename under which the parameters are stored locally.
+            pub fn versioned_filename() -> String {
+                const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".", $ftype, ".", $fdegree, ".metadata"));
+
+                let (expected_checksum, _) = $crate::macros::expected_checksum_and_size(METADATA, "checksum", "size");
+                $crate::macros::versioned_filename(concat!($fname, ".", $ftype, ".", $fdegree), &expected_checksum)
+            }
+
+            /// Loads the parameters from the given local directory, without attempting a remote fetch.
+            pub fn load_bytes_from_dir(directory: &std::path::Path) -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let file_path = directory.join(Self::versioned_filename());
+                if !file_path.exists() {
+                    return Err($crate::errors::ParameterError::Message(format!("{:?} does not exist", file_path)));
                 }
 
-                return Ok(buffer)
+                let buffer = std::fs::read(file_path)?;
+                Self::verify_bytes(&buffer)?;
+                Ok(buffer)
+            }
+
+            /// Ensures the given `buffer` matches the size and checksum in the metadata.
+            pub fn verify_bytes(buffer: &[u8]) -> Result<(), $crate::errors::ParameterError> {
+                const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".", $ftype, ".", $fdegree, ".metadata"));
+
+                let (expected_checksum, expected_size) = $crate::macros::expected_checksum_and_size(METADATA, "checksum", "size");
+                $crate::macros::verify_bytes(buffer, &expected_checksum, expected_size)
             }
 
-            #[cfg(not(feature = "wasm"))]
+            #[cfg(all(feature = "remote", not(feature = "wasm")))]
             fn store_bytes(
                 buffer: &[u8],
                 file_path: &std::path::Path,
@@ -428,7 +474,7 @@ macro_rules! impl_remote {
                 Ok(())
             }
 
-            #[cfg(not(feature = "wasm"))]
+            #[cfg(all(feature = "remote", not(feature = "wasm")))]
             fn remote_fetch(buffer: &mut Vec<u8>, url: &str) -> Result<(), $crate::errors::ParameterError> {
                 let mut easy = curl::easy::Easy::new();
                 easy.url(url)?;
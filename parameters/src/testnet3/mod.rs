@@ -17,6 +17,9 @@
 pub mod powers;
 pub use powers::*;
 
+pub mod source;
+pub use source::*;
+
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/aleo.parameters";
 
 // Degree 15
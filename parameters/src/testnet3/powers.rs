@@ -15,11 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::errors::ParameterError;
 use snarkvm_curves::traits::PairingEngine;
 use snarkvm_utilities::{
     CanonicalDeserialize,
     CanonicalSerialize,
     FromBytes,
+    MappedFile,
     Read,
     SerializationError,
     ToBytes,
@@ -46,6 +48,7 @@ const NUM_UNIVERSAL_SRS_GAMMA: usize = 84;
 // Size of a serialized power of G.
 const POWER_OF_G_SERIALIZED_SIZE: usize = 97;
 
+const DEGREE_15: usize = 1 << 15;
 const DEGREE_16: usize = 1 << 16;
 const DEGREE_17: usize = 1 << 17;
 const DEGREE_18: usize = 1 << 18;
@@ -60,17 +63,78 @@ const DEGREE_26: usize = 1 << 26;
 const DEGREE_27: usize = 1 << 27;
 const DEGREE_28: usize = 1 << 28;
 
+/// Dispatches `$method` to the universal SRS parameters of the given `$degree`.
+macro_rules! with_degree {
+    ($degree: expr, $method: ident($($arg: expr),*)) => {
+        match $degree {
+            DEGREE_16 => Degree16::$method($($arg),*),
+            DEGREE_17 => Degree17::$method($($arg),*),
+            DEGREE_18 => Degree18::$method($($arg),*),
+            DEGREE_19 => Degree19::$method($($arg),*),
+            DEGREE_20 => Degree20::$method($($arg),*),
+            DEGREE_21 => Degree21::$method($($arg),*),
+            DEGREE_22 => Degree22::$method($($arg),*),
+            DEGREE_23 => Degree23::$method($($arg),*),
+            DEGREE_24 => Degree24::$method($($arg),*),
+            DEGREE_25 => Degree25::$method($($arg),*),
+            DEGREE_26 => Degree26::$method($($arg),*),
+            DEGREE_27 => Degree27::$method($($arg),*),
+            DEGREE_28 => Degree28::$method($($arg),*),
+            degree => bail!("Invalid degree '{degree}' selected"),
+        }
+    };
+}
+
+/// The storage backing the powers of G.
+#[derive(Debug)]
+enum PowersStorage {
+    /// A file, to which missing powers are appended as they are loaded.
+    File(File),
+    /// A read-only, memory-mapped file, which holds all of the available powers.
+    ReadOnly(MappedFile),
+}
+
+impl PowersStorage {
+    /// Returns the size of the storage in bytes.
+    fn len(&self) -> Result<usize> {
+        match self {
+            Self::File(file) => Ok(file.metadata()?.len() as usize),
+            Self::ReadOnly(mapped_file) => Ok(mapped_file.len()),
+        }
+    }
+
+    /// Reads `num_powers` powers of G, starting at the byte `index_start`.
+    fn read_powers<E: PairingEngine>(&self, index_start: usize, num_powers: usize) -> Result<Vec<E::G1Affine>> {
+        let mut powers = Vec::with_capacity(num_powers);
+        match self {
+            Self::File(file) => {
+                // Move our offset to the start of the desired element.
+                let mut reader = BufReader::new(file);
+                reader.seek(SeekFrom::Start(index_start as u64))?;
+                for _ in 0..num_powers {
+                    powers.push(E::G1Affine::read_le(&mut reader)?);
+                }
+            }
+            Self::ReadOnly(mapped_file) => {
+                let mut reader = mapped_file.as_ref().get(index_start..).unwrap_or_default();
+                for _ in 0..num_powers {
+                    powers.push(E::G1Affine::read_le(&mut reader)?);
+                }
+            }
+        }
+        Ok(powers)
+    }
+}
+
 /// An abstraction over a vector of powers of G, meant to reduce
 /// memory burden when handling universal setup parameters.
 #[derive(Debug)]
 pub struct PowersOfG<E: PairingEngine> {
     /// Filepath of the powers we're using.
     file_path: String,
-    /// A handle to the file on disk containing the powers of G.
-    /// The handle is guarded to avoid read/write conflicts with potential
-    /// clones.
+    /// The storage on disk containing the powers of G.
     /// Contains group elements of the form `[G, \beta * G, \beta^2 * G, ..., \beta^{d} G]`.
-    file: File,
+    storage: PowersStorage,
     /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `degree`.
     /// These are used for hiding.
     powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
@@ -78,6 +142,8 @@ pub struct PowersOfG<E: PairingEngine> {
     /// Mostly just used for ensuring we download in the proper order,
     /// length checks are actually done by checking file metadata.
     degree: usize,
+    /// The source from which missing powers are loaded.
+    source: SRSSource,
 }
 
 // NOTE: this drops the powers into a tmp file.
@@ -98,7 +164,7 @@ impl<E: PairingEngine> From<(Vec<E::G1Affine>, BTreeMap<usize, E::G1Affine>)> fo
         }
 
         drop(file);
-        let mut powers = Self::new(dir).unwrap();
+        let mut powers = Self::open(dir, SRSSource::get()).unwrap();
         powers.powers_of_beta_times_gamma_g = value.1;
         powers
     }
@@ -106,8 +172,27 @@ impl<E: PairingEngine> From<(Vec<E::G1Affine>, BTreeMap<usize, E::G1Affine>)> fo
 
 impl<E: PairingEngine> PowersOfG<E> {
     /// Returns a new instance of `PowersOfG`, which will store its
-    /// powers in a file at `file_path`.
+    /// powers in a file at `file_path`, and load missing powers from
+    /// the universal SRS source of this process.
     pub fn new(file_path: PathBuf) -> Result<Self> {
+        Self::with_source(file_path, SRSSource::get())
+    }
+
+    /// Returns a new instance of `PowersOfG`, which will store its
+    /// powers in a file at `file_path`, and load missing powers from `source`.
+    ///
+    /// If `source` is a read-only file, the powers are instead read from that file,
+    /// and `file_path` is ignored.
+    pub fn with_source(file_path: PathBuf, source: SRSSource) -> Result<Self> {
+        match source {
+            SRSSource::File(read_only_file_path) => Self::open_read_only(read_only_file_path),
+            source => Self::open(file_path, source),
+        }
+    }
+
+    /// Returns a new instance of `PowersOfG`, which will store its
+    /// powers in a file at `file_path`.
+    fn open(file_path: PathBuf, source: SRSSource) -> Result<Self> {
         // Open the given file, creating it if it doesn't yet exist.
         let mut file = OpenOptions::new().read(true).write(true).create(true).open(file_path.clone())?;
 
@@ -120,15 +205,60 @@ impl<E: PairingEngine> PowersOfG<E> {
 
         let mut powers = Self {
             file_path: String::from(file_path.to_str().expect("could not get filepath for powers of g")),
-            file,
+            storage: PowersStorage::File(file),
             powers_of_beta_times_gamma_g: BTreeMap::new(),
             degree,
+            source,
         };
 
         powers.regenerate_powers_of_beta_times_gamma_g()?;
         Ok(powers)
     }
 
+    /// Returns a new instance of `PowersOfG`, which memory-maps the powers from the read-only file at `file_path`.
+    /// The file must hold the embedded powers, followed by the powers of each successive degree,
+    /// all of which are checked against their expected checksums.
+    fn open_read_only(file_path: PathBuf) -> Result<Self> {
+        let mapped_file = MappedFile::open(&file_path)?;
+        Self::verify_powers(mapped_file.as_ref())?;
+
+        let degree = mapped_file.len() / POWER_OF_G_SERIALIZED_SIZE;
+        let mut powers = Self {
+            file_path: String::from(file_path.to_str().expect("could not get filepath for powers of g")),
+            storage: PowersStorage::ReadOnly(mapped_file),
+            powers_of_beta_times_gamma_g: BTreeMap::new(),
+            degree,
+            source: SRSSource::File(file_path),
+        };
+
+        powers.regenerate_powers_of_beta_times_gamma_g()?;
+        Ok(powers)
+    }
+
+    /// Ensures the given `bytes` hold the embedded powers, followed by the powers of each
+    /// successive degree, and that every segment matches its expected size and checksum.
+    fn verify_powers(bytes: &[u8]) -> Result<()> {
+        let size = bytes.len();
+        if size < UNIVERSAL_SRS_15.len() {
+            bail!(ParameterError::SizeMismatch(UNIVERSAL_SRS_15.len(), size));
+        }
+        Degree15::verify_bytes(&bytes[..UNIVERSAL_SRS_15.len()])?;
+
+        // Each subsequent segment doubles the number of powers.
+        let mut start = UNIVERSAL_SRS_15.len();
+        let mut degree = DEGREE_15;
+        while start < size {
+            let end = start + degree * POWER_OF_G_SERIALIZED_SIZE;
+            if end > size {
+                bail!(ParameterError::SizeMismatch(end - start, size - start));
+            }
+            degree *= 2;
+            with_degree!(degree, verify_bytes(&bytes[start..end]))?;
+            start = end;
+        }
+        Ok(())
+    }
+
     /// Return the number of current powers of G.
     pub fn degree(&self) -> usize {
         self.degree
@@ -141,12 +271,8 @@ impl<E: PairingEngine> PowersOfG<E> {
     pub fn power_of_beta_g(&mut self, target_power: usize) -> E::G1Affine {
         let index_start = self.get_starting_byte_index(target_power).expect("Failed to load starting byte index");
 
-        // Move our offset to the start of the desired element.
-        let mut reader = BufReader::new(&self.file);
-        reader.seek(SeekFrom::Start(index_start as u64)).expect("could not seek to element starting index");
-
         // Now read it out, deserialize it, and return it.
-        FromBytes::read_le(&mut reader).expect("powers of g corrupted")
+        self.storage.read_powers::<E>(index_start, 1).expect("powers of g corrupted")[0]
     }
 
     /// Slices the underlying file to return a vector of affine elements
@@ -156,16 +282,8 @@ impl<E: PairingEngine> PowersOfG<E> {
         let _ = self.get_starting_byte_index(upper).expect("Failed to load upper power index");
         let index_start = self.get_starting_byte_index(lower).expect("Failed to load lower power index");
 
-        // Move our offset to the start of the desired element.
-        let mut reader = BufReader::new(&self.file);
-        reader.seek(SeekFrom::Start(index_start as u64)).expect("could not seek to element starting index");
-
         // Now iterate until we fill a vector with all desired elements.
-        let mut powers = Vec::with_capacity((upper - lower) as usize);
-        for _ in lower..upper {
-            powers.push(E::G1Affine::read_le(&mut reader).expect("powers of g corrupted"));
-        }
-        powers
+        self.storage.read_powers::<E>(index_start, upper - lower).expect("powers of g corrupted")
     }
 
     /// Returns the index in the `file` for the starting byte of the `target_power` being requested.
@@ -176,15 +294,15 @@ impl<E: PairingEngine> PowersOfG<E> {
         };
 
         // Ensure the powers exist, and download the missing powers if necessary.
-        if starting_byte_index > self.file.metadata()?.len() as usize {
+        if starting_byte_index > self.storage.len()? {
             self.download_up_to(target_power.next_power_of_two())?;
         }
 
         Ok(starting_byte_index)
     }
 
-    /// This method downloads the universal SRS powers up to the `next_power_of_two(target_degree)`,
-    /// and updates `Self` in place with the new powers.
+    /// This method loads the universal SRS powers up to the `next_power_of_two(target_degree)`
+    /// from the universal SRS source of `self`, and updates `Self` in place with the new powers.
     pub fn download_up_to(&mut self, target_degree: usize) -> Result<()> {
         // Determine the degrees to download.
         let mut degrees_to_download = vec![];
@@ -196,28 +314,25 @@ impl<E: PairingEngine> PowersOfG<E> {
 
         // If the `target_degree` exceeds the current `degree`, proceed to download the new powers.
         if !degrees_to_download.is_empty() {
+            let file = match &mut self.storage {
+                PowersStorage::File(file) => file,
+                // A read-only file already holds all of the powers it can provide.
+                PowersStorage::ReadOnly(_) => {
+                    bail!(ParameterError::SRSDegreeUnavailable(target_degree, self.source.to_string()))
+                }
+            };
+
             for degree in &degrees_to_download {
-                // Download the universal SRS powers.
-                let bytes = match *degree {
-                    DEGREE_16 => Degree16::load_bytes()?,
-                    DEGREE_17 => Degree17::load_bytes()?,
-                    DEGREE_18 => Degree18::load_bytes()?,
-                    DEGREE_19 => Degree19::load_bytes()?,
-                    DEGREE_20 => Degree20::load_bytes()?,
-                    DEGREE_21 => Degree21::load_bytes()?,
-                    DEGREE_22 => Degree22::load_bytes()?,
-                    DEGREE_23 => Degree23::load_bytes()?,
-                    DEGREE_24 => Degree24::load_bytes()?,
-                    DEGREE_25 => Degree25::load_bytes()?,
-                    DEGREE_26 => Degree26::load_bytes()?,
-                    DEGREE_27 => Degree27::load_bytes()?,
-                    DEGREE_28 => Degree28::load_bytes()?,
-                    _ => bail!("Invalid degree '{degree}' selected"),
+                // Load the universal SRS powers.
+                let bytes = match &self.source {
+                    SRSSource::Remote => with_degree!(*degree, load_bytes())?,
+                    SRSSource::Directory(directory) => with_degree!(*degree, load_bytes_from_dir(directory))?,
+                    source => bail!(ParameterError::SRSDegreeUnavailable(target_degree, source.to_string())),
                 };
 
                 // Write the powers to the file.
-                self.file.seek(SeekFrom::End(0))?;
-                self.file.write_all(&bytes)?;
+                file.seek(SeekFrom::End(0))?;
+                file.write_all(&bytes)?;
 
                 // Update the `degree`.
                 self.degree = *degree;
@@ -311,3 +426,61 @@ impl<E: PairingEngine> CanonicalDeserialize for PowersOfG<E> {
         Ok(FromBytes::read_le(&mut *reader)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    #[test]
+    fn test_srs_sources() {
+        // Keep every file in a temporary directory, which is removed at the end of the test.
+        let directory = tempfile::tempdir().unwrap();
+        let temp_file_path = |name: &str| directory.path().join(name);
+
+        // Write the embedded powers to a read-only file.
+        let read_only_file_path = temp_file_path("read_only_powers_of_g");
+        std::fs::write(&read_only_file_path, UNIVERSAL_SRS_15.as_slice()).unwrap();
+
+        // The embedded source should only provide the embedded powers.
+        let mut powers =
+            PowersOfG::<Bls12_377>::with_source(temp_file_path("powers_of_g"), SRSSource::Embedded).unwrap();
+        assert_eq!(powers.degree(), DEGREE_15);
+        let expected = powers.powers_of_beta_g(0, 16);
+        let error = powers.download_up_to(DEGREE_16).unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(ParameterError::SRSDegreeUnavailable(DEGREE_16, _))));
+        assert_eq!(powers.degree(), DEGREE_15);
+
+        // The directory source should fail on a directory without the powers.
+        let source = SRSSource::Directory(temp_file_path("empty_directory"));
+        let mut directory_powers = PowersOfG::<Bls12_377>::with_source(temp_file_path("powers_of_g"), source).unwrap();
+        assert!(directory_powers.download_up_to(DEGREE_16).is_err());
+        assert_eq!(directory_powers.degree(), DEGREE_15);
+
+        // The read-only file source should provide the same powers, but no more.
+        let source = SRSSource::File(read_only_file_path);
+        let mut read_only_powers = PowersOfG::<Bls12_377>::with_source(temp_file_path("ignored"), source).unwrap();
+        assert_eq!(read_only_powers.degree(), DEGREE_15);
+        assert_eq!(read_only_powers.powers_of_beta_g(0, 16), expected);
+        assert_eq!(read_only_powers.power_of_beta_g(7), expected[7]);
+        assert_eq!(read_only_powers.get_powers_times_gamma_g(), powers.get_powers_times_gamma_g());
+        let error = read_only_powers.download_up_to(DEGREE_16).unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(ParameterError::SRSDegreeUnavailable(DEGREE_16, _))));
+
+        // The read-only file source should reject corrupted and truncated files.
+        // As the original file is still mapped, these are written to new files.
+        let mut corrupted = UNIVERSAL_SRS_15.clone();
+        corrupted[POWER_OF_G_SERIALIZED_SIZE] ^= 1;
+        let corrupted_file_path = temp_file_path("corrupted_powers_of_g");
+        std::fs::write(&corrupted_file_path, &corrupted).unwrap();
+        let source = SRSSource::File(corrupted_file_path);
+        assert!(PowersOfG::<Bls12_377>::with_source(temp_file_path("ignored"), source).is_err());
+
+        let mut truncated = UNIVERSAL_SRS_15.clone();
+        truncated.extend_from_slice(&UNIVERSAL_SRS_15[..POWER_OF_G_SERIALIZED_SIZE]);
+        let truncated_file_path = temp_file_path("truncated_powers_of_g");
+        std::fs::write(&truncated_file_path, &truncated).unwrap();
+        let source = SRSSource::File(truncated_file_path);
+        assert!(PowersOfG::<Bls12_377>::with_source(temp_file_path("ignored"), source).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{fmt, path::PathBuf, sync::RwLock};
+
+lazy_static::lazy_static! {
+    static ref SRS_SOURCE: RwLock<SRSSource> = RwLock::new(SRSSource::default());
+}
+
+/// The source from which the universal SRS powers beyond the embedded degree are loaded.
+///
+/// The source is configured once per process with [`SRSSource::set`], or for a single instance with
+/// [`PowersOfG::with_source`](super::PowersOfG::with_source), and is consulted by
+/// [`PowersOfG`](super::PowersOfG) whenever it needs more powers than it currently holds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SRSSource {
+    /// Only the powers embedded in the binary are available.
+    Embedded,
+    /// The powers are loaded from a local directory, which holds one file per degree,
+    /// named as in the remote storage (e.g. `universal.srs.16.963d9a6`).
+    Directory(PathBuf),
+    /// The powers are memory-mapped from a single read-only file, which holds all of the powers of G
+    /// (starting with the embedded powers), in the same layout as the `PowersOfG` file.
+    File(PathBuf),
+    /// The powers are loaded from the local Aleo directory, and downloaded if they are missing.
+    Remote,
+}
+
+impl Default for SRSSource {
+    fn default() -> Self {
+        Self::Remote
+    }
+}
+
+impl SRSSource {
+    /// Returns the universal SRS source of this process.
+    pub fn get() -> Self {
+        SRS_SOURCE.read().expect("The universal SRS source lock is poisoned").clone()
+    }
+
+    /// Sets the universal SRS source of this process.
+    /// Parameters that were already loaded are not affected.
+    pub fn set(source: SRSSource) {
+        *SRS_SOURCE.write().expect("The universal SRS source lock is poisoned") = source;
+    }
+}
+
+impl fmt::Display for SRSSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Embedded => write!(f, "the embedded powers"),
+            Self::Directory(directory) => write!(f, "the directory {:?}", directory),
+            Self::File(file_path) => write!(f, "the read-only file {:?}", file_path),
+            Self::Remote => write!(f, "the remote parameter storage"),
+        }
+    }
+}
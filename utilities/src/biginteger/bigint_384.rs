@@ -21,15 +21,16 @@ use crate::{
     FromBytes,
     ToBits,
     ToBytes,
+    Vec,
 };
 
 use crate::biginteger::BigInteger;
+use core::fmt::{Debug, Display};
 use num_bigint::BigUint;
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::fmt::{Debug, Display};
 
 #[derive(Copy, Clone, PartialEq, Eq, Default, Hash)]
 pub struct BigInteger384(pub [u64; 6]);
@@ -116,7 +117,7 @@ impl BigInteger for BigInteger384 {
         while n >= 64 {
             let mut t = 0;
             for i in &mut self.0 {
-                std::mem::swap(&mut t, i);
+                core::mem::swap(&mut t, i);
             }
             n -= 64;
         }
@@ -151,7 +152,7 @@ impl BigInteger for BigInteger384 {
         while n >= 64 {
             let mut t = 0;
             for i in self.0.iter_mut().rev() {
-                std::mem::swap(&mut t, i);
+                core::mem::swap(&mut t, i);
             }
             n -= 64;
         }
@@ -280,7 +281,7 @@ impl FromBytes for BigInteger384 {
     }
 }
 impl Debug for BigInteger384 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for i in self.0.iter().rev() {
             write!(f, "{:016X}", *i)?;
         }
@@ -288,27 +289,27 @@ impl Debug for BigInteger384 {
     }
 }
 impl Display for BigInteger384 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.to_biguint())
     }
 }
 impl Ord for BigInteger384 {
     #[inline]
     #[allow(clippy::comparison_chain)]
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         for (a, b) in self.0.iter().rev().zip(other.0.iter().rev()) {
             if a < b {
-                return std::cmp::Ordering::Less;
+                return core::cmp::Ordering::Less;
             } else if a > b {
-                return std::cmp::Ordering::Greater;
+                return core::cmp::Ordering::Greater;
             }
         }
-        std::cmp::Ordering::Equal
+        core::cmp::Ordering::Equal
     }
 }
 impl PartialOrd for BigInteger384 {
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::biginteger::{BigInteger256, BigInteger384, BigInteger768};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+macro_rules! impl_biginteger_constant_time {
+    ($biginteger: ident) => {
+        impl ConstantTimeEq for $biginteger {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.0[..].ct_eq(&other.0[..])
+            }
+        }
+
+        impl ConditionallySelectable for $biginteger {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                let mut output = *a;
+                for (output, b) in output.0.iter_mut().zip(&b.0) {
+                    output.conditional_assign(b, choice);
+                }
+                output
+            }
+        }
+    };
+}
+
+impl_biginteger_constant_time!(BigInteger256);
+impl_biginteger_constant_time!(BigInteger384);
+impl_biginteger_constant_time!(BigInteger768);
@@ -14,10 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{rand::UniformRand, FromBits, FromBytes, ToBits, ToBytes};
+use crate::{rand::UniformRand, FromBits, FromBytes, ToBits, ToBytes, Vec};
 
+use core::fmt::{Debug, Display};
 use num_bigint::BigUint;
-use std::fmt::{Debug, Display};
 
 mod bigint_256;
 pub use bigint_256::*;
@@ -25,6 +25,11 @@ pub use bigint_256::*;
 mod bigint_384;
 pub use bigint_384::*;
 
+mod bigint_768;
+pub use bigint_768::*;
+
+mod constant_time;
+
 #[cfg(test)]
 mod tests;
 
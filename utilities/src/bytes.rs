@@ -19,6 +19,7 @@ use crate::{
     fmt,
     io::{Read, Result as IoResult, Write},
     marker::PhantomData,
+    string::{String, ToString},
     Vec,
 };
 use serde::{
@@ -60,7 +61,7 @@ pub trait ToBytes {
     where
         Self: Sized,
     {
-        Ok(to_bytes_le![self]?)
+        to_bytes_le![self].map_err(|error| anyhow::anyhow!(error))
     }
 }
 
@@ -75,11 +76,11 @@ pub trait FromBytes {
     where
         Self: Sized,
     {
-        Ok(Self::read_le(bytes)?)
+        Self::read_le(bytes).map_err(|error| anyhow::anyhow!(error))
     }
 }
 
-pub struct ToBytesSerializer<T: ToBytes>(String, Option<usize>, PhantomData<T>);
+pub struct ToBytesSerializer<T: ToBytes>(PhantomData<T>);
 
 impl<T: ToBytes> ToBytesSerializer<T> {
     ///
@@ -103,7 +104,7 @@ impl<T: ToBytes> ToBytesSerializer<T> {
     }
 }
 
-pub struct FromBytesDeserializer<T: FromBytes>(String, Option<usize>, PhantomData<T>);
+pub struct FromBytesDeserializer<T: FromBytes>(PhantomData<T>);
 
 impl<'de, T: FromBytes> FromBytesDeserializer<T> {
     ///
@@ -160,11 +161,11 @@ impl<'de, T: FromBytes> FromBytesDeserializer<T> {
     }
 }
 
-pub struct FromBytesVisitor<'a>(&'a mut Vec<u8>, String, Option<usize>);
+pub struct FromBytesVisitor<'a>(&'a mut Vec<u8>, String);
 
 impl<'a> FromBytesVisitor<'a> {
     pub fn new(buffer: &'a mut Vec<u8>, name: &str) -> Self {
-        Self(buffer, name.to_string(), None)
+        Self(buffer, name.to_string())
     }
 }
 
@@ -172,7 +173,7 @@ impl<'a, 'de> Visitor<'de> for FromBytesVisitor<'a> {
     type Value = ();
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str(&format!("a valid {} ", self.1))
+        formatter.write_str(&crate::format!("a valid {} ", self.1))
     }
 
     fn visit_borrowed_bytes<E: serde::de::Error>(self, bytes: &'de [u8]) -> Result<Self::Value, E> {
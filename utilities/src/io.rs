@@ -16,11 +16,17 @@
 
 //! no-std io replacement
 use crate::Vec;
-use core::{cmp, mem};
+use core::{cmp, fmt, mem};
 
 #[derive(Debug)]
 pub struct Error;
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "I/O error")
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
 
 pub trait Read {
@@ -15,14 +15,24 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), allow(hidden_glob_reexports))]
 
 #[cfg(all(test, not(feature = "std")))]
 #[macro_use]
 extern crate std;
 
 #[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+#[allow(ambiguous_glob_reexports)]
 pub use alloc::*;
 
+// `alloc` and `core` both define these modules, where the `alloc` ones re-export the `core` ones.
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+pub use alloc::{borrow, fmt, slice, str, sync};
+
 #[cfg(not(feature = "std"))]
 pub use core::*;
 
@@ -42,8 +52,23 @@ pub use alloc::{boxed::Box, vec::Vec};
 #[doc(hidden)]
 pub use std::{boxed::Box, vec::Vec};
 
-#[macro_use]
-extern crate thiserror;
+/// Without `std` there is no output stream, so `println!` only type-checks its arguments.
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! println {
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+/// Without `std` there is no error stream, so `eprintln!` only type-checks its arguments.
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! eprintln {
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
 
 pub mod biginteger;
 pub use biginteger::*;
@@ -62,9 +87,16 @@ pub use bytes::*;
 pub mod error;
 pub use error::*;
 
+#[cfg(feature = "std")]
 pub mod iterator;
+#[cfg(feature = "std")]
 pub use iterator::*;
 
+#[cfg(feature = "std")]
+pub mod mapped_file;
+#[cfg(feature = "std")]
+pub use mapped_file::*;
+
 #[macro_use]
 pub mod parallel;
 pub use parallel::*;
@@ -79,7 +111,7 @@ pub use serialize::*;
 pub mod io;
 
 #[cfg(not(feature = "std"))]
-pub fn error(_msg: &'static str) -> io::Error {
+pub fn error<S: Into<string::String>>(_msg: S) -> io::Error {
     io::Error
 }
 
@@ -88,11 +120,13 @@ pub fn error<S: Into<String>>(msg: S) -> io::Error {
     io::Error::new(io::ErrorKind::Other, msg.into())
 }
 
+#[cfg(feature = "std")]
 use std::sync::atomic::{AtomicBool, AtomicU64};
 // A flag used for performance purposes in the process of loading SNARK parameters; it allows the
 // PairingEngine::GXAffine values contained in them to be verified using the computationally-heavy
 // AffineCurve::is_in_correct_subgroup_assuming_on_curve method in parallel after the deserialization
 // is complete; the other instances of PairingEngine::GXAffine are verified during deserialization.
+#[cfg(feature = "std")]
 thread_local!(pub static PROCESSING_SNARK_PARAMS: AtomicBool = AtomicBool::new(false));
 
 // A value used in tandem with the optimization strategy enabled by PROCESSING_SNARK_PARAMS; its
@@ -100,4 +134,5 @@ thread_local!(pub static PROCESSING_SNARK_PARAMS: AtomicBool = AtomicBool::new(f
 // using the AffineCurve::is_in_correct_subgroup_assuming_on_curve method during deserialization
 // were indeed accounted for afterwards; this also future-proofs the codebase against possible
 // changes to the affected objects, i.e. marlin::snark::Parameters and all of its members.
+#[cfg(feature = "std")]
 thread_local!(pub static SNARK_PARAMS_AFFINE_COUNT: AtomicU64 = AtomicU64::new(0));
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{fmt, fs::File, io, path::Path};
+
+/// A read-only, memory-mapped file, used to load large parameters and keys on demand.
+///
+/// On targets without memory mapping, such as WebAssembly, the file is read into memory instead.
+pub struct MappedFile {
+    #[cfg(not(target_family = "wasm"))]
+    bytes: memmap2::Mmap,
+    #[cfg(target_family = "wasm")]
+    bytes: Vec<u8>,
+}
+
+impl MappedFile {
+    /// Memory-maps the file at the given path.
+    /// The file must not be modified or truncated while it is mapped.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only and is never exposed mutably. Its contents are only
+        // undefined if the file is modified or truncated while mapped, which the caller rules out.
+        #[allow(unsafe_code)]
+        let bytes = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(Self { bytes })
+    }
+
+    /// Reads the file at the given path into memory.
+    #[cfg(target_family = "wasm")]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        io::Read::read_to_end(&mut File::open(path)?, &mut bytes)?;
+        Ok(Self { bytes })
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for MappedFile {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Debug for MappedFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MappedFile").field("len", &self.len()).finish()
+    }
+}
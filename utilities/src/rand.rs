@@ -20,6 +20,7 @@ use rand::{
     Rng,
     SeedableRng,
 };
+#[cfg(feature = "std")]
 use rand_xorshift::XorShiftRng;
 
 pub trait UniformRand: Sized {
@@ -37,6 +38,7 @@ where
 }
 
 /// A fast Rng which should be used only in tests or benchmarks, but not for any real world purposes.
+#[cfg(feature = "std")]
 pub fn test_rng() -> XorShiftRng {
     // Obtain the initial seed using entropy provided by the OS.
     let seed = StdRng::from_entropy().gen();
@@ -46,6 +48,7 @@ pub fn test_rng() -> XorShiftRng {
 }
 
 /// An Rng which can be used in tests or benchmarks requiring a CryptoRng.
+#[cfg(feature = "std")]
 pub fn test_crypto_rng() -> StdRng {
     StdRng::from_entropy()
 }
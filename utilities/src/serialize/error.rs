@@ -14,28 +14,63 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-#[derive(Error, Debug)]
+use core::fmt;
+
+#[derive(Debug)]
 pub enum SerializationError {
     /// During serialization with bincode, we encountered a serialization issue
-    #[error(transparent)]
-    BincodeError(#[from] bincode::Error),
+    #[cfg(feature = "std")]
+    BincodeError(bincode::Error),
     /// During serialization, the data was invalid.
-    #[error("the input buffer contained invalid data")]
     InvalidData,
     /// During serialization, we countered an I/O error.
-    #[error("IoError: {0}")]
-    IoError(#[from] crate::io::Error),
+    IoError(crate::io::Error),
     /// During serialization, we didn't have enough space to write extra info.
-    #[error("the last byte does not have enough space to encode the extra info bits")]
     NotEnoughSpace,
     /// During serialization, non-empty flags were given where none were
     /// expected.
-    #[error("the call expects empty flags")]
     UnexpectedFlags,
 }
 
+impl fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Self::BincodeError(error) => fmt::Display::fmt(error, f),
+            Self::InvalidData => write!(f, "the input buffer contained invalid data"),
+            Self::IoError(error) => write!(f, "IoError: {}", error),
+            Self::NotEnoughSpace => write!(f, "the last byte does not have enough space to encode the extra info bits"),
+            Self::UnexpectedFlags => write!(f, "the call expects empty flags"),
+        }
+    }
+}
+
+impl crate::error::Error for SerializationError {
+    fn source(&self) -> Option<&(dyn crate::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            Self::BincodeError(error) => error.source(),
+            Self::IoError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<bincode::Error> for SerializationError {
+    fn from(error: bincode::Error) -> Self {
+        Self::BincodeError(error)
+    }
+}
+
+impl From<crate::io::Error> for SerializationError {
+    fn from(error: crate::io::Error) -> Self {
+        Self::IoError(error)
+    }
+}
+
 impl From<SerializationError> for crate::io::Error {
     fn from(error: SerializationError) -> Self {
-        crate::io::Error::new(crate::io::ErrorKind::Other, format!("{}", error))
+        crate::error(crate::format!("{}", error))
     }
 }
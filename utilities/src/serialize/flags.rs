@@ -52,6 +52,9 @@ pub enum SWFlags {
 }
 
 impl SWFlags {
+    /// The number of bits required for these flags.
+    pub const BIT_SIZE: usize = 2;
+
     #[inline]
     pub fn infinity() -> Self {
         SWFlags::Infinity
@@ -118,7 +121,7 @@ impl Flags for SWFlags {
     /// Number of bits required for these flags.
     #[inline]
     fn num_bits() -> usize {
-        2
+        Self::BIT_SIZE
     }
 }
 
@@ -131,6 +134,9 @@ pub enum EdwardsFlags {
 }
 
 impl EdwardsFlags {
+    /// The number of bits required for these flags.
+    pub const BIT_SIZE: usize = 1;
+
     #[inline]
     pub fn from_y_sign(is_positive: bool) -> Self {
         if is_positive { EdwardsFlags::PositiveY } else { EdwardsFlags::NegativeY }
@@ -180,6 +186,6 @@ impl Flags for EdwardsFlags {
     /// Number of bits required for these flags.
     #[inline]
     fn num_bits() -> usize {
-        1
+        Self::BIT_SIZE
     }
 }
@@ -26,7 +26,14 @@ pub use crate::{
 };
 use crate::{serialize::traits::*, SerializationError};
 
-use std::{borrow::Cow, collections::BTreeMap, mem, rc::Rc, sync::Arc};
+use crate::{
+    borrow::{Cow, ToOwned},
+    collections::BTreeMap,
+    rc::Rc,
+    string::String,
+    sync::Arc,
+};
+use core::mem;
 
 impl CanonicalSerialize for bool {
     #[inline]
@@ -50,7 +57,9 @@ impl CanonicalDeserialize for bool {
 impl CanonicalSerialize for String {
     #[inline]
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
-        Ok(bincode::serialize_into(writer, self)?)
+        // Matches the encoding of `bincode`: the length as a `u64`, followed by the bytes.
+        (self.len() as u64).serialize(writer)?;
+        Ok(writer.write_all(self.as_bytes())?)
     }
 
     #[inline]
@@ -62,7 +71,10 @@ impl CanonicalSerialize for String {
 impl CanonicalDeserialize for String {
     #[inline]
     fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
-        Ok(bincode::deserialize_from(reader)?)
+        let len = u64::deserialize(reader)?;
+        let mut bytes = crate::vec![0u8; len as usize];
+        reader.read_exact(&mut bytes)?;
+        String::from_utf8(bytes).map_err(|_| SerializationError::InvalidData)
     }
 }
 
@@ -81,7 +93,7 @@ macro_rules! impl_canonical_serialization_uint {
         }
 
         impl ConstantSerializedSize for $type {
-            const SERIALIZED_SIZE: usize = std::mem::size_of::<$type>();
+            const SERIALIZED_SIZE: usize = mem::size_of::<$type>();
             const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
         }
 
@@ -148,7 +160,7 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
 }
 
 // No-op
-impl<T> CanonicalSerialize for std::marker::PhantomData<T> {
+impl<T> CanonicalSerialize for core::marker::PhantomData<T> {
     #[inline]
     fn serialize<W: Write>(&self, _writer: &mut W) -> Result<(), SerializationError> {
         Ok(())
@@ -165,15 +177,15 @@ impl<T> CanonicalSerialize for std::marker::PhantomData<T> {
     }
 }
 
-impl<T> CanonicalDeserialize for std::marker::PhantomData<T> {
+impl<T> CanonicalDeserialize for core::marker::PhantomData<T> {
     #[inline]
     fn deserialize<R: Read>(_reader: &mut R) -> Result<Self, SerializationError> {
-        Ok(std::marker::PhantomData)
+        Ok(core::marker::PhantomData)
     }
 
     #[inline]
     fn deserialize_uncompressed<R: Read>(_reader: &mut R) -> Result<Self, SerializationError> {
-        Ok(std::marker::PhantomData)
+        Ok(core::marker::PhantomData)
     }
 }
 
@@ -509,6 +521,17 @@ mod test {
     #[test]
     fn test_string() {
         test_serialize("asdf".to_owned());
+        test_serialize(String::new());
+
+        // The encoding is the one of `bincode`.
+        let data = "asdf".to_owned();
+        let mut serialized = vec![];
+        data.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized, bincode::serialize(&data).unwrap());
+
+        // Invalid UTF-8 is rejected.
+        let invalid = [2u8, 0, 0, 0, 0, 0, 0, 0, 0xc3, 0x28];
+        assert!(String::deserialize(&mut &invalid[..]).is_err());
     }
 
     #[test]
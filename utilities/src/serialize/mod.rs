@@ -47,9 +47,31 @@ pub const fn number_of_bits_to_number_of_bytes(num_bits: usize) -> usize {
     (num_bits + 7) / 8
 }
 
+/// Return the number of bytes required to represent a value of `num_bytes` bytes, of which the top
+/// `num_spare_bits` bits are unused, together with `num_flag_bits` bits of flags.
+///
+/// The flags are packed into the spare bits when they fit, and into one additional byte otherwise.
+#[inline]
+pub const fn number_of_bytes_with_flags(num_bytes: usize, num_spare_bits: usize, num_flag_bits: usize) -> usize {
+    if num_flag_bits <= num_spare_bits {
+        num_bytes
+    } else {
+        num_bytes + 1
+    }
+}
+
 #[test]
 fn test_number_of_bits_and_bytes() {
     assert_eq!((64, 8), number_of_bits_and_bytes(64));
     assert_eq!((256, 32), number_of_bits_and_bytes(251));
     assert_eq!((1000, 125), number_of_bits_and_bytes(999));
 }
+
+#[test]
+fn test_number_of_bytes_with_flags() {
+    assert_eq!(32, number_of_bytes_with_flags(32, 3, 2));
+    assert_eq!(32, number_of_bytes_with_flags(32, 2, 2));
+    assert_eq!(33, number_of_bytes_with_flags(32, 1, 2));
+    assert_eq!(33, number_of_bytes_with_flags(32, 0, 1));
+    assert_eq!(32, number_of_bytes_with_flags(32, 0, 0));
+}
@@ -15,16 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::SerializationError;
-pub use crate::{
-    io::{
-        Read,
-        Write,
-        {self},
-    },
-    FromBytes,
-    ToBytes,
-    Vec,
-};
+pub use crate::io::{Read, Write};
 
 /// Serialization flags.
 pub trait Flags: Default + Clone + Copy + Sized {
@@ -94,6 +85,8 @@ pub trait CanonicalSerializeWithFlags: CanonicalSerialize {
 pub trait ConstantSerializedSize: CanonicalSerialize {
     const SERIALIZED_SIZE: usize;
     const UNCOMPRESSED_SIZE: usize;
+    /// The number of unused high bits in the serialization of `Self`, which are available for flags.
+    const SPARE_BITS: usize = 0;
 }
 
 /// Deserializer in little endian format.